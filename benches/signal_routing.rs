@@ -0,0 +1,44 @@
+use std::hint::black_box;
+
+use actix::prelude::*;
+use criterion::{criterion_group, criterion_main, Criterion};
+use gio::prelude::*;
+
+struct SinkActor;
+
+impl actix::Actor for SinkActor {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<woab::Signal> for SinkActor {
+    type Result = woab::SignalResult;
+
+    fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+        black_box(msg.raw_params());
+        Ok(None)
+    }
+}
+
+fn signal_creation(c: &mut Criterion) {
+    let name = glib::Quark::from_str("signal");
+    let parameters = [
+        gio::SimpleAction::new("action", None).to_value(),
+        1i32.to_value(),
+        2.0f64.to_value(),
+    ];
+    c.bench_function("signal creation", |b| {
+        b.iter(|| woab::Signal::new(name, black_box(&parameters[..]), ()))
+    });
+}
+
+fn routed_emission(c: &mut Criterion) {
+    woab::run_actix_inside_gtk_event_loop();
+    let action = gio::SimpleAction::new("action", None);
+    let sink = woab::block_on(async { SinkActor.start() });
+    woab::route_action(&action, sink).unwrap();
+    c.bench_function("routed action activation", |b| b.iter(|| action.activate(None)));
+    woab::close_actix_runtime().unwrap().unwrap();
+}
+
+criterion_group!(benches, signal_creation, routed_emission);
+criterion_main!(benches);
@@ -0,0 +1,385 @@
+//! Generate [WoAB](https://docs.rs/woab) bindings from GTK UI XML files in a build script.
+//!
+//! For applications that treat the UI XML as the source of truth, this generates the Rust code
+//! that would otherwise be written by hand next to it:
+//!
+//! * A [`woab::Factories`](https://docs.rs/woab/latest/woab/derive.Factories.html) struct named
+//!   `Factories`, with a field for each factory and a `load` method that reads the XML embedded in
+//!   the `UI_XML` constant.
+//! * For each factory, a
+//!   [`woab::WidgetsFromBuilder`](https://docs.rs/woab/latest/woab/derive.WidgetsFromBuilder.html)
+//!   struct (e.g. `WinAppWidgets` for the `win_app` factory) with a field for each object with an
+//!   ID that belongs to the factory.
+//! * For each factory, an enum of the signal handlers declared under it (e.g. `WinAppSignal`),
+//!   with `name`/`from_name` methods for converting to and from the handler names. Matching on the
+//!   enum lets the compiler check that all the signals are handled.
+//!
+//! In `build.rs`:
+//!
+//! ```no_run
+//! woab_build::UiBindings::new("assets/app.ui")
+//!     .generate_to_out_dir("app_ui.rs")
+//!     .unwrap();
+//! ```
+//!
+//! And in the crate:
+//!
+//! ```ignore
+//! #[allow(dead_code)]
+//! mod app_ui {
+//!     include!(concat!(env!("OUT_DIR"), "/app_ui.rs"));
+//! }
+//!
+//! impl actix::Handler<woab::Signal> for WindowActor {
+//!     type Result = woab::SignalResult;
+//!
+//!     fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+//!         let Some(signal) = app_ui::WinAppSignal::from_name(msg.name()) else {
+//!             return msg.cant_handle();
+//!         };
+//!         Ok(match signal {
+//!             app_ui::WinAppSignal::ClickButton => {
+//!                 // ...
+//!                 None
+//!             }
+//!         })
+//!     }
+//! }
+//! ```
+//!
+//! The generated code refers to the `woab`, `gtk4` and `glib` crates (and to `adw`, if the XML
+//! uses Libadwaita classes), so the crate must depend on them.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+#[doc(hidden)] // for internal use by woab-macros
+pub mod ui_xml;
+
+use ui_xml::ParsedUi;
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("Cannot read or write {path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Cannot parse {path}: {source}")]
+    Xml {
+        path: PathBuf,
+        #[source]
+        source: quick_xml::Error,
+    },
+
+    #[error("There is no object with the ID {0:?} in the UI XML")]
+    ObjectNotFound(String),
+
+    #[error("Cannot use {0:?} as a factory, because it is not a valid Rust identifier")]
+    InvalidFactoryId(String),
+
+    #[error("Both {first:?} and {second:?} are converted to the Rust name {rust_name}")]
+    NameCollision {
+        first: String,
+        second: String,
+        rust_name: String,
+    },
+
+    #[error("OUT_DIR is not set - generate_to_out_dir can only be used from a build script")]
+    NoOutDir,
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
+
+#[derive(Clone)]
+struct FactoryDef {
+    id: String,
+    extras: Vec<String>,
+}
+
+/// Generates Rust bindings for a UI XML file. See the [crate documentation](crate) for usage.
+pub struct UiBindings {
+    path: PathBuf,
+    factories: Vec<FactoryDef>,
+    class_types: HashMap<String, String>,
+}
+
+impl UiBindings {
+    /// Generate bindings for a UI XML file.
+    ///
+    /// A relative path is resolved relative to the crate's root (where the `Cargo.toml` is).
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        let path = path.as_ref();
+        let path = match std::env::var_os("CARGO_MANIFEST_DIR") {
+            Some(manifest_dir) if path.is_relative() => Path::new(&manifest_dir).join(path),
+            _ => path.to_owned(),
+        };
+        Self {
+            path,
+            factories: Vec::new(),
+            class_types: HashMap::new(),
+        }
+    }
+
+    /// Split a factory from the object with this ID.
+    ///
+    /// If no factories are specified, every root level object that has an ID gets its own factory.
+    pub fn factory(self, id: impl Into<String>) -> Self {
+        self.factory_with_extras(id, &[])
+    }
+
+    /// Split a factory from the object with this ID, together with some root level resources it
+    /// uses. See `#[factory(extra(...))]` in
+    /// [`woab::Factories`](https://docs.rs/woab/latest/woab/derive.Factories.html).
+    pub fn factory_with_extras(mut self, id: impl Into<String>, extras: &[&str]) -> Self {
+        self.factories.push(FactoryDef {
+            id: id.into(),
+            extras: extras.iter().map(|extra| (*extra).to_owned()).collect(),
+        });
+        self
+    }
+
+    /// Use a specific Rust type for widgets of a GTK class.
+    ///
+    /// By default, classes that start with `Gtk` and `Adw` are mapped to the `gtk4` and `adw`
+    /// crates, and all the other classes are typed as `glib::Object`.
+    ///
+    /// ```no_run
+    /// woab_build::UiBindings::new("assets/app.ui")
+    ///     .class_type("MyAppCanvas", "crate::canvas::Canvas")
+    ///     .generate_to_out_dir("app_ui.rs")
+    ///     .unwrap();
+    /// ```
+    pub fn class_type(mut self, class: impl Into<String>, rust_type: impl Into<String>) -> Self {
+        self.class_types.insert(class.into(), rust_type.into());
+        self
+    }
+
+    /// Generate the bindings and write them to a file in `OUT_DIR`.
+    ///
+    /// This also tells Cargo to rerun the build script when the UI XML file changes.
+    pub fn generate_to_out_dir(&self, file_name: impl AsRef<Path>) -> Result<()> {
+        let out_dir = std::env::var_os("OUT_DIR").ok_or(Error::NoOutDir)?;
+        let out_path = Path::new(&out_dir).join(file_name);
+        let code = self.generate()?;
+        std::fs::write(&out_path, code).map_err(|source| Error::Io { path: out_path, source })?;
+        println!("cargo:rerun-if-changed={}", self.path.display());
+        Ok(())
+    }
+
+    /// Generate the bindings as Rust source code.
+    pub fn generate(&self) -> Result<String> {
+        let xml = std::fs::read_to_string(&self.path).map_err(|source| Error::Io {
+            path: self.path.clone(),
+            source,
+        })?;
+        let ui = ParsedUi::parse(&xml).map_err(|source| Error::Xml {
+            path: self.path.clone(),
+            source,
+        })?;
+
+        let factories = if self.factories.is_empty() {
+            ui.objects
+                .iter()
+                .filter(|object| object.is_root)
+                .map(|object| FactoryDef {
+                    id: object.id.clone(),
+                    extras: Vec::new(),
+                })
+                .collect()
+        } else {
+            self.factories.clone()
+        };
+
+        // Maps each ID that starts a factory (including the extras) to the factory's index.
+        let mut factory_of_id = HashMap::<&str, usize>::new();
+        for (i, factory) in factories.iter().enumerate() {
+            for id in core::iter::once(&factory.id).chain(factory.extras.iter()) {
+                if field_name(id) != *id {
+                    return Err(Error::InvalidFactoryId(id.clone()));
+                }
+                if !ui.objects.iter().any(|object| object.id == *id) {
+                    return Err(Error::ObjectNotFound(id.clone()));
+                }
+                factory_of_id.insert(id.as_str(), i);
+            }
+        }
+        // The factory of an object (or a signal) is the factory of the innermost object with a
+        // factory ID it is declared in - the same way `woab::dissect_builder_xml` splits the XML.
+        let owner = |enclosing_ids: &[String], own_id: Option<&str>| {
+            own_id
+                .into_iter()
+                .chain(enclosing_ids.iter().rev().map(String::as_str))
+                .find_map(|id| factory_of_id.get(id).copied())
+        };
+
+        let mut code = String::new();
+        let out = &mut code;
+        writeln!(out, "// Generated by woab-build from {}. Do not edit.", self.path.display()).unwrap();
+        writeln!(out).unwrap();
+        writeln!(out, "/// The UI XML the bindings were generated from.").unwrap();
+        writeln!(
+            out,
+            "pub const UI_XML: &str = include_str!({:?});",
+            self.path.display().to_string()
+        )
+        .unwrap();
+        writeln!(out).unwrap();
+
+        writeln!(out, "#[derive(woab::Factories)]").unwrap();
+        writeln!(out, "pub struct Factories {{").unwrap();
+        for factory in factories.iter() {
+            if !factory.extras.is_empty() {
+                writeln!(out, "    #[factory(extra({}))]", factory.extras.join(", ")).unwrap();
+            }
+            writeln!(out, "    pub {}: woab::BuilderFactory,", factory.id).unwrap();
+        }
+        writeln!(out, "}}").unwrap();
+        writeln!(out).unwrap();
+        writeln!(out, "impl Factories {{").unwrap();
+        writeln!(out, "    /// Split [`UI_XML`] into the factories.").unwrap();
+        writeln!(out, "    pub fn load() -> woab::Result<Self> {{").unwrap();
+        writeln!(out, "        Self::read(UI_XML.as_bytes())").unwrap();
+        writeln!(out, "    }}").unwrap();
+        writeln!(out, "}}").unwrap();
+
+        for (i, factory) in factories.iter().enumerate() {
+            let type_prefix = type_name(&factory.id);
+
+            writeln!(out).unwrap();
+            writeln!(out, "/// The objects of the `{}` factory.", factory.id).unwrap();
+            writeln!(out, "#[derive(woab::WidgetsFromBuilder)]").unwrap();
+            writeln!(out, "pub struct {}Widgets {{", type_prefix).unwrap();
+            let mut fields = NameSet::default();
+            for object in ui.objects.iter() {
+                if owner(&object.enclosing_ids, Some(object.id.as_str())) != Some(i) {
+                    continue;
+                }
+                let field = field_name(&object.id);
+                fields.insert(&object.id, &field)?;
+                if field != object.id {
+                    writeln!(out, "    #[widget(name = {:?})]", object.id).unwrap();
+                }
+                writeln!(out, "    pub {}: {},", field, self.rust_type(&object.class)).unwrap();
+            }
+            writeln!(out, "}}").unwrap();
+
+            let mut variants = NameSet::default();
+            for signal in ui.signals.iter() {
+                if owner(&signal.enclosing_ids, None) == Some(i) && !variants.originals.contains(&signal.handler) {
+                    variants.insert(&signal.handler, &type_name(&signal.handler))?;
+                }
+            }
+            let variants = variants.originals.iter().zip(variants.rust_names.iter()).collect::<Vec<_>>();
+            writeln!(out).unwrap();
+            writeln!(out, "/// The signals declared under the `{}` factory.", factory.id).unwrap();
+            writeln!(out, "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]").unwrap();
+            writeln!(out, "pub enum {}Signal {{", type_prefix).unwrap();
+            for (_, variant) in variants.iter() {
+                writeln!(out, "    {},", variant).unwrap();
+            }
+            writeln!(out, "}}").unwrap();
+            writeln!(out).unwrap();
+            writeln!(out, "impl {}Signal {{", type_prefix).unwrap();
+            writeln!(out, "    /// All the signals, in the order they are declared in.").unwrap();
+            write!(out, "    pub const ALL: &'static [Self] = &[").unwrap();
+            for (j, (_, variant)) in variants.iter().enumerate() {
+                write!(out, "{}Self::{}", if j == 0 { "" } else { ", " }, variant).unwrap();
+            }
+            writeln!(out, "];").unwrap();
+            writeln!(out).unwrap();
+            writeln!(out, "    /// The name of the signal's handler in the UI XML.").unwrap();
+            writeln!(out, "    pub fn name(self) -> &'static str {{").unwrap();
+            writeln!(out, "        match self {{").unwrap();
+            for (handler, variant) in variants.iter() {
+                writeln!(out, "            Self::{} => {:?},", variant, handler).unwrap();
+            }
+            writeln!(out, "        }}").unwrap();
+            writeln!(out, "    }}").unwrap();
+            writeln!(out).unwrap();
+            writeln!(out, "    /// Find the signal by the name of its handler in the UI XML.").unwrap();
+            writeln!(out, "    pub fn from_name(name: &str) -> Option<Self> {{").unwrap();
+            writeln!(out, "        match name {{").unwrap();
+            for (handler, variant) in variants.iter() {
+                writeln!(out, "            {:?} => Some(Self::{}),", handler, variant).unwrap();
+            }
+            writeln!(out, "            _ => None,").unwrap();
+            writeln!(out, "        }}").unwrap();
+            writeln!(out, "    }}").unwrap();
+            writeln!(out, "}}").unwrap();
+        }
+
+        Ok(code)
+    }
+
+    fn rust_type(&self, class: &str) -> String {
+        if let Some(rust_type) = self.class_types.get(class) {
+            rust_type.clone()
+        } else if let Some(name) = class.strip_prefix("Gtk") {
+            format!("gtk4::{}", name)
+        } else if let Some(name) = class.strip_prefix("Adw") {
+            format!("adw::{}", name)
+        } else {
+            "glib::Object".to_owned()
+        }
+    }
+}
+
+/// Tracks the Rust names generated for a struct or an enum, to detect collisions.
+#[derive(Default)]
+struct NameSet {
+    originals: Vec<String>,
+    rust_names: Vec<String>,
+}
+
+impl NameSet {
+    fn insert(&mut self, original: &str, rust_name: &str) -> Result<()> {
+        if let Some(i) = self.rust_names.iter().position(|existing| existing == rust_name) {
+            return Err(Error::NameCollision {
+                first: self.originals[i].clone(),
+                second: original.to_owned(),
+                rust_name: rust_name.to_owned(),
+            });
+        }
+        self.originals.push(original.to_owned());
+        self.rust_names.push(rust_name.to_owned());
+        Ok(())
+    }
+}
+
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "box", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern", "false", "fn", "for",
+    "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "self", "static", "struct",
+    "super", "trait", "true", "type", "unsafe", "use", "where", "while", "yield",
+];
+
+/// Convert an object ID to a field name.
+fn field_name(id: &str) -> String {
+    let mut result: String = id.chars().map(|c| if c.is_ascii_alphanumeric() { c } else { '_' }).collect();
+    if !result.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+        result.insert(0, '_');
+    }
+    if KEYWORDS.contains(&result.as_str()) {
+        result.insert_str(0, "r#");
+    }
+    result
+}
+
+/// Convert an object ID or a signal handler name to a type or variant name.
+fn type_name(name: &str) -> String {
+    let mut result = String::new();
+    for part in name.split(|c: char| !c.is_ascii_alphanumeric()) {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            result.push(first.to_ascii_uppercase());
+            result.extend(chars);
+        }
+    }
+    if !result.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        result.insert(0, '_');
+    }
+    result
+}
@@ -0,0 +1,93 @@
+//! Scanning of GTK UI XML files for the objects and the signals declared in them.
+//!
+//! Shared with `woab-macros`, which uses it for the signal constants of
+//! `#[derive(woab::Factories)]`.
+
+/// An `<object>` declaration with an ID and a class.
+pub struct UiObject {
+    pub id: String,
+    pub class: String,
+    /// The IDs of the objects this object is declared inside, outermost first.
+    pub enclosing_ids: Vec<String>,
+    /// Whether the object is declared directly under `<interface>`.
+    pub is_root: bool,
+}
+
+/// A `<signal>` declaration.
+pub struct UiSignal {
+    pub handler: String,
+    /// The IDs of the objects the signal is declared inside, outermost first.
+    pub enclosing_ids: Vec<String>,
+}
+
+/// The objects and the signals of a UI XML file, in the order they are declared in.
+pub struct ParsedUi {
+    pub objects: Vec<UiObject>,
+    pub signals: Vec<UiSignal>,
+}
+
+impl ParsedUi {
+    pub fn parse(xml: &str) -> Result<Self, quick_xml::Error> {
+        use quick_xml::events::{BytesStart, Event};
+        use quick_xml::Reader;
+
+        fn attribute(tag: &BytesStart, name: &str) -> Result<Option<String>, quick_xml::Error> {
+            Ok(if let Some(attr) = tag.try_get_attribute(name)? {
+                Some(attr.unescape_value()?.into_owned())
+            } else {
+                None
+            })
+        }
+
+        let mut reader = Reader::from_str(xml);
+        let mut buf = Vec::new();
+        // One entry for each open element - the ID if it is an object with an ID.
+        let mut stack = Vec::<Option<String>>::new();
+        let mut result = Self {
+            objects: Vec::new(),
+            signals: Vec::new(),
+        };
+        loop {
+            buf.clear();
+            let (tag, is_empty) = match reader.read_event_into(&mut buf)? {
+                Event::Eof => break,
+                Event::Start(tag) => (tag, false),
+                Event::Empty(tag) => (tag, true),
+                Event::End(_) => {
+                    stack.pop();
+                    continue;
+                }
+                _ => continue,
+            };
+            let enclosing_ids = || stack.iter().flatten().cloned().collect();
+            let mut id = None;
+            match tag.name().0 {
+                b"object" => {
+                    id = attribute(&tag, "id")?;
+                    if let (Some(id), Some(class)) = (&id, attribute(&tag, "class")?) {
+                        result.objects.push(UiObject {
+                            id: id.clone(),
+                            class,
+                            enclosing_ids: enclosing_ids(),
+                            // Only the `<interface>` element is open.
+                            is_root: stack.len() == 1,
+                        });
+                    }
+                }
+                b"signal" => {
+                    if let Some(handler) = attribute(&tag, "handler")? {
+                        result.signals.push(UiSignal {
+                            handler,
+                            enclosing_ids: enclosing_ids(),
+                        });
+                    }
+                }
+                _ => {}
+            }
+            if !is_empty {
+                stack.push(id);
+            }
+        }
+        Ok(result)
+    }
+}
@@ -0,0 +1,40 @@
+#[test]
+fn test_generate_default_factories() {
+    let code = woab_build::UiBindings::new("../tests/basic.ui").generate().unwrap();
+    assert!(code.contains("pub win_test: woab::BuilderFactory,"));
+    assert!(code.contains("pub struct WinTestWidgets {"));
+    assert!(code.contains("pub btn_copy_right_to_left: gtk4::Button,"));
+    assert!(code.contains("pub buf_left: gtk4::TextBuffer,"));
+    assert!(code.contains("pub enum WinTestSignal {"));
+    assert!(code.contains("Self::CopyRightToLeft => \"copy_right_to_left\","));
+    assert!(code.contains("\"copy_left_to_right\" => Some(Self::CopyLeftToRight),"));
+}
+
+#[test]
+fn test_generate_nested_factories() {
+    let code = woab_build::UiBindings::new("../examples/example.ui")
+        .factory("win_app")
+        .factory("row_addend")
+        .generate()
+        .unwrap();
+
+    let (win_app, row_addend) = code.split_once("pub struct RowAddendWidgets").unwrap();
+
+    // The row is split from the window, so its widgets and signals are not part of the window's.
+    assert!(win_app.contains("pub lst_addition: gtk4::ListBox,"));
+    assert!(win_app.contains("pub buf_sum: gtk4::TextBuffer,"));
+    assert!(!win_app.contains("buf_addend"));
+    assert!(win_app.contains("pub const ALL: &'static [Self] = &[Self::ClickButton];"));
+
+    assert!(row_addend.contains("pub row_addend: gtk4::ListBoxRow,"));
+    assert!(row_addend.contains("pub buf_addend: gtk4::TextBuffer,"));
+    assert!(row_addend.contains("pub const ALL: &'static [Self] = &[Self::AddendChanged, Self::RemoveAddend];"));
+}
+
+#[test]
+fn test_generate_unknown_factory() {
+    let result = woab_build::UiBindings::new("../tests/basic.ui")
+        .factory("no_such_object")
+        .generate();
+    assert!(matches!(result, Err(woab_build::Error::ObjectNotFound(id)) if id == "no_such_object"));
+}
@@ -81,7 +81,7 @@ fn main() -> woab::Result<()> {
             }
 
             let widgets: WindowWidgets = bld.widgets().unwrap();
-            widgets.win_app.show();
+            widgets.win_app.present();
             WindowActor {
                 widgets,
                 simple_data: 0,
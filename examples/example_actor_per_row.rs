@@ -25,7 +25,7 @@ impl actix::Actor for WindowActor {
     type Context = actix::Context<Self>;
 
     fn started(&mut self, ctx: &mut Self::Context) {
-        self.widgets.win_app.show();
+        self.widgets.win_app.present();
         ctx.address().do_send(Recalculate);
     }
 }
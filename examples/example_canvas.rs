@@ -1,5 +1,3 @@
-use core::time::Duration;
-
 use actix::prelude::*;
 use gtk4::prelude::*;
 use send_wrapper::SendWrapper;
@@ -10,6 +8,7 @@ struct WindowActor {
     area_size: [f64; 2],
     draw_area: gtk4::DrawingArea,
     ball: Ball,
+    _animator: woab::Animator,
 }
 
 impl actix::Actor for WindowActor {
@@ -58,21 +57,14 @@ impl actix::Handler<Draw> for WindowActor {
     }
 }
 
-struct Step(Duration);
-
-impl actix::Message for Step {
+impl actix::Handler<woab::Step> for WindowActor {
     type Result = ();
-}
 
-impl actix::Handler<Step> for WindowActor {
-    type Result = ();
-
-    fn handle(&mut self, msg: Step, _ctx: &mut Self::Context) -> Self::Result {
+    fn handle(&mut self, msg: woab::Step, _ctx: &mut Self::Context) -> Self::Result {
         if self.area_size[0] <= 0.0 || self.area_size[1] <= 0.0 {
             return;
         }
-        let Step(step_length) = msg;
-        let step_length = step_length.as_secs_f64();
+        let step_length = msg.delta.as_secs_f64();
 
         let min_pos = [BALL_RADIUS, BALL_RADIUS];
         let max_pos = [self.area_size[0] - BALL_RADIUS, self.area_size[1] - BALL_RADIUS];
@@ -110,20 +102,7 @@ fn main() -> woab::Result<()> {
         WindowActor::create(|ctx| {
             let bld = factory.instantiate_route_to(ctx.address());
             bld.set_application(app);
-            bld.get_object::<gtk4::ApplicationWindow>("win_app").unwrap().show();
-
-            actix::spawn({
-                let addr = ctx.address();
-                async move {
-                    use actix::clock::Instant;
-                    let mut last_step_time = Instant::now();
-                    loop {
-                        let step_time = Instant::now();
-                        addr.send(Step(step_time - last_step_time)).await.unwrap();
-                        last_step_time = step_time;
-                    }
-                }
-            });
+            bld.get_object::<gtk4::ApplicationWindow>("win_app").unwrap().present();
 
             let draw_area: gtk4::DrawingArea = bld.get_object("draw_area").unwrap();
 
@@ -132,6 +111,8 @@ fn main() -> woab::Result<()> {
                 woab::block_on(addr.send(Draw(SendWrapper::new(draw_ctx.clone())))).unwrap();
             });
 
+            let animator = woab::Animator::new(&draw_area, ctx.address());
+
             WindowActor {
                 area_size: [0.0, 0.0],
                 draw_area,
@@ -139,6 +120,7 @@ fn main() -> woab::Result<()> {
                     position: [BALL_RADIUS * 2.0, BALL_RADIUS * 2.0],
                     velocity: [100.0, 100.0],
                 },
+                _animator: animator,
             }
         });
         Ok(())
@@ -84,7 +84,7 @@ fn main() -> woab::Result<()> {
 
         // When the builder loads the window, it starts as hidden. We can use the extracted widgets
         // to show it.
-        widgets.window.show();
+        widgets.window.present();
 
         // This is where the actor is actually launched.
         ctx.run(MyActor { widgets });
@@ -2,7 +2,6 @@ use std::time::Instant;
 
 use actix::prelude::*;
 use gtk4::prelude::*;
-use send_wrapper::SendWrapper;
 
 #[derive(woab::Factories)]
 struct Factories {
@@ -61,15 +60,24 @@ impl WindowActor {
             RowActor::create(|ctx| {
                 let bld = self.factories.row.instantiate_without_routing_signals();
                 let widgets: RowWidgets = bld.widgets().unwrap();
-                let addr = ctx.address();
-                widgets.draw_area.set_draw_func(move |_, draw_ctx, _, _| {
-                    woab::block_on(addr.send(Draw(SendWrapper::new(draw_ctx.clone())))).unwrap();
+                let draw_state = woab::DrawState::new(0.0);
+                woab::draw_func_from_actor_state(&widgets.draw_area, &draw_state, |position, draw_ctx, width, height| {
+                    draw_ctx.arc(
+                        position * width as f64,
+                        0.5 * height as f64,
+                        10.0,
+                        0.0,
+                        2.0 * std::f64::consts::PI,
+                    );
+                    draw_ctx.set_source_rgb(0.5, 0.5, 0.5);
+                    draw_ctx.fill().unwrap();
                 });
                 self.widgets.lst_rows.append(&widgets.row);
                 self.rows.push(ctx.address().clone());
                 ctx.address().do_send(Step);
                 RowActor {
                     widgets,
+                    draw_state,
                     position: 0.0,
                     velocity: 0.1 + (i as f64 * 0.001).sqrt(),
                     prev_update: Instant::now(),
@@ -83,6 +91,7 @@ impl WindowActor {
 #[removable(self.widgets.row in gtk4::ListBox)]
 struct RowActor {
     widgets: RowWidgets,
+    draw_state: woab::DrawState<f64>,
     position: f64,
     velocity: f64,
     prev_update: Instant,
@@ -99,30 +108,6 @@ struct RowWidgets {
     draw_area: gtk4::DrawingArea,
 }
 
-struct Draw(SendWrapper<cairo::Context>);
-
-impl actix::Message for Draw {
-    type Result = ();
-}
-
-impl actix::Handler<Draw> for RowActor {
-    type Result = ();
-
-    fn handle(&mut self, msg: Draw, _ctx: &mut Self::Context) -> Self::Result {
-        let draw_ctx = msg.0.take();
-        let area_size = self.widgets.draw_area.allocation();
-        draw_ctx.arc(
-            self.position * area_size.width() as f64,
-            0.5 * area_size.height() as f64,
-            10.0,
-            0.0,
-            2.0 * std::f64::consts::PI,
-        );
-        draw_ctx.set_source_rgb(0.5, 0.5, 0.5);
-        draw_ctx.fill().unwrap();
-    }
-}
-
 struct Step;
 
 impl actix::Message for Step {
@@ -138,7 +123,7 @@ impl actix::Handler<Step> for RowActor {
         self.prev_update = update_time;
         let new_position = self.position + self.velocity * frame_length.as_secs_f64();
         self.position = new_position % 1.0;
-        self.widgets.draw_area.queue_draw();
+        self.draw_state.set(self.position);
         let addr = ctx.address();
         ctx.spawn(
             async move {
@@ -160,7 +145,7 @@ fn main() -> woab::Result<()> {
             let bld = factories.win_app.instantiate_route_to(ctx.address());
             bld.set_application(app);
             let widgets: WindowWidgets = bld.widgets().unwrap();
-            widgets.win_app.show();
+            widgets.win_app.present();
             WindowActor {
                 factories,
                 widgets,
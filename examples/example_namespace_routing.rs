@@ -102,7 +102,7 @@ fn main() -> woab::Result<()> {
             });
             bld.set_application(app);
             let widgets: WindowWidgets = bld.widgets().unwrap();
-            widgets.win_app.show();
+            widgets.win_app.present();
             WindowActor { widgets }
         });
         Ok(())
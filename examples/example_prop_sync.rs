@@ -63,18 +63,9 @@ fn main() -> woab::Result<()> {
         WindowActor::create(|ctx| {
             let bld = factory.instantiate_route_to(ctx.address());
             bld.set_application(app);
-            bld.get_object::<gtk4::ApplicationWindow>("win_app").unwrap().show();
+            bld.get_object::<gtk4::ApplicationWindow>("win_app").unwrap().present();
 
-            let addr = ctx.address();
-            let mut next_step = actix::clock::Instant::now();
-            let step_duration = std::time::Duration::from_secs(1);
-            actix::spawn(async move {
-                loop {
-                    next_step += step_duration;
-                    actix::clock::sleep_until(next_step).await;
-                    addr.send(Step).await.unwrap();
-                }
-            });
+            woab::interval(std::time::Duration::from_secs(1), ctx.address(), || Step).detach();
             WindowActor {
                 widgets: bld.widgets().unwrap(),
             }
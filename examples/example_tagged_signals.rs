@@ -25,7 +25,7 @@ impl actix::Actor for WindowActor {
     type Context = actix::Context<Self>;
 
     fn started(&mut self, _ctx: &mut Self::Context) {
-        self.widgets.win_app.show();
+        self.widgets.win_app.present();
         self.recalculate();
     }
 }
@@ -0,0 +1,79 @@
+use quote::quote;
+use syn::parse::Error;
+use syn::spanned::Spanned;
+
+pub fn impl_action_group_derive(ast: &syn::DeriveInput) -> Result<proc_macro2::TokenStream, Error> {
+    let fields = if let syn::Data::Struct(syn::DataStruct {
+        fields: syn::Fields::Named(fields),
+        ..
+    }) = &ast.data
+    {
+        fields
+    } else {
+        return Err(Error::new_spanned(ast, "ActionGroup only supports structs with named fields"));
+    };
+    let struct_ident = &ast.ident;
+
+    let mut field_idents = Vec::with_capacity(fields.named.len());
+    let mut ctor_arms = Vec::with_capacity(fields.named.len());
+    for field in fields.named.iter() {
+        let field_ident = field
+            .ident
+            .as_ref()
+            .ok_or_else(|| Error::new(field.span(), "Nameless field"))?;
+        let mut name = None;
+        let mut parameter_type = None;
+        let mut state = None;
+        for attr in field.attrs.iter() {
+            if !attr.path().is_ident("action") {
+                continue;
+            }
+            attr.parse_nested_meta(|meta| {
+                let meta_name = meta.path.get_ident().map(|ident| ident.to_string());
+                match meta_name.as_deref() {
+                    Some("name") => {
+                        name = Some(meta.value()?.parse::<syn::LitStr>()?);
+                    }
+                    Some("parameter") => {
+                        parameter_type = Some(meta.value()?.parse::<syn::Type>()?);
+                    }
+                    Some("state") => {
+                        state = Some(meta.value()?.parse::<syn::Expr>()?);
+                    }
+                    _ => return Err(Error::new_spanned(meta.path, "Unsupported parameter")),
+                }
+                Ok(())
+            })?;
+        }
+        // GIO action names cannot contain underscores
+        let name = name.unwrap_or_else(|| syn::LitStr::new(&field_ident.to_string().replace('_', "-"), field_ident.span()));
+        let parameter_type = if let Some(parameter_type) = parameter_type {
+            quote!(Some(<#parameter_type as glib::variant::StaticVariantType>::static_variant_type().into_owned()))
+        } else {
+            quote!(None)
+        };
+        let state = if let Some(state) = state {
+            quote!(Some(glib::variant::ToVariant::to_variant(&(#state))))
+        } else {
+            quote!(None)
+        };
+        ctor_arms.push(quote! {
+            #field_ident: woab::action_group::new_action(#name, #parameter_type, #state),
+        });
+        field_idents.push(field_ident);
+    }
+
+    Ok(quote! {
+        impl woab::action_group::ActionGroup for #struct_ident {
+            fn create_actions() -> Self {
+                Self {
+                    #(#ctor_arms)*
+                }
+            }
+
+            fn actions(&self) -> Vec<&gio::SimpleAction> {
+                vec![#(&self.#field_idents),*]
+            }
+        }
+    })
+}
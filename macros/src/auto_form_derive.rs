@@ -0,0 +1,188 @@
+use quote::quote;
+use syn::parse::Error;
+use syn::spanned::Spanned;
+
+pub fn impl_auto_form_derive(ast: &syn::DeriveInput) -> Result<proc_macro2::TokenStream, Error> {
+    let fields = if let syn::Data::Struct(syn::DataStruct {
+        fields: syn::Fields::Named(fields),
+        ..
+    }) = &ast.data
+    {
+        fields
+    } else {
+        return Err(Error::new_spanned(ast, "AutoForm only supports structs with named fields"));
+    };
+    let struct_ident = &ast.ident;
+    let form_ident = syn::Ident::new(&format!("{}Form", struct_ident), struct_ident.span());
+    let vis = &ast.vis;
+
+    let mut form_fields = Vec::new();
+    let mut ctor_rows = Vec::new();
+    let mut form_idents = Vec::new();
+    let mut getter_fields = Vec::new();
+    let mut setter_stmts = Vec::new();
+    for field in fields.named.iter() {
+        let field_ident = field
+            .ident
+            .as_ref()
+            .ok_or_else(|| Error::new(field.span(), "Nameless field"))?;
+        let field_type = &field.ty;
+        let mut skip = false;
+        let mut label = None;
+        let mut widget_type = None;
+        let mut range = None;
+        let mut step = None;
+        for attr in field.attrs.iter() {
+            if !attr.path().is_ident("form") {
+                continue;
+            }
+            attr.parse_nested_meta(|meta| {
+                let meta_name = meta.path.get_ident().map(|ident| ident.to_string());
+                match meta_name.as_deref() {
+                    Some("skip") => {
+                        skip = true;
+                    }
+                    Some("label") => {
+                        label = Some(meta.value()?.parse::<syn::LitStr>()?);
+                    }
+                    Some("widget") => {
+                        widget_type = Some(meta.value()?.parse::<syn::Type>()?);
+                    }
+                    Some("range") => {
+                        let expr = meta.value()?.parse::<syn::Expr>()?;
+                        let syn::Expr::Range(syn::ExprRange {
+                            start: Some(start),
+                            limits: syn::RangeLimits::Closed(_),
+                            end: Some(end),
+                            ..
+                        }) = expr
+                        else {
+                            return Err(Error::new_spanned(expr, "expected an inclusive range (`min..=max`)"));
+                        };
+                        range = Some((start, end));
+                    }
+                    Some("step") => {
+                        step = Some(meta.value()?.parse::<syn::Expr>()?);
+                    }
+                    _ => return Err(Error::new_spanned(meta.path, "Unsupported parameter")),
+                }
+                Ok(())
+            })?;
+        }
+        if skip {
+            getter_fields.push(quote!(#field_ident: Default::default()));
+            continue;
+        }
+        let widget_type = if let Some(widget_type) = widget_type {
+            widget_type
+        } else {
+            default_widget_type(field_type).ok_or_else(|| {
+                Error::new_spanned(
+                    field_type,
+                    "no default widget for this type - set one with `#[form(widget = ...)]`, or skip the field with `#[form(skip)]`",
+                )
+            })?
+        };
+        let label = label.unwrap_or_else(|| syn::LitStr::new(&default_label(field_ident), field_ident.span()));
+        let range = if let Some((start, end)) = range {
+            quote!(Some(((#start) as f64, (#end) as f64)))
+        } else {
+            quote!(None)
+        };
+        let step = if let Some(step) = step {
+            quote!(Some((#step) as f64))
+        } else {
+            quote!(None)
+        };
+        let row = form_idents.len() as i32;
+        let as_form_widget = quote!(<#widget_type as woab::auto_form::FormWidget<#field_type>>);
+
+        form_fields.push(quote!(#vis #field_ident: #widget_type));
+        ctor_rows.push(quote! {
+            let #field_ident = #as_form_widget::create_form_widget(#range, #step);
+            woab::auto_form::attach_row(&grid, #row, #label, &#field_ident);
+        });
+        getter_fields.push(quote!(#field_ident: #as_form_widget::form_value(&self.#field_ident)));
+        setter_stmts.push(quote!(#as_form_widget::set_form_value(&self.#field_ident, &value.#field_ident);));
+        form_idents.push(field_ident);
+    }
+
+    Ok(quote! {
+        #vis struct #form_ident {
+            #vis grid: gtk4::Grid,
+            #(#form_fields,)*
+        }
+
+        impl woab::auto_form::AutoForm for #struct_ident {
+            type Form = #form_ident;
+
+            fn build_form() -> Self::Form {
+                let grid = woab::auto_form::new_grid();
+                #(#ctor_rows)*
+                #form_ident {
+                    grid,
+                    #(#form_idents,)*
+                }
+            }
+        }
+
+        impl woab::auto_form::FormWidgets<#struct_ident> for #form_ident {
+            fn grid(&self) -> &gtk4::Grid {
+                &self.grid
+            }
+
+            fn get(&self) -> #struct_ident {
+                #struct_ident {
+                    #(#getter_fields,)*
+                }
+            }
+
+            fn set(&self, value: &#struct_ident) {
+                #(#setter_stmts)*
+            }
+        }
+
+        impl woab::prop_sync::ConnectChanges for #form_ident {
+            fn connect_changes(
+                &self,
+                callback: std::rc::Rc<dyn Fn()>,
+            ) -> Vec<(glib::Object, glib::SignalHandlerId)> {
+                let mut connections = Vec::new();
+                #(
+                    connections.extend(woab::prop_sync::ConnectChanges::connect_changes(
+                        &self.#form_idents,
+                        callback.clone(),
+                    ));
+                )*
+                connections
+            }
+        }
+    })
+}
+
+/// The widget used for fields of well known types, when no `widget = ...` is given.
+fn default_widget_type(ty: &syn::Type) -> Option<syn::Type> {
+    let syn::Type::Path(syn::TypePath { qself: None, path }) = ty else {
+        return None;
+    };
+    let type_name = path.get_ident()?.to_string();
+    let widget_type: syn::Type = match type_name.as_str() {
+        "String" => syn::parse_quote!(gtk4::Entry),
+        "bool" => syn::parse_quote!(gtk4::Switch),
+        "i8" | "i16" | "i32" | "i64" | "u8" | "u16" | "u32" | "u64" | "isize" | "usize" | "f32" | "f64" => {
+            syn::parse_quote!(gtk4::SpinButton)
+        }
+        _ => return None,
+    };
+    Some(widget_type)
+}
+
+/// `field_name` becomes `Field name`.
+fn default_label(ident: &syn::Ident) -> String {
+    let name = ident.to_string().replace('_', " ");
+    let mut chars = name.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
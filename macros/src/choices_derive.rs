@@ -0,0 +1,86 @@
+use crate::util::{iter_attrs_parameters, path_to_single_string};
+use quote::quote;
+use syn::parse::Error;
+
+pub fn impl_choices_derive(ast: &syn::DeriveInput) -> Result<proc_macro2::TokenStream, Error> {
+    let variants = if let syn::Data::Enum(syn::DataEnum { variants, .. }) = &ast.data {
+        variants
+    } else {
+        return Err(Error::new_spanned(ast, "Choices only supports enums"));
+    };
+    let enum_ident = &ast.ident;
+
+    let mut index_arms = Vec::new();
+    let mut from_index_arms = Vec::new();
+    let mut id_arms = Vec::new();
+    let mut label_arms = Vec::new();
+
+    for (index, variant) in variants.iter().enumerate() {
+        if !matches!(variant.fields, syn::Fields::Unit) {
+            return Err(Error::new_spanned(variant, "Choices only supports fieldless variants"));
+        }
+        let variant_ident = &variant.ident;
+        let mut id = None;
+        let mut label = None;
+        iter_attrs_parameters(&variant.attrs, "choice", |attr_name, value| {
+            let value = match value {
+                Some(syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(value),
+                    ..
+                })) => value,
+                None => return Err(Error::new_spanned(attr_name, "attribute must have a value")),
+                value => return Err(Error::new_spanned(value, "attribute must have a string literal value")),
+            };
+            match path_to_single_string(&attr_name)?.as_str() {
+                "id" => {
+                    id = Some(value);
+                }
+                "label" => {
+                    label = Some(value);
+                }
+                _ => {
+                    return Err(Error::new_spanned(attr_name, "unknown attribute"));
+                }
+            }
+            Ok(())
+        })?;
+        let variant_name = syn::LitStr::new(&variant_ident.to_string(), variant_ident.span());
+        let id = id.unwrap_or_else(|| variant_name.clone());
+        let label = label.unwrap_or(variant_name);
+
+        let index = index as u32;
+        index_arms.push(quote!(Self::#variant_ident => #index,));
+        from_index_arms.push(quote!(#index => Some(Self::#variant_ident),));
+        id_arms.push(quote!(Self::#variant_ident => #id,));
+        label_arms.push(quote!(Self::#variant_ident => #label,));
+    }
+
+    Ok(quote! {
+        impl woab::choices::Choices for #enum_ident {
+            fn index(&self) -> u32 {
+                match self {
+                    #(#index_arms)*
+                }
+            }
+
+            fn from_index(index: u32) -> Option<Self> {
+                match index {
+                    #(#from_index_arms)*
+                    _ => None,
+                }
+            }
+
+            fn id(&self) -> &'static str {
+                match self {
+                    #(#id_arms)*
+                }
+            }
+
+            fn label(&self) -> &'static str {
+                match self {
+                    #(#label_arms)*
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,106 @@
+use crate::util::{iter_attrs_parameters, path_to_single_string};
+use quote::quote;
+use syn::parse::Error;
+
+pub fn impl_columns_derive(ast: &syn::DeriveInput) -> Result<proc_macro2::TokenStream, Error> {
+    let fields = if let syn::Data::Struct(syn::DataStruct {
+        fields: syn::Fields::Named(fields),
+        ..
+    }) = &ast.data
+    {
+        fields
+    } else {
+        return Err(Error::new_spanned(ast, "Columns only supports structs with named fields"));
+    };
+    let struct_ident = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let mut columns = Vec::new();
+    for field in fields.named.iter() {
+        if !field.attrs.iter().any(|attr| attr.path().is_ident("column")) {
+            continue;
+        }
+        let field_ident = field.ident.as_ref().unwrap();
+
+        let mut title = None;
+        let mut numeric = false;
+        let mut check = false;
+        let mut sort = false;
+        let mut edit = None;
+        iter_attrs_parameters(&field.attrs, "column", |attr_name, value| {
+            let flag = |value: Option<syn::Expr>, flag: &mut bool| {
+                if value.is_some() {
+                    return Err(Error::new_spanned(value, "attribute cannot have a value"));
+                }
+                *flag = true;
+                Ok(())
+            };
+            let string_value = |value: Option<syn::Expr>| match value {
+                Some(syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(value),
+                    ..
+                })) => Ok(value),
+                None => Err(Error::new_spanned(&attr_name, "attribute must have a value")),
+                value => Err(Error::new_spanned(value, "attribute must have a string literal value")),
+            };
+            match path_to_single_string(&attr_name)?.as_str() {
+                "title" => {
+                    title = Some(string_value(value)?);
+                }
+                "numeric" => flag(value, &mut numeric)?,
+                "check" => flag(value, &mut check)?,
+                "sort" => flag(value, &mut sort)?,
+                "edit" => {
+                    edit = Some(string_value(value)?);
+                }
+                _ => {
+                    return Err(Error::new_spanned(&attr_name, "unknown attribute"));
+                }
+            }
+            Ok(())
+        })?;
+        if check && numeric {
+            return Err(Error::new_spanned(field, "`check` and `numeric` are mutually exclusive"));
+        }
+
+        let title = title.unwrap_or_else(|| syn::LitStr::new(&field_ident.to_string(), field_ident.span()));
+        let compare = if sort {
+            quote! {
+                Some((|a: &Self, b: &Self| {
+                    core::cmp::PartialOrd::partial_cmp(&a.#field_ident, &b.#field_ident).unwrap_or(core::cmp::Ordering::Equal)
+                }) as fn(&Self, &Self) -> core::cmp::Ordering)
+            }
+        } else {
+            quote!(None)
+        };
+        let edit = if let Some(edit) = edit {
+            quote!(edit_target.clone().map(|target| (#edit, target)))
+        } else {
+            quote!(None)
+        };
+        columns.push(if check {
+            quote! {
+                woab::columns::check_column::<Self>(#title, |row: &Self| row.#field_ident, #compare, #edit)
+            }
+        } else {
+            quote! {
+                woab::columns::text_column::<Self>(
+                    #title,
+                    #numeric,
+                    |row: &Self| std::string::ToString::to_string(&row.#field_ident),
+                    #compare,
+                    #edit,
+                )
+            }
+        });
+    }
+
+    Ok(quote! {
+        impl #impl_generics woab::columns::Columns for #struct_ident #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn columns(edit_target: Option<actix::Recipient<woab::Signal>>) -> Vec<gtk4::ColumnViewColumn> {
+                vec![#(#columns),*]
+            }
+        }
+    })
+}
@@ -13,21 +13,40 @@ pub fn impl_factories_derive(ast: &syn::DeriveInput) -> Result<proc_macro2::Toke
         return Err(Error::new_spanned(ast, "Factories only supports structs with named fields"));
     };
     let struct_ident = &ast.ident;
+    let struct_vis = &ast.vis;
+    let mut ui_path = None;
+    for attr in ast.attrs.iter() {
+        if !attr.path().is_ident("factory") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            let meta_name = meta.path.get_ident().map(|ident| ident.to_string());
+            match meta_name.as_deref() {
+                Some("ui") => {
+                    ui_path = Some(meta.value()?.parse::<syn::LitStr>()?);
+                }
+                _ => return Err(Error::new_spanned(meta.path, "Unsupported parameter")),
+            }
+            Ok(())
+        })?;
+    }
     let num_factories = fields.named.len();
 
-    let single_buffer = quote! {Vec::new()};
-    let buffers = std::iter::repeat(&single_buffer).take(num_factories);
-
     let mut match_arms = Vec::with_capacity(num_factories);
     let mut deconstruct_buffers_array = Vec::new();
     let mut ctor_arms = Vec::new();
+    let mut ids_per_factory = Vec::<(&syn::Ident, Vec<String>)>::with_capacity(num_factories);
+    let mut field_names = Vec::with_capacity(num_factories);
+    let mut rest_field = None;
 
-    for (i, field) in fields.named.iter().enumerate() {
+    for field in fields.named.iter() {
         let field_ident = field
             .ident
             .as_ref()
             .ok_or_else(|| Error::new(field.span(), "Nameless field"))?;
         let mut strings_that_match = vec![syn::LitStr::new(&field_ident.to_string(), field_ident.span())];
+        let mut css_path = None;
+        let mut is_rest = false;
 
         for attr in field.attrs.iter() {
             if !attr.path().get_ident().map_or(false, |ident| ident == "factory") {
@@ -44,31 +63,161 @@ pub fn impl_factories_derive(ast: &syn::DeriveInput) -> Result<proc_macro2::Toke
                             Ok(())
                         })?;
                     }
+                    Some("css") => {
+                        css_path = Some(meta.value()?.parse::<syn::LitStr>()?);
+                    }
+                    Some("rest") => {
+                        is_rest = true;
+                    }
                     _ => return Err(Error::new_spanned(meta.path, "Unsupported parameter")),
                 }
                 Ok(())
             })?;
         }
+        if is_rest {
+            if rest_field.is_some() {
+                return Err(Error::new_spanned(field, "Only one field can be #[factory(rest)]"));
+            }
+            if 1 < strings_that_match.len() || css_path.is_some() {
+                return Err(Error::new_spanned(
+                    field,
+                    "#[factory(rest)] cannot be combined with other parameters",
+                ));
+            }
+            rest_field = Some(field_ident);
+            continue;
+        }
+        let i = match_arms.len();
+        for (j, id) in strings_that_match.iter().enumerate() {
+            let claimed_by = if strings_that_match[..j].iter().any(|other| other.value() == id.value()) {
+                Some(field_ident.to_string())
+            } else {
+                ids_per_factory
+                    .iter()
+                    .find(|(_, ids)| ids.contains(&id.value()))
+                    .map(|(other_field, _)| other_field.to_string())
+            };
+            if let Some(claimed_by) = claimed_by {
+                return Err(Error::new_spanned(
+                    id,
+                    format!("ID {:?} is already claimed by `{}`", id.value(), claimed_by),
+                ));
+            }
+        }
         match_arms.push(quote! {
             #(#strings_that_match)|* => Some(#i),
         });
+        ids_per_factory.push((
+            field_ident,
+            strings_that_match.iter().map(|id| id.value()).collect::<Vec<_>>(),
+        ));
         deconstruct_buffers_array.push(field_ident);
-        ctor_arms.push(quote! {
-            #field_ident: String::from_utf8(#field_ident)?.into(),
+        field_names.push(syn::LitStr::new(&field_ident.to_string(), field_ident.span()));
+        ctor_arms.push(if let Some(css_path) = css_path {
+            quote! {
+                #field_ident: woab::BuilderFactory::from(String::from_utf8(#field_ident)?).with_css(include_str!(#css_path)),
+            }
+        } else {
+            quote! {
+                #field_ident: String::from_utf8(#field_ident)?.into(),
+            }
         });
     }
 
+    let (signals_module, factory_signals) = if let Some(ui_path) = ui_path {
+        let ui_xml = crate::ui_xml::UiXml::load(&ui_path)?;
+        let mut const_names = Vec::<String>::new();
+        let mut consts = Vec::new();
+        for handler in ui_xml.handlers() {
+            let const_name = crate::ui_xml::signal_const_name(handler);
+            if const_names.contains(&const_name) {
+                return Err(Error::new_spanned(
+                    &ui_path,
+                    format!("Multiple signals map to the constant {}", const_name),
+                ));
+            }
+            let const_ident = syn::Ident::new(&const_name, ui_path.span());
+            consts.push(quote! {
+                pub const #const_ident: &str = #handler;
+            });
+            const_names.push(const_name);
+        }
+        let track_changes = ui_xml.track_changes();
+        let all_factory_ids = ids_per_factory
+            .iter()
+            .flat_map(|(_, ids)| ids.iter().cloned())
+            .collect::<Vec<_>>();
+        let factory_signals = ids_per_factory.iter().map(|(field_ident, ids)| {
+            let const_ident = crate::ui_xml::factory_signals_const_ident(field_ident);
+            let handlers = ui_xml.factory_handlers(ids, &all_factory_ids);
+            quote! {
+                #[doc(hidden)] // for internal use by woab::match_signal!
+                pub const #const_ident: &'static [&'static str] = &[#(#handlers),*];
+            }
+        });
+        let signals_module_ident = crate::ui_xml::signals_module_ident(struct_ident);
+        (
+            quote! {
+                /// The names of the signals declared in the UI XML.
+                #[allow(dead_code)]
+                #struct_vis mod #signals_module_ident {
+                    #(#consts)*
+                    #track_changes
+                }
+            },
+            quote! {
+                #[allow(dead_code)]
+                impl #struct_ident {
+                    #(#factory_signals)*
+                }
+            },
+        )
+    } else {
+        (quote! {}, quote! {})
+    };
+
+    let single_buffer = quote! {Vec::new()};
+    let buffers = std::iter::repeat(&single_buffer).take(match_arms.len());
+
+    let (rest_decl, rest_arg, rest_ctor) = if let Some(rest_field) = rest_field {
+        (
+            quote! {
+                let mut rest = Vec::new();
+            },
+            quote! {Some(&mut rest)},
+            quote! {
+                #rest_field: rest
+                    .into_iter()
+                    .map(|(id, buffer)| Ok((id, String::from_utf8(buffer)?.into())))
+                    .collect::<Result<_, woab::Error>>()?,
+            },
+        )
+    } else {
+        (quote! {}, quote! {None}, quote! {})
+    };
+
     Ok(quote! {
+        #signals_module
+        #factory_signals
+
         impl #struct_ident {
             pub fn read(buf_read: impl std::io::BufRead) -> Result<Self, woab::Error> {
                 let mut buffers = [#(#buffers),*];
-                woab::dissect_builder_xml(buf_read, &mut buffers, |id| match id {
-                    #(#match_arms)*
-                    _ => None,
-                })?;
+                #rest_decl
+                woab::dissect_builder_xml(
+                    buf_read,
+                    &mut buffers,
+                    &[#(#field_names),*],
+                    |id| match id {
+                        #(#match_arms)*
+                        _ => None,
+                    },
+                    #rest_arg,
+                )?;
                 let [#(#deconstruct_buffers_array),*] = buffers;
                 Ok(Self {
                     #(#ctor_arms)*
+                    #rest_ctor
                 })
             }
         }
@@ -1,8 +1,16 @@
+mod action_group_derive;
+mod auto_form_derive;
+mod choices_derive;
+mod columns_derive;
 mod factories_derive;
+mod match_signal;
+mod model_derive;
 mod param_extraction;
 mod prop_sync_derive;
 mod removable_derive;
+mod ui_xml;
 mod util;
+mod validate_derive;
 mod widgets_from_builder_derive;
 
 #[proc_macro_derive(WidgetsFromBuilder, attributes(widget))]
@@ -41,6 +49,15 @@ pub fn params(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     }
 }
 
+#[proc_macro]
+pub fn match_signal(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(input as match_signal::Input);
+    match input.impl_match_signal() {
+        Ok(output) => output.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
 #[proc_macro_derive(PropSync, attributes(prop_sync))]
 pub fn derive_prop_sync(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = syn::parse_macro_input!(input as syn::DeriveInput);
@@ -49,3 +66,57 @@ pub fn derive_prop_sync(input: proc_macro::TokenStream) -> proc_macro::TokenStre
         Err(error) => error.to_compile_error().into(),
     }
 }
+
+#[proc_macro_derive(Columns, attributes(column))]
+pub fn derive_columns(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    match columns_derive::impl_columns_derive(&input) {
+        Ok(output) => output.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+#[proc_macro_derive(Choices, attributes(choice))]
+pub fn derive_choices(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    match choices_derive::impl_choices_derive(&input) {
+        Ok(output) => output.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+#[proc_macro_derive(Model, attributes(model))]
+pub fn derive_model(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    match model_derive::impl_model_derive(&input) {
+        Ok(output) => output.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+#[proc_macro_derive(Validate, attributes(validate))]
+pub fn derive_validate(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    match validate_derive::impl_validate_derive(&input) {
+        Ok(output) => output.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+#[proc_macro_derive(ActionGroup, attributes(action))]
+pub fn derive_action_group(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    match action_group_derive::impl_action_group_derive(&input) {
+        Ok(output) => output.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+#[proc_macro_derive(AutoForm, attributes(form))]
+pub fn derive_auto_form(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    match auto_form_derive::impl_auto_form_derive(&input) {
+        Ok(output) => output.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
@@ -0,0 +1,137 @@
+use quote::{quote, quote_spanned};
+use syn::parse::{Error, Parse, ParseStream};
+
+pub struct Input {
+    msg: syn::Expr,
+    factories_type: syn::Path,
+    factory_ident: syn::Ident,
+    arms: Vec<syn::Arm>,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let msg = input.parse()?;
+        input.parse::<syn::Token![,]>()?;
+        for keyword in ["from", "factory"] {
+            let ident: syn::Ident = input.parse()?;
+            if ident != keyword {
+                return Err(Error::new_spanned(ident, format!("expected `{}`", keyword)));
+            }
+        }
+        let factories_path: syn::Path = input.call(syn::Path::parse_mod_style)?;
+        let mut segments = factories_path.segments.iter().cloned().collect::<Vec<_>>();
+        let factory_segment = segments
+            .pop()
+            .filter(|_| !segments.is_empty())
+            .ok_or_else(|| Error::new_spanned(&factories_path, "expected `FactoriesType::field`"))?;
+        let factories_type = syn::Path {
+            leading_colon: factories_path.leading_colon,
+            segments: segments.into_iter().collect(),
+        };
+        let content;
+        syn::braced!(content in input);
+        let mut arms = Vec::new();
+        while !content.is_empty() {
+            let mut arm: syn::Arm = content.parse()?;
+            // The last arm may not have a comma, but we add a wildcard arm after it.
+            arm.comma.get_or_insert_with(Default::default);
+            arms.push(arm);
+        }
+        Ok(Self {
+            msg,
+            factories_type,
+            factory_ident: factory_segment.ident,
+            arms,
+        })
+    }
+}
+
+fn signal_names(pat: &syn::Pat, names: &mut Vec<syn::LitStr>) -> Result<bool, Error> {
+    match pat {
+        syn::Pat::Lit(syn::PatLit {
+            lit: syn::Lit::Str(name),
+            ..
+        }) => {
+            names.push(name.clone());
+            Ok(false)
+        }
+        syn::Pat::Or(pat_or) => {
+            for case in pat_or.cases.iter() {
+                if signal_names(case, names)? {
+                    return Err(Error::new_spanned(case, "`_` cannot be combined with signal names"));
+                }
+            }
+            Ok(false)
+        }
+        syn::Pat::Wild(_) => Ok(true),
+        _ => Err(Error::new_spanned(pat, "match_signal! arms must be string literals or `_`")),
+    }
+}
+
+fn escape_format(message: &str) -> String {
+    message.replace('{', "{{").replace('}', "}}")
+}
+
+impl Input {
+    pub fn impl_match_signal(&self) -> Result<proc_macro2::TokenStream, Error> {
+        let Self {
+            msg,
+            factories_type,
+            factory_ident,
+            arms,
+        } = self;
+        let factory_description = format!("{}::{}", quote!(#factories_type).to_string().replace(' ', ""), factory_ident);
+
+        let mut all_names = Vec::new();
+        let mut covered_names = Vec::new();
+        let mut has_wildcard = false;
+        for arm in arms.iter() {
+            let mut names = Vec::new();
+            if signal_names(&arm.pat, &mut names)? {
+                if has_wildcard {
+                    return Err(Error::new_spanned(&arm.pat, "only one `_` arm is allowed"));
+                }
+                has_wildcard = true;
+            }
+            if arm.guard.is_none() {
+                covered_names.extend(names.iter().cloned());
+            }
+            all_names.extend(names);
+        }
+
+        let declared = crate::ui_xml::factory_signals_const_ident(factory_ident);
+        let existence_checks = all_names.iter().map(|name| {
+            let message = format!("Signal {:?} is not declared for {}", name.value(), factory_description);
+            let message = escape_format(&message);
+            quote_spanned! {name.span()=>
+                assert!(woab::signal_names_contain(declared, #name), #message);
+            }
+        });
+        let coverage_message = escape_format(&format!(
+            "match_signal! does not cover all the signals declared for {}",
+            factory_description
+        ));
+
+        let wildcard_arm = if has_wildcard {
+            quote! {}
+        } else {
+            quote! {
+                _ => #msg.cant_handle_expecting(&[#(#all_names),*])?,
+            }
+        };
+
+        Ok(quote! {
+            {
+                const _: () = {
+                    let declared = <#factories_type>::#declared;
+                    #(#existence_checks)*
+                    assert!(woab::signal_names_cover(&[#(#covered_names),*], declared), #coverage_message);
+                };
+                match #msg.name() {
+                    #(#arms)*
+                    #wildcard_arm
+                }
+            }
+        })
+    }
+}
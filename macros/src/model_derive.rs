@@ -0,0 +1,99 @@
+use crate::util::{iter_attrs_parameters, path_to_single_string};
+use quote::quote;
+use syn::parse::Error;
+
+pub fn impl_model_derive(ast: &syn::DeriveInput) -> Result<proc_macro2::TokenStream, Error> {
+    let fields = if let syn::Data::Struct(syn::DataStruct {
+        fields: syn::Fields::Named(fields),
+        ..
+    }) = &ast.data
+    {
+        fields
+    } else {
+        return Err(Error::new_spanned(ast, "Model only supports structs with named fields"));
+    };
+    let struct_ident = &ast.ident;
+
+    let mut widgets_type = None;
+    iter_attrs_parameters(&ast.attrs, "model", |attr_name, value| {
+        match path_to_single_string(&attr_name)?.as_str() {
+            "widgets" => match value {
+                Some(syn::Expr::Path(path)) => {
+                    widgets_type = Some(path.path);
+                }
+                None => return Err(Error::new_spanned(attr_name, "attribute `widgets` must have a value")),
+                value => return Err(Error::new_spanned(value, "attribute `widgets` must be a type")),
+            },
+            _ => {
+                return Err(Error::new_spanned(attr_name, "unknown attribute"));
+            }
+        }
+        Ok(())
+    })?;
+    let widgets_type =
+        widgets_type.ok_or_else(|| Error::new_spanned(ast, "#[model(widgets = ...)] is mandatory when deriving Model"))?;
+    let with_suffix = |suffix: &str| {
+        let mut path = widgets_type.clone();
+        let last_segment = path.segments.last_mut().unwrap();
+        last_segment.ident = syn::Ident::new(&format!("{}{}", last_segment.ident, suffix), last_segment.ident.span());
+        path
+    };
+    let setter_type = with_suffix("PropSetter");
+    let getter_type = with_suffix("PropGetter");
+
+    let mut field_idents = Vec::new();
+    for field in fields.named.iter() {
+        let mut skip = false;
+        iter_attrs_parameters(&field.attrs, "model", |attr_name, value| {
+            match path_to_single_string(&attr_name)?.as_str() {
+                "skip" => {
+                    if value.is_some() {
+                        return Err(Error::new_spanned(value, "attribute `skip` cannot have a value"));
+                    }
+                    skip = true;
+                }
+                _ => {
+                    return Err(Error::new_spanned(attr_name, "unknown attribute"));
+                }
+            }
+            Ok(())
+        })?;
+        if !skip {
+            field_idents.push(field.ident.as_ref().unwrap());
+        }
+    }
+
+    Ok(quote! {
+        impl woab::model::Model for #struct_ident {
+            type Widgets = #widgets_type;
+
+            fn apply_to_widgets(&self, widgets: &Self::Widgets) {
+                widgets.set_props(&#setter_type {
+                    #(#field_idents: woab::model::AsSetter::as_setter(&self.#field_idents),)*
+                });
+            }
+
+            fn update_from_widgets(&mut self, widgets: &Self::Widgets) {
+                let #getter_type {
+                    #(#field_idents,)*
+                    ..
+                } = widgets.get_props();
+                #(self.#field_idents = core::convert::Into::into(#field_idents);)*
+            }
+
+            fn connect_widgets_changes(
+                widgets: &Self::Widgets,
+                callback: std::rc::Rc<dyn Fn()>,
+            ) -> Vec<(glib::Object, glib::SignalHandlerId)> {
+                let mut connections = Vec::new();
+                #(
+                    connections.extend(woab::prop_sync::ConnectChanges::connect_changes(
+                        &widgets.#field_idents,
+                        callback.clone(),
+                    ));
+                )*
+                connections
+            }
+        }
+    })
+}
@@ -1,3 +1,4 @@
+use crate::util::option_inner_type;
 use quote::quote;
 use syn::parse::Error;
 
@@ -40,8 +41,14 @@ impl Input {
         for param in self.params.iter().rev() {
             result = match param {
                 SingleParam::Extract { pat, ty } => {
-                    quote! {
-                        (#pat, core::marker::PhantomData::<#ty>, #result)
+                    if let Some(inner_ty) = option_inner_type(ty) {
+                        quote! {
+                            ((#pat, core::marker::PhantomData::<#inner_ty>), #result)
+                        }
+                    } else {
+                        quote! {
+                            (#pat, core::marker::PhantomData::<#ty>, #result)
+                        }
                     }
                 }
                 SingleParam::Ignore { pat } => {
@@ -1,4 +1,4 @@
-use crate::util::{iter_attrs_parts, path_to_single_string};
+use crate::util::{iter_attrs_parts, option_inner_type, path_to_single_string};
 use quote::{quote, quote_spanned};
 use syn::parse::Error;
 use syn::spanned::Spanned;
@@ -13,11 +13,26 @@ pub fn impl_prop_sync_derive(ast: &syn::DeriveInput) -> Result<proc_macro2::Toke
     } else {
         return Err(Error::new_spanned(ast, "PropSync only supports structs with named fields"));
     };
+    let mut notify = false;
+    iter_attrs_parts(&ast.attrs, "prop_sync", |expr| {
+        match expr {
+            syn::Expr::Path(path) if path.path.is_ident("notify") => {
+                notify = true;
+            }
+            _ => {
+                return Err(Error::new_spanned(expr, "unknown attribute"));
+            }
+        }
+        Ok(())
+    })?;
     let mut fields_to_sync = Vec::new();
     for field in fields.named.iter() {
         let mut getter = false;
         let mut setter = false;
         let mut field_property = None;
+        let mut field_choice = None;
+        let mut accessible = Vec::new();
+        let mut setting = None;
         iter_attrs_parts(&field.attrs, "prop_sync", |expr| {
             match expr {
                 syn::Expr::Path(path) => match path_to_single_string(&path.path)?.as_str() {
@@ -27,35 +42,74 @@ pub fn impl_prop_sync_derive(ast: &syn::DeriveInput) -> Result<proc_macro2::Toke
                     "set" => {
                         setter = true;
                     }
+                    attr @ ("accessible_label" | "accessible_description" | "accessible_value_text") => {
+                        accessible.push(syn::Ident::new(attr, path.span()));
+                    }
                     _ => {
                         return Err(Error::new_spanned(path, "unknown attribute"));
                     }
                 },
-                syn::Expr::Cast(syn::ExprCast { expr, ty, .. }) => {
-                    if let syn::Expr::Lit(syn::ExprLit {
+                syn::Expr::Assign(syn::ExprAssign { left, right, .. }) => match (*left, *right) {
+                    (
+                        syn::Expr::Path(path),
+                        syn::Expr::Lit(syn::ExprLit {
+                            lit: syn::Lit::Str(key), ..
+                        }),
+                    ) if path.path.is_ident("setting") => {
+                        setting = Some(key);
+                    }
+                    (left, _) => {
+                        return Err(Error::new_spanned(left, "unknown attribute"));
+                    }
+                },
+                syn::Expr::Cast(syn::ExprCast { expr, ty, .. }) => match *expr {
+                    syn::Expr::Lit(syn::ExprLit {
                         attrs: _,
                         lit: syn::Lit::Str(property),
-                    }) = *expr
-                    {
+                    }) => {
                         field_property = Some((property, *ty));
-                    } else {
+                    }
+                    syn::Expr::Path(path) if path.path.is_ident("choice") => {
+                        field_choice = Some(*ty);
+                    }
+                    expr => {
                         return Err(Error::new_spanned(
                             expr,
-                            "expected a string literal (representing a GTK property)",
+                            "expected a string literal (representing a GTK property) or `choice`",
                         ));
                     }
-                }
+                },
                 _ => {
                     return Err(Error::new_spanned(expr, "illegal attribute option"));
                 }
             }
             Ok(())
         })?;
-        if getter || setter {
+        if field_property.is_some() && field_choice.is_some() {
+            return Err(Error::new_spanned(field, "a property and `choice` are mutually exclusive"));
+        }
+        if !accessible.is_empty() && !setter {
+            return Err(Error::new_spanned(
+                field,
+                "accessible properties can only be synced with `set`",
+            ));
+        }
+        if let Some(setting) = &setting {
+            if field_property.is_none() {
+                return Err(Error::new_spanned(
+                    setting,
+                    "binding to a setting requires a property (`#[prop_sync(\"property-name\" as Type, ...)]`)",
+                ));
+            }
+        }
+        if getter || setter || setting.is_some() {
             fields_to_sync.push(FieldToSync {
                 ident: field.ident.as_ref().unwrap(),
                 ty: &field.ty,
                 property: field_property,
+                choice: field_choice,
+                accessible,
+                setting,
                 getter,
                 setter,
             });
@@ -63,9 +117,17 @@ pub fn impl_prop_sync_derive(ast: &syn::DeriveInput) -> Result<proc_macro2::Toke
     }
     let setter = gen_setter(ast, &fields_to_sync)?;
     let getter = gen_getter(ast, &fields_to_sync)?;
+    let notifier = if notify {
+        gen_notifier(ast, &fields_to_sync)
+    } else {
+        quote!()
+    };
+    let settings_binder = gen_settings_binder(ast, &fields_to_sync);
     Ok(quote! {
         #setter
         #getter
+        #notifier
+        #settings_binder
     })
 }
 
@@ -74,6 +136,11 @@ struct FieldToSync<'a> {
     ident: &'a syn::Ident,
     ty: &'a syn::Type,
     property: Option<(syn::LitStr, syn::Type)>,
+    choice: Option<syn::Type>,
+    /// `accessible_label`, `accessible_description` and/or `accessible_value_text`.
+    accessible: Vec<syn::Ident>,
+    /// The GSettings key from `setting = "..."`.
+    setting: Option<syn::LitStr>,
     getter: bool,
     setter: bool,
 }
@@ -99,7 +166,14 @@ fn gen_setter(ast: &syn::DeriveInput, fields: &[FieldToSync]) -> Result<proc_mac
         }
         let ident = field.ident;
         let field_type = field.ty;
-        if let Some((prop, ty)) = &field.property {
+        if let Some(choice) = &field.choice {
+            struct_fields.push(quote! {
+                #ident: #choice
+            });
+            prop_assignment.push(quote! {
+                woab::choices::ChoicesWidget::set_choice(&self.#ident, &setter.#ident);
+            });
+        } else if let Some((prop, ty)) = &field.property {
             if let syn::Type::Reference(ty_ref) = ty {
                 let mut ty_ref = ty_ref.clone();
                 ty_ref.lifetime = Some(
@@ -110,6 +184,16 @@ fn gen_setter(ast: &syn::DeriveInput, fields: &[FieldToSync]) -> Result<proc_mac
                 struct_fields.push(quote! {
                     #ident: #ty_ref
                 });
+            } else if let Some(syn::Type::Reference(ty_ref)) = option_inner_type(ty) {
+                let mut ty_ref = ty_ref.clone();
+                ty_ref.lifetime = Some(
+                    lifetime
+                        .get_or_insert_with(|| syn::Lifetime::new("'a", proc_macro2::Span::call_site()))
+                        .clone(),
+                );
+                struct_fields.push(quote! {
+                    #ident: Option<#ty_ref>
+                });
             } else {
                 struct_fields.push(quote! {
                     #ident: #ty
@@ -130,6 +214,25 @@ fn gen_setter(ast: &syn::DeriveInput, fields: &[FieldToSync]) -> Result<proc_mac
                 #as_trait::set_props(&self.#ident, &setter.#ident);
             });
         }
+        for accessible in field.accessible.iter() {
+            let lifetime = lifetime.get_or_insert_with(|| syn::Lifetime::new("'a", proc_macro2::Span::call_site()));
+            let setter_field = syn::Ident::new(&format!("{}_{}", ident, accessible), ident.span());
+            let property = match accessible.to_string().as_str() {
+                "accessible_label" => quote!(Label),
+                "accessible_description" => quote!(Description),
+                "accessible_value_text" => quote!(ValueText),
+                _ => unreachable!(),
+            };
+            struct_fields.push(quote! {
+                #setter_field: &#lifetime str
+            });
+            prop_assignment.push(quote_spanned! { field_type.span() =>
+                gtk4::prelude::AccessibleExtManual::update_property(
+                    &self.#ident,
+                    &[gtk4::accessible::Property::#property(setter.#setter_field)],
+                );
+            });
+        }
     }
 
     let lifetime_for_trait = if let Some(lifetime) = &lifetime {
@@ -171,6 +274,7 @@ fn gen_getter(ast: &syn::DeriveInput, fields: &[FieldToSync]) -> Result<proc_mac
 
     let mut struct_fields = Vec::new();
     let mut field_from_prop = Vec::new();
+    let mut try_field_from_prop = Vec::new();
 
     for field in fields.iter() {
         if !field.getter {
@@ -178,19 +282,26 @@ fn gen_getter(ast: &syn::DeriveInput, fields: &[FieldToSync]) -> Result<proc_mac
         }
         let ident = field.ident;
         let field_type = field.ty;
-        if let Some((prop, ty)) = &field.property {
-            if let syn::Type::Reference(ty_ref) = ty {
-                let ty = &ty_ref.elem;
-                struct_fields.push(quote! {
-                    #ident: <#ty as std::borrow::ToOwned>::Owned
-                });
-            } else {
-                struct_fields.push(quote! {
-                    #ident: #ty
-                });
-            }
+        if let Some(choice) = &field.choice {
+            struct_fields.push(quote! {
+                #ident: Option<#choice>
+            });
+            field_from_prop.push(quote! {
+                #ident: woab::choices::ChoicesWidget::choice::<#choice>(&self.#ident)
+            });
+            try_field_from_prop.push(quote! {
+                #ident: woab::choices::ChoicesWidget::choice::<#choice>(&self.#ident)
+            });
+        } else if let Some((prop, ty)) = &field.property {
+            let owned_ty = owned_property_type(ty);
+            struct_fields.push(quote! {
+                #ident: #owned_ty
+            });
             field_from_prop.push(quote! {
-                #ident: glib::object::ObjectExt::property::<#ty>(&self.#ident, #prop)
+                #ident: glib::object::ObjectExt::property::<#owned_ty>(&self.#ident, #prop)
+            });
+            try_field_from_prop.push(quote! {
+                #ident: woab::prop_sync::try_get_property::<#owned_ty>(&self.#ident, #prop)?
             });
         } else {
             let as_trait = quote_spanned! { field_type.span() =>
@@ -202,6 +313,9 @@ fn gen_getter(ast: &syn::DeriveInput, fields: &[FieldToSync]) -> Result<proc_mac
             field_from_prop.push(quote_spanned! { field_type.span() =>
                 #ident: #as_trait::get_props(&self.#ident)
             });
+            try_field_from_prop.push(quote_spanned! { field_type.span() =>
+                #ident: #as_trait::try_get_props(&self.#ident)?
+            });
         }
     }
 
@@ -218,12 +332,103 @@ fn gen_getter(ast: &syn::DeriveInput, fields: &[FieldToSync]) -> Result<proc_mac
                     #(#field_from_prop),*
                 }
             }
+
+            fn try_get_props(&self) -> woab::Result<Self::GetterType> {
+                Ok(#getter_name {
+                    #(#try_field_from_prop),*
+                })
+            }
         }
 
         impl #struct_name {
             #vis fn get_props(&self) -> <Self as woab::prop_sync::GetProps>::GetterType {
                 <Self as woab::prop_sync::GetProps>::get_props(self)
             }
+
+            #vis fn try_get_props(&self) -> woab::Result<<Self as woab::prop_sync::GetProps>::GetterType> {
+                <Self as woab::prop_sync::GetProps>::try_get_props(self)
+            }
         }
     })
 }
+
+fn gen_notifier(ast: &syn::DeriveInput, fields: &[FieldToSync]) -> proc_macro2::TokenStream {
+    let struct_name = &ast.ident;
+    let vis = &ast.vis;
+
+    let idents = fields.iter().filter(|f| f.getter).map(|f| f.ident).collect::<Vec<_>>();
+    let signal_names = idents.iter().map(|ident| syn::LitStr::new(&ident.to_string(), ident.span()));
+
+    quote! {
+        impl woab::prop_sync::ConnectChanges for #struct_name {
+            fn connect_changes(
+                &self,
+                callback: std::rc::Rc<dyn Fn()>,
+            ) -> Vec<(glib::Object, glib::SignalHandlerId)> {
+                let mut connections = Vec::new();
+                #(
+                    connections.extend(woab::prop_sync::ConnectChanges::connect_changes(
+                        &self.#idents,
+                        callback.clone(),
+                    ));
+                )*
+                connections
+            }
+        }
+
+        impl #struct_name {
+            #vis fn connect_changes(
+                &self,
+                target: impl woab::IntoGenerateRoutingGtkHandler,
+            ) -> Vec<(glib::Object, glib::SignalHandlerId)> {
+                let generator = woab::IntoGenerateRoutingGtkHandler::into_generate_routing_gtk_handler(target);
+                let mut connections = Vec::new();
+                #(
+                    connections.extend(woab::prop_sync::connect_changes_to_signal(
+                        &self.#idents,
+                        woab::GenerateRoutingGtkHandler::raw_signal_callback(&generator, #signal_names),
+                    ));
+                )*
+                connections
+            }
+        }
+    }
+}
+
+fn gen_settings_binder(ast: &syn::DeriveInput, fields: &[FieldToSync]) -> proc_macro2::TokenStream {
+    let bindings = fields
+        .iter()
+        .filter_map(|field| {
+            let ident = field.ident;
+            let key = field.setting.as_ref()?;
+            let (property, _) = field.property.as_ref()?;
+            Some(quote!((#key, &self.#ident, #property)))
+        })
+        .collect::<Vec<_>>();
+    if bindings.is_empty() {
+        return quote!();
+    }
+    let struct_name = &ast.ident;
+    let vis = &ast.vis;
+    quote! {
+        impl #struct_name {
+            #vis fn bind_settings(&self, settings: &gio::Settings) -> woab::Result<()> {
+                woab::bind_settings(settings, &[#(#bindings),*])
+            }
+        }
+    }
+}
+
+/// The type used for getting a property - references (`&T` and `Option<&T>`) are replaced with
+/// their owned versions.
+fn owned_property_type(ty: &syn::Type) -> proc_macro2::TokenStream {
+    if let syn::Type::Reference(ty_ref) = ty {
+        let ty = &ty_ref.elem;
+        quote!(<#ty as std::borrow::ToOwned>::Owned)
+    } else if let Some(syn::Type::Reference(ty_ref)) = option_inner_type(ty) {
+        let ty = &ty_ref.elem;
+        quote!(Option<<#ty as std::borrow::ToOwned>::Owned>)
+    } else {
+        quote!(#ty)
+    }
+}
@@ -18,30 +18,149 @@ pub fn impl_removable_derive(ast: &syn::DeriveInput) -> Result<proc_macro2::Toke
         }
     }
 
-    let (widget_to_remove, container_type) = removable_attr
+    let (widget_to_remove, container_type, children, on_destroy, signal_scope) = removable_attr
         .ok_or_else(|| Error::new_spanned(ast, "#[removable(...)] is mandatory when deriving Removable"))?
         .parse_args_with(|p: syn::parse::ParseStream| {
             let widget_to_remove: syn::Expr = p.parse()?;
             p.parse::<syn::token::In>()?;
             let container_type: syn::Type = p.parse()?;
-            Ok((widget_to_remove, container_type))
+            let mut children = None;
+            let mut on_destroy = false;
+            let mut signal_scope = None;
+            while !p.is_empty() {
+                p.parse::<syn::token::Comma>()?;
+                if p.is_empty() {
+                    break;
+                }
+                let param_name: syn::Ident = p.parse()?;
+                match param_name.to_string().as_str() {
+                    "children" => {
+                        if children.is_some() {
+                            return Err(Error::new_spanned(param_name, "`children` can only be specified once"));
+                        }
+                        p.parse::<syn::token::Eq>()?;
+                        children = Some(p.parse::<syn::Expr>()?);
+                    }
+                    "signal_scope" => {
+                        if signal_scope.is_some() {
+                            return Err(Error::new_spanned(param_name, "`signal_scope` can only be specified once"));
+                        }
+                        p.parse::<syn::token::Eq>()?;
+                        signal_scope = Some(p.parse::<syn::Expr>()?);
+                    }
+                    "on_destroy" => {
+                        if on_destroy {
+                            return Err(Error::new_spanned(param_name, "`on_destroy` can only be specified once"));
+                        }
+                        on_destroy = true;
+                    }
+                    _ => return Err(Error::new_spanned(param_name, "Unsupported parameter")),
+                }
+            }
+            Ok((widget_to_remove, container_type, children, on_destroy, signal_scope))
         })?;
 
+    let clear_signal_scope = signal_scope.map(|signal_scope| {
+        quote! {
+            woab::SignalScope::clear(&mut #signal_scope);
+        }
+    });
+
+    let remove_widget = quote! {
+        use gtk4::prelude::*;
+        use actix::prelude::*;
+
+        #clear_signal_scope
+
+        let widget = &#widget_to_remove;
+        let removed = if let Some(parent) = widget.parent() {
+            match parent.downcast::<#container_type>() {
+                Ok(parent) => {
+                    let widget = widget.clone();
+                    parent.remove(&widget);
+                }
+                // Containers like `gtk4::ListBox` and `gtk4::FlowBox` wrap children that are not
+                // rows in rows of their own - so the row is what needs to be removed.
+                Err(row) => {
+                    let parent = row.parent().and_downcast::<#container_type>().unwrap();
+                    parent.remove(&row);
+                }
+            }
+            true
+        } else {
+            false
+        };
+        ctx.stop();
+        removed
+    };
+
+    let on_destroy_impl = if on_destroy {
+        quote! {
+            impl #type_ident {
+                /// Remove the actor when GTK destroys its widget, or detaches it from its window.
+                ///
+                /// Call this from the actor's `started` method.
+                fn remove_on_destroy(&self, ctx: &mut <Self as actix::Actor>::Context) -> Vec<(glib::Object, glib::SignalHandlerId)> {
+                    use actix::prelude::*;
+
+                    woab::bind_lifetime(&#widget_to_remove, ctx.address())
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    if let Some(children) = children {
+        return Ok(quote! {
+            #on_destroy_impl
+
+            impl #type_ident {
+                fn __woab_remove_widget(&mut self, ctx: &mut <Self as actix::Actor>::Context) -> bool {
+                    #remove_widget
+                }
+            }
+
+            impl actix::Handler<woab::Remove> for #type_ident {
+                type Result = ();
+
+                fn handle(&mut self, _: woab::Remove, ctx: &mut Self::Context) -> Self::Result {
+                    use actix::prelude::*;
+
+                    let removal = actix::Handler::handle(self, woab::RemoveAndConfirm, ctx);
+                    ctx.wait(removal.map(|_, _, _| ()));
+                }
+            }
+
+            impl actix::Handler<woab::RemoveAndConfirm> for #type_ident {
+                type Result = actix::ResponseActFuture<Self, bool>;
+
+                fn handle(&mut self, _: woab::RemoveAndConfirm, _ctx: &mut Self::Context) -> Self::Result {
+                    use actix::prelude::*;
+
+                    let children_removed = woab::RemovableChildren::remove_all(&mut #children);
+                    Box::pin(actix::fut::wrap_future::<_, Self>(children_removed).map(|(), act, ctx| act.__woab_remove_widget(ctx)))
+                }
+            }
+        });
+    }
+
     Ok(quote! {
+        #on_destroy_impl
+
         impl actix::Handler<woab::Remove> for #type_ident {
             type Result = ();
 
             fn handle(&mut self, _: woab::Remove, ctx: &mut Self::Context) -> Self::Result {
-                use gtk4::prelude::*;
-                use actix::prelude::*;
+                actix::Handler::handle(self, woab::RemoveAndConfirm, ctx);
+            }
+        }
 
-                let widget = &#widget_to_remove;
-                if let Some(parent) = widget.parent() {
-                    let parent = parent.downcast::<#container_type>().unwrap();
-                    let widget = widget.clone();
-                    parent.remove(&widget);
-                    ctx.stop();
-                }
+        impl actix::Handler<woab::RemoveAndConfirm> for #type_ident {
+            type Result = bool;
+
+            fn handle(&mut self, _: woab::RemoveAndConfirm, ctx: &mut Self::Context) -> Self::Result {
+                #remove_widget
             }
         }
     })
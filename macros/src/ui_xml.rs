@@ -0,0 +1,106 @@
+use std::path::PathBuf;
+
+use quote::quote;
+use syn::parse::Error;
+use woab_build::ui_xml::{ParsedUi, UiSignal};
+
+/// A UI XML file, read during compilation.
+pub struct UiXml {
+    pub path: PathBuf,
+    pub signals: Vec<UiSignal>,
+}
+
+impl UiXml {
+    /// Read a UI XML file. The path is relative to the crate's root (where the `Cargo.toml` is).
+    pub fn load(path: &syn::LitStr) -> Result<Self, Error> {
+        let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").map_err(|err| Error::new_spanned(path, err))?;
+        let full_path = PathBuf::from(manifest_dir).join(path.value());
+        let xml = std::fs::read_to_string(&full_path)
+            .map_err(|err| Error::new_spanned(path, format!("Cannot read {}: {}", full_path.display(), err)))?;
+        let ui = ParsedUi::parse(&xml)
+            .map_err(|err| Error::new_spanned(path, format!("Cannot parse {}: {}", full_path.display(), err)))?;
+        Ok(Self {
+            path: full_path,
+            signals: ui.signals,
+        })
+    }
+
+    /// All the signal handlers declared in the file, without duplicates.
+    pub fn handlers(&self) -> Vec<&str> {
+        let mut result = Vec::new();
+        for signal in self.signals.iter() {
+            if !result.contains(&signal.handler.as_str()) {
+                result.push(signal.handler.as_str());
+            }
+        }
+        result
+    }
+
+    /// The signal handlers that belong to a factory, without duplicates.
+    ///
+    /// A signal belongs to the factory of the innermost object it is declared inside that has one
+    /// of the `factory_ids` - the same way `woab::dissect_builder_xml` splits the XML.
+    pub fn factory_handlers<'a>(&'a self, ids: &[String], factory_ids: &[String]) -> Vec<&'a str> {
+        let mut result = Vec::new();
+        for signal in self.signals.iter() {
+            let owner = signal.enclosing_ids.iter().rev().find(|id| factory_ids.contains(id));
+            if owner.is_some_and(|owner| ids.contains(owner)) && !result.contains(&signal.handler.as_str()) {
+                result.push(signal.handler.as_str());
+            }
+        }
+        result
+    }
+
+    /// Tokens that make the compiler rebuild the crate when the file changes.
+    pub fn track_changes(&self) -> proc_macro2::TokenStream {
+        let path = self.path.to_string_lossy();
+        quote! {
+            const _: &[u8] = include_bytes!(#path);
+        }
+    }
+}
+
+/// The name of the constant generated for a signal handler.
+pub fn signal_const_name(handler: &str) -> String {
+    let mut result: String = handler
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if !result.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+        result.insert(0, '_');
+    }
+    result
+}
+
+/// The name of the hidden constant that lists the signals of a factory.
+pub fn factory_signals_const_ident(field_ident: &syn::Ident) -> syn::Ident {
+    syn::Ident::new(
+        &format!("__WOAB_SIGNALS_{}", signal_const_name(&field_ident.to_string())),
+        field_ident.span(),
+    )
+}
+
+/// The name of the module generated for the signal constants of a factories struct - e.g.
+/// `my_factories_signals` for `MyFactories`.
+pub fn signals_module_ident(struct_ident: &syn::Ident) -> syn::Ident {
+    let mut name = String::new();
+    let mut prev_is_lower = false;
+    for c in struct_ident.to_string().trim_start_matches("r#").chars() {
+        if c.is_ascii_uppercase() {
+            if prev_is_lower {
+                name.push('_');
+            }
+            name.push(c.to_ascii_lowercase());
+        } else {
+            name.push(c);
+        }
+        prev_is_lower = c.is_ascii_lowercase() || c.is_ascii_digit();
+    }
+    syn::Ident::new(&format!("{}_signals", name), struct_ident.span())
+}
@@ -0,0 +1,104 @@
+use crate::util::{iter_attrs_parameters, path_to_single_string};
+use quote::quote;
+use syn::parse::Error;
+
+pub fn impl_validate_derive(ast: &syn::DeriveInput) -> Result<proc_macro2::TokenStream, Error> {
+    let fields = if let syn::Data::Struct(syn::DataStruct {
+        fields: syn::Fields::Named(fields),
+        ..
+    }) = &ast.data
+    {
+        fields
+    } else {
+        return Err(Error::new_spanned(ast, "Validate only supports structs with named fields"));
+    };
+    let struct_ident = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let mut checks = Vec::new();
+    let mut field_widget_arms = Vec::new();
+    for field in fields.named.iter() {
+        let field_ident = field.ident.as_ref().unwrap();
+        let field_name = syn::LitStr::new(&field_ident.to_string(), field_ident.span());
+        iter_attrs_parameters(&field.attrs, "validate", |attr_name, value| {
+            let rule = path_to_single_string(&attr_name)?;
+            let check = match (rule.as_str(), value) {
+                ("required", None) => quote! {
+                    if woab::validation::IsBlank::is_blank(&getter.#field_ident) {
+                        errors.push(woab::validation::FieldError::new(#field_name, "This field is required"));
+                    }
+                },
+                ("range", Some(range)) => {
+                    let message = format!("Must be in the range {}", quote!(#range));
+                    quote! {
+                        if !core::ops::RangeBounds::contains(&(#range), &getter.#field_ident) {
+                            errors.push(woab::validation::FieldError::new(#field_name, #message));
+                        }
+                    }
+                }
+                (
+                    "regex",
+                    Some(syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(pattern),
+                        ..
+                    })),
+                ) => {
+                    let message = format!("Must match {}", pattern.value());
+                    quote! {
+                        if !woab::validation::matches_regex(&getter.#field_ident, #pattern) {
+                            errors.push(woab::validation::FieldError::new(#field_name, #message));
+                        }
+                    }
+                }
+                ("regex", Some(value)) => {
+                    return Err(Error::new_spanned(value, "attribute `regex` must be a string literal"));
+                }
+                ("custom", Some(validator)) => quote! {
+                    if let Err(message) = (#validator)(&getter.#field_ident) {
+                        errors.push(woab::validation::FieldError::new(#field_name, message));
+                    }
+                },
+                ("required", Some(value)) => {
+                    return Err(Error::new_spanned(value, "attribute `required` cannot have a value"));
+                }
+                ("range" | "regex" | "custom", None) => {
+                    return Err(Error::new_spanned(
+                        attr_name,
+                        format!("attribute `{}` must have a value", rule),
+                    ));
+                }
+                _ => {
+                    return Err(Error::new_spanned(&attr_name, "unknown attribute"));
+                }
+            };
+            checks.push(check);
+            Ok(())
+        })?;
+        // Fields without rules still need an arm, for errors reported by the form's validators.
+        field_widget_arms.push(quote! {
+            #field_name => glib::object::Cast::downcast::<gtk4::Widget>(
+                glib::object::Cast::upcast::<glib::Object>(self.#field_ident.clone()),
+            ).ok(),
+        });
+    }
+
+    Ok(quote! {
+        impl #impl_generics woab::validation::Validate for #struct_ident #ty_generics #where_clause {
+            fn validate(&self) -> Vec<woab::validation::FieldError> {
+                #[allow(unused_variables)]
+                let getter = self.get_props();
+                #[allow(unused_mut)]
+                let mut errors = Vec::new();
+                #(#checks)*
+                errors
+            }
+
+            fn field_widget(&self, field: &str) -> Option<gtk4::Widget> {
+                match field {
+                    #(#field_widget_arms)*
+                    _ => None,
+                }
+            }
+        }
+    })
+}
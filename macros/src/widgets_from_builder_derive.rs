@@ -17,6 +17,7 @@ pub fn impl_widgets_from_builder_derive(ast: &syn::DeriveInput) -> Result<proc_m
         ));
     };
     let struct_ident = &ast.ident;
+    let mut root_field = None;
     let ctor_arms = fields
         .named
         .iter()
@@ -24,6 +25,9 @@ pub fn impl_widgets_from_builder_derive(ast: &syn::DeriveInput) -> Result<proc_m
             /* Handle renaming */
             let mut nested = false;
             let mut name = None;
+            let mut root = None;
+            let mut whole_builder = false;
+            let mut compute = None;
             iter_attrs_parameters(&field.attrs, "widget", |attr_name, value| {
                 match path_to_single_string(&attr_name)?.as_str() {
                     "nested" => {
@@ -42,6 +46,46 @@ pub fn impl_widgets_from_builder_derive(ast: &syn::DeriveInput) -> Result<proc_m
                         }
                         name = Some(value);
                     }
+                    "root" => {
+                        if root.is_some() {
+                            return Err(Error::new_spanned(attr_name, "attribute `root` can only be specified once"));
+                        }
+                        if value.is_some() {
+                            return Err(Error::new_spanned(value, "attribute `root` cannot have a value"));
+                        }
+                        root = Some(attr_name);
+                    }
+                    "builder" => {
+                        if whole_builder {
+                            return Err(Error::new_spanned(
+                                attr_name,
+                                "attribute `builder` can only be specified once",
+                            ));
+                        }
+                        if value.is_some() {
+                            return Err(Error::new_spanned(value, "attribute `builder` cannot have a value"));
+                        }
+                        whole_builder = true;
+                    }
+                    "compute" => {
+                        let value =
+                            value.ok_or_else(|| Error::new_spanned(&attr_name, "attribute `compute` must have a value"))?;
+                        if compute.is_some() {
+                            return Err(Error::new_spanned(value, "attribute `compute` can only be specified once"));
+                        }
+                        compute = Some(match value {
+                            syn::Expr::Lit(syn::ExprLit {
+                                lit: syn::Lit::Str(expr),
+                                ..
+                            }) => expr.parse::<syn::Expr>()?,
+                            _ => {
+                                return Err(Error::new_spanned(
+                                    value,
+                                    "`compute` attribute must have a string literal value",
+                                ))
+                            }
+                        });
+                    }
                     _ => {
                         return Err(Error::new_spanned(attr_name, "unknown attribute"));
                     }
@@ -51,12 +95,34 @@ pub fn impl_widgets_from_builder_derive(ast: &syn::DeriveInput) -> Result<proc_m
             if nested && name.is_some() {
                 return Err(Error::new_spanned(field, "`nested` and `name` are mutually exclusive"));
             }
+            if nested && root.is_some() {
+                return Err(Error::new_spanned(field, "`nested` and `root` are mutually exclusive"));
+            }
+            if whole_builder && (nested || name.is_some() || root.is_some()) {
+                return Err(Error::new_spanned(
+                    field,
+                    "`builder` cannot be combined with `nested`, `name` or `root`",
+                ));
+            }
+            if compute.is_some() && (nested || name.is_some() || whole_builder) {
+                return Err(Error::new_spanned(
+                    field,
+                    "`compute` cannot be combined with `nested`, `name` or `builder`",
+                ));
+            }
 
             let field_ident = field
                 .ident
                 .as_ref()
                 .ok_or_else(|| Error::new(field.span(), "Nameless field"))?;
 
+            if let Some(root) = root {
+                if root_field.is_some() {
+                    return Err(Error::new_spanned(root, "only one field can be the `root`"));
+                }
+                root_field = Some((field_ident, &field.ty));
+            }
+
             if nested {
                 // NOTE: Not using `?` because it `into`es the error and the type checker does not like that.
                 return Ok(quote! {
@@ -70,6 +136,17 @@ pub fn impl_widgets_from_builder_derive(ast: &syn::DeriveInput) -> Result<proc_m
             }
 
             let field_type = &field.ty;
+            if whole_builder {
+                return Ok(quote! {
+                    #field_ident: <#field_type as std::convert::From<gtk4::Builder>>::from(builder.clone()),
+                });
+            }
+            if let Some(compute) = compute {
+                return Ok(quote! {
+                    #field_ident: #compute,
+                });
+            }
+
             let ident_as_str = match name {
                 Some(syn::Expr::Lit(syn::ExprLit {
                     lit: syn::Lit::Str(name),
@@ -88,12 +165,23 @@ pub fn impl_widgets_from_builder_derive(ast: &syn::DeriveInput) -> Result<proc_m
                             actual_type: object.type_(),
                         }
                     } else {
-                        woab::Error::WidgetMissingInBuilder(#ident_as_str.to_owned())
+                        woab::Error::widget_missing_in_builder(builder, #ident_as_str)
                     }
                 })?,
             })
         })
         .collect::<Result<Vec<_>, Error>>()?;
+    let view_impl = root_field.map(|(field_ident, field_type)| {
+        quote! {
+            impl woab::View for #struct_ident {
+                type Root = #field_type;
+
+                fn root(&self) -> &Self::Root {
+                    &self.#field_ident
+                }
+            }
+        }
+    });
     Ok(quote! {
         impl std::convert::TryFrom<&gtk4::Builder> for #struct_ident {
             type Error = woab::Error;
@@ -112,5 +200,7 @@ pub fn impl_widgets_from_builder_derive(ast: &syn::DeriveInput) -> Result<proc_m
                 <Self as std::convert::TryFrom<&gtk4::Builder>>::try_from(&builder)
             }
         }
+
+        #view_impl
     })
 }
@@ -0,0 +1,150 @@
+use gtk4::prelude::*;
+
+/// The information shown by [`woab::show_about`](show_about).
+///
+/// Only the name is required - everything else is omitted from the about window when left at
+/// the default value.
+///
+/// ```no_run
+/// let info = woab::AboutInfo {
+///     name: "Example".to_owned(),
+///     version: Some(env!("CARGO_PKG_VERSION").to_owned()),
+///     authors: vec!["Jane Doe <jane@example.com>".to_owned()],
+///     license: Some(gtk4::License::MitX11),
+///     website: Some("https://example.com".to_owned()),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct AboutInfo {
+    /// The name of the application.
+    pub name: String,
+    pub version: Option<String>,
+    pub authors: Vec<String>,
+    /// One of the well known licenses. Ignored if [`license_text`](Self::license_text) is set.
+    pub license: Option<gtk4::License>,
+    /// The full text of a custom license.
+    pub license_text: Option<String>,
+    pub website: Option<String>,
+    /// A short description of the application.
+    pub comments: Option<String>,
+    pub copyright: Option<String>,
+    /// Looked up in the icon theme, like [`MainConfig::default_icon_name`](crate::MainConfig::default_icon_name).
+    pub icon_name: Option<String>,
+}
+
+#[cfg(feature = "adw")]
+type AboutWindow = adw::AboutWindow;
+
+#[cfg(not(feature = "adw"))]
+type AboutWindow = gtk4::AboutDialog;
+
+#[cfg(feature = "adw")]
+fn build_about_window(info: &AboutInfo) -> AboutWindow {
+    let window = adw::AboutWindow::builder()
+        .application_name(info.name.as_str())
+        .developers(info.authors.clone())
+        .build();
+    if let Some(version) = &info.version {
+        window.set_version(version);
+    }
+    if let Some(license_text) = &info.license_text {
+        window.set_license_type(gtk4::License::Custom);
+        window.set_license(license_text);
+    } else if let Some(license) = info.license {
+        window.set_license_type(license);
+    }
+    if let Some(website) = &info.website {
+        window.set_website(website);
+    }
+    if let Some(comments) = &info.comments {
+        window.set_comments(comments);
+    }
+    if let Some(copyright) = &info.copyright {
+        window.set_copyright(copyright);
+    }
+    if let Some(icon_name) = &info.icon_name {
+        window.set_application_icon(icon_name);
+    }
+    window
+}
+
+#[cfg(not(feature = "adw"))]
+fn build_about_window(info: &AboutInfo) -> AboutWindow {
+    let window = gtk4::AboutDialog::builder()
+        .program_name(info.name.as_str())
+        .authors(info.authors.clone())
+        .build();
+    window.set_version(info.version.as_deref());
+    if let Some(license_text) = &info.license_text {
+        // Also sets the license type to `Custom`
+        window.set_license(Some(license_text));
+    } else if let Some(license) = info.license {
+        window.set_license_type(license);
+    }
+    window.set_website(info.website.as_deref());
+    window.set_comments(info.comments.as_deref());
+    window.set_copyright(info.copyright.as_deref());
+    window.set_logo_icon_name(info.icon_name.as_deref());
+    window
+}
+
+/// Show an about window for the application.
+///
+/// The window is `gtk4::AboutDialog`, or `adw::AboutWindow` with the `adw` feature. It is modal
+/// and transient for the parent, and is destroyed when closed. If an about window is already
+/// open for the same parent, it is presented again instead of opening a second one.
+///
+/// To show the about window from the standard `app.about` action, use
+/// [`woab::add_about_action`](add_about_action) or [`MainConfig::about`](crate::MainConfig::about).
+///
+/// ```no_run
+/// # let window: gtk4::ApplicationWindow = panic!();
+/// woab::show_about(
+///     Some(&window),
+///     &woab::AboutInfo {
+///         name: "Example".to_owned(),
+///         ..Default::default()
+///     },
+/// );
+/// ```
+pub fn show_about(parent: Option<&impl IsA<gtk4::Window>>, info: &AboutInfo) -> gtk4::Window {
+    let parent = parent.map(|parent| parent.upcast_ref::<gtk4::Window>().clone());
+    if let Some(about_window) = parent.as_ref().and_then(|parent| parent.downcast_ref::<AboutWindow>()) {
+        // Usually happens with `app.about` while the about window is the active window
+        about_window.present();
+        return about_window.clone().upcast();
+    }
+    let existing = gtk4::Window::list_toplevels()
+        .into_iter()
+        .filter_map(|window| window.downcast::<AboutWindow>().ok())
+        .find(|window| window.transient_for() == parent);
+    let window = existing.unwrap_or_else(|| {
+        let window = build_about_window(info);
+        window.set_modal(true);
+        window.set_destroy_with_parent(true);
+        window.set_transient_for(parent.as_ref());
+        if let Some(parent) = &parent {
+            window.set_application(parent.application().as_ref());
+        }
+        window
+    });
+    window.present();
+    window.upcast()
+}
+
+/// Add the standard `app.about` action to the application, showing an about window with
+/// [`woab::show_about`](show_about).
+///
+/// The about window is transient for the application's active window.
+pub fn add_about_action(app: &gtk4::Application, info: AboutInfo) {
+    let action = gio::SimpleAction::new("about", None);
+    action.connect_activate({
+        let app = app.clone();
+        move |_, _| {
+            let window = show_about(app.active_window().as_ref(), &info);
+            window.set_application(Some(&app));
+        }
+    });
+    app.add_action(&action);
+}
@@ -0,0 +1,111 @@
+//! Create groups of GIO actions and route them to actors.
+//!
+//! See [`#[derive(woab::ActionGroup)]`](crate::ActionGroup) for usage instructions.
+
+use gtk4::prelude::*;
+
+/// A struct of `gio::SimpleAction`s that can be created and routed together.
+///
+/// Usually implemented with [`#[derive(woab::ActionGroup)]`](crate::ActionGroup).
+pub trait ActionGroup: Sized {
+    /// Create the actions, without routing them.
+    fn create_actions() -> Self;
+
+    /// All the actions of the group.
+    fn actions(&self) -> Vec<&gio::SimpleAction>;
+
+    /// Create the actions, add them to a new `gio::SimpleActionGroup`, and route them to an actor
+    /// like [`woab::route_action`](crate::route_action) does.
+    fn route_to(target: impl crate::IntoGenerateRoutingGtkHandler) -> RoutedActions<Self> {
+        let actions = Self::create_actions();
+        let group = gio::SimpleActionGroup::new();
+        let generator = target.into_generate_routing_gtk_handler();
+        let handlers = actions
+            .actions()
+            .into_iter()
+            .map(|action| {
+                group.add_action(action);
+                let signal = if action.state().is_some() {
+                    "change-state"
+                } else {
+                    "activate"
+                };
+                let handler_id =
+                    crate::GenerateRoutingGtkHandler::connect_local(&generator, action, signal, action.name().as_str());
+                (action.clone(), handler_id)
+            })
+            .collect();
+        RoutedActions {
+            actions,
+            group,
+            handlers,
+        }
+    }
+}
+
+/// An [`ActionGroup`] whose actions are routed to an actor, created by
+/// [`ActionGroup::route_to`].
+///
+/// Dereferences to the action group struct, so the individual actions can be accessed as its
+/// fields. Dropping it does not stop the routing.
+pub struct RoutedActions<T> {
+    actions: T,
+    group: gio::SimpleActionGroup,
+    handlers: Vec<(gio::SimpleAction, glib::SignalHandlerId)>,
+}
+
+impl<T> core::ops::Deref for RoutedActions<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.actions
+    }
+}
+
+impl<T> RoutedActions<T> {
+    /// The `gio::SimpleActionGroup` that contains all the actions.
+    pub fn group(&self) -> &gio::SimpleActionGroup {
+        &self.group
+    }
+
+    /// Add the action group to a widget, so that its actions can be used as `<prefix>.<action>`.
+    pub fn insert_into(&self, widget: &impl IsA<gtk4::Widget>, prefix: &str) {
+        widget.insert_action_group(prefix, Some(&self.group));
+    }
+
+    /// The ID of the signal handler that routes the action.
+    ///
+    /// Returns `None` if the action is not part of the group.
+    pub fn handler_id(&self, action: &gio::SimpleAction) -> Option<&glib::SignalHandlerId> {
+        self.handlers
+            .iter()
+            .find_map(|(routed_action, handler_id)| (routed_action == action).then_some(handler_id))
+    }
+
+    /// Stop routing the action until it is [unblocked](Self::unblock).
+    ///
+    /// Does nothing if the action is not part of the group.
+    pub fn block(&self, action: &gio::SimpleAction) {
+        if let Some(handler_id) = self.handler_id(action) {
+            action.block_signal(handler_id);
+        }
+    }
+
+    /// Resume routing an action that was [blocked](Self::block).
+    ///
+    /// Does nothing if the action is not part of the group.
+    pub fn unblock(&self, action: &gio::SimpleAction) {
+        if let Some(handler_id) = self.handler_id(action) {
+            action.unblock_signal(handler_id);
+        }
+    }
+}
+
+#[doc(hidden)] // for internal use by #[derive(ActionGroup)]
+pub fn new_action(name: &str, parameter_type: Option<glib::VariantType>, state: Option<glib::Variant>) -> gio::SimpleAction {
+    if let Some(state) = state {
+        gio::SimpleAction::new_stateful(name, parameter_type.as_deref(), &state)
+    } else {
+        gio::SimpleAction::new(name, parameter_type.as_deref())
+    }
+}
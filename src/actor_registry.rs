@@ -0,0 +1,61 @@
+use gtk4::prelude::*;
+
+const ACTOR_DATA_KEY: &str = "woab-actor";
+
+/// Register an actor as the one responsible for a widget.
+///
+/// Generic code (context menus, drag handlers, debugging tools...) can then use
+/// [`woab::actor_for`](actor_for) to find the actor from the widget, without having to pass the
+/// actor's address around. Registering another actor for the same widget replaces the previous
+/// registration.
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// # struct RowActor;
+/// # impl actix::Actor for RowActor { type Context = actix::Context<Self>; }
+/// # impl actix::Handler<woab::Signal> for RowActor {
+/// #     type Result = woab::SignalResult;
+/// #     fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result { msg.cant_handle() }
+/// # }
+/// # let row: gtk4::ListBoxRow = panic!();
+/// # let label_inside_row: gtk4::Label = panic!();
+/// let addr = RowActor.start();
+/// woab::register_actor(&row, addr.recipient());
+///
+/// // Elsewhere - the lookup also checks the ancestors of the widget:
+/// if let Some(actor) = woab::actor_for(&label_inside_row) {
+///     // ...
+/// }
+/// ```
+pub fn register_actor(widget: &impl IsA<gtk4::Widget>, actor: actix::Recipient<crate::Signal>) {
+    // SAFETY: the data under this key is only ever accessed as `actix::Recipient<crate::Signal>`.
+    unsafe {
+        widget.set_data(ACTOR_DATA_KEY, actor);
+    }
+}
+
+/// Remove the registration made by [`woab::register_actor`](register_actor).
+///
+/// Returns the actor that was registered for the widget (if there was one).
+pub fn unregister_actor(widget: &impl IsA<gtk4::Widget>) -> Option<actix::Recipient<crate::Signal>> {
+    // SAFETY: the data under this key is only ever accessed as `actix::Recipient<crate::Signal>`.
+    unsafe { widget.steal_data(ACTOR_DATA_KEY) }
+}
+
+/// Find the actor responsible for a widget.
+///
+/// Returns the actor registered with [`woab::register_actor`](register_actor) for the widget, or
+/// for its closest ancestor that has a registered actor.
+pub fn actor_for(widget: &impl IsA<gtk4::Widget>) -> Option<actix::Recipient<crate::Signal>> {
+    let mut widget = Some(widget.clone().upcast::<gtk4::Widget>());
+    while let Some(current) = widget {
+        // SAFETY: the data under this key is only ever accessed as `actix::Recipient<crate::Signal>`.
+        if let Some(actor) = unsafe { current.data::<actix::Recipient<crate::Signal>>(ACTOR_DATA_KEY) } {
+            // SAFETY: the pointer is valid as long as the widget holds the data, and we clone it
+            // right away.
+            return Some(unsafe { actor.as_ref() }.clone());
+        }
+        widget = current.parent();
+    }
+    None
+}
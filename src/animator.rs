@@ -0,0 +1,152 @@
+use core::cell::{Cell, RefCell};
+use core::time::Duration;
+use std::rc::Rc;
+
+use gtk4::prelude::*;
+
+/// A message sent by an [`Animator`] on each frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Step {
+    /// The time passed since the previous step.
+    ///
+    /// Time during which the animator was paused, or its widget was not mapped, is not counted -
+    /// the first step after resuming has a zero delta.
+    pub delta: Duration,
+}
+
+impl actix::Message for Step {
+    type Result = ();
+}
+
+struct AnimatorState {
+    widget: glib::WeakRef<gtk4::Widget>,
+    recipient: actix::Recipient<Step>,
+    tick_callback_id: RefCell<Option<gtk4::TickCallbackId>>,
+    // In microseconds, like the frame clock's frame time.
+    last_step_time: Cell<Option<i64>>,
+    min_step_interval: Cell<i64>,
+}
+
+impl AnimatorState {
+    fn tick(&self, widget: &gtk4::Widget, frame_clock: &gdk4::FrameClock) -> glib::ControlFlow {
+        if !self.recipient.connected() {
+            // GTK removes the callback by itself, so the guard must not remove it again.
+            self.tick_callback_id.take();
+            return glib::ControlFlow::Break;
+        }
+        if !widget.is_mapped() {
+            self.last_step_time.set(None);
+            return glib::ControlFlow::Continue;
+        }
+        let now = frame_clock.frame_time();
+        let delta = if let Some(last_step_time) = self.last_step_time.get() {
+            let delta = now - last_step_time;
+            if delta < self.min_step_interval.get() {
+                return glib::ControlFlow::Continue;
+            }
+            delta
+        } else {
+            0
+        };
+        self.last_step_time.set(Some(now));
+        self.recipient.do_send(Step {
+            delta: Duration::from_micros(delta.max(0) as u64),
+        });
+        glib::ControlFlow::Continue
+    }
+}
+
+/// Send a [`Step`] message to an actor on every frame of a widget.
+///
+/// The steps are driven by the widget's `gdk4::FrameClock`, so they are synchronized with the
+/// frames GTK draws. They are only sent while the widget is mapped (i.e. shown on the screen), so
+/// a minimized window or a hidden page of a `gtk4::Stack` does not run its animations. The
+/// animator stops when the guard is dropped, when the widget is destroyed, or when the actor
+/// stops.
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// struct CanvasActor {
+///     position: f64,
+///     animator: Option<woab::Animator>,
+/// }
+///
+/// impl actix::Actor for CanvasActor {
+///     type Context = actix::Context<Self>;
+///
+///     fn started(&mut self, ctx: &mut Self::Context) {
+///         # let drawing_area: gtk4::DrawingArea = panic!();
+///         self.animator = Some(woab::Animator::new(&drawing_area, ctx.address()).max_fps(30.0));
+///     }
+/// }
+///
+/// impl actix::Handler<woab::Step> for CanvasActor {
+///     type Result = ();
+///
+///     fn handle(&mut self, msg: woab::Step, _ctx: &mut Self::Context) -> Self::Result {
+///         self.position += 10.0 * msg.delta.as_secs_f64();
+///     }
+/// }
+/// ```
+#[must_use = "the animator stops when the guard is dropped"]
+pub struct Animator(Rc<AnimatorState>);
+
+impl Animator {
+    /// Start sending steps to the recipient on the widget's frames.
+    pub fn new(widget: &impl IsA<gtk4::Widget>, recipient: impl Into<actix::Recipient<Step>>) -> Self {
+        let animator = Self(Rc::new(AnimatorState {
+            widget: widget.upcast_ref().downgrade(),
+            recipient: recipient.into(),
+            tick_callback_id: RefCell::new(None),
+            last_step_time: Cell::new(None),
+            min_step_interval: Cell::new(0),
+        }));
+        animator.resume();
+        animator
+    }
+
+    /// Send at most `fps` steps per second.
+    ///
+    /// Frames that come too soon after the previous step are skipped, and their time is added to
+    /// the next step's delta.
+    pub fn max_fps(self, fps: f64) -> Self {
+        self.0.min_step_interval.set((1_000_000.0 / fps) as i64);
+        self
+    }
+
+    /// Stop sending steps until [`resume`](Self::resume) is called.
+    ///
+    /// While paused, the animator does not make GTK draw frames for the widget.
+    pub fn pause(&self) {
+        if let Some(tick_callback_id) = self.0.tick_callback_id.take() {
+            tick_callback_id.remove();
+        }
+        self.0.last_step_time.set(None);
+    }
+
+    /// Resume sending steps after [`pause`](Self::pause).
+    ///
+    /// Does nothing if the animator is not paused, or if the widget was destroyed.
+    pub fn resume(&self) {
+        if self.0.tick_callback_id.borrow().is_some() {
+            return;
+        }
+        let Some(widget) = self.0.widget.upgrade() else {
+            return;
+        };
+        let state = self.0.clone();
+        let tick_callback_id = widget.add_tick_callback(move |widget, frame_clock| state.tick(widget, frame_clock));
+        *self.0.tick_callback_id.borrow_mut() = Some(tick_callback_id);
+    }
+
+    /// Check if the animator is paused (or stopped).
+    pub fn is_paused(&self) -> bool {
+        self.0.tick_callback_id.borrow().is_none()
+    }
+}
+
+impl Drop for Animator {
+    fn drop(&mut self) {
+        self.pause();
+    }
+}
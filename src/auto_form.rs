@@ -0,0 +1,172 @@
+//! Build forms for editing structs at runtime.
+//!
+//! See [`#[derive(woab::AutoForm)]`](crate::AutoForm) for usage instructions.
+
+use gtk4::prelude::*;
+
+/// A struct that can be edited with a generated form.
+///
+/// Usually implemented with [`#[derive(woab::AutoForm)]`](crate::AutoForm).
+pub trait AutoForm: Sized {
+    /// The widgets of the form.
+    type Form: FormWidgets<Self>;
+
+    /// Create the form's widgets, in a `gtk4::Grid` of labeled rows.
+    ///
+    /// The widgets start with their default values - use [`FormWidgets::set`] to fill them.
+    fn build_form() -> Self::Form;
+}
+
+/// The widgets of a form created by [`AutoForm::build_form`].
+pub trait FormWidgets<T> {
+    /// The grid that contains all the labels and the input widgets.
+    fn grid(&self) -> &gtk4::Grid;
+
+    /// Read the values of the input widgets.
+    ///
+    /// Fields that are not part of the form are set to their default values.
+    fn get(&self) -> T;
+
+    /// Set the values of the input widgets.
+    fn set(&self, value: &T);
+}
+
+/// An input widget of a form, for editing values of type `T`.
+///
+/// Used by [`#[derive(woab::AutoForm)]`](crate::AutoForm) - implement it to use custom widgets in
+/// the generated forms.
+pub trait FormWidget<T>: IsA<gtk4::Widget> {
+    /// Create the widget.
+    ///
+    /// `range` and `step` are the values from the field's `#[form(...)]` attribute. Widgets that
+    /// have no use for them should ignore them.
+    fn create_form_widget(range: Option<(f64, f64)>, step: Option<f64>) -> Self;
+
+    /// The value currently in the widget.
+    fn form_value(&self) -> T;
+
+    /// Set the value of the widget.
+    fn set_form_value(&self, value: &T);
+}
+
+impl FormWidget<String> for gtk4::Entry {
+    fn create_form_widget(_range: Option<(f64, f64)>, _step: Option<f64>) -> Self {
+        gtk4::Entry::builder().hexpand(true).build()
+    }
+
+    fn form_value(&self) -> String {
+        self.text().into()
+    }
+
+    fn set_form_value(&self, value: &String) {
+        self.set_text(value);
+    }
+}
+
+impl FormWidget<bool> for gtk4::Switch {
+    fn create_form_widget(_range: Option<(f64, f64)>, _step: Option<f64>) -> Self {
+        gtk4::Switch::builder().halign(gtk4::Align::Start).build()
+    }
+
+    fn form_value(&self) -> bool {
+        self.is_active()
+    }
+
+    fn set_form_value(&self, value: &bool) {
+        self.set_active(*value);
+    }
+}
+
+impl FormWidget<bool> for gtk4::CheckButton {
+    fn create_form_widget(_range: Option<(f64, f64)>, _step: Option<f64>) -> Self {
+        gtk4::CheckButton::new()
+    }
+
+    fn form_value(&self) -> bool {
+        self.is_active()
+    }
+
+    fn set_form_value(&self, value: &bool) {
+        self.set_active(*value);
+    }
+}
+
+/// Uses a model created with [`Choices::string_list`](crate::choices::Choices::string_list).
+impl<C: crate::choices::Choices> FormWidget<C> for gtk4::DropDown {
+    fn create_form_widget(_range: Option<(f64, f64)>, _step: Option<f64>) -> Self {
+        gtk4::DropDown::builder().model(&C::string_list()).build()
+    }
+
+    fn form_value(&self) -> C {
+        crate::choices::ChoicesWidget::choice(self)
+            .or_else(|| C::from_index(0))
+            .expect("Choices enum has no variants")
+    }
+
+    fn set_form_value(&self, value: &C) {
+        crate::choices::ChoicesWidget::set_choice(self, value);
+    }
+}
+
+macro_rules! impl_numeric_form_widgets {
+    ($default_step:expr; $($numeric_type:ty),*) => {
+        $(
+            // The casts are unnecessary when the type is `f64`.
+            #[allow(clippy::unnecessary_cast)]
+            impl FormWidget<$numeric_type> for gtk4::SpinButton {
+                fn create_form_widget(range: Option<(f64, f64)>, step: Option<f64>) -> Self {
+                    let (min, max) = range.unwrap_or((<$numeric_type>::MIN as f64, <$numeric_type>::MAX as f64));
+                    let spin_button = gtk4::SpinButton::with_range(min, max, step.unwrap_or($default_step));
+                    spin_button.set_hexpand(true);
+                    spin_button
+                }
+
+                fn form_value(&self) -> $numeric_type {
+                    self.value() as $numeric_type
+                }
+
+                fn set_form_value(&self, value: &$numeric_type) {
+                    self.set_value(*value as f64);
+                }
+            }
+
+            #[allow(clippy::unnecessary_cast)]
+            impl FormWidget<$numeric_type> for gtk4::Scale {
+                fn create_form_widget(range: Option<(f64, f64)>, step: Option<f64>) -> Self {
+                    let (min, max) = range.unwrap_or((0.0, 100.0));
+                    let scale = gtk4::Scale::with_range(gtk4::Orientation::Horizontal, min, max, step.unwrap_or($default_step));
+                    scale.set_draw_value(true);
+                    scale.set_hexpand(true);
+                    scale
+                }
+
+                fn form_value(&self) -> $numeric_type {
+                    self.value() as $numeric_type
+                }
+
+                fn set_form_value(&self, value: &$numeric_type) {
+                    self.set_value(*value as f64);
+                }
+            }
+        )*
+    };
+}
+
+impl_numeric_form_widgets!(1.0; i8, i16, i32, i64, u8, u16, u32, u64, isize, usize);
+impl_numeric_form_widgets!(0.1; f32, f64);
+
+#[doc(hidden)] // for internal use by #[derive(AutoForm)]
+pub fn new_grid() -> gtk4::Grid {
+    gtk4::Grid::builder().row_spacing(6).column_spacing(12).build()
+}
+
+#[doc(hidden)] // for internal use by #[derive(AutoForm)]
+pub fn attach_row(grid: &gtk4::Grid, row: i32, label: &str, widget: &impl IsA<gtk4::Widget>) {
+    let label = gtk4::Label::builder()
+        .label(label)
+        .xalign(0.0)
+        .mnemonic_widget(widget)
+        .build();
+    grid.attach(&label, 0, row, 1, 1);
+    grid.attach(widget, 1, row, 1, 1);
+}
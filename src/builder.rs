@@ -1,4 +1,6 @@
 use core::convert::TryInto;
+use core::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 
 use glib::object::IsA;
 use gtk4::prelude::*;
@@ -94,7 +96,14 @@ use crate::GenerateRoutingGtkHandler;
 #[derive(Clone)]
 pub struct BuilderFactory {
     xml: String,
-    signals: Vec<String>,
+    pub(crate) signals: Vec<String>,
+    css: Option<Arc<FactoryCss>>,
+    translation_domain: Option<String>,
+}
+
+struct FactoryCss {
+    css: String,
+    loaded: AtomicBool,
 }
 
 fn extract_signals(xml: &str) -> Vec<String> {
@@ -122,11 +131,76 @@ fn extract_signals(xml: &str) -> Vec<String> {
 impl From<String> for BuilderFactory {
     fn from(xml: String) -> Self {
         let signals = extract_signals(&xml);
-        Self { xml, signals }
+        Self {
+            xml,
+            signals,
+            css: None,
+            translation_domain: crate::i18n::default_translation_domain(),
+        }
     }
 }
 
 impl BuilderFactory {
+    /// Style the widgets created by this factory with CSS.
+    ///
+    /// The CSS is loaded into a `gtk4::CssProvider` and registered for the default display (with
+    /// application priority) when the factory is first instantiated - so it applies to all the
+    /// widgets on the display, not just the ones created by this factory. Clones of the factory
+    /// share the provider, so it is only registered once.
+    ///
+    /// When using [`#[derive(woab::Factories)]`](crate::Factories), this can be set with the
+    /// `#[factory(css = "...")]` attribute.
+    ///
+    /// ```no_run
+    /// # let builder_xml = String::new();
+    /// let builder_factory = woab::BuilderFactory::from(builder_xml).with_css(".warning { color: red; }");
+    /// ```
+    pub fn with_css(mut self, css: impl Into<String>) -> Self {
+        self.css = Some(Arc::new(FactoryCss {
+            css: css.into(),
+            loaded: AtomicBool::new(false),
+        }));
+        self
+    }
+
+    fn load_css(&self) {
+        let Some(css) = &self.css else {
+            return;
+        };
+        if css.loaded.swap(true, Ordering::Relaxed) {
+            return;
+        }
+        let Some(display) = gdk4::Display::default() else {
+            // Try again on the next instantiation.
+            css.loaded.store(false, Ordering::Relaxed);
+            return;
+        };
+        let provider = gtk4::CssProvider::new();
+        #[cfg(feature = "gtk_v4_12")]
+        provider.load_from_string(&css.css);
+        #[cfg(not(feature = "gtk_v4_12"))]
+        provider.load_from_data(&css.css);
+        gtk4::style_context_add_provider_for_display(&display, &provider, gtk4::STYLE_PROVIDER_PRIORITY_APPLICATION);
+    }
+
+    /// Set the gettext domain for translating the strings marked as translatable in the UI XML.
+    ///
+    /// By default, the domain set with [`woab::init_i18n`](crate::init_i18n) is used (if it was
+    /// called before the factory was created).
+    pub fn with_translation_domain(mut self, domain: impl Into<String>) -> Self {
+        self.translation_domain = Some(domain.into());
+        self
+    }
+
+    fn new_builder(&self) -> gtk4::Builder {
+        self.load_css();
+        let builder = gtk4::Builder::new();
+        if let Some(domain) = &self.translation_domain {
+            builder.set_translation_domain(Some(domain));
+        }
+        builder
+    }
+
     /// Create a `gtk4::Builder` from the instructions inside this factory.
     ///
     /// Note that "creating a builder" means that the GTK widgets are created (but not yet shown)
@@ -134,7 +208,9 @@ impl BuilderFactory {
     /// This will panic if the builder declares any signals. To connect the signals, use
     /// [`Self::instantiate_route_to`] (or the lower level [`Self::instantiate_with_scope`])
     pub fn instantiate_without_routing_signals(&self) -> BuilderWidgets {
-        gtk4::Builder::from_string(&self.xml).into()
+        let builder = self.new_builder();
+        builder.add_from_string(&self.xml).unwrap();
+        builder.into()
     }
 
     /// Create a `gtk4::Builder` from the instructions inside this factory, routing its signals
@@ -142,7 +218,7 @@ impl BuilderFactory {
     ///
     /// Note that "creating a builder" means that the GTK widgets are created (but not yet shown)
     pub fn instantiate_with_scope(&self, scope: &impl IsA<gtk4::BuilderScope>) -> BuilderWidgets {
-        let builder = gtk4::Builder::new();
+        let builder = self.new_builder();
         builder.set_scope(Some(scope));
         builder.add_from_string(&self.xml).unwrap();
         builder.into()
@@ -167,6 +243,104 @@ impl BuilderFactory {
         }
         self.instantiate_with_scope(&scope)
     }
+
+    /// Create a `gtk4::Builder` from the instructions inside this factory, routing its signals
+    /// with the builder ID of the emitting object as the tag.
+    ///
+    /// Note that "creating a builder" means that the GTK widgets are created (but not yet shown)
+    ///
+    /// This allows a single handler to tell apart several widgets that share the same signal
+    /// handler, without creating a separate builder for each of them. The emitting object is the
+    /// first parameter of the signal - if it has no ID, the tag is an empty string.
+    ///
+    /// ```no_run
+    /// # use actix::prelude::*;
+    /// # struct WindowActor;
+    /// # impl actix::Actor for WindowActor { type Context = actix::Context<Self>; }
+    /// # impl WindowActor { fn set_digit(&mut self, _digit: u8) {} }
+    /// impl actix::Handler<woab::Signal<String>> for WindowActor {
+    ///     type Result = woab::SignalResult;
+    ///
+    ///     fn handle(&mut self, msg: woab::Signal<String>, _ctx: &mut Self::Context) -> Self::Result {
+    ///         Ok(match msg.name() {
+    ///             "digit_clicked" => {
+    ///                 // The buttons are named `digit_0`, `digit_1`, etc.
+    ///                 let digit = msg.tag().strip_prefix("digit_").unwrap().parse().unwrap();
+    ///                 self.set_digit(digit);
+    ///                 None
+    ///             }
+    ///             _ => msg.cant_handle()?,
+    ///         })
+    ///     }
+    /// }
+    ///
+    /// # let builder_factory: woab::BuilderFactory = panic!();
+    /// builder_factory.instantiate_route_tagged_by_id(WindowActor.start());
+    /// ```
+    pub fn instantiate_route_tagged_by_id(&self, target: impl Into<actix::Recipient<crate::Signal<String>>>) -> BuilderWidgets {
+        let scope = gtk4::BuilderRustScope::new();
+        let generator = crate::signal_routing::TagByBuilderId(target.into());
+        for signal_name in self.signals.iter() {
+            generator.register_into_builder_rust_scope(&scope, signal_name);
+        }
+        self.instantiate_with_scope(&scope)
+    }
+
+    /// Check that every signal handler declared in the builder XML is handled by at least one of
+    /// the targets, by sending them [`woab::ProbeSignal`](crate::ProbeSignal)s.
+    ///
+    /// Fails with [`woab::Error::UnhandledSignals`](crate::Error::UnhandledSignals), listing the
+    /// signals none of the targets handle - which helps finding buttons that do nothing without
+    /// clicking them one by one. Targets that cannot be reached (e.g. because their actor was
+    /// stopped) do not handle any signal.
+    ///
+    /// ```no_run
+    /// # use actix::prelude::*;
+    /// # struct WindowActor;
+    /// # impl actix::Actor for WindowActor { type Context = actix::Context<Self>; }
+    /// # impl actix::Handler<woab::Signal> for WindowActor {
+    /// #     type Result = woab::SignalResult;
+    /// #     fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+    /// #         msg.cant_handle()
+    /// #     }
+    /// # }
+    /// # impl actix::Handler<woab::ProbeSignal> for WindowActor {
+    /// #     type Result = bool;
+    /// #     fn handle(&mut self, _msg: woab::ProbeSignal, _ctx: &mut Self::Context) -> Self::Result {
+    /// #         false
+    /// #     }
+    /// # }
+    /// # let factory: woab::BuilderFactory = panic!();
+    /// let addr = WindowActor::create(|ctx| {
+    ///     factory.instantiate_route_to(ctx.address());
+    ///     WindowActor
+    /// });
+    /// if cfg!(debug_assertions) {
+    ///     actix::spawn(async move {
+    ///         factory.check_signals_handled(&[addr.recipient()]).await.unwrap();
+    ///     });
+    /// }
+    /// ```
+    pub async fn check_signals_handled(&self, targets: &[actix::Recipient<crate::ProbeSignal>]) -> crate::Result<()> {
+        let mut signals = self.signals.clone();
+        signals.sort();
+        signals.dedup();
+        let mut unhandled = Vec::new();
+        'signals: for signal in signals {
+            for target in targets.iter() {
+                let probe = crate::ProbeSignal { name: signal.clone() };
+                if target.send(probe).await.unwrap_or(false) {
+                    continue 'signals;
+                }
+            }
+            unhandled.push(signal);
+        }
+        if unhandled.is_empty() {
+            Ok(())
+        } else {
+            Err(crate::Error::UnhandledSignals { signals: unhandled })
+        }
+    }
 }
 
 /// Context for utilizing a `gtk4::Builder`.
@@ -205,7 +379,7 @@ impl BuilderWidgets {
                     actual_type: object.type_(),
                 }
             } else {
-                crate::Error::WidgetMissingInBuilder(id.to_owned())
+                crate::Error::widget_missing_in_builder(&self.builder, id)
             }
         })
     }
@@ -228,7 +402,7 @@ impl BuilderWidgets {
     /// # let builder_factory: woab::BuilderFactory = panic!();
     /// builder_factory.instantiate_route_to(MyActor.start())
     ///     .with_object("window", |window: gtk4::ApplicationWindow| {
-    ///         window.show();
+    ///         window.present();
     ///     });
     /// ```
     pub fn with_object<W>(&self, id: &str, dlg: impl FnOnce(W)) -> &Self
@@ -4,31 +4,45 @@ use quick_xml::Reader;
 pub fn dissect_builder_xml(
     buf_read: impl std::io::BufRead,
     targets: &mut [Vec<u8>],
+    target_names: &[&str],
     id_to_idx: impl Fn(&str) -> Option<usize>,
+    rest: Option<&mut Vec<(String, Vec<u8>)>>,
 ) -> Result<(), crate::Error> {
     let mut reader = Reader::from_reader(buf_read);
     let mut buf = Vec::new();
 
-    struct WriteContext<'a> {
-        writer: quick_xml::Writer<std::io::Cursor<&'a mut std::vec::Vec<u8>>>,
+    struct WriteContext {
+        writer: quick_xml::Writer<Vec<u8>>,
         prev_idx: Option<usize>,
         nesting: usize,
     }
 
-    let mut contexts = targets
-        .iter_mut()
-        .map(|target| WriteContext {
-            writer: quick_xml::Writer::new(std::io::Cursor::new(target)),
-            prev_idx: None,
-            nesting: 0,
-        })
-        .collect::<Vec<_>>();
+    impl WriteContext {
+        fn new(content: Vec<u8>) -> Self {
+            Self {
+                writer: quick_xml::Writer::new(content),
+                prev_idx: None,
+                nesting: 0,
+            }
+        }
+    }
+
+    // The first contexts are for the targets, and the rest are for the unclaimed top-level objects
+    // (if they are collected)
+    let mut contexts = targets.iter().map(|_| WriteContext::new(Vec::new())).collect::<Vec<_>>();
+    // Everything that is not inside an object, so that it can be copied to the contexts of the
+    // unclaimed objects when they are encountered.
+    let mut common = rest.as_ref().map(|_| quick_xml::Writer::new(Vec::<u8>::new()));
+    let mut rest_ids = Vec::new();
 
     let mut context_idx = None;
     let mut current_nesting = 0;
+    let mut known_ids = Vec::new();
+    let mut matched = vec![false; contexts.len()];
 
     fn write_event(
         contexts: &mut [WriteContext],
+        common: &mut Option<quick_xml::Writer<Vec<u8>>>,
         idx: Option<usize>,
         event: quick_xml::events::Event,
     ) -> Result<(), crate::Error> {
@@ -38,34 +52,60 @@ pub fn dissect_builder_xml(
             for context in contexts.iter_mut() {
                 context.writer.write_event(event.clone())?;
             }
+            if let Some(common) = common {
+                common.write_event(event)?;
+            }
         }
         Ok(())
     }
 
+    fn object_id(e: &quick_xml::events::BytesStart) -> Option<String> {
+        if e.name().0 != b"object" {
+            return None;
+        }
+        e.attributes().find_map(|attr| {
+            let attr = attr.ok()?;
+            if attr.key.0 != b"id" {
+                return None;
+            }
+            Some(std::str::from_utf8(&attr.value).ok()?.to_owned())
+        })
+    }
+
     loop {
-        match reader.read_event_into(&mut buf)? {
+        let event = reader.read_event_into(&mut buf)?;
+        let object_idx = match &event {
+            quick_xml::events::Event::Start(e) | quick_xml::events::Event::Empty(e) => {
+                let id = object_id(e);
+                let mut object_idx = id.as_deref().and_then(&id_to_idx);
+                if let Some(object_idx) = object_idx {
+                    matched[object_idx] = true;
+                } else if let (Some(id), Some(common), None) = (&id, &common, context_idx) {
+                    object_idx = Some(contexts.len());
+                    contexts.push(WriteContext::new(common.get_ref().clone()));
+                    rest_ids.push(id.clone());
+                }
+                known_ids.extend(id);
+                object_idx
+            }
+            _ => None,
+        };
+        match event {
             quick_xml::events::Event::Start(e) => {
                 current_nesting += 1;
-                if e.name().0 == b"object" {
-                    let new_idx = e.attributes().find_map(|attr| {
-                        let attr = attr.ok()?;
-                        if attr.key.0 != b"id" {
-                            return None;
-                        }
-                        let id = std::str::from_utf8(&attr.value).ok()?;
-                        id_to_idx(id)
-                    });
-                    if let Some(new_idx) = new_idx {
-                        contexts[new_idx].prev_idx = context_idx;
-                        assert!(contexts[new_idx].nesting == 0);
-                        contexts[new_idx].nesting = current_nesting;
-                        context_idx = Some(new_idx);
-                    }
+                if let Some(new_idx) = object_idx {
+                    contexts[new_idx].prev_idx = context_idx;
+                    assert!(contexts[new_idx].nesting == 0);
+                    contexts[new_idx].nesting = current_nesting;
+                    context_idx = Some(new_idx);
                 }
-                write_event(&mut contexts, context_idx, quick_xml::events::Event::Start(e))?;
+                write_event(&mut contexts, &mut common, context_idx, quick_xml::events::Event::Start(e))?;
+            }
+            e @ quick_xml::events::Event::Empty(_) => {
+                write_event(&mut contexts, &mut common, object_idx.or(context_idx), e)?;
             }
             e @ quick_xml::events::Event::End(_) => {
-                write_event(&mut contexts, context_idx, e)?;
+                write_event(&mut contexts, &mut common, context_idx, e)?;
                 if let Some(idx) = context_idx {
                     if current_nesting == contexts[idx].nesting {
                         context_idx = contexts[idx].prev_idx;
@@ -76,8 +116,21 @@ pub fn dissect_builder_xml(
                 current_nesting -= 1;
             }
             quick_xml::events::Event::Eof => break,
-            e => write_event(&mut contexts, context_idx, e)?,
+            e => write_event(&mut contexts, &mut common, context_idx, e)?,
         }
     }
+    if let Some(idx) = matched.iter().position(|matched| !matched) {
+        return Err(crate::Error::FactoryIdNotFound {
+            field: target_names[idx].to_owned(),
+            known_ids,
+        });
+    }
+    let mut contexts = contexts.into_iter().map(|context| context.writer.into_inner());
+    for (target, content) in targets.iter_mut().zip(contexts.by_ref()) {
+        *target = content;
+    }
+    if let Some(rest) = rest {
+        rest.extend(rest_ids.into_iter().zip(contexts));
+    }
     Ok(())
 }
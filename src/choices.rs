@@ -0,0 +1,95 @@
+//! Bind fieldless enums to selection widgets.
+//!
+//! See [`#[derive(woab::Choices)]`](crate::Choices) for usage instructions.
+
+use gtk4::prelude::*;
+
+/// A fieldless enum that can be selected with a `gtk4::DropDown` or a `gtk4::ComboBox`.
+///
+/// Usually implemented with [`#[derive(woab::Choices)]`](crate::Choices).
+pub trait Choices: Sized {
+    /// The position of the choice in the list of choices.
+    fn index(&self) -> u32;
+
+    /// The choice at the given position, or `None` if the position is out of range.
+    fn from_index(index: u32) -> Option<Self>;
+
+    /// The ID of the choice, for use with the ID column of a `gtk4::ComboBox`.
+    fn id(&self) -> &'static str;
+
+    /// The text displayed for the choice.
+    fn label(&self) -> &'static str;
+
+    /// The choice with the given ID, or `None` if there is no such choice.
+    fn from_id(id: &str) -> Option<Self> {
+        Self::iter().find(|choice| choice.id() == id)
+    }
+
+    /// Iterate over all the choices, in order.
+    fn iter() -> impl Iterator<Item = Self> {
+        (0..).map_while(Self::from_index)
+    }
+
+    /// Create a string model of the choices' labels, for use as the model of a `gtk4::DropDown`.
+    fn string_list() -> gtk4::StringList {
+        let labels = Self::iter().map(|choice| choice.label()).collect::<Vec<_>>();
+        gtk4::StringList::new(&labels)
+    }
+
+    /// Add the choices to a `gtk4::ComboBoxText`, using their IDs and labels.
+    #[cfg_attr(feature = "gtk_v4_10", allow(deprecated))]
+    fn fill_combo_box_text(combo_box: &gtk4::ComboBoxText) {
+        for choice in Self::iter() {
+            combo_box.append(Some(choice.id()), choice.label());
+        }
+    }
+}
+
+/// A widget that can select one of a [`Choices`] enum's variants.
+///
+/// Used by [`#[derive(woab::PropSync)]`](crate::PropSync) for fields annotated with
+/// `#[prop_sync(choice as EnumType)]`.
+pub trait ChoicesWidget {
+    /// Select the choice in the widget.
+    fn set_choice<C: Choices>(&self, choice: &C);
+
+    /// The choice currently selected in the widget, or `None` if nothing is selected.
+    fn choice<C: Choices>(&self) -> Option<C>;
+}
+
+/// Uses the `selected` property, so the widget's model should be created with
+/// [`Choices::string_list`].
+impl ChoicesWidget for gtk4::DropDown {
+    fn set_choice<C: Choices>(&self, choice: &C) {
+        self.set_selected(choice.index());
+    }
+
+    fn choice<C: Choices>(&self) -> Option<C> {
+        C::from_index(self.selected())
+    }
+}
+
+/// Uses the `active-id` property, so the IDs in the widget's model should match [`Choices::id`].
+#[cfg_attr(feature = "gtk_v4_10", allow(deprecated))]
+impl ChoicesWidget for gtk4::ComboBox {
+    fn set_choice<C: Choices>(&self, choice: &C) {
+        self.set_active_id(Some(choice.id()));
+    }
+
+    fn choice<C: Choices>(&self) -> Option<C> {
+        C::from_id(&self.active_id()?)
+    }
+}
+
+/// Uses the `active-id` property, so the widget should be filled with
+/// [`Choices::fill_combo_box_text`].
+#[cfg_attr(feature = "gtk_v4_10", allow(deprecated))]
+impl ChoicesWidget for gtk4::ComboBoxText {
+    fn set_choice<C: Choices>(&self, choice: &C) {
+        self.set_active_id(Some(choice.id()));
+    }
+
+    fn choice<C: Choices>(&self) -> Option<C> {
+        C::from_id(&self.active_id()?)
+    }
+}
@@ -0,0 +1,130 @@
+use gtk4::prelude::*;
+
+/// Whether the application is displayed with a light or a dark style. Sent by
+/// [`woab::watch_color_scheme`](watch_color_scheme).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorScheme {
+    Light,
+    Dark,
+}
+
+impl ColorScheme {
+    /// The current color scheme.
+    ///
+    /// With the `adw` feature this is taken from `adw::StyleManager`, and otherwise from the
+    /// `gtk-application-prefer-dark-theme` setting.
+    pub fn current() -> Self {
+        Self::from_is_dark(is_dark(&source()))
+    }
+
+    fn from_is_dark(is_dark: bool) -> Self {
+        if is_dark {
+            Self::Dark
+        } else {
+            Self::Light
+        }
+    }
+}
+
+impl actix::Message for ColorScheme {
+    type Result = ();
+}
+
+#[cfg(feature = "adw")]
+type Source = adw::StyleManager;
+
+#[cfg(not(feature = "adw"))]
+type Source = gtk4::Settings;
+
+#[cfg(feature = "adw")]
+fn source() -> Source {
+    adw::StyleManager::default()
+}
+
+#[cfg(not(feature = "adw"))]
+fn source() -> Source {
+    gtk4::Settings::default().expect("GTK has no default display")
+}
+
+#[cfg(feature = "adw")]
+fn is_dark(source: &Source) -> bool {
+    source.is_dark()
+}
+
+#[cfg(not(feature = "adw"))]
+fn is_dark(source: &Source) -> bool {
+    source.is_gtk_application_prefer_dark_theme()
+}
+
+#[cfg(feature = "adw")]
+fn connect_changed(source: &Source, callback: impl Fn(&Source) + 'static) -> glib::SignalHandlerId {
+    source.connect_dark_notify(callback)
+}
+
+#[cfg(not(feature = "adw"))]
+fn connect_changed(source: &Source, callback: impl Fn(&Source) + 'static) -> glib::SignalHandlerId {
+    source.connect_gtk_application_prefer_dark_theme_notify(callback)
+}
+
+/// Keeps a watch created by [`woab::watch_color_scheme`](watch_color_scheme) active.
+///
+/// The color scheme stops being watched when this is dropped.
+#[must_use = "the color scheme stops being watched when the ColorSchemeWatch is dropped"]
+pub struct ColorSchemeWatch {
+    source: Source,
+    handler_id: Option<glib::SignalHandlerId>,
+}
+
+impl Drop for ColorSchemeWatch {
+    fn drop(&mut self) {
+        if let Some(handler_id) = self.handler_id.take() {
+            self.source.disconnect(handler_id);
+        }
+    }
+}
+
+/// Send the current [`ColorScheme`] to an actor, and send it again whenever the user switches
+/// between light and dark styles.
+///
+/// With the `adw` feature, the changes are taken from `adw::StyleManager` (which follows the
+/// system's dark style preference). Otherwise, they are taken from the
+/// `gtk-application-prefer-dark-theme` setting.
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// struct WindowActor {
+///     _color_scheme_watch: Option<woab::ColorSchemeWatch>,
+///     icon: gtk4::Image,
+/// }
+///
+/// impl actix::Actor for WindowActor {
+///     type Context = actix::Context<Self>;
+///
+///     fn started(&mut self, ctx: &mut Self::Context) {
+///         self._color_scheme_watch = Some(woab::watch_color_scheme(ctx.address()));
+///     }
+/// }
+///
+/// impl actix::Handler<woab::ColorScheme> for WindowActor {
+///     type Result = ();
+///
+///     fn handle(&mut self, msg: woab::ColorScheme, _ctx: &mut Self::Context) -> Self::Result {
+///         self.icon.set_icon_name(Some(match msg {
+///             woab::ColorScheme::Light => "logo-light",
+///             woab::ColorScheme::Dark => "logo-dark",
+///         }));
+///     }
+/// }
+/// ```
+pub fn watch_color_scheme(target: impl Into<actix::Recipient<ColorScheme>>) -> ColorSchemeWatch {
+    let source = source();
+    let target = target.into();
+    target.do_send(ColorScheme::from_is_dark(is_dark(&source)));
+    let handler_id = connect_changed(&source, move |source| {
+        target.do_send(ColorScheme::from_is_dark(is_dark(source)))
+    });
+    ColorSchemeWatch {
+        source,
+        handler_id: Some(handler_id),
+    }
+}
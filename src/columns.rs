@@ -0,0 +1,179 @@
+//! Create `gtk4::ColumnViewColumn`s for displaying struct fields.
+//!
+//! See [`#[derive(woab::Columns)]`](crate::Columns) for usage instructions.
+
+use core::cmp::Ordering;
+use std::rc::Rc;
+
+use gtk4::prelude::*;
+
+/// A row-data type that can generate the columns of a `gtk4::ColumnView`.
+///
+/// Usually implemented with [`#[derive(woab::Columns)]`](crate::Columns).
+pub trait Columns: 'static + Sized {
+    /// Create the columns.
+    ///
+    /// If `edit_target` is set, edits of editable columns will be routed to it.
+    fn columns(edit_target: Option<actix::Recipient<crate::Signal>>) -> Vec<gtk4::ColumnViewColumn>;
+
+    /// Create the columns and append them to a column view.
+    fn append_columns(column_view: &gtk4::ColumnView, edit_target: Option<actix::Recipient<crate::Signal>>) {
+        for column in Self::columns(edit_target) {
+            column_view.append_column(&column);
+        }
+    }
+}
+
+fn list_item_from_signal_params(parameters: &[glib::Value]) -> gtk4::ListItem {
+    parameters[1]
+        .get()
+        .expect("list item factory signal must be called with a ListItem")
+}
+
+fn new_column<T: 'static>(
+    title: &str,
+    factory: gtk4::SignalListItemFactory,
+    compare: Option<fn(&T, &T) -> Ordering>,
+) -> gtk4::ColumnViewColumn {
+    let column = gtk4::ColumnViewColumn::new(Some(title), Some(factory));
+    if let Some(compare) = compare {
+        column.set_sorter(Some(&gtk4::CustomSorter::new(move |a, b| {
+            match (crate::list_binding::item::<T>(a), crate::list_binding::item::<T>(b)) {
+                (Some(a), Some(b)) => compare(&a, &b).into(),
+                _ => gtk4::Ordering::Equal,
+            }
+        })));
+    }
+    column
+}
+
+fn send_edit(target: &actix::Recipient<crate::Signal>, signal_name: glib::Quark, item: glib::Object, value: glib::Value) {
+    target.do_send(crate::Signal::new(signal_name, [item.to_value(), value], ()));
+}
+
+/// Create a column that displays a value as text.
+///
+/// * `numeric`: align the text to the right, as is customary for numbers.
+/// * `compare`: if set, the column's sorter will use it. Note that for the sorting to work, the
+///   column view's model needs to be wrapped in a `gtk4::SortListModel` that uses the column
+///   view's sorter.
+/// * `edit`: if set, the text will be editable, and when the user edits it a signal with the given
+///   name will be sent to the recipient. The signal's parameters are the row's item (the
+///   `glib::BoxedAnyObject` from the list model) and the new text.
+pub fn text_column<T: 'static>(
+    title: &str,
+    numeric: bool,
+    display: impl 'static + Fn(&T) -> String,
+    compare: Option<fn(&T, &T) -> Ordering>,
+    edit: Option<(&str, actix::Recipient<crate::Signal>)>,
+) -> gtk4::ColumnViewColumn {
+    let display = Rc::new(display);
+    let edit = edit.map(|(signal_name, target)| (glib::Quark::from_str(signal_name), target));
+    let xalign = if numeric { 1.0 } else { 0.0 };
+    let factory = gtk4::SignalListItemFactory::new();
+    factory.connect_local("setup", false, {
+        let display = display.clone();
+        move |parameters| {
+            let list_item = list_item_from_signal_params(parameters);
+            if let Some((signal_name, target)) = &edit {
+                let label = gtk4::EditableLabel::new("");
+                label.set_alignment(xalign);
+                let signal_name = *signal_name;
+                let target = target.clone();
+                let display = display.clone();
+                let list_item_weak = list_item.downgrade();
+                label.connect_notify_local(Some("editing"), move |label, _| {
+                    if label.is_editing() {
+                        return;
+                    }
+                    let Some(item) = list_item_weak.upgrade().and_then(|list_item| list_item.item()) else {
+                        return;
+                    };
+                    let text = label.text();
+                    let changed = crate::list_binding::item::<T>(&item).is_some_and(|row| display(&row) != text.as_str());
+                    if changed {
+                        send_edit(&target, signal_name, item, text.to_value());
+                    }
+                });
+                list_item.set_child(Some(&label));
+            } else {
+                list_item.set_child(Some(&gtk4::Label::builder().xalign(xalign).build()));
+            }
+            None
+        }
+    });
+    factory.connect_local("bind", false, move |parameters| {
+        let list_item = list_item_from_signal_params(parameters);
+        let (Some(child), Some(item)) = (list_item.child(), list_item.item()) else {
+            return None;
+        };
+        let row = crate::list_binding::item::<T>(&item)?;
+        if let Some(label) = child.downcast_ref::<gtk4::Label>() {
+            label.set_text(&display(&row));
+        } else if let Some(label) = child.downcast_ref::<gtk4::EditableLabel>() {
+            label.set_text(&display(&row));
+        }
+        None
+    });
+    new_column(title, factory, compare)
+}
+
+/// Create a column that displays a boolean value as a check button.
+///
+/// * `compare`: if set, the column's sorter will use it. Note that for the sorting to work, the
+///   column view's model needs to be wrapped in a `gtk4::SortListModel` that uses the column
+///   view's sorter.
+/// * `edit`: if set, the check button will be sensitive, and when the user toggles it a signal
+///   with the given name will be sent to the recipient. The signal's parameters are the row's
+///   item (the `glib::BoxedAnyObject` from the list model) and the new value.
+pub fn check_column<T: 'static>(
+    title: &str,
+    get: impl 'static + Fn(&T) -> bool,
+    compare: Option<fn(&T, &T) -> Ordering>,
+    edit: Option<(&str, actix::Recipient<crate::Signal>)>,
+) -> gtk4::ColumnViewColumn {
+    let get = Rc::new(get);
+    let edit = edit.map(|(signal_name, target)| (glib::Quark::from_str(signal_name), target));
+    let factory = gtk4::SignalListItemFactory::new();
+    factory.connect_local("setup", false, {
+        let get = get.clone();
+        move |parameters| {
+            let list_item = list_item_from_signal_params(parameters);
+            let check_button = gtk4::CheckButton::builder().sensitive(edit.is_some()).build();
+            if let Some((signal_name, target)) = &edit {
+                let signal_name = *signal_name;
+                let target = target.clone();
+                let get = get.clone();
+                let list_item_weak = list_item.downgrade();
+                check_button.connect_toggled(move |check_button| {
+                    let Some(item) = list_item_weak.upgrade().and_then(|list_item| list_item.item()) else {
+                        return;
+                    };
+                    let active = check_button.is_active();
+                    // Setting the value in `bind` also triggers this signal, but then the value
+                    // will already be the same as the one in the item.
+                    let changed = crate::list_binding::item::<T>(&item).is_some_and(|row| get(&row) != active);
+                    if changed {
+                        send_edit(&target, signal_name, item, active.to_value());
+                    }
+                });
+            }
+            list_item.set_child(Some(&check_button));
+            None
+        }
+    });
+    factory.connect_local("bind", false, move |parameters| {
+        let list_item = list_item_from_signal_params(parameters);
+        let (Some(child), Some(item)) = (list_item.child(), list_item.item()) else {
+            return None;
+        };
+        if let (Some(check_button), Some(row)) = (
+            child.downcast_ref::<gtk4::CheckButton>(),
+            crate::list_binding::item::<T>(&item),
+        ) {
+            check_button.set_active(get(&row));
+        }
+        None
+    });
+    new_column(title, factory, compare)
+}
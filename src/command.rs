@@ -0,0 +1,191 @@
+use core::cell::Cell;
+use core::future::Future;
+use std::rc::Rc;
+
+use gtk4::prelude::*;
+
+#[derive(Default)]
+struct CommandState {
+    running: Cell<bool>,
+    disabled_widgets: Vec<gtk4::Widget>,
+    spinners: Vec<gtk4::Spinner>,
+}
+
+/// Restores the widgets once the command's future finishes (or is dropped).
+struct Busy {
+    state: Rc<CommandState>,
+    sensitivities: Vec<bool>,
+}
+
+impl Busy {
+    fn start(state: Rc<CommandState>) -> Self {
+        state.running.set(true);
+        let sensitivities = state
+            .disabled_widgets
+            .iter()
+            .map(|widget| {
+                let sensitive = widget.is_sensitive();
+                widget.set_sensitive(false);
+                sensitive
+            })
+            .collect();
+        for spinner in state.spinners.iter() {
+            spinner.set_visible(true);
+            spinner.start();
+        }
+        Self { state, sensitivities }
+    }
+}
+
+impl Drop for Busy {
+    fn drop(&mut self) {
+        for (widget, sensitive) in self.state.disabled_widgets.iter().zip(self.sensitivities.iter()) {
+            widget.set_sensitive(*sensitive);
+        }
+        for spinner in self.state.spinners.iter() {
+            spinner.stop();
+            spinner.set_visible(false);
+        }
+        self.state.running.set(false);
+    }
+}
+
+/// An async operation that disables parts of the UI while it runs.
+///
+/// Operations like saving a file or sending a request are usually triggered by a signal, and while
+/// they run the widgets that trigger them should be disabled and some progress indicator should be
+/// shown. A `Command` takes care of that - [`run`](Command::run) it from the signal handler, and
+/// until the future finishes:
+///
+/// * The widgets registered with [`disable`](Command::disable) are insensitive.
+/// * The spinners registered with [`spinner`](Command::spinner) are shown and spinning.
+/// * Running the command again is refused.
+///
+/// Once the future finishes, the widgets are restored and its result is sent to the actor as a
+/// message.
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// # use gtk4::prelude::*;
+/// struct EditorActor {
+///     widgets: EditorWidgets,
+///     save_command: woab::Command,
+/// }
+///
+/// # #[derive(woab::WidgetsFromBuilder)]
+/// struct EditorWidgets {
+///     save_button: gtk4::Button,
+///     save_spinner: gtk4::Spinner,
+///     text_view: gtk4::TextView,
+/// }
+///
+/// impl actix::Actor for EditorActor {
+///     type Context = actix::Context<Self>;
+///
+///     fn started(&mut self, _ctx: &mut Self::Context) {
+///         self.save_command = woab::Command::new()
+///             .disable(&self.widgets.save_button)
+///             .disable(&self.widgets.text_view)
+///             .spinner(&self.widgets.save_spinner);
+///     }
+/// }
+///
+/// struct Saved(std::io::Result<()>);
+///
+/// impl actix::Message for Saved {
+///     type Result = ();
+/// }
+///
+/// impl actix::Handler<woab::Signal> for EditorActor {
+///     type Result = woab::SignalResult;
+///
+///     fn handle(&mut self, msg: woab::Signal, ctx: &mut Self::Context) -> Self::Result {
+///         Ok(match msg.name() {
+///             "save_clicked" => {
+///                 let text = self.widgets.text_view.buffer().text(
+///                     &self.widgets.text_view.buffer().start_iter(),
+///                     &self.widgets.text_view.buffer().end_iter(),
+///                     false,
+///                 );
+///                 self.save_command.run(ctx.address(), async move {
+///                     Saved(std::fs::write("file.txt", text.as_str()))
+///                 });
+///                 None
+///             }
+///             _ => msg.cant_handle()?,
+///         })
+///     }
+/// }
+///
+/// impl actix::Handler<Saved> for EditorActor {
+///     type Result = ();
+///
+///     fn handle(&mut self, msg: Saved, _ctx: &mut Self::Context) -> Self::Result {
+///         if let Err(err) = msg.0 {
+///             println!("Failed to save: {}", err);
+///         }
+///     }
+/// }
+/// ```
+///
+/// The `Command` is a cheap handle - clones of it share the same state. It must be configured
+/// before it is cloned.
+#[derive(Default, Clone)]
+pub struct Command(Rc<CommandState>);
+
+impl Command {
+    /// Create a command that does not disable anything.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn state_mut(&mut self) -> &mut CommandState {
+        Rc::get_mut(&mut self.0).expect("Command cannot be configured after it was cloned or while it is running")
+    }
+
+    /// Make the widget insensitive while the command runs.
+    ///
+    /// Once the command finishes, the widget's sensitivity is restored to what it was before the
+    /// command started.
+    pub fn disable(mut self, widget: &impl IsA<gtk4::Widget>) -> Self {
+        self.state_mut().disabled_widgets.push(widget.clone().upcast());
+        self
+    }
+
+    /// Show the spinner and make it spin while the command runs.
+    ///
+    /// The spinner is hidden when the command is not running.
+    pub fn spinner(mut self, spinner: &gtk4::Spinner) -> Self {
+        spinner.stop();
+        spinner.set_visible(false);
+        self.state_mut().spinners.push(spinner.clone());
+        self
+    }
+
+    /// Check if the command is currently running.
+    pub fn is_running(&self) -> bool {
+        self.0.running.get()
+    }
+
+    /// Run the future, and send its result to `target` once it finishes.
+    ///
+    /// If the command is already running, the future is dropped without running and `false` is
+    /// returned.
+    pub fn run<M>(&self, target: impl Into<actix::Recipient<M>>, fut: impl Future<Output = M> + 'static) -> bool
+    where
+        M: actix::Message<Result = ()> + Send + 'static,
+    {
+        if self.is_running() {
+            return false;
+        }
+        let target = target.into();
+        let busy = Busy::start(self.0.clone());
+        actix::spawn(async move {
+            let result = fut.await;
+            // Restore the widgets before the actor handles the result, so it can change them.
+            drop(busy);
+            target.do_send(result);
+        });
+        true
+    }
+}
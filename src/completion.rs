@@ -0,0 +1,285 @@
+use core::cell::{Cell, RefCell};
+use core::time::Duration;
+use std::rc::Rc;
+
+use gtk4::prelude::*;
+
+/// A message sent by [`Completion`] to ask the actor for the suggestions for the text of the
+/// entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompletionQuery {
+    pub prefix: String,
+}
+
+impl actix::Message for CompletionQuery {
+    type Result = Vec<String>;
+}
+
+struct CompletionState {
+    entry: gtk4::Editable,
+    popover: gtk4::Popover,
+    list_box: gtk4::ListBox,
+    recipient: actix::Recipient<CompletionQuery>,
+    debounce: Cell<Duration>,
+    min_length: Cell<usize>,
+    generation: Cell<u64>,
+    pending_query: RefCell<Option<tokio::task::JoinHandle<()>>>,
+    suggestions: RefCell<Vec<String>>,
+    // Set while a suggestion is written to the entry, so that it would not be queried.
+    applying_suggestion: Cell<bool>,
+    handlers: RefCell<Vec<(glib::Object, glib::SignalHandlerId)>>,
+    key_controllers: RefCell<Vec<(gtk4::Widget, gtk4::EventControllerKey)>>,
+}
+
+impl CompletionState {
+    fn cancel_pending_query(&self) {
+        if let Some(pending_query) = self.pending_query.take() {
+            pending_query.abort();
+        }
+    }
+
+    fn on_changed(self: &Rc<Self>) {
+        if self.applying_suggestion.get() {
+            return;
+        }
+        let generation = self.generation.get() + 1;
+        self.generation.set(generation);
+        self.cancel_pending_query();
+        let prefix = self.entry.text().to_string();
+        if prefix.chars().count() < self.min_length.get() {
+            self.set_suggestions(Vec::new());
+            return;
+        }
+        let state = self.clone();
+        let query = Cell::new(Some(async move {
+            // Use the Actix clock (and not a GLib timeout) so that tests can control it.
+            actix::clock::sleep(state.debounce.get()).await;
+            let Ok(suggestions) = state.recipient.send(CompletionQuery { prefix }).await else {
+                return;
+            };
+            if state.generation.get() == generation {
+                state.pending_query.take();
+                state.set_suggestions(suggestions);
+            }
+        }));
+        let spawn = || query.take().map(actix::spawn);
+        let pending_query = match crate::try_block_on(async { spawn() }) {
+            Ok(pending_query) => pending_query,
+            // Already inside the Actix runtime.
+            Err(_) => spawn(),
+        };
+        *self.pending_query.borrow_mut() = pending_query;
+    }
+
+    fn set_suggestions(&self, suggestions: Vec<String>) {
+        while let Some(row) = self.list_box.first_child() {
+            self.list_box.remove(&row);
+        }
+        for suggestion in suggestions.iter() {
+            let label = gtk4::Label::new(Some(suggestion));
+            label.set_xalign(0.0);
+            self.list_box.append(&label);
+        }
+        if suggestions.is_empty() || !self.entry.is_mapped() {
+            self.popover.popdown();
+        } else {
+            self.popover.popup();
+        }
+        *self.suggestions.borrow_mut() = suggestions;
+    }
+
+    fn add_key_controller(
+        self: &Rc<Self>,
+        widget: &impl IsA<gtk4::Widget>,
+        on_key_pressed: impl 'static + Fn(&Self, gdk4::Key) -> glib::Propagation,
+    ) {
+        let key_controller = gtk4::EventControllerKey::new();
+        key_controller.connect_key_pressed({
+            let state = self.clone();
+            move |_, key, _, _| on_key_pressed(&state, key)
+        });
+        widget.add_controller(key_controller.clone());
+        self.key_controllers
+            .borrow_mut()
+            .push((widget.clone().upcast(), key_controller));
+    }
+
+    fn apply_suggestion(&self, row: &gtk4::ListBoxRow) {
+        let Some(suggestion) = usize::try_from(row.index())
+            .ok()
+            .and_then(|index| self.suggestions.borrow().get(index).cloned())
+        else {
+            return;
+        };
+        self.applying_suggestion.set(true);
+        self.entry.set_text(&suggestion);
+        self.applying_suggestion.set(false);
+        self.entry.set_position(-1);
+        self.set_suggestions(Vec::new());
+        self.entry.grab_focus();
+    }
+}
+
+/// Show suggestions for the text of a `gtk4::Entry` (or any other `gtk4::Editable`, like
+/// `gtk4::SearchEntry`), fetched from an actor.
+///
+/// Whenever the text changes, the completion waits until the user stops typing for the
+/// [debounce](Self::debounce) duration, and then sends a [`CompletionQuery`] to the actor. The
+/// actor may take its time to respond (e.g. when the suggestions come from a database or a remote
+/// API) - if the text changes in the meantime, the response is ignored. The suggestions are shown
+/// in a popover under the entry, and picking one sets it as the text of the entry. The Down key
+/// moves the focus from the entry to the suggestions, and Escape hides them.
+///
+/// The completion is removed when the guard is dropped.
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// struct CityPickerActor {
+///     cities: Vec<String>,
+///     completion: Option<woab::Completion>,
+/// }
+///
+/// impl actix::Actor for CityPickerActor {
+///     type Context = actix::Context<Self>;
+///
+///     fn started(&mut self, ctx: &mut Self::Context) {
+///         # let city_entry: gtk4::Entry = panic!();
+///         self.completion = Some(woab::Completion::new(&city_entry, ctx.address()).min_length(2));
+///     }
+/// }
+///
+/// impl actix::Handler<woab::CompletionQuery> for CityPickerActor {
+///     type Result = Vec<String>;
+///
+///     fn handle(&mut self, msg: woab::CompletionQuery, _ctx: &mut Self::Context) -> Self::Result {
+///         self.cities.iter().filter(|city| city.starts_with(&msg.prefix)).cloned().collect()
+///     }
+/// }
+/// ```
+#[must_use = "the completion is removed when the guard is dropped"]
+pub struct Completion(Rc<CompletionState>);
+
+impl Completion {
+    /// Start completing the entry with suggestions from the recipient.
+    pub fn new(entry: &impl IsA<gtk4::Editable>, recipient: impl Into<actix::Recipient<CompletionQuery>>) -> Self {
+        let list_box = gtk4::ListBox::new();
+        list_box.set_selection_mode(gtk4::SelectionMode::Browse);
+        let popover = gtk4::Popover::builder()
+            .child(&list_box)
+            .autohide(false)
+            .has_arrow(false)
+            .position(gtk4::PositionType::Bottom)
+            .build();
+        // Every `gtk4::Editable` is a widget.
+        let entry = entry.upcast_ref::<gtk4::Editable>();
+        popover.set_parent(entry);
+        let state = Rc::new(CompletionState {
+            entry: entry.clone(),
+            popover,
+            list_box,
+            recipient: recipient.into(),
+            debounce: Cell::new(Duration::from_millis(150)),
+            min_length: Cell::new(1),
+            generation: Cell::new(0),
+            pending_query: RefCell::new(None),
+            suggestions: RefCell::new(Vec::new()),
+            applying_suggestion: Cell::new(false),
+            handlers: RefCell::new(Vec::new()),
+            key_controllers: RefCell::new(Vec::new()),
+        });
+
+        let mut handlers = vec![
+            (
+                entry.clone().upcast(),
+                entry.connect_changed({
+                    let state = state.clone();
+                    move |_| state.on_changed()
+                }),
+            ),
+            (
+                state.list_box.clone().upcast(),
+                state.list_box.connect_row_activated({
+                    let state = state.clone();
+                    move |_, row| state.apply_suggestion(row)
+                }),
+            ),
+        ];
+        // `gtk4::Editable` has no `activate` signal, but the common editables (`gtk4::Entry`,
+        // `gtk4::SearchEntry`, `gtk4::PasswordEntry`) do.
+        if glib::subclass::SignalId::lookup("activate", entry.type_()).is_some() {
+            handlers.push((
+                entry.clone().upcast(),
+                entry.connect_local("activate", false, {
+                    let state = state.clone();
+                    move |_| {
+                        state.generation.set(state.generation.get() + 1);
+                        state.cancel_pending_query();
+                        state.set_suggestions(Vec::new());
+                        None
+                    }
+                }),
+            ));
+        }
+        *state.handlers.borrow_mut() = handlers;
+
+        state.add_key_controller(entry, |state, key| match key {
+            gdk4::Key::Down if state.popover.is_visible() => {
+                if let Some(row) = state.list_box.row_at_index(0) {
+                    state.list_box.select_row(Some(&row));
+                    row.grab_focus();
+                }
+                glib::Propagation::Stop
+            }
+            gdk4::Key::Escape if state.popover.is_visible() => {
+                state.set_suggestions(Vec::new());
+                glib::Propagation::Stop
+            }
+            _ => glib::Propagation::Proceed,
+        });
+        state.add_key_controller(&state.popover, |state, key| {
+            if key == gdk4::Key::Escape {
+                state.set_suggestions(Vec::new());
+                state.entry.grab_focus();
+                glib::Propagation::Stop
+            } else {
+                glib::Propagation::Proceed
+            }
+        });
+        Self(state)
+    }
+
+    /// Wait until the text did not change for this duration before querying the actor.
+    ///
+    /// The default is 150 milliseconds.
+    pub fn debounce(self, debounce: Duration) -> Self {
+        self.0.debounce.set(debounce);
+        self
+    }
+
+    /// Only query the actor when the text has at least this many characters.
+    ///
+    /// The default is 1, so that the suggestions are hidden when the entry is empty.
+    pub fn min_length(self, min_length: usize) -> Self {
+        self.0.min_length.set(min_length);
+        self
+    }
+
+    /// The suggestions that are currently shown.
+    pub fn suggestions(&self) -> Vec<String> {
+        self.0.suggestions.borrow().clone()
+    }
+}
+
+impl Drop for Completion {
+    fn drop(&mut self) {
+        let state = &self.0;
+        state.cancel_pending_query();
+        for (object, handler_id) in state.handlers.take() {
+            object.disconnect(handler_id);
+        }
+        for (widget, key_controller) in state.key_controllers.take() {
+            widget.remove_controller(&key_controller);
+        }
+        state.popover.unparent();
+    }
+}
@@ -0,0 +1,252 @@
+//! Actors that own a part of the UI and communicate with their parent through typed messages.
+//!
+//! See [`Component`] for usage instructions.
+
+use gtk4::prelude::*;
+
+/// An actor that owns a widget, which is mounted into a container of its parent.
+///
+/// A component communicates with its parent with two message types - the parent sends it
+/// [`Input`](Component::Input) messages, and it sends [`Output`](Component::Output) messages to a
+/// recipient the parent provides when [mounting](Component::mount) it. A component is unmounted by
+/// sending it [`woab::Remove`](crate::Remove) or [`woab::RemoveAndConfirm`](crate::RemoveAndConfirm),
+/// which should remove its widget from the container and stop the actor - usually by deriving
+/// [`woab::Removable`](crate::Removable).
+///
+/// To host a dynamic set of components of the same type, use [`Children`].
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// # use gtk4::prelude::*;
+/// use woab::component::{Children, Component};
+///
+/// #[derive(woab::Removable)]
+/// #[removable(self.widgets.row in gtk4::Box)]
+/// struct TaskComponent {
+///     id: usize,
+///     widgets: TaskWidgets,
+///     output: actix::Recipient<TaskOutput>,
+/// }
+///
+/// #[derive(woab::WidgetsFromBuilder)]
+/// struct TaskWidgets {
+///     row: gtk4::Box,
+///     title: gtk4::Label,
+/// }
+///
+/// impl actix::Actor for TaskComponent {
+///     type Context = actix::Context<Self>;
+/// }
+///
+/// struct Rename(String);
+///
+/// impl actix::Message for Rename {
+///     type Result = ();
+/// }
+///
+/// struct TaskOutput {
+///     id: usize,
+///     done: bool,
+/// }
+///
+/// impl actix::Message for TaskOutput {
+///     type Result = ();
+/// }
+///
+/// impl Component for TaskComponent {
+///     type Init = (usize, woab::BuilderFactory);
+///     type Input = Rename;
+///     type Output = TaskOutput;
+///
+///     fn create_component(
+///         (id, factory): Self::Init,
+///         output: actix::Recipient<TaskOutput>,
+///         ctx: &mut Self::Context,
+///     ) -> Self {
+///         let widgets = factory.instantiate_route_to(ctx.address()).widgets().unwrap();
+///         Self { id, widgets, output }
+///     }
+///
+///     fn root_widget(&self) -> gtk4::Widget {
+///         self.widgets.row.clone().upcast()
+///     }
+/// }
+///
+/// impl actix::Handler<Rename> for TaskComponent {
+///     type Result = ();
+///
+///     fn handle(&mut self, msg: Rename, _ctx: &mut Self::Context) -> Self::Result {
+///         self.widgets.title.set_text(&msg.0);
+///     }
+/// }
+///
+/// impl actix::Handler<woab::Signal> for TaskComponent {
+///     type Result = woab::SignalResult;
+///
+///     fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+///         Ok(match msg.name() {
+///             "done_clicked" => {
+///                 self.output.do_send(TaskOutput { id: self.id, done: true });
+///                 None
+///             }
+///             _ => msg.cant_handle()?,
+///         })
+///     }
+/// }
+///
+/// # let tasks_box: gtk4::Box = panic!();
+/// # let factory: woab::BuilderFactory = panic!();
+/// # let parent: actix::Recipient<TaskOutput> = panic!();
+/// let mut tasks = Children::<TaskComponent>::new(&tasks_box, parent);
+/// let task = tasks.mount((1, factory));
+/// task.do_send(Rename("Write docs".to_owned()));
+/// tasks.unmount(&task);
+/// ```
+pub trait Component:
+    actix::Actor<Context = actix::Context<Self>>
+    + actix::Handler<Self::Input>
+    + actix::Handler<crate::Remove>
+    + actix::Handler<crate::RemoveAndConfirm>
+{
+    /// The data needed for creating the component.
+    type Init: 'static;
+
+    /// Messages the parent sends to the component.
+    type Input: actix::Message<Result = ()> + Send + 'static;
+
+    /// Messages the component sends to the parent.
+    type Output: actix::Message<Result = ()> + Send + 'static;
+
+    /// Create the component's actor.
+    ///
+    /// This is called from inside the actor's context, so the component can route the signals of
+    /// its widgets to `ctx.address()`.
+    fn create_component(init: Self::Init, output: actix::Recipient<Self::Output>, ctx: &mut Self::Context) -> Self;
+
+    /// The widget that should be added to the parent's container.
+    fn root_widget(&self) -> gtk4::Widget;
+
+    /// Create the component, start its actor, and add its widget to the container.
+    fn mount(
+        container: &(impl ComponentContainer + ?Sized),
+        init: Self::Init,
+        output: impl Into<actix::Recipient<Self::Output>>,
+    ) -> actix::Addr<Self> {
+        let output = output.into();
+        <Self as actix::Actor>::create(move |ctx| {
+            let component = Self::create_component(init, output, ctx);
+            container.add_component(&component.root_widget());
+            component
+        })
+    }
+
+    /// Remove the component's widget and stop its actor.
+    fn unmount(addr: &actix::Addr<Self>) {
+        addr.do_send(crate::Remove);
+    }
+}
+
+/// A container that [`Component`]s can be mounted into.
+pub trait ComponentContainer {
+    /// Add the component's root widget at the end of the container.
+    fn add_component(&self, widget: &gtk4::Widget);
+}
+
+impl ComponentContainer for gtk4::Box {
+    fn add_component(&self, widget: &gtk4::Widget) {
+        self.append(widget);
+    }
+}
+
+/// GTK wraps root widgets that are not `gtk4::ListBoxRow`s in rows of their own. Components that
+/// derive [`woab::Removable`](crate::Removable) with `in gtk4::ListBox` remove that row too.
+impl ComponentContainer for gtk4::ListBox {
+    fn add_component(&self, widget: &gtk4::Widget) {
+        self.append(widget);
+    }
+}
+
+/// GTK wraps root widgets that are not `gtk4::FlowBoxChild`s in children of its own. Components
+/// that derive [`woab::Removable`](crate::Removable) with `in gtk4::FlowBox` remove that child too.
+impl ComponentContainer for gtk4::FlowBox {
+    fn add_component(&self, widget: &gtk4::Widget) {
+        self.insert(widget, -1);
+    }
+}
+
+impl ComponentContainer for gtk4::Stack {
+    fn add_component(&self, widget: &gtk4::Widget) {
+        self.add_child(widget);
+    }
+}
+
+/// A dynamic set of [`Component`]s of the same type, mounted into the same container.
+///
+/// All the children send their [`Output`](Component::Output) messages to the same recipient, so
+/// if the parent needs to know which child sent a message the component should include some
+/// identification in it.
+pub struct Children<C: Component> {
+    container: Box<dyn ComponentContainer>,
+    output: actix::Recipient<C::Output>,
+    children: Vec<actix::Addr<C>>,
+}
+
+impl<C: Component> Children<C> {
+    /// Create an empty set of children, to be mounted into `container`.
+    pub fn new(container: &(impl ComponentContainer + Clone + 'static), output: impl Into<actix::Recipient<C::Output>>) -> Self {
+        Self {
+            container: Box::new(container.clone()),
+            output: output.into(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Mount a new child.
+    ///
+    /// Children that already stopped are forgotten, so there is no need to explicitly unregister
+    /// them.
+    pub fn mount(&mut self, init: C::Init) -> actix::Addr<C> {
+        self.children.retain(|child| child.connected());
+        let addr = C::mount(&*self.container, init, self.output.clone());
+        self.children.push(addr.clone());
+        addr
+    }
+
+    /// Unmount a child.
+    pub fn unmount(&mut self, addr: &actix::Addr<C>) {
+        self.children.retain(|child| child != addr);
+        C::unmount(addr);
+    }
+
+    /// Unmount all the children.
+    ///
+    /// The children are sent [`woab::RemoveAndConfirm`](crate::RemoveAndConfirm) immediately, and
+    /// the returned future resolves once all of them are removed.
+    pub fn unmount_all(&mut self) -> impl core::future::Future<Output = ()> + 'static {
+        crate::remove::remove_children(self.children.drain(..).map(|child| child.recipient()).collect())
+    }
+
+    /// Send an input message to all the children.
+    pub fn send_all(&self, input: C::Input)
+    where
+        C::Input: Clone,
+    {
+        for child in self.iter() {
+            child.do_send(input.clone());
+        }
+    }
+
+    /// The children that are still running, in the order they were mounted.
+    pub fn iter(&self) -> impl Iterator<Item = &actix::Addr<C>> {
+        self.children.iter().filter(|child| child.connected())
+    }
+
+    /// The number of children that are still running.
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
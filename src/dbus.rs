@@ -0,0 +1,150 @@
+//! Route D-Bus signals and property changes to actors.
+//!
+//! Requires the `dbus` feature. The D-Bus connection is handled by [zbus](https://docs.rs/zbus)
+//! on the Tokio runtime that WoAB cranks inside the GTK event loop, so the connection (and its
+//! proxies) must be created inside the Actix runtime:
+//!
+//! ```no_run
+//! # use actix::prelude::*;
+//! # struct PlayerActor;
+//! # impl actix::Actor for PlayerActor { type Context = actix::Context<Self>; }
+//! # impl actix::Handler<woab::dbus::DbusSignal> for PlayerActor {
+//! #     type Result = ();
+//! #     fn handle(&mut self, _msg: woab::dbus::DbusSignal, _ctx: &mut Self::Context) {}
+//! # }
+//! # fn main() -> zbus::Result<()> {
+//! woab::block_on(async {
+//!     let connection = zbus::Connection::session().await?;
+//!     let proxy = zbus::Proxy::new(
+//!         &connection,
+//!         "org.mpris.MediaPlayer2.vlc",
+//!         "/org/mpris/MediaPlayer2",
+//!         "org.mpris.MediaPlayer2.Player",
+//!     )
+//!     .await?;
+//!     let player = PlayerActor.start();
+//!     let routing = woab::dbus::route_dbus_signal(&proxy, "Seeked", player.recipient()).await?;
+//!     // Keep `routing` for as long as the signal should be routed.
+//!     # drop(routing);
+//!     Ok(())
+//! })
+//! # }
+//! ```
+
+use core::future::Future;
+use core::pin::Pin;
+use core::task::Poll;
+
+use futures_core::Stream;
+use tokio::sync::oneshot;
+
+/// A D-Bus signal routed by [`route_dbus_signal`].
+#[derive(Debug, Clone)]
+pub struct DbusSignal {
+    /// The name of the D-Bus signal (the member of the message).
+    pub name: String,
+    /// The message of the signal - use `message.body().deserialize()` to get its arguments.
+    pub message: zbus::Message,
+}
+
+impl actix::Message for DbusSignal {
+    type Result = ();
+}
+
+/// A change of a D-Bus property routed by [`route_dbus_property`].
+#[derive(Debug, Clone)]
+pub struct DbusPropertyChanged<T> {
+    /// The name of the property.
+    pub name: String,
+    /// The new value of the property.
+    pub value: T,
+}
+
+impl<T: 'static + Send> actix::Message for DbusPropertyChanged<T> {
+    type Result = ();
+}
+
+/// Keeps a routing created by [`route_dbus_signal`] or [`route_dbus_property`] active.
+///
+/// The routing stops when this is dropped, or when the target actor stops.
+#[must_use = "the routing stops when the DbusRouting is dropped"]
+pub struct DbusRouting {
+    _cancel: oneshot::Sender<()>,
+}
+
+/// Forward the stream's items to the target until it is cancelled or the target is stopped.
+fn spawn_forwarding<S, M, F>(
+    stream: S,
+    target: actix::Recipient<M>,
+    mut to_message: impl 'static + FnMut(S::Item) -> F,
+) -> DbusRouting
+where
+    S: 'static + Stream,
+    M: 'static + actix::Message<Result = ()> + Send,
+    F: Future<Output = Option<M>>,
+{
+    let (cancel_tx, mut cancel_rx) = oneshot::channel();
+    actix::spawn(async move {
+        let mut stream = Box::pin(stream);
+        loop {
+            let item = core::future::poll_fn(|cx| {
+                // Resolves (with an error) when the `DbusRouting` is dropped.
+                if Pin::new(&mut cancel_rx).poll(cx).is_ready() {
+                    return Poll::Ready(None);
+                }
+                stream.as_mut().poll_next(cx)
+            })
+            .await;
+            let Some(item) = item else {
+                return;
+            };
+            if !target.connected() {
+                return;
+            }
+            if let Some(message) = to_message(item).await {
+                target.do_send(message);
+            }
+        }
+    });
+    DbusRouting { _cancel: cancel_tx }
+}
+
+/// Route a D-Bus signal emitted by the object behind a proxy to an actor, as a [`DbusSignal`]
+/// message.
+pub async fn route_dbus_signal(
+    proxy: &zbus::Proxy<'static>,
+    signal_name: &'static str,
+    target: impl Into<actix::Recipient<DbusSignal>>,
+) -> zbus::Result<DbusRouting> {
+    let stream = proxy.receive_signal(signal_name).await?;
+    Ok(spawn_forwarding(stream, target.into(), move |message| {
+        let name = message
+            .header()
+            .member()
+            .map_or_else(|| signal_name.to_owned(), |member| member.to_string());
+        async move { Some(DbusSignal { name, message }) }
+    }))
+}
+
+/// Route the changes of a D-Bus property of the object behind a proxy to an actor, as a
+/// [`DbusPropertyChanged`] message.
+///
+/// Values that cannot be converted to `T` are skipped.
+pub async fn route_dbus_property<T>(
+    proxy: &zbus::Proxy<'static>,
+    property_name: &'static str,
+    target: impl Into<actix::Recipient<DbusPropertyChanged<T>>>,
+) -> zbus::Result<DbusRouting>
+where
+    T: 'static + Send + Unpin + TryFrom<zbus::zvariant::OwnedValue>,
+    T::Error: Into<zbus::Error>,
+{
+    let stream = proxy.receive_property_changed::<T>(property_name).await;
+    Ok(spawn_forwarding(stream, target.into(), move |changed| async move {
+        let value = changed.get().await.ok()?;
+        Some(DbusPropertyChanged {
+            name: property_name.to_owned(),
+            value,
+        })
+    }))
+}
@@ -0,0 +1,292 @@
+use gtk4::prelude::*;
+
+/// Show a dialog and wait for the user to respond to it.
+///
+/// Similar to `gtk4::prelude::DialogExtManual::run_future`, except that in tests the response
+/// can be scripted with [`woab::test::dialogs::auto_respond`](crate::test::dialogs::auto_respond)
+/// (requires the `test-util` feature). The dialog is not closed after the response.
+///
+/// ```no_run
+/// # use gtk4::prelude::*;
+/// # async fn asyncfunc() {
+/// let dialog: gtk4::MessageDialog;
+/// # dialog = panic!();
+/// if woab::run_dialog(&dialog).await == gtk4::ResponseType::Yes {
+///     // ...
+/// }
+/// dialog.close();
+/// # }
+/// ```
+#[cfg_attr(feature = "gtk_v4_10", allow(deprecated))]
+pub async fn run_dialog(dialog: &impl IsA<gtk4::Dialog>) -> gtk4::ResponseType {
+    #[cfg(feature = "test-util")]
+    if let Some(response) = crate::test::dialogs::scripted_response(dialog.upcast_ref()) {
+        dialog.response(response);
+        return response;
+    }
+    dialog.run_future().await
+}
+
+/// Show a dialog and wait for the user to respond to it, unless cancelled.
+///
+/// Similar to [`woab::run_dialog`](run_dialog), but if the cancellable is cancelled before the
+/// user responds the dialog is closed and the future resolves with
+/// [`woab::Error::Cancelled`](crate::Error::Cancelled). If the cancellable was already cancelled,
+/// the dialog is not shown at all.
+///
+/// ```no_run
+/// # use gtk4::prelude::*;
+/// # async fn asyncfunc() -> woab::Result<()> {
+/// let dialog: gtk4::MessageDialog;
+/// let cancellable: gio::Cancellable;
+/// # dialog = panic!();
+/// # cancellable = panic!();
+/// // Some other code can call `cancellable.cancel()` to dismiss the dialog.
+/// if woab::run_dialog_cancellable(&dialog, &cancellable).await? == gtk4::ResponseType::Yes {
+///     // ...
+/// }
+/// dialog.close();
+/// # Ok(())
+/// # }
+/// ```
+#[cfg_attr(feature = "gtk_v4_10", allow(deprecated))]
+pub async fn run_dialog_cancellable(
+    dialog: &impl IsA<gtk4::Dialog>,
+    cancellable: &gio::Cancellable,
+) -> crate::Result<gtk4::ResponseType> {
+    let result = crate::until_cancelled(cancellable, run_dialog(dialog)).await;
+    if let Err(crate::Error::Cancelled) = result {
+        dialog.upcast_ref::<gtk4::Dialog>().close();
+    }
+    result
+}
+
+#[cfg_attr(feature = "gtk_v4_10", allow(deprecated))]
+async fn run_message_dialog(
+    parent: &impl IsA<gtk4::Window>,
+    message_type: gtk4::MessageType,
+    buttons: gtk4::ButtonsType,
+    text: &str,
+) -> gtk4::ResponseType {
+    let dialog = gtk4::MessageDialog::builder()
+        .transient_for(parent)
+        .modal(true)
+        .message_type(message_type)
+        .buttons(buttons)
+        .text(text)
+        .build();
+    let response = run_dialog(&dialog).await;
+    dialog.close();
+    response
+}
+
+/// Show an informational message and wait for the user to dismiss it.
+///
+/// The dialog is a modal `gtk4::MessageDialog`, shown with [`woab::run_dialog`](run_dialog) - so
+/// it can be scripted in tests.
+///
+/// ```no_run
+/// # async fn asyncfunc() {
+/// # let window: gtk4::ApplicationWindow = panic!();
+/// woab::alert_info(&window, "The file was saved").await;
+/// # }
+/// ```
+pub async fn alert_info(parent: &impl IsA<gtk4::Window>, text: &str) {
+    run_message_dialog(parent, gtk4::MessageType::Info, gtk4::ButtonsType::Ok, text).await;
+}
+
+/// Show an error message and wait for the user to dismiss it.
+///
+/// Like [`woab::alert_info`](alert_info), but the dialog is styled as an error.
+pub async fn alert_error(parent: &impl IsA<gtk4::Window>, text: &str) {
+    run_message_dialog(parent, gtk4::MessageType::Error, gtk4::ButtonsType::Ok, text).await;
+}
+
+/// Ask the user to confirm something.
+///
+/// Resolves to `true` if the user pressed OK, and to `false` if they pressed Cancel or closed the
+/// dialog. Like [`woab::alert_info`](alert_info), the dialog can be scripted in tests.
+///
+/// ```no_run
+/// # async fn asyncfunc() {
+/// # let window: gtk4::ApplicationWindow = panic!();
+/// if woab::confirm(&window, "Discard unsaved changes?").await {
+///     // ...
+/// }
+/// # }
+/// ```
+pub async fn confirm(parent: &impl IsA<gtk4::Window>, text: &str) -> bool {
+    run_message_dialog(parent, gtk4::MessageType::Question, gtk4::ButtonsType::OkCancel, text).await == gtk4::ResponseType::Ok
+}
+
+#[cfg(feature = "gtk_v4_10")]
+fn dialog_result<T>(result: Result<T, glib::Error>) -> crate::Result<T> {
+    result.map_err(|err| {
+        if err.matches(gtk4::DialogError::Dismissed) || err.matches(gtk4::DialogError::Cancelled) {
+            crate::Error::Cancelled
+        } else {
+            crate::Error::GenericError(Box::new(err))
+        }
+    })
+}
+
+/// Show a `gtk4::AlertDialog` and wait for the user to pick one of its buttons.
+///
+/// Resolves to the index of the button, or to [`woab::Error::Cancelled`](crate::Error::Cancelled)
+/// if the dialog was dismissed. Requires the `gtk_v4_10` feature.
+///
+/// ```no_run
+/// # async fn asyncfunc() -> woab::Result<()> {
+/// # let window: gtk4::ApplicationWindow = panic!();
+/// let dialog = gtk4::AlertDialog::builder()
+///     .message("Discard changes?")
+///     .buttons(["Cancel", "Discard"])
+///     .build();
+/// if woab::choose_alert(&dialog, Some(&window)).await? == 1 {
+///     // ...
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "gtk_v4_10")]
+pub async fn choose_alert(dialog: &gtk4::AlertDialog, parent: Option<&impl IsA<gtk4::Window>>) -> crate::Result<i32> {
+    dialog_result(dialog.choose_future(parent).await)
+}
+
+/// Show a `gtk4::FileDialog` for opening a file and wait for the user to pick one.
+///
+/// Resolves to [`woab::Error::Cancelled`](crate::Error::Cancelled) if the dialog was dismissed.
+/// Requires the `gtk_v4_10` feature.
+#[cfg(feature = "gtk_v4_10")]
+pub async fn open_file(dialog: &gtk4::FileDialog, parent: Option<&impl IsA<gtk4::Window>>) -> crate::Result<gio::File> {
+    dialog_result(dialog.open_future(parent).await)
+}
+
+/// Show a `gtk4::FileDialog` for saving a file and wait for the user to pick one.
+///
+/// Resolves to [`woab::Error::Cancelled`](crate::Error::Cancelled) if the dialog was dismissed.
+/// Requires the `gtk_v4_10` feature.
+#[cfg(feature = "gtk_v4_10")]
+pub async fn save_file(dialog: &gtk4::FileDialog, parent: Option<&impl IsA<gtk4::Window>>) -> crate::Result<gio::File> {
+    dialog_result(dialog.save_future(parent).await)
+}
+
+/// Show a `gtk4::FileDialog` for selecting a folder and wait for the user to pick one.
+///
+/// Resolves to [`woab::Error::Cancelled`](crate::Error::Cancelled) if the dialog was dismissed.
+/// Requires the `gtk_v4_10` feature.
+#[cfg(feature = "gtk_v4_10")]
+pub async fn select_folder(dialog: &gtk4::FileDialog, parent: Option<&impl IsA<gtk4::Window>>) -> crate::Result<gio::File> {
+    dialog_result(dialog.select_folder_future(parent).await)
+}
+
+/// Build the filters of a file dialog.
+///
+/// `gtk4::FileDialog` takes its filters as a `gio::ListStore` of `gtk4::FileFilter`s, with the
+/// default one set separately. `FileFilters` builds both:
+///
+/// ```no_run
+/// let filters = woab::FileFilters::new()
+///     .filter("Images", ["*.png", "*.jpg", "*.jpeg"])
+///     .as_default()
+///     .mime_filter("Text files", ["text/plain"])
+///     .all_files("All files");
+/// let list_store: gio::ListStore = filters.list_store();
+/// let default_filter: Option<&gtk4::FileFilter> = filters.default_filter();
+/// ```
+///
+/// With the `gtk_v4_10` feature, [`apply_to`](Self::apply_to) sets them on the dialog:
+#[cfg_attr(
+    feature = "gtk_v4_10",
+    doc = r#"
+```no_run
+# async fn asyncfunc() -> woab::Result<()> {
+# let window: gtk4::ApplicationWindow = panic!();
+let dialog = gtk4::FileDialog::new();
+woab::FileFilters::new()
+    .filter("Images", ["*.png", "*.jpg", "*.jpeg"])
+    .as_default()
+    .all_files("All files")
+    .apply_to(&dialog);
+let file = woab::open_file(&dialog, Some(&window)).await?;
+# Ok(())
+# }
+```
+"#
+)]
+#[derive(Debug, Default, Clone)]
+pub struct FileFilters {
+    filters: Vec<gtk4::FileFilter>,
+    default: Option<usize>,
+}
+
+impl FileFilters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn add(mut self, name: &str, configure: impl FnOnce(&gtk4::FileFilter)) -> Self {
+        let filter = gtk4::FileFilter::new();
+        filter.set_name(Some(name));
+        configure(&filter);
+        self.filters.push(filter);
+        self
+    }
+
+    /// Add a filter that matches files by glob patterns (e.g. `*.txt`).
+    pub fn filter<'a>(self, name: &str, patterns: impl IntoIterator<Item = &'a str>) -> Self {
+        self.add(name, |filter| {
+            for pattern in patterns {
+                filter.add_pattern(pattern);
+            }
+        })
+    }
+
+    /// Add a filter that matches files by MIME types (e.g. `text/plain` or `image/*`).
+    pub fn mime_filter<'a>(self, name: &str, mime_types: impl IntoIterator<Item = &'a str>) -> Self {
+        self.add(name, |filter| {
+            for mime_type in mime_types {
+                filter.add_mime_type(mime_type);
+            }
+        })
+    }
+
+    /// Add a filter that matches all the files.
+    ///
+    /// The name is a parameter so that it can be translated.
+    pub fn all_files(self, name: &str) -> Self {
+        self.filter(name, ["*"])
+    }
+
+    /// Make the last added filter the default one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no filter was added yet.
+    pub fn as_default(mut self) -> Self {
+        assert!(!self.filters.is_empty(), "as_default called before any filter was added");
+        self.default = Some(self.filters.len() - 1);
+        self
+    }
+
+    /// The filters, as the `gio::ListStore` that GTK expects.
+    pub fn list_store(&self) -> gio::ListStore {
+        let list_store = gio::ListStore::new::<gtk4::FileFilter>();
+        list_store.extend_from_slice(&self.filters);
+        list_store
+    }
+
+    /// The filter marked with [`as_default`](Self::as_default).
+    pub fn default_filter(&self) -> Option<&gtk4::FileFilter> {
+        self.filters.get(self.default?)
+    }
+
+    /// Set the filters, and the default filter, of a `gtk4::FileDialog`.
+    ///
+    /// Requires the `gtk_v4_10` feature.
+    #[cfg(feature = "gtk_v4_10")]
+    pub fn apply_to(&self, dialog: &gtk4::FileDialog) {
+        dialog.set_filters(Some(&self.list_store()));
+        dialog.set_default_filter(self.default_filter());
+    }
+}
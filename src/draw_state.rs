@@ -0,0 +1,118 @@
+use core::cell::{Ref, RefCell};
+use std::rc::Rc;
+
+use gtk4::prelude::*;
+
+struct DrawStateInner<S> {
+    state: RefCell<S>,
+    drawing_areas: RefCell<Vec<glib::WeakRef<gtk4::DrawingArea>>>,
+}
+
+/// State that an actor shares with the draw functions of its drawing areas.
+///
+/// Routing each `draw` to the actor (with `woab::block_on(addr.send(...))`) means a full mailbox
+/// round-trip per drawing area per frame. Instead, the actor can keep the data needed for drawing
+/// in a `DrawState`, and use [`woab::draw_func_from_actor_state`](draw_func_from_actor_state) (or
+/// [`woab::tick_callback_from_actor_state`](tick_callback_from_actor_state)) to draw directly
+/// from it in the GTK thread.
+///
+/// Updating the state with [`set`](DrawState::set) or [`update`](DrawState::update) queues a
+/// redraw of all the drawing areas that draw from it. `DrawState` is a cheap handle - clones refer
+/// to the same state.
+pub struct DrawState<S>(Rc<DrawStateInner<S>>);
+
+impl<S> Clone for DrawState<S> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<S> DrawState<S> {
+    /// Create a draw state with an initial value.
+    pub fn new(state: S) -> Self {
+        Self(Rc::new(DrawStateInner {
+            state: RefCell::new(state),
+            drawing_areas: Default::default(),
+        }))
+    }
+
+    /// Borrow the current state.
+    pub fn get(&self) -> Ref<'_, S> {
+        self.0.state.borrow()
+    }
+
+    /// Replace the state and queue a redraw.
+    pub fn set(&self, state: S) {
+        *self.0.state.borrow_mut() = state;
+        self.queue_draw();
+    }
+
+    /// Modify the state and queue a redraw.
+    pub fn update<R>(&self, dlg: impl FnOnce(&mut S) -> R) -> R {
+        let result = dlg(&mut self.0.state.borrow_mut());
+        self.queue_draw();
+        result
+    }
+
+    /// Queue a redraw of all the drawing areas that draw from this state.
+    pub fn queue_draw(&self) {
+        self.0.drawing_areas.borrow_mut().retain(|drawing_area| {
+            if let Some(drawing_area) = drawing_area.upgrade() {
+                drawing_area.queue_draw();
+                true
+            } else {
+                false
+            }
+        });
+    }
+}
+
+/// Set the draw function of a drawing area to draw from an actor's [`DrawState`], without
+/// involving the actor itself.
+///
+/// The draw function gets the state, the Cairo context, and the width and height of the drawing
+/// area. It runs in the GTK thread while GTK renders the frame, so it should not try to access the
+/// actor.
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// struct BallActor {
+///     draw_state: woab::DrawState<f64>,
+/// }
+/// # impl actix::Actor for BallActor { type Context = actix::Context<Self>; }
+///
+/// # let drawing_area: gtk4::DrawingArea = panic!();
+/// let draw_state = woab::DrawState::new(0.0);
+/// woab::draw_func_from_actor_state(&drawing_area, &draw_state, |position, draw_ctx, width, height| {
+///     draw_ctx.arc(position * width as f64, 0.5 * height as f64, 10.0, 0.0, 2.0 * std::f64::consts::PI);
+///     draw_ctx.fill().unwrap();
+/// });
+/// BallActor { draw_state }.start();
+/// // Inside the actor:
+/// // self.draw_state.set(new_position);
+/// ```
+pub fn draw_func_from_actor_state<S: 'static>(
+    drawing_area: &gtk4::DrawingArea,
+    state: &DrawState<S>,
+    draw: impl 'static + Fn(&S, &gtk4::cairo::Context, i32, i32),
+) {
+    state.0.drawing_areas.borrow_mut().push(drawing_area.downgrade());
+    let state = state.clone();
+    drawing_area.set_draw_func(move |_, draw_ctx, width, height| {
+        draw(&state.get(), draw_ctx, width, height);
+    });
+}
+
+/// Add a tick callback to a widget that reads an actor's [`DrawState`], without involving the
+/// actor itself.
+///
+/// Like `gtk4::prelude::WidgetExt::add_tick_callback`, the callback is called once per frame
+/// until it returns `glib::ControlFlow::Break` or until it is removed with the returned ID.
+pub fn tick_callback_from_actor_state<S: 'static>(
+    widget: &impl IsA<gtk4::Widget>,
+    state: &DrawState<S>,
+    tick: impl 'static + Fn(&S, &gtk4::Widget, &gdk4::FrameClock) -> glib::ControlFlow,
+) -> gtk4::TickCallbackId {
+    let state = state.clone();
+    widget.add_tick_callback(move |widget, frame_clock| tick(&state.get(), widget.upcast_ref(), frame_clock))
+}
@@ -0,0 +1,107 @@
+use gtk4::prelude::*;
+
+use crate::{GenerateRoutingGtkHandler, IntoGenerateRoutingGtkHandler};
+
+/// The standard clipboard and selection commands, dispatched with
+/// [`woab::dispatch_edit_command`](dispatch_edit_command).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditCommand {
+    Copy,
+    Cut,
+    Paste,
+    SelectAll,
+}
+
+impl EditCommand {
+    /// All the commands.
+    pub const ALL: [Self; 4] = [Self::Copy, Self::Cut, Self::Paste, Self::SelectAll];
+
+    /// The name of the action [`woab::add_edit_actions`](add_edit_actions) creates for the
+    /// command - e.g. `select-all`, to be used as `win.select-all`.
+    pub fn action_name(&self) -> &'static str {
+        match self {
+            Self::Copy => "copy",
+            Self::Cut => "cut",
+            Self::Paste => "paste",
+            Self::SelectAll => "select-all",
+        }
+    }
+
+    /// The name of the [`woab::Signal`](crate::Signal) routed to the actor of the focused widget,
+    /// when the widget cannot handle the command by itself.
+    pub fn signal_name(&self) -> &'static str {
+        match self {
+            Self::Copy => "edit_copy",
+            Self::Cut => "edit_cut",
+            Self::Paste => "edit_paste",
+            Self::SelectAll => "edit_select_all",
+        }
+    }
+
+    /// The GTK widget action that editable widgets (`gtk4::Text`, `gtk4::TextView`, selectable
+    /// `gtk4::Label`s...) use for the command.
+    fn widget_action_name(&self) -> &'static str {
+        match self {
+            Self::Copy => "clipboard.copy",
+            Self::Cut => "clipboard.cut",
+            Self::Paste => "clipboard.paste",
+            Self::SelectAll => "selection.select-all",
+        }
+    }
+}
+
+/// Run an edit command on a widget - usually the focused widget of a window.
+///
+/// If the widget (or, for editables like `gtk4::Entry`, the `gtk4::Text` inside it) supports the
+/// command, it handles it like it would handle the keyboard shortcut. Otherwise, the command is
+/// routed as a [`woab::Signal`](crate::Signal) named after [`EditCommand::signal_name`] to the
+/// actor [registered](crate::register_actor) for the widget or its closest ancestor. The only
+/// parameter of the signal is the widget.
+///
+/// Returns `false` if neither the widget nor an actor could handle the command.
+pub fn dispatch_edit_command(widget: &impl IsA<gtk4::Widget>, command: EditCommand) -> bool {
+    let widget = widget.upcast_ref::<gtk4::Widget>();
+    let delegate = widget
+        .downcast_ref::<gtk4::Editable>()
+        .and_then(|editable| editable.delegate())
+        .map(|delegate| delegate.upcast::<gtk4::Widget>());
+    let target = delegate.as_ref().unwrap_or(widget);
+    if target.activate_action(command.widget_action_name(), None).is_ok() {
+        return true;
+    }
+    let Some(actor) = crate::actor_for(widget) else {
+        return false;
+    };
+    let callback = actor
+        .into_generate_routing_gtk_handler()
+        .raw_signal_callback(command.signal_name());
+    callback(&[widget.to_value()]);
+    true
+}
+
+/// Add the standard `copy`, `cut`, `paste` and `select-all` actions to a window.
+///
+/// GTK4 widgets handle these commands with their keyboard shortcuts, but menu items that use the
+/// conventional `win.copy` (etc.) actions need someone to implement them. The actions added by
+/// this function [dispatch](dispatch_edit_command) the command to whichever widget has the focus
+/// in the window when they are activated.
+///
+/// ```no_run
+/// # use gtk4::prelude::*;
+/// # let app: gtk4::Application = panic!();
+/// # let window: gtk4::ApplicationWindow = panic!();
+/// woab::add_edit_actions(&window);
+/// app.set_accels_for_action("win.select-all", &["<Control>a"]);
+/// ```
+pub fn add_edit_actions(window: &(impl IsA<gtk4::Window> + IsA<gio::ActionMap>)) {
+    for command in EditCommand::ALL {
+        let action = gio::SimpleAction::new(command.action_name(), None);
+        let window_ref = window.upcast_ref::<gtk4::Window>().downgrade();
+        action.connect_activate(move |_, _| {
+            if let Some(focus) = window_ref.upgrade().and_then(|window| GtkWindowExt::focus(&window)) {
+                dispatch_edit_command(&focus, command);
+            }
+        });
+        window.add_action(&action);
+    }
+}
@@ -1,6 +1,7 @@
 pub type Result<T> = core::result::Result<T, Error>;
 
 #[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
 pub enum Error {
     #[error(transparent)]
     IoError(#[from] std::io::Error),
@@ -20,8 +21,12 @@ pub enum Error {
     /// When extracting widgets using
     /// [`BuilderWidgets::widgets`](crate::BuilderWidgets::widgets) and one of the widgets is
     /// missing.
-    #[error("Builder is missing widget with ID {0:?}")]
-    WidgetMissingInBuilder(String),
+    #[error("Builder is missing widget with ID {widget_id:?}{}", did_you_mean(.suggestions))]
+    WidgetMissingInBuilder {
+        widget_id: String,
+        /// IDs of widgets that do exist in the builder and are similar to `widget_id`.
+        suggestions: Vec<String>,
+    },
 
     /// When extracting widgets using
     /// [`BuilderWidgets::widgets`](crate::BuilderWidgets::widgets) and one of the widgets has the
@@ -33,9 +38,29 @@ pub enum Error {
         actual_type: glib::types::Type,
     },
 
+    /// When reading a [`woab::Factories`](crate::Factories) struct and no object in the UI XML has
+    /// the ID of one of its fields.
+    #[error("No object in the UI XML has the ID {field:?}{}", did_you_mean(&suggestions_for(.field, .known_ids)))]
+    FactoryIdNotFound {
+        field: String,
+        /// The IDs of all the objects in the UI XML.
+        known_ids: Vec<String>,
+    },
+
     /// When a signal handler does not recognize the name of the signal routed to it.
-    #[error("Cannot handle the signal named {0:?}")]
-    NoSuchSignalError(String),
+    #[error("Cannot handle the signal named {signal:?}{}", did_you_mean(.suggestions))]
+    NoSuchSignalError {
+        signal: String,
+        /// Names of signals the handler does handle that are similar to `signal`. Only available
+        /// when the handler used
+        /// [`Signal::cant_handle_expecting`](crate::Signal::cant_handle_expecting).
+        suggestions: Vec<String>,
+    },
+
+    /// When [`BuilderFactory::check_signals_handled`](crate::BuilderFactory::check_signals_handled)
+    /// finds signals that none of the targets handle.
+    #[error("No actor handles the signals {signals:?}")]
+    UnhandledSignals { signals: Vec<String> },
 
     /// When a signal parameter has the wrong type.
     #[error("Expected the parameter at index {index} of {signal:?} to be {expected_type} - not {actual_type}")]
@@ -78,9 +103,77 @@ pub enum Error {
         num_extracted: usize,
     },
 
+    /// When [`woab::route_detailed_action`](crate::route_detailed_action) gets a name that is not
+    /// a detailed action name with a target (like `app.alignment::center`).
+    #[error("{0:?} is not a detailed action name with a target")]
+    InvalidDetailedActionName(String),
+
+    /// When [`Signal::detailed_target`](crate::Signal::detailed_target) is used on an action
+    /// signal that has no target.
+    #[error("Action signal {signal:?} has no target")]
+    MissingActionTarget { signal: String },
+
+    /// When trying to set a property that the object does not have.
+    #[error("{object_type} does not have a property named {property:?}")]
+    NoSuchProperty {
+        object_type: glib::types::Type,
+        property: String,
+    },
+
+    /// When [`woab::bind_settings`](crate::bind_settings) gets a key that is not in the settings'
+    /// schema.
+    #[error("The settings schema has no key named {key:?}{}", did_you_mean(&suggestions_for(.key, .known_keys)))]
+    NoSuchSettingsKey {
+        key: String,
+        /// All the keys in the settings' schema.
+        known_keys: Vec<String>,
+    },
+
+    /// When trying to set a property to a value of the wrong type, or to get a property into a
+    /// type that cannot hold its value (including getting a `None` value into a type that is not
+    /// an `Option`).
+    #[error("Expected the value of property {property:?} to be {expected_type} - not {actual_type}")]
+    IncorrectPropertyType {
+        property: String,
+        expected_type: glib::types::Type,
+        actual_type: glib::types::Type,
+    },
+
+    /// When [`woab::route_shortcuts`](crate::route_shortcuts) gets a trigger string that GTK
+    /// cannot parse.
+    #[error("Cannot parse {0:?} as a shortcut trigger")]
+    InvalidShortcutTrigger(String),
+
+    /// When navigating with a [`woab::Navigator`](crate::Navigator) to a page ID that was not
+    /// registered.
+    #[error("No page is registered with the ID {page_id:?}{}", did_you_mean(&suggestions_for(.page_id, .known_ids)))]
+    NoSuchPage {
+        page_id: String,
+        /// The IDs of all the registered pages.
+        known_ids: Vec<String>,
+    },
+
+    /// When navigating with a [`woab::Navigator`](crate::Navigator) with params that are not of
+    /// the page's [`Params`](crate::navigation::Page::Params) type.
+    #[error("Expected the params of page {page_id:?} to be {expected_type}")]
+    IncorrectPageParams { page_id: String, expected_type: &'static str },
+
+    /// When a [`list_binding::Reset`](crate::list_binding::Reset) contains multiple items with the
+    /// same key.
+    #[error("The items of a ListBinding must have unique keys")]
+    DuplicateListBindingKeys,
+
     #[error(transparent)]
     WakerPerished(#[from] WakerPerished),
 
+    /// When the future passed to [`woab::outside`](crate::outside) panics.
+    #[error("Future ran outside the Actix runtime panicked: {0}")]
+    OutsidePanicked(String),
+
+    /// When an operation was aborted by cancelling its `gio::Cancellable`.
+    #[error("The operation was cancelled")]
+    Cancelled,
+
     #[error(transparent)]
     RuntimeStopError(#[from] crate::RuntimeStopError),
 
@@ -88,6 +181,65 @@ pub enum Error {
     GenericError(#[from] Box<dyn 'static + Send + Sync + std::error::Error>),
 }
 
+impl Error {
+    #[doc(hidden)]
+    pub fn widget_missing_in_builder(builder: &gtk4::Builder, widget_id: &str) -> Self {
+        use crate::FindWidget;
+
+        Self::WidgetMissingInBuilder {
+            widget_id: widget_id.to_owned(),
+            suggestions: suggestions_for(widget_id, builder.widget_ids()),
+        }
+    }
+}
+
+/// The candidates that are similar enough to `name` to be suggested instead of it, most similar
+/// first.
+pub(crate) fn suggestions_for(name: &str, candidates: impl IntoIterator<Item = impl AsRef<str>>) -> Vec<String> {
+    const MAX_SUGGESTIONS: usize = 3;
+    let max_distance = (name.chars().count() / 3).max(1);
+    let mut suggestions = candidates
+        .into_iter()
+        .filter_map(|candidate| {
+            let candidate = candidate.as_ref();
+            let distance = levenshtein_distance(name, candidate);
+            (distance <= max_distance && candidate != name).then(|| (distance, candidate.to_owned()))
+        })
+        .collect::<Vec<_>>();
+    suggestions.sort();
+    suggestions.dedup();
+    suggestions
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, candidate)| candidate)
+        .collect()
+}
+
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut previous_row = (0..=b.len()).collect::<Vec<_>>();
+    let mut current_row = vec![0; b.len() + 1];
+    for (i, a_char) in a.chars().enumerate() {
+        current_row[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution_cost = if a_char == *b_char { 0 } else { 1 };
+            current_row[j + 1] = (previous_row[j] + substitution_cost)
+                .min(previous_row[j + 1] + 1)
+                .min(current_row[j] + 1);
+        }
+        core::mem::swap(&mut previous_row, &mut current_row);
+    }
+    previous_row[b.len()]
+}
+
+fn did_you_mean(suggestions: &[String]) -> String {
+    match suggestions {
+        [] => String::new(),
+        [suggestion] => format!(" - did you mean {:?}?", suggestion),
+        suggestions => format!(" - did you mean one of {:?}?", suggestions),
+    }
+}
+
 /// When a future cannot be woken.
 ///
 /// When using a function like [`woab::wake_from_signal`](crate::wake_from_signal) there is no
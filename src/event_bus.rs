@@ -0,0 +1,140 @@
+//! Broadcast application-wide events to multiple actors.
+//!
+//! See [`EventBus`] for usage instructions.
+
+use hashbrown::HashMap;
+
+/// An actor that broadcasts events to all the recipients that subscribed to it.
+///
+/// * [`Subscribe`] - register a recipient. Resolves to a [`SubscriptionId`].
+/// * [`Unsubscribe`] - unregister a recipient using its `SubscriptionId`.
+/// * [`Publish`] - send a clone of the event to all the registered recipients.
+///
+/// Recipients whose actors were stopped are removed automatically, so actors are not required
+/// to unsubscribe before stopping.
+///
+/// The event bus is a system service - use `EventBus::<E>::from_registry()` to get the same bus
+/// everywhere in the application:
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// use woab::event_bus::{EventBus, Publish, Subscribe};
+///
+/// #[derive(Clone)]
+/// enum AppEvent {
+///     ThemeChanged,
+///     DocumentSaved(String),
+/// }
+///
+/// impl actix::Message for AppEvent {
+///     type Result = ();
+/// }
+///
+/// struct StatusActor;
+///
+/// impl actix::Actor for StatusActor {
+///     type Context = actix::Context<Self>;
+///
+///     fn started(&mut self, ctx: &mut Self::Context) {
+///         EventBus::<AppEvent>::from_registry().do_send(Subscribe(ctx.address().recipient()));
+///     }
+/// }
+///
+/// impl actix::Handler<AppEvent> for StatusActor {
+///     type Result = ();
+///
+///     fn handle(&mut self, msg: AppEvent, _ctx: &mut Self::Context) -> Self::Result {
+///         // ...
+///     }
+/// }
+///
+/// EventBus::<AppEvent>::from_registry().do_send(Publish(AppEvent::DocumentSaved("notes.txt".to_owned())));
+/// ```
+pub struct EventBus<E: actix::Message<Result = ()> + Send + Clone + 'static> {
+    subscribers: HashMap<SubscriptionId, actix::Recipient<E>>,
+    last_id: u64,
+}
+
+impl<E: actix::Message<Result = ()> + Send + Clone + 'static> Default for EventBus<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: actix::Message<Result = ()> + Send + Clone + 'static> EventBus<E> {
+    /// Create an event bus with no subscribers.
+    ///
+    /// Only needed for buses that are not shared application-wide - otherwise, use
+    /// `EventBus::<E>::from_registry()`.
+    pub fn new() -> Self {
+        Self {
+            subscribers: HashMap::new(),
+            last_id: 0,
+        }
+    }
+}
+
+impl<E: actix::Message<Result = ()> + Send + Clone + 'static> actix::Actor for EventBus<E> {
+    type Context = actix::Context<Self>;
+}
+
+impl<E: actix::Message<Result = ()> + Send + Clone + 'static> actix::Supervised for EventBus<E> {}
+
+impl<E: actix::Message<Result = ()> + Send + Clone + 'static> actix::SystemService for EventBus<E> {}
+
+/// Identifies a subscription to an [`EventBus`], for [`Unsubscribe`]-ing it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SubscriptionId(u64);
+
+/// Register a recipient to the events of an [`EventBus`].
+pub struct Subscribe<E: actix::Message<Result = ()> + Send>(pub actix::Recipient<E>);
+
+impl<E: actix::Message<Result = ()> + Send + Clone + 'static> actix::Message for Subscribe<E> {
+    type Result = SubscriptionId;
+}
+
+impl<E: actix::Message<Result = ()> + Send + Clone + 'static> actix::Handler<Subscribe<E>> for EventBus<E> {
+    type Result = actix::MessageResult<Subscribe<E>>;
+
+    fn handle(&mut self, msg: Subscribe<E>, _ctx: &mut Self::Context) -> Self::Result {
+        self.last_id += 1;
+        let id = SubscriptionId(self.last_id);
+        self.subscribers.insert(id, msg.0);
+        actix::MessageResult(id)
+    }
+}
+
+/// Unregister a recipient from an [`EventBus`].
+///
+/// Resolves to `false` if there was no such subscription.
+pub struct Unsubscribe(pub SubscriptionId);
+
+impl actix::Message for Unsubscribe {
+    type Result = bool;
+}
+
+impl<E: actix::Message<Result = ()> + Send + Clone + 'static> actix::Handler<Unsubscribe> for EventBus<E> {
+    type Result = bool;
+
+    fn handle(&mut self, msg: Unsubscribe, _ctx: &mut Self::Context) -> Self::Result {
+        self.subscribers.remove(&msg.0).is_some()
+    }
+}
+
+/// Send an event to all the subscribers of an [`EventBus`].
+pub struct Publish<E>(pub E);
+
+impl<E: actix::Message<Result = ()> + Send + Clone + 'static> actix::Message for Publish<E> {
+    type Result = ();
+}
+
+impl<E: actix::Message<Result = ()> + Send + Clone + 'static> actix::Handler<Publish<E>> for EventBus<E> {
+    type Result = ();
+
+    fn handle(&mut self, msg: Publish<E>, _ctx: &mut Self::Context) -> Self::Result {
+        self.subscribers.retain(|_, recipient| recipient.connected());
+        for recipient in self.subscribers.values() {
+            recipient.do_send(msg.0.clone());
+        }
+    }
+}
@@ -1,15 +1,223 @@
 use core::cell::RefCell;
 use core::future::Future;
+use core::time::Duration;
 
 struct WoabRuntime {
     actix_system_runner: actix::SystemRunner,
-    runtime_cranker_source_id: glib::SourceId,
 }
 
 thread_local! {
     static WOAB_RUNTIME: RefCell<Option<WoabRuntime>> = const { RefCell::new(None) };
 }
 
+#[cfg(feature = "test-util")]
+thread_local! {
+    /// Set while the clock is paused by [`woab::test::clock`](crate::test::clock).
+    pub(crate) static CRANK_WITHOUT_SLEEPING: core::cell::Cell<bool> = const { core::cell::Cell::new(false) };
+}
+
+/// How WoAB cranks the Actix runtime from the GTK event loop.
+///
+/// GTK signals that are routed to actors are handled immediately regardless of the mode - the
+/// cranking is for everything else (messages between actors, futures, timers, events from other
+/// threads...).
+///
+/// Set with [`woab::set_cranking_mode`](set_cranking_mode).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrankingMode {
+    /// Crank the runtime whenever GTK is idle, letting it run for up to 10ms each time.
+    ///
+    /// This is the default. It has the lowest latency, but it never lets the process sleep.
+    Continuous,
+    /// Crank the runtime periodically, backing off when there is nothing to do.
+    ///
+    /// The interval between cranks starts at `min_interval` and doubles after each crank (up to
+    /// `max_interval`). It is reset to `min_interval` whenever Actix is used from the GTK side
+    /// (a routed signal is handled, [`woab::block_on`](block_on) is called...) because this usually
+    /// means more work is coming.
+    ///
+    /// Events that come from other threads may take up to `max_interval` to be handled.
+    Adaptive { min_interval: Duration, max_interval: Duration },
+}
+
+struct Cranker {
+    mode: CrankingMode,
+    priority: Option<glib::Priority>,
+    source_id: Option<glib::SourceId>,
+    interval: Duration,
+    // Changes whenever the cranker is stopped, so that callbacks of old sources will not
+    // reschedule themselves.
+    generation: u64,
+}
+
+thread_local! {
+    static CRANKER: RefCell<Cranker> = const {
+        RefCell::new(Cranker {
+            mode: CrankingMode::Continuous,
+            priority: None,
+            source_id: None,
+            interval: Duration::ZERO,
+            generation: 0,
+        })
+    };
+}
+
+impl Cranker {
+    fn priority(&self) -> glib::Priority {
+        self.priority.unwrap_or(match self.mode {
+            CrankingMode::Continuous => glib::Priority::DEFAULT_IDLE,
+            CrankingMode::Adaptive { .. } => glib::Priority::DEFAULT,
+        })
+    }
+}
+
+fn crank_once(mode: CrankingMode) {
+    WOAB_RUNTIME.with(|woab_runtime| {
+        let woab_runtime = woab_runtime
+            .try_borrow_mut()
+            .map_err(|_| "WoAB's cranker called inside Actix context")
+            .unwrap();
+        let Some(woab_runtime) = woab_runtime.as_ref() else {
+            return;
+        };
+        woab_runtime.actix_system_runner.block_on(async {
+            #[cfg(feature = "test-util")]
+            if CRANK_WITHOUT_SLEEPING.with(|flag| flag.get()) {
+                // Sleeping while the clock is paused would auto-advance it.
+                tokio::task::yield_now().await;
+                return;
+            }
+            match mode {
+                CrankingMode::Continuous => actix::clock::sleep(Duration::from_millis(10)).await,
+                // Run whatever is ready without holding the GTK thread.
+                CrankingMode::Adaptive { .. } => tokio::task::yield_now().await,
+            }
+        });
+    });
+}
+
+fn start_cranker() {
+    CRANKER.with(|cranker| {
+        let mut cranker = cranker.borrow_mut();
+        let mode = cranker.mode;
+        let priority = cranker.priority();
+        cranker.source_id = Some(match mode {
+            CrankingMode::Continuous => glib::idle_source_new(None, priority, move || {
+                crank_once(mode);
+                glib::ControlFlow::Continue
+            })
+            .attach(None),
+            CrankingMode::Adaptive { min_interval, .. } => {
+                cranker.interval = min_interval;
+                schedule_adaptive_crank(min_interval, priority, cranker.generation)
+            }
+        });
+    });
+}
+
+fn schedule_adaptive_crank(interval: Duration, priority: glib::Priority, generation: u64) -> glib::SourceId {
+    glib::timeout_source_new(interval, None, priority, move || {
+        let mode = CRANKER.with(|cranker| cranker.borrow().mode);
+        crank_once(mode);
+        CRANKER.with(|cranker| {
+            let mut cranker = cranker.borrow_mut();
+            if cranker.generation != generation {
+                // The cranker was stopped or restarted while we were cranking.
+                return;
+            }
+            if let CrankingMode::Adaptive { max_interval, .. } = cranker.mode {
+                cranker.interval = (cranker.interval * 2).min(max_interval);
+                // This source is removed when we return `Break`, so it must be replaced.
+                cranker.source_id = Some(schedule_adaptive_crank(cranker.interval, cranker.priority(), generation));
+            }
+        });
+        glib::ControlFlow::Break
+    })
+    .attach(None)
+}
+
+fn stop_cranker() {
+    CRANKER.with(|cranker| {
+        let mut cranker = cranker.borrow_mut();
+        cranker.generation += 1;
+        if let Some(source_id) = cranker.source_id.take() {
+            source_id.remove();
+        }
+    });
+}
+
+/// Reset the adaptive cranking interval, since Actix was used from the GTK side.
+fn notify_activity() {
+    CRANKER.with(|cranker| {
+        let mut cranker = cranker.borrow_mut();
+        let CrankingMode::Adaptive { min_interval, .. } = cranker.mode else {
+            return;
+        };
+        if cranker.interval <= min_interval {
+            return;
+        }
+        let Some(source_id) = cranker.source_id.take() else {
+            return;
+        };
+        source_id.remove();
+        cranker.interval = min_interval;
+        cranker.source_id = Some(schedule_adaptive_crank(min_interval, cranker.priority(), cranker.generation));
+    });
+}
+
+/// Change the way WoAB cranks the Actix runtime from the GTK event loop.
+///
+/// Can be called before or after the runtime is started.
+///
+/// ```no_run
+/// # use core::time::Duration;
+/// // Let a tray application sleep when nothing happens:
+/// woab::set_cranking_mode(woab::CrankingMode::Adaptive {
+///     min_interval: Duration::from_millis(10),
+///     max_interval: Duration::from_millis(500),
+/// });
+/// ```
+pub fn set_cranking_mode(mode: CrankingMode) {
+    let was_running = CRANKER.with(|cranker| {
+        let mut cranker = cranker.borrow_mut();
+        cranker.mode = mode;
+        cranker.source_id.is_some()
+    });
+    if was_running {
+        stop_cranker();
+        start_cranker();
+    }
+}
+
+/// Change the GLib priority of the source that cranks the Actix runtime.
+///
+/// By default, [`CrankingMode::Continuous`] cranks with `glib::Priority::DEFAULT_IDLE` and
+/// [`CrankingMode::Adaptive`] with `glib::Priority::DEFAULT`. GTK's frame clock, which resizes
+/// and redraws the widgets, runs with a priority between `HIGH_IDLE` and `DEFAULT_IDLE`, so:
+///
+/// * With a lower priority (numerically higher, like the default `DEFAULT_IDLE`) GTK's layout and
+///   drawing always come before the actors, so a busy runtime cannot make the UI stutter. This
+///   suits games and animations.
+/// * With a higher priority (numerically lower, like `HIGH_IDLE`) the actors come first, which
+///   lowers their latency at the cost of smooth redrawing. This suits latency-sensitive tools.
+///
+/// Can be called before or after the runtime is started. Pass `None` to restore the default.
+///
+/// ```no_run
+/// woab::set_cranking_priority(Some(glib::Priority::HIGH_IDLE));
+/// ```
+pub fn set_cranking_priority(priority: Option<glib::Priority>) {
+    let was_running = CRANKER.with(|cranker| {
+        let mut cranker = cranker.borrow_mut();
+        cranker.priority = priority;
+        cranker.source_id.is_some()
+    });
+    if was_running {
+        stop_cranker();
+        start_cranker();
+    }
+}
+
 /// Run a feature inside the Actix system GTK will be spinning.
 ///
 /// Use this when starting actors from outside Tokio/Actix (e.g. - when creating the starteup
@@ -27,7 +235,7 @@ pub fn block_on<F: Future>(fut: F) -> <F as Future>::Output {
 /// using, and instead return the future as the error value so that it could be executed in some
 /// other fashion.
 pub fn try_block_on<F: Future>(fut: F) -> Result<<F as Future>::Output, F> {
-    WOAB_RUNTIME.with(|woab_runtime| {
+    let result = WOAB_RUNTIME.with(|woab_runtime| {
         if let Ok(woab_runtime) = woab_runtime.try_borrow_mut() {
             let woab_runtime = woab_runtime
                 .as_ref()
@@ -37,29 +245,27 @@ pub fn try_block_on<F: Future>(fut: F) -> Result<<F as Future>::Output, F> {
         } else {
             Err(fut)
         }
-    })
+    });
+    if result.is_ok() {
+        notify_activity();
+    }
+    result
 }
 
 /// Start an Actix `System` that runs inside the GTK thread.
+///
+/// The runtime is cranked according to the [`CrankingMode`].
 pub fn run_actix_inside_gtk_event_loop() {
     WOAB_RUNTIME.with(|woab_runtime| {
         let mut woab_runtime = woab_runtime.borrow_mut();
         if woab_runtime.is_some() {
             panic!("WoAB is already running Actix inside the GTK event loop");
         }
-        let runtime_cranker_source_id = glib::idle_add(|| {
-            try_block_on(async {
-                actix::clock::sleep(core::time::Duration::new(0, 10_000_000)).await;
-            })
-            .map_err(|_| "`idle_add` function called inside Actix context")
-            .unwrap();
-            glib::ControlFlow::Continue
-        });
         *woab_runtime = Some(WoabRuntime {
             actix_system_runner: actix::System::new(),
-            runtime_cranker_source_id,
         });
     });
+    start_cranker();
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -86,7 +292,7 @@ pub fn close_actix_runtime() -> Result<Result<(), std::io::Error>, RuntimeStopEr
     woab_runtime.actix_system_runner.block_on(async {
         actix::System::current().stop();
     });
-    woab_runtime.runtime_cranker_source_id.remove();
+    stop_cranker();
     Ok(woab_runtime.actix_system_runner.run())
 }
 
@@ -0,0 +1,116 @@
+use std::path::PathBuf;
+
+use gio::prelude::*;
+
+/// A change of a file watched with [`woab::watch_file`](watch_file).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileEvent {
+    /// The contents of the file changed. Usually followed by [`ChangesDone`](Self::ChangesDone).
+    Changed(PathBuf),
+    /// A batch of changes to the file is done - a good time to reload it.
+    ChangesDone(PathBuf),
+    /// The file was created.
+    Created(PathBuf),
+    /// The file was deleted.
+    Deleted(PathBuf),
+    /// The metadata of the file (permissions, timestamps...) changed.
+    AttributeChanged(PathBuf),
+    /// The file was moved or renamed. `from` or `to` are `None` if they are outside the watched
+    /// location.
+    Moved { from: Option<PathBuf>, to: Option<PathBuf> },
+}
+
+impl actix::Message for FileEvent {
+    type Result = ();
+}
+
+/// Keeps a watch created by [`woab::watch_file`](watch_file) active.
+///
+/// The file stops being watched when this is dropped.
+#[must_use = "the file stops being watched when the FileWatch is dropped"]
+pub struct FileWatch {
+    monitor: gio::FileMonitor,
+    handler_id: Option<glib::SignalHandlerId>,
+}
+
+impl FileWatch {
+    /// The underlying `gio::FileMonitor`.
+    pub fn monitor(&self) -> &gio::FileMonitor {
+        &self.monitor
+    }
+}
+
+impl Drop for FileWatch {
+    fn drop(&mut self) {
+        if let Some(handler_id) = self.handler_id.take() {
+            self.monitor.disconnect(handler_id);
+        }
+        self.monitor.cancel();
+    }
+}
+
+/// Watch a file (or a directory) for changes, and send them to an actor as [`FileEvent`]
+/// messages.
+///
+/// Only files that have a local path are supported - events about files that do not are ignored.
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// struct DocumentActor {
+///     _watch: woab::FileWatch,
+/// }
+/// # impl actix::Actor for DocumentActor { type Context = actix::Context<Self>; }
+///
+/// impl actix::Handler<woab::FileEvent> for DocumentActor {
+///     type Result = ();
+///
+///     fn handle(&mut self, msg: woab::FileEvent, _ctx: &mut Self::Context) -> Self::Result {
+///         if let woab::FileEvent::ChangesDone(path) = msg {
+///             // Reload the document from `path`...
+///         }
+///     }
+/// }
+///
+/// let file = gio::File::for_path("notes.txt");
+/// DocumentActor::create(|ctx| DocumentActor {
+///     _watch: woab::watch_file(&file, ctx.address()).expect("cannot watch the file"),
+/// });
+/// ```
+pub fn watch_file(file: &gio::File, target: impl Into<actix::Recipient<FileEvent>>) -> crate::Result<FileWatch> {
+    let monitor = file
+        .monitor(gio::FileMonitorFlags::WATCH_MOVES, None::<&gio::Cancellable>)
+        .map_err(|err| crate::Error::GenericError(Box::new(err)))?;
+    let target = target.into();
+    let handler_id = monitor.connect_changed(move |_, file, other_file, event| {
+        let path = file.path();
+        let other_path = other_file.and_then(|other_file| other_file.path());
+        let event = match event {
+            gio::FileMonitorEvent::Changed => path.map(FileEvent::Changed),
+            gio::FileMonitorEvent::ChangesDoneHint => path.map(FileEvent::ChangesDone),
+            gio::FileMonitorEvent::Created => path.map(FileEvent::Created),
+            gio::FileMonitorEvent::Deleted => path.map(FileEvent::Deleted),
+            gio::FileMonitorEvent::AttributeChanged => path.map(FileEvent::AttributeChanged),
+            gio::FileMonitorEvent::Moved | gio::FileMonitorEvent::Renamed => Some(FileEvent::Moved {
+                from: path,
+                to: other_path,
+            }),
+            // For these events, `file` is the one inside the watched location.
+            gio::FileMonitorEvent::MovedIn => Some(FileEvent::Moved {
+                from: other_path,
+                to: path,
+            }),
+            gio::FileMonitorEvent::MovedOut => Some(FileEvent::Moved {
+                from: path,
+                to: other_path,
+            }),
+            _ => None,
+        };
+        if let Some(event) = event {
+            target.do_send(event);
+        }
+    });
+    Ok(FileWatch {
+        monitor,
+        handler_id: Some(handler_id),
+    })
+}
@@ -53,16 +53,327 @@ impl<S, F: 'static + FnOnce(&gtk4::Application) -> crate::Result<S>> ActivationS
     }
 }
 
+type StartupErrorHandler = Box<dyn Fn(&gtk4::Application, &crate::Error) -> Option<gtk4::Window>>;
+
+thread_local! {
+    static STARTUP_ERROR_HANDLER: RefCell<Option<StartupErrorHandler>> = const { RefCell::new(None) };
+}
+
+/// Change how [`woab::main`](main) presents an error returned by its closure.
+///
+/// The handler is called inside the `startup` signal, right after the closure fails. If it returns
+/// a window, the application keeps running until that window is closed. If it returns `None`, the
+/// application quits immediately. Either way, `woab::main` returns the error once the application
+/// exits.
+///
+/// The default handler is [`woab::show_startup_error_dialog`](show_startup_error_dialog).
+///
+/// ```no_run
+/// woab::set_startup_error_handler(|app, error| {
+///     eprintln!("Failed to start: {}", error);
+///     woab::show_startup_error_dialog(app, error)
+/// });
+/// ```
+pub fn set_startup_error_handler(handler: impl 'static + Fn(&gtk4::Application, &crate::Error) -> Option<gtk4::Window>) {
+    STARTUP_ERROR_HANDLER.with(|cell| *cell.borrow_mut() = Some(Box::new(handler)));
+}
+
+/// Show a modal error dialog with the error that made the application fail to start.
+///
+/// This is the default handler of [`woab::set_startup_error_handler`](set_startup_error_handler).
+#[cfg_attr(feature = "gtk_v4_10", allow(deprecated))]
+pub fn show_startup_error_dialog(app: &gtk4::Application, error: &crate::Error) -> Option<gtk4::Window> {
+    let dialog = gtk4::MessageDialog::builder()
+        .application(app)
+        .modal(true)
+        .message_type(gtk4::MessageType::Error)
+        .buttons(gtk4::ButtonsType::Close)
+        .text("The application failed to start")
+        .secondary_text(error.to_string())
+        .build();
+    dialog.connect_response(|dialog, _| dialog.close());
+    dialog.present();
+    Some(dialog.upcast())
+}
+
+fn handle_startup_error(app: &gtk4::Application, error: &crate::Error) {
+    let window = STARTUP_ERROR_HANDLER.with(|cell| match &*cell.borrow() {
+        Some(handler) => handler(app, error),
+        None => show_startup_error_dialog(app, error),
+    });
+    if let Some(window) = window {
+        window.connect_close_request({
+            let app = app.clone();
+            move |_| {
+                app.quit();
+                glib::Propagation::Proceed
+            }
+        });
+    } else {
+        app.quit();
+    }
+}
+
 /// Run GTK and Actix.
 ///
 /// The closure passed to this function will run inside the application's `startup` signal. Use it
 /// to setup the application: build and run the initial window and launch any actors that need to
 /// run at bootstrap.
-pub fn main(app: gtk4::Application, dlg: impl 'static + FnOnce(&gtk4::Application) -> crate::Result<()>) -> crate::Result<()> {
-    gtk4::init()?;
+///
+/// The value the closure returns (e.g. some state the actors fill while the application runs) is
+/// returned from this function once the application exits. If the application exits without
+/// running the closure (e.g. because it is a remote instance of a single-instance application, or
+/// because a command-line option like `--help` made it exit early) the default value is returned
+/// instead - so closures that return `()` work exactly as before.
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// # struct RootActor { results: std::rc::Rc<std::cell::RefCell<Vec<String>>> }
+/// # impl actix::Actor for RootActor { type Context = actix::Context<Self>; }
+/// fn main() -> woab::Result<()> {
+///     let results = woab::main(gtk4::Application::default(), |_app| {
+///         let results = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+///         RootActor { results: results.clone() }.start();
+///         Ok(results)
+///     })?;
+///     for result in results.borrow().iter() {
+///         println!("{}", result);
+///     }
+///     Ok(())
+/// }
+/// ```
+///
+/// If the closure fails, the error is shown to the user with a dialog before the application quits -
+/// see [`woab::set_startup_error_handler`](set_startup_error_handler).
+///
+/// Icon search paths added with [`woab::add_icon_search_path`](crate::add_icon_search_path) before
+/// calling this function are applied after GTK is initialized, before the `startup` signal.
+///
+/// To set the application's metadata (name, icon...) use [`woab::MainConfig`](MainConfig).
+pub fn main<T: 'static + Default>(
+    app: gtk4::Application,
+    dlg: impl 'static + FnOnce(&gtk4::Application) -> crate::Result<T>,
+) -> crate::Result<T> {
+    MainConfig::new().main(app, dlg)
+}
+
+type NewWindowCallback = Rc<dyn Fn(&gtk4::Application)>;
+
+/// Application metadata for [`woab::main`](main).
+///
+/// Some of the metadata must be set before GTK is initialized, some before the application starts,
+/// and some before the first window is created. `MainConfig` applies each setting at the right
+/// time:
+///
+/// ```no_run
+/// fn main() -> woab::Result<()> {
+///     let app = gtk4::Application::new(Some("org.example.MyApp"), Default::default());
+///     woab::MainConfig::new()
+///         .program_name("my-app")
+///         .application_name("My App")
+///         .default_icon_name("org.example.MyApp")
+///         .resource_base_path("/org/example/MyApp")
+///         .main(app, |_app| {
+///             // Create the windows and the actors
+///             Ok(())
+///         })
+/// }
+/// ```
+#[derive(Default, Clone)]
+pub struct MainConfig {
+    program_name: Option<String>,
+    application_name: Option<String>,
+    default_icon_name: Option<String>,
+    resource_base_path: Option<String>,
+    new_window: Option<NewWindowCallback>,
+    about: Option<crate::AboutInfo>,
+    #[cfg(feature = "i18n")]
+    translations: Option<(String, std::path::PathBuf)>,
+}
+
+impl MainConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The name of the program, as used by the window manager (e.g. for matching the windows
+    /// with the `.desktop` file). Set with `glib::set_prgname` before GTK is initialized.
+    pub fn program_name(mut self, program_name: impl Into<String>) -> Self {
+        self.program_name = Some(program_name.into());
+        self
+    }
+
+    /// The human readable name of the application. Set with `glib::set_application_name` before
+    /// GTK is initialized.
+    pub fn application_name(mut self, application_name: impl Into<String>) -> Self {
+        self.application_name = Some(application_name.into());
+        self
+    }
+
+    /// The icon of windows that do not set their own icon. Set with
+    /// `gtk4::Window::set_default_icon_name` before the `startup` signal.
+    ///
+    /// The icon is looked up in the icon theme - see also
+    /// [`woab::add_icon_search_path`](crate::add_icon_search_path).
+    pub fn default_icon_name(mut self, icon_name: impl Into<String>) -> Self {
+        self.default_icon_name = Some(icon_name.into());
+        self
+    }
+
+    /// The path in the GIO resources where GTK looks for the application's resources (e.g.
+    /// `gtk/menus.ui` and `gtk/help-overlay.ui`). Set with
+    /// `gio::prelude::ApplicationExt::set_resource_base_path` before the application runs.
+    ///
+    /// By default GTK uses a path derived from the application ID.
+    pub fn resource_base_path(mut self, resource_base_path: impl Into<String>) -> Self {
+        self.resource_base_path = Some(resource_base_path.into());
+        self
+    }
+
+    /// Create a new window whenever the application is activated, or when the `app.new-window`
+    /// action is activated.
+    ///
+    /// GTK activates the application when it starts (right after the `startup` signal) and
+    /// whenever the user launches it again while it is running (e.g. from the desktop shell) - so
+    /// with this setting, the closure passed to [`main`](Self::main) should only set up the
+    /// application, and this callback should create all the windows - including the first one.
+    /// The `app.new-window` action can be used from menus, or bound to a keyboard shortcut with
+    /// `gtk4::prelude::GtkApplicationExt::set_accels_for_action`.
+    ///
+    /// The callback runs inside the Actix runtime, so it can start actors for the new window. The
+    /// windows should be attached to the application (e.g. with
+    /// [`BuilderWidgets::set_application`](crate::BuilderWidgets::set_application)) so that it keeps
+    /// track of them.
+    ///
+    /// ```no_run
+    /// # use actix::prelude::*;
+    /// # struct WindowActor;
+    /// # impl actix::Actor for WindowActor { type Context = actix::Context<Self>; }
+    /// # impl actix::Handler<woab::Signal> for WindowActor {
+    /// #     type Result = woab::SignalResult;
+    /// #     fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+    /// #         msg.cant_handle()
+    /// #     }
+    /// # }
+    /// # let factory: woab::BuilderFactory = panic!();
+    /// woab::MainConfig::new()
+    ///     .on_new_window(move |app| {
+    ///         WindowActor::create(|ctx| {
+    ///             let bld = factory.instantiate_route_to(ctx.address());
+    ///             bld.set_application(app);
+    ///             bld.get_object::<gtk4::ApplicationWindow>("win_app").unwrap().present();
+    ///             WindowActor
+    ///         });
+    ///     })
+    ///     .main(gtk4::Application::default(), |app| {
+    ///         woab::shutdown_when_last_window_is_closed(app);
+    ///         Ok(())
+    ///     })?;
+    /// # Ok::<(), woab::Error>(())
+    /// ```
+    pub fn on_new_window(mut self, new_window: impl 'static + Fn(&gtk4::Application)) -> Self {
+        self.new_window = Some(Rc::new(new_window));
+        self
+    }
+
+    /// Add the standard `app.about` action, showing an about window with
+    /// [`woab::show_about`](crate::show_about).
+    pub fn about(mut self, info: crate::AboutInfo) -> Self {
+        self.about = Some(info);
+        self
+    }
+
+    /// Setup gettext translations with [`woab::init_i18n`](crate::init_i18n) before GTK is
+    /// initialized. Requires the `i18n` feature.
+    ///
+    /// The domain is only used by [`BuilderFactory`](crate::BuilderFactory)s that are created
+    /// after that - e.g. inside the closure passed to [`main`](Self::main).
+    #[cfg(feature = "i18n")]
+    pub fn translations(mut self, domain: impl Into<String>, locale_dir: impl Into<std::path::PathBuf>) -> Self {
+        self.translations = Some((domain.into(), locale_dir.into()));
+        self
+    }
+
+    /// Apply the metadata, and run GTK and Actix like [`woab::main`](main).
+    pub fn main<T: 'static + Default>(
+        self,
+        app: gtk4::Application,
+        dlg: impl 'static + FnOnce(&gtk4::Application) -> crate::Result<T>,
+    ) -> crate::Result<T> {
+        if let Some(program_name) = &self.program_name {
+            glib::set_prgname(Some(program_name.as_str()));
+        }
+        if let Some(application_name) = &self.application_name {
+            glib::set_application_name(application_name);
+        }
+        #[cfg(feature = "i18n")]
+        if let Some((domain, locale_dir)) = self.translations {
+            crate::init_i18n(&domain, locale_dir)?;
+        }
+        gtk4::init()?;
+        crate::resources::apply_pending_icon_search_paths();
+        if let Some(icon_name) = &self.default_icon_name {
+            gtk4::Window::set_default_icon_name(icon_name);
+        }
+        if let Some(resource_base_path) = &self.resource_base_path {
+            app.set_resource_base_path(Some(resource_base_path.as_str()));
+        }
+        if let Some(info) = self.about {
+            crate::add_about_action(&app, info);
+        }
+        run_main(app, dlg, self.new_window)
+    }
+}
 
+fn create_new_window(app: &gtk4::Application, new_window: &NewWindowCallback) {
+    let fut = {
+        let app = app.clone();
+        let new_window = new_window.clone();
+        async move { new_window(&app) }
+    };
+    if let Err(fut) = crate::try_block_on(fut) {
+        // Already inside the Actix runtime (e.g. an actor activated the action)
+        actix::spawn(fut);
+    }
+}
+
+fn run_main<T: 'static + Default, F: 'static + FnOnce(&gtk4::Application) -> crate::Result<T>>(
+    app: gtk4::Application,
+    dlg: F,
+    new_window: Option<NewWindowCallback>,
+) -> crate::Result<T> {
     let startup_state = Rc::new(RefCell::new(ActivationState::BeforeStartup(dlg)));
 
+    if let Some(new_window) = new_window {
+        // When the startup fails the application keeps running to show the error, but it should
+        // not create windows that would cover it.
+        let startup_succeeded = {
+            let startup_state = Rc::downgrade(&startup_state);
+            move || {
+                startup_state
+                    .upgrade()
+                    .is_some_and(|state| matches!(*state.borrow(), ActivationState::StartupSucceeded(_)))
+            }
+        };
+        let action = gio::SimpleAction::new("new-window", None);
+        action.connect_activate({
+            let app = app.clone();
+            let new_window = new_window.clone();
+            let startup_succeeded = startup_succeeded.clone();
+            move |_, _| {
+                if startup_succeeded() {
+                    create_new_window(&app, &new_window);
+                }
+            }
+        });
+        app.add_action(&action);
+        app.connect_activate(move |app| {
+            if startup_succeeded() {
+                create_new_window(app, &new_window);
+            }
+        });
+    }
+
     app.connect_startup({
         let startup_state = startup_state.clone();
         move |app| {
@@ -72,11 +383,10 @@ pub fn main(app: gtk4::Application, dlg: impl 'static + FnOnce(&gtk4::Applicatio
                     panic!("woab::main was used, but the `startup` signal was invoked more than once");
                 };
                 let result = dlg(app);
-                let failed = result.is_err();
-                startup_state.borrow_mut().set_startup_result(result);
-                if failed {
-                    app.quit();
+                if let Err(error) = &result {
+                    handle_startup_error(app, error);
                 }
+                startup_state.borrow_mut().set_startup_result(result);
             })
         }
     });
@@ -85,7 +395,7 @@ pub fn main(app: gtk4::Application, dlg: impl 'static + FnOnce(&gtk4::Applicatio
         return if exit_code != glib::ExitCode::SUCCESS {
             Err(crate::Error::GtkBadExitCode(exit_code))
         } else {
-            Ok(())
+            Ok(T::default())
         };
     }
     crate::close_actix_runtime()??;
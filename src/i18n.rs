@@ -0,0 +1,39 @@
+use std::sync::RwLock;
+
+static DEFAULT_TRANSLATION_DOMAIN: RwLock<Option<String>> = RwLock::new(None);
+
+pub(crate) fn default_translation_domain() -> Option<String> {
+    DEFAULT_TRANSLATION_DOMAIN.read().unwrap().clone()
+}
+
+/// Setup gettext translations for the application.
+///
+/// This sets the locale from the environment, binds `domain` to the message catalogs in
+/// `locale_dir` (with UTF-8 encoding), and makes it the default domain for `gettext` calls. It
+/// also makes `domain` the translation domain of every [`BuilderFactory`](crate::BuilderFactory)
+/// created afterwards, so that translatable strings in the UI XML get translated. To use a
+/// different domain for a specific factory, use
+/// [`BuilderFactory::with_translation_domain`](crate::BuilderFactory::with_translation_domain).
+///
+/// Call this before [`woab::main`](crate::main), and before reading the factories (or use
+/// [`MainConfig::translations`](crate::MainConfig::translations)):
+///
+/// ```no_run
+/// fn main() -> woab::Result<()> {
+///     woab::init_i18n("my-app", "/usr/share/locale")?;
+///     let factory = woab::BuilderFactory::from(std::fs::read_to_string("my-app.ui")?);
+///     woab::main(gtk4::Application::default(), move |_app| {
+///         // ...
+///         Ok(())
+///     })
+/// }
+/// ```
+#[cfg(feature = "i18n")]
+pub fn init_i18n(domain: &str, locale_dir: impl Into<std::path::PathBuf>) -> crate::Result<()> {
+    gettextrs::setlocale(gettextrs::LocaleCategory::LcAll, "");
+    gettextrs::bindtextdomain(domain, locale_dir)?;
+    gettextrs::bind_textdomain_codeset(domain, "UTF-8")?;
+    gettextrs::textdomain(domain)?;
+    *DEFAULT_TRANSLATION_DOMAIN.write().unwrap() = Some(domain.to_owned());
+    Ok(())
+}
@@ -120,7 +120,7 @@
 //!
 //!         // When the builder loads the window, it starts as hidden. We can use the extracted widgets
 //!         // to show it.
-//!         widgets.window.show();
+//!         widgets.window.present();
 //!
 //!         // This is where the actor is actually launched.
 //!         ctx.run(MyActor { widgets });
@@ -141,16 +141,67 @@
 //!   [`woab::route_signal`](crate::route_signal) to route the application's `activate` signal
 //!   to the actor and do the startup in the actor's signal handler.
 
+mod about;
+pub mod action_group;
+mod actor_registry;
+mod animator;
+pub mod auto_form;
 mod builder;
 mod builder_dissect;
+pub mod choices;
+mod color_scheme;
+pub mod columns;
+mod command;
+mod completion;
+pub mod component;
+#[cfg(feature = "dbus")]
+pub mod dbus;
+mod dialogs;
+mod draw_state;
+mod edit_commands;
 mod error;
+pub mod event_bus;
 mod event_loops_bridge;
+mod file_monitor;
 mod gtk_app_helpers;
+mod i18n;
+pub mod list_adapters;
+pub mod list_binding;
+pub mod media;
+pub mod menu;
+pub mod model;
+pub mod navigation;
+mod network_monitor;
+pub mod paged_list;
+pub mod progress_dialog;
 pub mod prop_sync;
+mod remote;
 mod remove;
+mod resources;
+pub mod row_manager;
+mod send;
+mod set_widget_property;
+mod settings;
 mod signal;
+mod signal_diagnostics;
+mod signal_match;
+#[cfg(feature = "metrics")]
+mod signal_metrics;
 mod signal_routing;
+mod signal_scope;
+mod signal_stream;
+#[cfg(feature = "tracing")]
+mod signal_tracing;
+pub mod status_bar;
+#[cfg(feature = "test-util")]
+pub mod test;
+mod timers;
+mod ui_batcher;
+mod unmatched_signals;
+pub mod validation;
+mod view;
 mod waking_helpers;
+mod widget_ops;
 
 /// Represent a set of GTK widgets created by a GTK builder.
 ///
@@ -176,6 +227,28 @@ mod waking_helpers;
 ///   type (or any other type that implements `TryFrom<&gtk4::Builder>`) as the field's type and
 ///   have take all its widgets from the same builder. The name of the field is ignored, because
 ///   the nested type already names all the widgets it needs.
+///
+/// - `root`: Implement [`woab::View`](View) for the struct, with this field as the root widget.
+///   Can only be set on one field.
+///
+/// - `builder`: Instead of taking a widget from the builder, put the builder itself in the field.
+///   The field's type can be `gtk4::Builder`, [`woab::BuilderWidgets`](BuilderWidgets), or
+///   anything else that implements `From<gtk4::Builder>`. This is useful for fetching objects
+///   that are only needed later, without adding them to the struct.
+///
+/// - `compute = "..."`: Instead of taking a widget by ID, compute the field's value with an
+///   expression. The expression can use `builder` (a `&gtk4::Builder`), and can use `?` to fail
+///   with any error that converts into [`woab::Error`](Error).
+///
+/// ```no_run
+/// # use gtk4::prelude::*;
+/// #[derive(woab::WidgetsFromBuilder)]
+/// struct MyAppWidgets {
+///     list_box: gtk4::ListBox,
+///     #[widget(compute = r#"builder.object::<gtk4::ListBox>("list_box").and_then(|list_box| list_box.row_at_index(0))"#)]
+///     first_row: Option<gtk4::ListBoxRow>,
+/// }
+/// ```
 pub use woab_macros::WidgetsFromBuilder;
 
 /// Dissect a single Cambalache emitted XML file to multiple builder factories.
@@ -193,6 +266,32 @@ pub use woab_macros::WidgetsFromBuilder;
 /// (this is leftover from GTK3 and less likely needed in GTK4 where said resources can be placed
 /// under the widget in the UI XML)
 ///
+/// A factory can be accompanied by a CSS file using a `#[factory(css = "path/to/style.css")]`
+/// attribute. The file is embedded with `include_str!` (so the path is relative to the source
+/// file) and loaded with [`BuilderFactory::with_css`] - which means the field must be a
+/// [`woab::BuilderFactory`](BuilderFactory). Both attributes can be combined:
+/// `#[factory(extra(...), css = "...")]`.
+///
+/// If the XML has no object with the ID of one of the fields, `read` fails with
+/// [`woab::Error::FactoryIdNotFound`](Error::FactoryIdNotFound). An ID claimed by more than one
+/// field (e.g. listed in the `extra` of two fields) is a compilation error.
+///
+/// A field marked with `#[factory(rest)]`, of type `HashMap<String, woab::BuilderFactory>` (or any
+/// other map that can be collected from `(String, T)` pairs where `T` is `From<String>`), gets a
+/// factory for every top-level object that no other field claims, keyed by the object's ID. This is
+/// useful when the objects to instantiate are only known at runtime - e.g. panels selected by a
+/// configuration file:
+///
+/// ```no_run
+/// # use std::collections::HashMap;
+/// #[derive(woab::Factories)]
+/// struct Factories {
+///     main_window: woab::BuilderFactory,
+///     #[factory(rest)]
+///     panels: HashMap<String, woab::BuilderFactory>,
+/// }
+/// ```
+///
 /// ```no_run
 /// # type MainWindowActor = ();
 /// # type MainWindowWidgets = ();
@@ -217,6 +316,42 @@ pub use woab_macros::WidgetsFromBuilder;
 ///     # Ok(())
 /// }
 /// ```
+///
+/// If the struct itself has a `#[factory(ui = "path/to/file.ui")]` attribute, the UI XML file is
+/// read during compilation (the path is relative to the crate's root) and a module named after the
+/// struct is generated next to it (e.g. `factories_signals` for `Factories`), with a `&str`
+/// constant for each signal handler declared in the file - e.g.
+/// `factories_signals::BUTTON_CLICKED` for `handler="button_clicked"`. Using these constants in
+/// the signal handlers, instead of string literals, means that renaming or removing a handler in
+/// the XML becomes a compilation error. The generated `read` method still needs to be called with
+/// the XML at runtime.
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// # struct WindowActor;
+/// # impl actix::Actor for WindowActor { type Context = actix::Context<Self>; }
+/// # impl WindowActor { fn add_row(&mut self) {} }
+/// #[derive(woab::Factories)]
+/// #[factory(ui = "examples/example.ui")]
+/// struct Factories {
+///     win_app: woab::BuilderFactory,
+///     row_addend: woab::BuilderFactory,
+/// }
+///
+/// impl actix::Handler<woab::Signal> for WindowActor {
+///     type Result = woab::SignalResult;
+///
+///     fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+///         Ok(match msg.name() {
+///             factories_signals::CLICK_BUTTON => {
+///                 self.add_row();
+///                 None
+///             }
+///             _ => msg.cant_handle()?,
+///         })
+///     }
+/// }
+/// ```
 pub use woab_macros::Factories;
 
 /// Make the actor remove itself and its widgets when it gets the [`woab::Remove`](Remove) message.
@@ -228,7 +363,44 @@ pub use woab_macros::Factories;
 /// * `<ParentType>` is the GTK type of the parent. That type must have a `remove` method.
 ///
 /// When the `woab::Remove` message is received, this actor will remove that widget
-/// from its parent and close itself.
+/// from its parent and close itself. If the widget was already removed from its parent (e.g. by
+/// GTK, see [`woab::bind_lifetime`](crate::bind_lifetime)) the actor will just close itself. The
+/// [`woab::RemoveAndConfirm`](crate::RemoveAndConfirm) message does the same, but its response
+/// can be awaited to know when the removal is complete.
+///
+/// The attribute can also have a `children = <expr>` parameter, where `<expr>` resolves to a
+/// [`woab::RemovableChildren`](RemovableChildren). When the actor is removed, it first removes all
+/// the child actors registered there (and waits for them to be removed), and only then removes
+/// its own widget and stops: `#[removable(self.widgets.window_content in gtk4::Box, children =
+/// self.rows)]`.
+///
+/// The `signal_scope = <expr>` parameter, where `<expr>` resolves to a
+/// [`woab::SignalScope`](SignalScope), disconnects all the signals in the scope when the actor is
+/// removed.
+///
+/// With the `on_destroy` parameter (e.g. `#[removable(self.widgets.row in gtk4::ListBox,
+/// on_destroy)]`), the derive also generates a `remove_on_destroy(&self, ctx)` method, which makes
+/// the actor get removed when GTK destroys the widget or detaches it from its window - e.g. when
+/// the window that contains it is closed - so that it does not keep running with a dead widget.
+/// Call it from the actor's `started` method (see [`woab::bind_lifetime`](bind_lifetime), which
+/// it uses, for the details):
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// #[derive(woab::Removable)]
+/// #[removable(self.row in gtk4::ListBox, on_destroy)]
+/// struct RowActor {
+///     row: gtk4::ListBoxRow,
+/// }
+///
+/// impl actix::Actor for RowActor {
+///     type Context = actix::Context<Self>;
+///
+///     fn started(&mut self, ctx: &mut Self::Context) {
+///         self.remove_on_destroy(ctx);
+///     }
+/// }
+/// ```
 ///
 /// ```no_run
 /// # use actix::prelude::*;
@@ -295,8 +467,80 @@ pub use woab_macros::Removable;
 /// All the signal parameters must be matched against, but `_` can be used for unneeded parameters.
 /// Parameters with types will be converted to that type, and untyped parameters will be
 /// `&glib::Value`.
+///
+/// Parameters with an `Option<T>` type are `None` if GTK passed NULL for them (e.g. an optional
+/// `gtk4::TreePath` or string), and `Some` otherwise.
 pub use woab_macros::params;
 
+/// Match a [`woab::Signal`](crate::Signal)'s name against the signals a factory declares in the
+/// UI XML, checking at compile time that the match is exhaustive.
+///
+/// The factories struct must be annotated with `#[factory(ui = "...")]` (see
+/// [`woab::Factories`](Factories)). The syntax is `match_signal!(<msg>, from factory
+/// <FactoriesType>::<field> { <arms> })`, where the arms are like the arms of a regular `match`
+/// on [`msg.name()`](crate::Signal::name), except that their patterns must be string literals
+/// (or `|` combinations of them) or `_`. Compilation fails if:
+///
+/// * A signal declared under the factory's objects is not covered by an arm. Arms with guards do
+///   not count as covering their signals.
+/// * An arm matches a signal that is not declared under the factory's objects - which usually
+///   means a typo.
+///
+/// If there is no `_` arm, one that calls
+/// [`cant_handle_expecting`](crate::Signal::cant_handle_expecting) and propagates its error with
+/// `?` is added - so the arms should evaluate to `Option<glib::Propagation>`, like in a typical
+/// signal handler. A `_` arm is still useful when the actor also gets signals that are not
+/// declared in the XML (e.g. from [`woab::route_signal`](crate::route_signal)).
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// # struct WindowActor;
+/// # impl actix::Actor for WindowActor { type Context = actix::Context<Self>; }
+/// # impl WindowActor { fn add_row(&mut self) {} }
+/// #[derive(woab::Factories)]
+/// #[factory(ui = "examples/example.ui")]
+/// struct Factories {
+///     win_app: woab::BuilderFactory,
+///     row_addend: woab::BuilderFactory,
+/// }
+///
+/// impl actix::Handler<woab::Signal> for WindowActor {
+///     type Result = woab::SignalResult;
+///
+///     fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+///         Ok(woab::match_signal!(msg, from factory Factories::win_app {
+///             "click_button" => {
+///                 self.add_row();
+///                 None
+///             }
+///         }))
+///     }
+/// }
+/// ```
+///
+/// ```compile_fail
+/// # use actix::prelude::*;
+/// # struct RowActor;
+/// # impl actix::Actor for RowActor { type Context = actix::Context<Self>; }
+/// # #[derive(woab::Factories)]
+/// # #[factory(ui = "examples/example.ui")]
+/// # struct Factories {
+/// #     win_app: woab::BuilderFactory,
+/// #     row_addend: woab::BuilderFactory,
+/// # }
+/// impl actix::Handler<woab::Signal> for RowActor {
+///     type Result = woab::SignalResult;
+///
+///     fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+///         Ok(woab::match_signal!(msg, from factory Factories::row_addend {
+///             "addend_changed" => None,
+///             // Compilation fails, because `remove_addend` is not covered.
+///         }))
+///     }
+/// }
+/// ```
+pub use woab_macros::match_signal;
+
 /// Generate methods for setting/getting the widgets' data.
 ///
 /// Useful for syncing the view with a model - use struct literals and struct destructuring on the
@@ -319,10 +563,37 @@ pub use woab_macros::params;
 /// Use `#[prop_sync("property-name" as PropertyType)]` to set the property that will be used for
 /// the syncing and its type. If `PropertyType` is a reference (`&PropertyType`), the reference
 /// will be used for the setter (the macro will add a lifetime) and its [`ToOwned::Owned`] will be
-/// used for the getter.
+/// used for the getter. The same goes for optional references (`Option<&PropertyType>`). Use an
+/// `Option` type for properties that can be `None` (like the `active-id` of a combo box with no
+/// active row) - otherwise getting them will fail.
+///
+/// `get_props` panics if a property cannot be converted to its declared type. The generated
+/// `try_get_props` returns a [`woab::Error`](Error) instead.
+///
+/// Annotate the struct itself with `#[prop_sync(notify)]` to also generate a `connect_changes`
+/// method, which routes the change signals (as determined by
+/// [`ConnectChanges`](crate::prop_sync::ConnectChanges)) of every field in the getter to an actor.
+/// Each change is sent as a parameterless [`woab::Signal`](Signal) named after the field - the
+/// handler can use `get_props` to get the new data. The struct will also implement
+/// [`ConnectChanges`](crate::prop_sync::ConnectChanges), so that it can be nested in another
+/// struct that uses `notify`.
+///
+/// Use `#[prop_sync(choice as EnumType)]` to sync a `gtk4::DropDown` or a `gtk4::ComboBox` with
+/// an enum that derives [`Choices`]. The setter will accept `EnumType` and the getter will return
+/// `Option<EnumType>` (`None` when nothing is selected).
+///
+/// Use `#[prop_sync(set, accessible_label)]`, `#[prop_sync(set, accessible_description)]` and/or
+/// `#[prop_sync(set, accessible_value_text)]` to also set the widget's `gtk4::Accessible`
+/// properties, so that screen readers describe the same data the widget shows. Each of these adds
+/// a `&str` field named `<field>_accessible_label` (etc.) to the setter.
 ///
-/// There is no need to set a property for some common widgets (like `gtk4::Entry`) - they already
-/// implement [`SetProps`](crate::prop_sync::SetProps) and
+/// Use `#[prop_sync("property-name" as PropertyType, setting = "settings-key")]` to generate a
+/// `bind_settings` method that binds the property of every such field to a key of a
+/// `gio::Settings` using [`woab::bind_settings`](bind_settings). The field does not have to be in
+/// the setter or the getter.
+///
+/// There is no need to set a property for some common widgets (like `gtk4::Entry` or
+/// `gtk4::TextView`) - they already implement [`SetProps`](crate::prop_sync::SetProps) and
 /// [`GetProps`](crate::prop_sync::GetProps), so the macro will use the traits to set/get the data.
 /// Similarly, structs that use this derive implement these two traits so they can be used with
 /// [`WidgetsFromBuilder`]'s `#[widget(nested)]`.
@@ -336,21 +607,28 @@ pub use woab_macros::params;
 ///     #[prop_sync(set, get)]
 ///     some_text: gtk4::Entry,
 ///
-///     // Combo boxes use the active-id property to select a row in their model.
-///     #[prop_sync("active-id" as String, set, get)]
+///     // Combo boxes use the active-id property to select a row in their model. It is `None` when
+///     // no row is selected.
+///     #[prop_sync("active-id" as Option<String>, set, get)]
 ///     some_combo_box: gtk4::ComboBox,
 ///
 ///     // We only want to get the value of this checkbox, not set it, so we don't generate a setter.
 ///     #[prop_sync("active" as bool, get)]
 ///     some_check_box: gtk4::CheckButton,
+///
+///     // Screen readers will read the value text instead of the raw fraction.
+///     #[prop_sync("fraction" as f64, set, accessible_value_text)]
+///     some_progress_bar: gtk4::ProgressBar,
 /// }
 ///
 /// # let widgets: AppWidgets = panic!();
 /// // Set the widgets' data
 /// widgets.set_props(&AppWidgetsPropSetter {
 ///     some_text: "some test",
-///     some_combo_box: "1".to_owned(), // the combo box ID column is always a string
+///     some_combo_box: Some("1".to_owned()), // the combo box ID column is always a string
 ///     // No some_check_box - it was not generated for the setter
+///     some_progress_bar: 0.25,
+///     some_progress_bar_accessible_value_text: "3 of 12 files copied",
 /// });
 ///
 /// // Get the widgets' data
@@ -359,20 +637,408 @@ pub use woab_macros::params;
 ///     some_combo_box,
 ///     some_check_box,
 /// } = widgets.get_props();
+///
+/// // Get the widgets' data without panicking
+/// # fn report(_: woab::Error) {}
+/// match widgets.try_get_props() {
+///     Ok(AppWidgetsPropGetter { .. }) => {}
+///     Err(err) => report(err),
+/// }
 /// ```
 pub use woab_macros::PropSync;
 
+/// Generate `gtk4::ColumnViewColumn`s for displaying a struct in a `gtk4::ColumnView`.
+///
+/// The struct is the row data - the items of the column view's model must be
+/// `glib::BoxedAnyObject`s wrapping it, as created by [`woab::ListBinding`](ListBinding). This
+/// derive implements [`woab::columns::Columns`](crate::columns::Columns) for the struct.
+///
+/// Only fields annotated with `#[column(...)]` become columns. The attribute supports the
+/// following values:
+///
+/// - `title = "..."`: The title of the column. Defaults to the name of the field.
+/// - `numeric`: Align the value to the right.
+/// - `check`: Display the value (which must be a `bool`) as a check button. Otherwise, the value
+///   is displayed as text using its `Display` implementation.
+/// - `sort`: Add a sorter to the column, based on the field's `PartialOrd` implementation.
+/// - `edit = "..."`: Allow the user to edit the value. When the user does so, a
+///   [`woab::Signal`](Signal) with the given name will be sent to the `edit_target` that was
+///   passed when creating the columns. The signal's parameters are the item and the new value.
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// use woab::columns::Columns;
+///
+/// #[derive(woab::Columns)]
+/// struct Task {
+///     #[column(title = "Description")]
+///     description: String,
+///     #[column(title = "Priority", numeric, sort)]
+///     priority: u32,
+///     #[column(title = "Done", check, edit = "task_done_toggled")]
+///     done: bool,
+/// }
+///
+/// struct TasksActor;
+/// # impl actix::Actor for TasksActor { type Context = actix::Context<Self>; }
+///
+/// impl actix::Handler<woab::Signal> for TasksActor {
+///     type Result = woab::SignalResult;
+///
+///     fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+///         Ok(match msg.name() {
+///             "task_done_toggled" => {
+///                 let woab::params!(item: glib::BoxedAnyObject, done: bool) = msg.params()?;
+///                 let task = item.borrow::<Task>();
+///                 println!("{:?} is now {}", task.description, if done { "done" } else { "not done" });
+///                 None
+///             }
+///             _ => msg.cant_handle()?,
+///         })
+///     }
+/// }
+///
+/// # let column_view: gtk4::ColumnView = panic!();
+/// let actor = TasksActor.start();
+/// Task::append_columns(&column_view, Some(actor.recipient()));
+/// ```
+pub use woab_macros::Columns;
+
+/// Make a fieldless enum selectable with a `gtk4::DropDown` or a `gtk4::ComboBox`.
+///
+/// This derive implements [`woab::choices::Choices`](crate::choices::Choices) for the enum, which
+/// can create the model for a `gtk4::DropDown` (with
+/// [`string_list`](crate::choices::Choices::string_list)) or fill a `gtk4::ComboBoxText` (with
+/// [`fill_combo_box_text`](crate::choices::Choices::fill_combo_box_text)), and convert the enum
+/// to and from the widget's selected index or ID.
+///
+/// The `#[choice(...)]` attribute is supported on the variants of the enum, with the following
+/// values:
+///
+/// - `id = "..."`: The ID of the variant, for `gtk4::ComboBox`. Defaults to the variant's name.
+/// - `label = "..."`: The text displayed for the variant. Defaults to the variant's name.
+///
+/// To use the enum in a [`PropSync`] struct, annotate the widget with
+/// `#[prop_sync(choice as EnumType)]`:
+///
+/// ```no_run
+/// use woab::choices::Choices;
+///
+/// #[derive(woab::Choices)]
+/// enum Color {
+///     Red,
+///     Green,
+///     #[choice(id = "blue", label = "Blue (default)")]
+///     Blue,
+/// }
+///
+/// #[derive(woab::WidgetsFromBuilder, woab::PropSync)]
+/// struct AppWidgets {
+///     #[prop_sync(choice as Color, set, get)]
+///     color: gtk4::DropDown,
+/// }
+///
+/// # let widgets: AppWidgets = panic!();
+/// widgets.color.set_model(Some(&Color::string_list()));
+/// widgets.set_props(&AppWidgetsPropSetter { color: Color::Blue });
+/// let AppWidgetsPropGetter { color } = widgets.get_props();
+/// assert!(matches!(color, Some(Color::Blue)));
+/// ```
+pub use woab_macros::Choices;
+
+/// Bind a model struct to a [`PropSync`] widgets struct.
+///
+/// The mandatory attribute `#[model(widgets = WidgetsType)]` specifies the widgets struct. Each
+/// field of the model (except fields annotated with `#[model(skip)]`) must match, by name, a
+/// field of the widgets struct that is prop-synced for both `set` and `get`. All the fields of the
+/// widgets struct that are prop-synced for `set` must be present in the model.
+///
+/// This derive implements [`woab::model::Model`](crate::model::Model), which can:
+///
+/// * Apply the model to the widgets.
+/// * Update the model from the widgets.
+/// * Connect to the change signals of the widgets (e.g. `changed` for `gtk4::Entry` or `toggled`
+///   for `gtk4::CheckButton`).
+///
+/// Use [`woab::model::ModelBinding`](crate::model::ModelBinding) to combine all three, so that
+/// widget edits automatically update the model and notify the actor:
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// #[derive(woab::Model)]
+/// #[model(widgets = SettingsWidgets)]
+/// struct Settings {
+///     user_name: String,
+///     notifications: bool,
+///     #[model(skip)]
+///     dirty: bool,
+/// }
+///
+/// #[derive(Clone, woab::WidgetsFromBuilder, woab::PropSync)]
+/// struct SettingsWidgets {
+///     #[prop_sync(set, get)]
+///     user_name: gtk4::Entry,
+///     #[prop_sync(set, get)]
+///     notifications: gtk4::CheckButton,
+/// }
+///
+/// struct SettingsChanged;
+///
+/// impl actix::Message for SettingsChanged {
+///     type Result = ();
+/// }
+///
+/// # let widgets: SettingsWidgets = panic!();
+/// # let addr: actix::Recipient<SettingsChanged> = panic!();
+/// let settings = Settings {
+///     user_name: "".to_owned(),
+///     notifications: true,
+///     dirty: false,
+/// };
+/// let binding = woab::model::ModelBinding::new(settings, widgets, move || addr.do_send(SettingsChanged));
+/// ```
+pub use woab_macros::Model;
+
+/// Declare validation rules for a [`PropSync`] widgets struct.
+///
+/// Rules are declared on the fields with `#[validate(...)]`, and check the value returned by the
+/// field's getter - so all validated fields must be prop-synced for `get`:
+///
+/// * `required` - the value must not be blank. Works for strings (blank means empty or only
+///   whitespace), `Option`s (blank means `None`) and `bool`s (blank means `false`).
+/// * `range = <range expression>` - the value must be within the range (e.g. `range = 1..=10`).
+/// * `regex = "<pattern>"` - the value must match the regular expression (using GLib's syntax).
+/// * `custom = <function>` - a function that receives a reference to the value and returns
+///   `Result<(), String>`, where the `String` is the error message.
+///
+/// This derive implements [`woab::validation::Validate`](crate::validation::Validate). Use
+/// [`woab::Form`](crate::Form) to run the validations and mark the offending widgets. Errors are
+/// matched to the widgets by the field names - including errors of fields without rules, which
+/// custom validators of the form can report - so all the fields must be GObjects.
+///
+/// ```no_run
+/// fn not_admin(user_name: &String) -> Result<(), String> {
+///     if user_name == "admin" {
+///         Err("This user name is reserved".to_owned())
+///     } else {
+///         Ok(())
+///     }
+/// }
+///
+/// #[derive(woab::WidgetsFromBuilder, woab::PropSync, woab::Validate)]
+/// struct SignupWidgets {
+///     #[prop_sync(get)]
+///     #[validate(required, regex = "^[a-z_]+$", custom = not_admin)]
+///     user_name: gtk4::Entry,
+///     #[prop_sync("value" as f64, get)]
+///     #[validate(range = 13.0..=120.0)]
+///     age: gtk4::SpinButton,
+///     #[prop_sync(get)]
+///     #[validate(required)]
+///     accept_terms: gtk4::CheckButton,
+/// }
+/// ```
+pub use woab_macros::Validate;
+
+/// Create a group of GIO actions and route them to an actor.
+///
+/// The fields of the struct must all be `gio::SimpleAction`s. This derive implements
+/// [`woab::action_group::ActionGroup`](crate::action_group::ActionGroup) for the struct, and
+/// [`route_to`](crate::action_group::ActionGroup::route_to) creates all the actions, adds them to
+/// a `gio::SimpleActionGroup` and routes them to an actor - which gets them as
+/// [`woab::Signal`](Signal)s named after the actions. The returned
+/// [`RoutedActions`](crate::action_group::RoutedActions) keeps the signal handler IDs, so that
+/// individual actions can be [blocked](crate::action_group::RoutedActions::block) and
+/// [unblocked](crate::action_group::RoutedActions::unblock) later.
+///
+/// The `#[action(...)]` attribute is supported on the fields, with the following values:
+///
+/// - `name = "..."`: The name of the action. Defaults to the field's name, with underscores
+///   replaced by dashes (GIO action names cannot contain underscores).
+/// - `parameter = Type`: The type of the action's parameter. `Type` must implement
+///   `glib::variant::StaticVariantType`. Without it, the action has no parameter.
+/// - `state = <expr>`: Make the action stateful, with the expression (which must implement
+///   `glib::variant::ToVariant`) as its initial state.
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// use woab::action_group::{ActionGroup, RoutedActions};
+///
+/// #[derive(woab::ActionGroup)]
+/// struct EditorActions {
+///     save: gio::SimpleAction,
+///     #[action(parameter = i32)]
+///     zoom_to: gio::SimpleAction,
+///     #[action(name = "dark", state = false)]
+///     dark_mode: gio::SimpleAction,
+/// }
+///
+/// struct EditorActor {
+///     actions: Option<RoutedActions<EditorActions>>,
+/// }
+///
+/// impl actix::Actor for EditorActor {
+///     type Context = actix::Context<Self>;
+///
+///     fn started(&mut self, ctx: &mut Self::Context) {
+///         # let window: gtk4::ApplicationWindow = panic!();
+///         let actions = EditorActions::route_to(ctx.address());
+///         actions.insert_into(&window, "editor");
+///         self.actions = Some(actions);
+///     }
+/// }
+///
+/// impl actix::Handler<woab::Signal> for EditorActor {
+///     type Result = woab::SignalResult;
+///
+///     fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+///         let actions = self.actions.as_ref().unwrap();
+///         Ok(match msg.name() {
+///             "save" => {
+///                 // Don't save again while saving
+///                 actions.block(&actions.save);
+///                 None
+///             }
+///             "zoom-to" => {
+///                 let _zoom: i32 = msg.action_param()?;
+///                 None
+///             }
+///             "dark" => {
+///                 let dark: bool = msg.action_param()?;
+///                 actions.dark_mode.set_state(&glib::variant::ToVariant::to_variant(&dark));
+///                 None
+///             }
+///             _ => msg.cant_handle()?,
+///         })
+///     }
+/// }
+/// ```
+pub use woab_macros::ActionGroup;
+
+/// Generate a form for editing a struct, built at runtime.
+///
+/// The derive generates a `<StructName>Form` struct, with a `grid: gtk4::Grid` field that
+/// contains a row for each field of the original struct - a label and an input widget - and a
+/// field with the same name for each of the input widgets. It also implements:
+///
+/// * [`woab::auto_form::AutoForm`](crate::auto_form::AutoForm) for the struct, whose
+///   [`build_form`](crate::auto_form::AutoForm::build_form) creates the form.
+/// * [`woab::auto_form::FormWidgets`](crate::auto_form::FormWidgets) for the form, for
+///   [getting](crate::auto_form::FormWidgets::get) and [setting](crate::auto_form::FormWidgets::set)
+///   all the values at once.
+/// * [`woab::prop_sync::ConnectChanges`](crate::prop_sync::ConnectChanges) for the form, so that
+///   the changes can be routed to an actor with
+///   [`woab::prop_sync::route_changes`](crate::prop_sync::route_changes).
+///
+/// By default, `String` fields are edited with a `gtk4::Entry`, `bool` fields with a
+/// `gtk4::Switch`, and numeric fields with a `gtk4::SpinButton`. Other types must set a widget
+/// that implements [`FormWidget`](crate::auto_form::FormWidget) for them - e.g. a `gtk4::DropDown`
+/// for an enum that derives [`woab::Choices`](crate::Choices).
+///
+/// The `#[form(...)]` attribute is supported on the fields, with the following values:
+///
+/// - `label = "..."`: The text of the field's label. Defaults to the field's name, capitalized and
+///   with underscores replaced by spaces.
+/// - `widget = Type`: The input widget. Besides the defaults, `gtk4::Scale` can be used for
+///   numbers and `gtk4::CheckButton` for booleans.
+/// - `range = min..=max`: The range of numeric widgets.
+/// - `step = <expr>`: The step of numeric widgets.
+/// - `skip`: Do not include the field in the form. When reading the form, the field is set to its
+///   default value.
+///
+/// A field cannot be named `grid`, since the generated struct already has a field with that name.
+///
+/// ```no_run
+/// # use gtk4::prelude::*;
+/// use woab::auto_form::{AutoForm, FormWidgets};
+///
+/// #[derive(woab::Choices)]
+/// enum Theme {
+///     Light,
+///     Dark,
+/// }
+///
+/// #[derive(woab::AutoForm)]
+/// struct Settings {
+///     user_name: String,
+///     #[form(label = "Volume (%)", widget = gtk4::Scale, range = 0.0..=100.0, step = 5.0)]
+///     volume: f64,
+///     #[form(range = 0..=10)]
+///     retries: u32,
+///     autosave: bool,
+///     #[form(widget = gtk4::DropDown)]
+///     theme: Theme,
+///     #[form(skip)]
+///     last_opened_file: Option<std::path::PathBuf>,
+/// }
+///
+/// # let settings: Settings = panic!();
+/// # let dialog: gtk4::Window = panic!();
+/// let form = Settings::build_form();
+/// form.set(&settings);
+/// dialog.set_child(Some(form.grid()));
+/// // ...
+/// let settings = form.get();
+/// ```
+pub use woab_macros::AutoForm;
+
+pub use about::{add_about_action, show_about, AboutInfo};
+pub use actor_registry::{actor_for, register_actor, unregister_actor};
+pub use animator::{Animator, Step};
 pub use builder::*;
 pub use builder_dissect::dissect_builder_xml;
+pub use color_scheme::{watch_color_scheme, ColorScheme, ColorSchemeWatch};
+pub use command::Command;
+pub use completion::{Completion, CompletionQuery};
+pub use dialogs::{alert_error, alert_info, confirm, run_dialog, run_dialog_cancellable, FileFilters};
+#[cfg(feature = "gtk_v4_10")]
+pub use dialogs::{choose_alert, open_file, save_file, select_folder};
+pub use draw_state::{draw_func_from_actor_state, tick_callback_from_actor_state, DrawState};
+pub use edit_commands::{add_edit_actions, dispatch_edit_command, EditCommand};
 pub use error::{Error, Result, WakerPerished};
+pub use event_bus::EventBus;
 pub use event_loops_bridge::{
-    block_on, close_actix_runtime, is_runtime_running, run_actix_inside_gtk_event_loop, try_block_on, RuntimeStopError,
+    block_on, close_actix_runtime, is_runtime_running, run_actix_inside_gtk_event_loop, set_cranking_mode, set_cranking_priority,
+    try_block_on, CrankingMode, RuntimeStopError,
 };
-pub use gtk_app_helpers::{main, shutdown_when_last_window_is_closed};
-pub use remove::Remove;
-pub use signal::{Signal, SignalResult};
+pub use file_monitor::{watch_file, FileEvent, FileWatch};
+pub use gtk_app_helpers::{
+    main, set_startup_error_handler, show_startup_error_dialog, shutdown_when_last_window_is_closed, MainConfig,
+};
+#[cfg(feature = "i18n")]
+pub use i18n::init_i18n;
+pub use list_binding::ListBinding;
+pub use navigation::Navigator;
+pub use network_monitor::{watch_network, NetworkStatus, NetworkWatch};
+pub use paged_list::PagedList;
+pub use progress_dialog::ProgressDialog;
+pub use remote::{channel_to_actor, RemoteAddr};
+pub use remove::{bind_lifetime, RemovableChildren, Remove, RemoveAndConfirm};
+pub use resources::{add_icon_resource_path, add_icon_search_path, register_resources, ResourceSource};
+pub use row_manager::RowManager;
+pub use send::{send, SendResult};
+pub use set_widget_property::{FindWidget, SetWidgetProperty};
+pub use settings::bind_settings;
+pub use signal::{FromSignalParams, IntoSignalParameters, ProbeSignal, Signal, SignalResult};
+pub use signal_diagnostics::{reset_signal_diagnostics, set_signal_diagnostics_enabled, signal_diagnostics, SignalStats};
+pub use signal_match::{signal_names_contain, signal_names_cover};
+#[cfg(feature = "metrics")]
+pub use signal_metrics::{reset_signal_metrics, signal_metrics, SignalMetrics};
 pub use signal_routing::{
-    route_action, route_signal, GenerateRoutingGtkHandler, IntoGenerateRoutingGtkHandler, NamespacedSignalRouter,
-    RawSignalCallback,
+    route_action, route_detailed_action, route_expression, route_lifecycle, route_list_item_factory, route_search,
+    route_selection, route_shortcuts, route_signal, GenerateRoutingGtkHandler, IntoGenerateRoutingGtkHandler,
+    NamespacedSignalRouter, RawSignalCallback, SelectionChange,
+};
+pub use signal_scope::SignalScope;
+pub use signal_stream::{route_signal_stream, signal_stream, signal_stream_from_builder, SignalStream};
+pub use status_bar::StatusBar;
+pub use timers::{interval, timeout_once, Timer};
+pub use ui_batcher::UiBatcher;
+pub use unmatched_signals::{set_unmatched_signal_policy, UnmatchedSignalPolicy};
+pub use validation::Form;
+pub use view::View;
+pub use waking_helpers::{
+    gio_task, outside, spawn_outside, until_cancelled, wait_for_any, wake_from, wake_from_cancellable, wake_from_signal,
+    wake_from_signal_cancellable, ActixContextExt, GioTaskCallback,
 };
-pub use waking_helpers::{outside, spawn_outside, wake_from, wake_from_signal};
+pub use widget_ops::WidgetOps;
@@ -0,0 +1,318 @@
+//! Filter and sort list models using predicates that are evaluated by actors.
+//!
+//! See [`FilterAdapter`] and [`SortAdapter`] for usage instructions.
+
+use core::cell::RefCell;
+use core::future::Future;
+use core::hash::Hash;
+use core::marker::PhantomData;
+use std::rc::Rc;
+
+use gtk4::prelude::*;
+use hashbrown::{HashMap, HashSet};
+
+use crate::list_binding::ListBindingItem;
+
+struct CacheState<K, V> {
+    values: HashMap<K, V>,
+    pending: HashSet<K>,
+    generation: u64,
+    notify_scheduled: bool,
+}
+
+type SharedCache<K, V> = Rc<RefCell<CacheState<K, V>>>;
+
+fn new_cache<K, V>() -> SharedCache<K, V> {
+    Rc::new(RefCell::new(CacheState {
+        values: HashMap::new(),
+        pending: HashSet::new(),
+        generation: 0,
+        notify_scheduled: false,
+    }))
+}
+
+fn clear_cache<K, V>(cache: &SharedCache<K, V>) {
+    let mut state = cache.borrow_mut();
+    state.values.clear();
+    state.pending.clear();
+    state.generation += 1;
+}
+
+/// Return the cached value, or send a request and call `notify` (once per batch of responses)
+/// when it gets resolved.
+fn cached_or_request<K, V, F>(
+    cache: &SharedCache<K, V>,
+    key: K,
+    request: impl FnOnce(K) -> F,
+    notify: impl 'static + FnOnce(),
+) -> Option<V>
+where
+    K: 'static + Eq + Hash + Clone,
+    V: 'static + Clone,
+    F: 'static + Future<Output = Result<V, actix::MailboxError>>,
+{
+    let mut state = cache.borrow_mut();
+    if let Some(value) = state.values.get(&key) {
+        return Some(value.clone());
+    }
+    if state.pending.insert(key.clone()) {
+        let generation = state.generation;
+        let response = request(key.clone());
+        let cache = cache.clone();
+        glib::MainContext::default().spawn_local(async move {
+            let result = response.await;
+            let mut state = cache.borrow_mut();
+            if state.generation != generation {
+                return;
+            }
+            state.pending.remove(&key);
+            let Ok(value) = result else {
+                return;
+            };
+            state.values.insert(key, value);
+            if !state.notify_scheduled {
+                state.notify_scheduled = true;
+                let cache = cache.clone();
+                glib::idle_add_local_once(move || {
+                    cache.borrow_mut().notify_scheduled = false;
+                    notify();
+                });
+            }
+        });
+    }
+    None
+}
+
+/// An actor that owns a `gtk4::CustomFilter` whose predicate is evaluated by another actor.
+///
+/// The filter is meant for models of [`ListBinding`](crate::ListBinding) items. For each item, the
+/// predicate actor is sent a [`Matches`] message with the item's key, and the result is cached.
+/// Items are hidden until their result arrives.
+///
+/// The cache is not invalidated automatically - send [`Refilter`] to the adapter when the
+/// predicate's result may have changed (e.g. when the actor's search text or the items' data
+/// change).
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// use woab::list_adapters::{FilterAdapter, Matches};
+/// use woab::list_binding::ListBinding;
+/// # struct Person { id: u64 }
+/// # impl woab::list_binding::ListBindingItem for Person {
+/// #     type Key = u64;
+/// #     fn key(&self) -> u64 { self.id }
+/// # }
+///
+/// struct PeopleActor {
+///     // ...
+/// }
+/// # impl actix::Actor for PeopleActor { type Context = actix::Context<Self>; }
+/// # impl PeopleActor { fn is_visible(&self, _id: u64) -> bool { true } }
+///
+/// impl actix::Handler<Matches<u64>> for PeopleActor {
+///     type Result = bool;
+///
+///     fn handle(&mut self, msg: Matches<u64>, _ctx: &mut Self::Context) -> Self::Result {
+///         self.is_visible(msg.key)
+///     }
+/// }
+///
+/// # let list_view: gtk4::ListView = panic!();
+/// # let people_actor: actix::Addr<PeopleActor> = panic!();
+/// let binding = ListBinding::<Person>::new();
+/// let filter = FilterAdapter::<Person>::new(people_actor.recipient());
+/// let model = gtk4::FilterListModel::new(Some(binding.store().clone()), Some(filter.filter().clone()));
+/// list_view.set_model(Some(&gtk4::NoSelection::new(Some(model))));
+/// ```
+pub struct FilterAdapter<T: ListBindingItem> {
+    filter: gtk4::CustomFilter,
+    cache: SharedCache<T::Key, bool>,
+}
+
+impl<T: ListBindingItem> FilterAdapter<T> {
+    /// Create a filter that sends [`Matches`] messages to `predicate`.
+    pub fn new(predicate: actix::Recipient<Matches<T::Key>>) -> Self {
+        let filter = gtk4::CustomFilter::new(|_| false);
+        let cache = new_cache();
+        filter.set_filter_func({
+            let filter = filter.downgrade();
+            let cache = cache.clone();
+            move |obj| {
+                let Some(key) = crate::list_binding::item::<T>(obj).map(|item| item.key()) else {
+                    return false;
+                };
+                let filter = filter.clone();
+                cached_or_request(
+                    &cache,
+                    key,
+                    |key| predicate.send(Matches { key }),
+                    move || {
+                        if let Some(filter) = filter.upgrade() {
+                            filter.changed(gtk4::FilterChange::Different);
+                        }
+                    },
+                )
+                .unwrap_or(false)
+            }
+        });
+        Self { filter, cache }
+    }
+
+    /// The filter, to be used in a `gtk4::FilterListModel`.
+    pub fn filter(&self) -> &gtk4::CustomFilter {
+        &self.filter
+    }
+}
+
+impl<T: ListBindingItem> actix::Actor for FilterAdapter<T> {
+    type Context = actix::Context<Self>;
+}
+
+/// Ask the predicate actor of a [`FilterAdapter`] whether or not the item should be displayed.
+pub struct Matches<K> {
+    /// The key of the item.
+    pub key: K,
+}
+
+impl<K: 'static + Send> actix::Message for Matches<K> {
+    type Result = bool;
+}
+
+/// Clear the cache of a [`FilterAdapter`] and reevaluate the filter for all the items.
+pub struct Refilter;
+
+impl actix::Message for Refilter {
+    type Result = ();
+}
+
+impl<T: ListBindingItem> actix::Handler<Refilter> for FilterAdapter<T> {
+    type Result = ();
+
+    fn handle(&mut self, _msg: Refilter, _ctx: &mut Self::Context) -> Self::Result {
+        clear_cache(&self.cache);
+        self.filter.changed(gtk4::FilterChange::Different);
+    }
+}
+
+/// An actor that owns a `gtk4::CustomSorter` whose sort keys are provided by another actor.
+///
+/// The sorter is meant for models of [`ListBinding`](crate::ListBinding) items. For each item, the
+/// sort key actor is sent a [`SortKey`] message with the item's key, and the result is cached.
+/// Items are sorted by their sort keys, and items whose sort keys did not arrive yet are placed at
+/// the end.
+///
+/// The cache is not invalidated automatically - send [`Resort`] to the adapter when the sort keys
+/// may have changed (e.g. when the user picked a different sort column or the items' data
+/// change).
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// use woab::list_adapters::{SortAdapter, SortKey};
+/// use woab::list_binding::ListBinding;
+/// # struct Person { id: u64 }
+/// # impl woab::list_binding::ListBindingItem for Person {
+/// #     type Key = u64;
+/// #     fn key(&self) -> u64 { self.id }
+/// # }
+///
+/// struct PeopleActor {
+///     // ...
+/// }
+/// # impl actix::Actor for PeopleActor { type Context = actix::Context<Self>; }
+/// # impl PeopleActor { fn name_of(&self, _id: u64) -> String { String::new() } }
+///
+/// impl actix::Handler<SortKey<u64, String>> for PeopleActor {
+///     type Result = String;
+///
+///     fn handle(&mut self, msg: SortKey<u64, String>, _ctx: &mut Self::Context) -> Self::Result {
+///         self.name_of(msg.key)
+///     }
+/// }
+///
+/// # let list_view: gtk4::ListView = panic!();
+/// # let people_actor: actix::Addr<PeopleActor> = panic!();
+/// let binding = ListBinding::<Person>::new();
+/// let sorter = SortAdapter::<Person, String>::new(people_actor.recipient());
+/// let model = gtk4::SortListModel::new(Some(binding.store().clone()), Some(sorter.sorter().clone()));
+/// list_view.set_model(Some(&gtk4::NoSelection::new(Some(model))));
+/// ```
+pub struct SortAdapter<T: ListBindingItem, S: 'static + Send + Ord + Clone> {
+    sorter: gtk4::CustomSorter,
+    cache: SharedCache<T::Key, S>,
+}
+
+impl<T: ListBindingItem, S: 'static + Send + Ord + Clone> SortAdapter<T, S> {
+    /// Create a sorter that sends [`SortKey`] messages to `sort_key`.
+    pub fn new(sort_key: actix::Recipient<SortKey<T::Key, S>>) -> Self {
+        let sorter = gtk4::CustomSorter::new(|_, _| gtk4::Ordering::Equal);
+        let cache = new_cache();
+        sorter.set_sort_func({
+            let sorter = sorter.downgrade();
+            let cache = cache.clone();
+            move |a, b| {
+                let sort_key_of = |obj: &glib::Object| {
+                    let key = crate::list_binding::item::<T>(obj)?.key();
+                    let sorter = sorter.clone();
+                    cached_or_request(
+                        &cache,
+                        key,
+                        |key| {
+                            sort_key.send(SortKey {
+                                key,
+                                _phantom: PhantomData,
+                            })
+                        },
+                        move || {
+                            if let Some(sorter) = sorter.upgrade() {
+                                sorter.changed(gtk4::SorterChange::Different);
+                            }
+                        },
+                    )
+                };
+                match (sort_key_of(a), sort_key_of(b)) {
+                    (Some(a), Some(b)) => a.cmp(&b).into(),
+                    (Some(_), None) => gtk4::Ordering::Smaller,
+                    (None, Some(_)) => gtk4::Ordering::Larger,
+                    (None, None) => gtk4::Ordering::Equal,
+                }
+            }
+        });
+        Self { sorter, cache }
+    }
+
+    /// The sorter, to be used in a `gtk4::SortListModel`.
+    pub fn sorter(&self) -> &gtk4::CustomSorter {
+        &self.sorter
+    }
+}
+
+impl<T: ListBindingItem, S: 'static + Send + Ord + Clone> actix::Actor for SortAdapter<T, S> {
+    type Context = actix::Context<Self>;
+}
+
+/// Ask the sort key actor of a [`SortAdapter`] for the sort key of an item.
+pub struct SortKey<K, S> {
+    /// The key of the item.
+    pub key: K,
+    _phantom: PhantomData<fn() -> S>,
+}
+
+impl<K: 'static + Send, S: 'static + Send> actix::Message for SortKey<K, S> {
+    type Result = S;
+}
+
+/// Clear the cache of a [`SortAdapter`] and resort all the items.
+pub struct Resort;
+
+impl actix::Message for Resort {
+    type Result = ();
+}
+
+impl<T: ListBindingItem, S: 'static + Send + Ord + Clone> actix::Handler<Resort> for SortAdapter<T, S> {
+    type Result = ();
+
+    fn handle(&mut self, _msg: Resort, _ctx: &mut Self::Context) -> Self::Result {
+        clear_cache(&self.cache);
+        self.sorter.changed(gtk4::SorterChange::Different);
+    }
+}
@@ -0,0 +1,224 @@
+//! Keep a `gio::ListStore` in sync with actor-side data.
+//!
+//! See [`ListBinding`] for usage instructions.
+
+use core::hash::Hash;
+
+use gio::prelude::*;
+use hashbrown::HashSet;
+
+/// An item that can be stored in a [`ListBinding`].
+///
+/// The key identifies the item - it must be unique within the list and stay the same when the
+/// item's data is updated.
+pub trait ListBindingItem: 'static + Send {
+    type Key: 'static + Send + Unpin + Eq + Hash + Clone;
+
+    fn key(&self) -> Self::Key;
+}
+
+/// An actor that owns a `gio::ListStore` and keeps it in sync with the items sent to it.
+///
+/// The items are stored in the list store wrapped in `glib::BoxedAnyObject` - use [`item`] to get
+/// them back (e.g. inside the `bind` signal of a `gtk4::SignalListItemFactory`).
+///
+/// The list store is updated with these messages:
+///
+/// * [`Insert`] - add an item to the end of the list, or replace the item with the same key.
+/// * [`Update`] - replace the item with the same key.
+/// * [`Remove`] - remove the item with the given key.
+/// * [`Reset`] - replace all the items of the list. Items with keys that were already in the list
+///   are updated in place, so the list view does not lose track of them.
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// use woab::list_binding::{ListBinding, ListBindingItem, Reset};
+///
+/// struct Person {
+///     id: u64,
+///     name: String,
+/// }
+///
+/// impl ListBindingItem for Person {
+///     type Key = u64;
+///
+///     fn key(&self) -> Self::Key {
+///         self.id
+///     }
+/// }
+///
+/// # let list_view: gtk4::ListView = panic!();
+/// let binding = ListBinding::<Person>::new();
+/// list_view.set_model(Some(&gtk4::NoSelection::new(Some(binding.store().clone()))));
+/// let binding = binding.start();
+/// binding.do_send(Reset(vec![
+///     Person { id: 1, name: "Alice".to_owned() },
+///     Person { id: 2, name: "Bob".to_owned() },
+/// ]));
+/// ```
+pub struct ListBinding<T: ListBindingItem> {
+    store: gio::ListStore,
+    keys: Vec<T::Key>,
+}
+
+impl<T: ListBindingItem> Default for ListBinding<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: ListBindingItem> ListBinding<T> {
+    /// Create a binding with a new, empty list store.
+    pub fn new() -> Self {
+        Self {
+            store: gio::ListStore::new::<glib::BoxedAnyObject>(),
+            keys: Vec::new(),
+        }
+    }
+
+    /// The list store that this binding updates.
+    ///
+    /// This should be used as the model of the list widget. The list store should only be
+    /// modified by the binding.
+    pub fn store(&self) -> &gio::ListStore {
+        &self.store
+    }
+
+    fn position(&self, key: &T::Key) -> Option<usize> {
+        self.keys.iter().position(|k| k == key)
+    }
+
+    fn insert_at(&mut self, position: usize, item: T) {
+        self.keys.insert(position, item.key());
+        self.store.insert(position as u32, &glib::BoxedAnyObject::new(item));
+    }
+
+    fn replace_at(&mut self, position: usize, item: T) {
+        self.store.splice(position as u32, 1, &[glib::BoxedAnyObject::new(item)]);
+    }
+
+    fn remove_at(&mut self, position: usize) {
+        self.keys.remove(position);
+        self.store.remove(position as u32);
+    }
+}
+
+impl<T: ListBindingItem> actix::Actor for ListBinding<T> {
+    type Context = actix::Context<Self>;
+}
+
+/// Get the item of a [`ListBinding`] from the object stored in its list store.
+///
+/// Returns `None` if the object was not created by a `ListBinding<T>`.
+pub fn item<T: 'static>(obj: &glib::Object) -> Option<std::cell::Ref<'_, T>> {
+    obj.downcast_ref::<glib::BoxedAnyObject>()?.try_borrow().ok()
+}
+
+/// Add an item to the end of a [`ListBinding`], or replace the item with the same key if there is
+/// one.
+pub struct Insert<T>(pub T);
+
+impl<T: ListBindingItem> actix::Message for Insert<T> {
+    type Result = ();
+}
+
+impl<T: ListBindingItem> actix::Handler<Insert<T>> for ListBinding<T> {
+    type Result = ();
+
+    fn handle(&mut self, msg: Insert<T>, _ctx: &mut Self::Context) -> Self::Result {
+        let Insert(item) = msg;
+        if let Some(position) = self.position(&item.key()) {
+            self.replace_at(position, item);
+        } else {
+            self.insert_at(self.keys.len(), item);
+        }
+    }
+}
+
+/// Replace the item with the same key in a [`ListBinding`].
+///
+/// Resolves to `false` if there was no item with that key.
+pub struct Update<T>(pub T);
+
+impl<T: ListBindingItem> actix::Message for Update<T> {
+    type Result = bool;
+}
+
+impl<T: ListBindingItem> actix::Handler<Update<T>> for ListBinding<T> {
+    type Result = bool;
+
+    fn handle(&mut self, msg: Update<T>, _ctx: &mut Self::Context) -> Self::Result {
+        let Update(item) = msg;
+        if let Some(position) = self.position(&item.key()) {
+            self.replace_at(position, item);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Remove the item with the given key from a [`ListBinding`].
+///
+/// Resolves to `false` if there was no item with that key.
+pub struct Remove<K>(pub K);
+
+impl<K: 'static + Send> actix::Message for Remove<K> {
+    type Result = bool;
+}
+
+impl<T: ListBindingItem> actix::Handler<Remove<T::Key>> for ListBinding<T> {
+    type Result = bool;
+
+    fn handle(&mut self, msg: Remove<T::Key>, _ctx: &mut Self::Context) -> Self::Result {
+        if let Some(position) = self.position(&msg.0) {
+            self.remove_at(position);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Replace all the items of a [`ListBinding`].
+///
+/// The new items are diffed against the existing ones by their keys - items that were already in
+/// the list are updated (and moved if their position changed), items that are no longer in the
+/// list are removed, and new items are inserted.
+///
+/// Resolves to [`woab::Error::DuplicateListBindingKeys`](crate::Error::DuplicateListBindingKeys)
+/// (without changing the list) if multiple new items have the same key.
+pub struct Reset<T>(pub Vec<T>);
+
+impl<T: ListBindingItem> actix::Message for Reset<T> {
+    type Result = crate::Result<()>;
+}
+
+impl<T: ListBindingItem> actix::Handler<Reset<T>> for ListBinding<T> {
+    type Result = crate::Result<()>;
+
+    fn handle(&mut self, msg: Reset<T>, _ctx: &mut Self::Context) -> Self::Result {
+        let Reset(items) = msg;
+        let new_keys = items.iter().map(|item| item.key()).collect::<HashSet<_>>();
+        if new_keys.len() < items.len() {
+            return Err(crate::Error::DuplicateListBindingKeys);
+        }
+        for position in (0..self.keys.len()).rev() {
+            if !new_keys.contains(&self.keys[position]) {
+                self.remove_at(position);
+            }
+        }
+        for (position, item) in items.into_iter().enumerate() {
+            let key = item.key();
+            if self.keys.get(position) == Some(&key) {
+                self.replace_at(position, item);
+                continue;
+            }
+            if let Some(old_position) = self.position(&key) {
+                self.remove_at(old_position);
+            }
+            self.insert_at(position, item);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,241 @@
+//! Route the playback state of `gtk4::MediaStream`s to actors, and control playback with messages.
+//!
+//! [`watch_media_stream`] (or [`watch_video`], for the stream of a `gtk4::Video`) sends
+//! [`MediaEvent`] messages whenever the playback state changes. [`MediaController`] is a small
+//! actor that handles [`MediaCommand`] messages, so that the playback can be controlled from other
+//! actors:
+//!
+//! ```no_run
+//! # use actix::prelude::*;
+//! use woab::media::{MediaCommand, MediaController, MediaEvent};
+//!
+//! struct PlayerActor {
+//!     video: gtk4::Video,
+//!     position_label: gtk4::Label,
+//!     controller: Option<actix::Addr<MediaController>>,
+//!     _media_watch: Option<woab::media::MediaWatch>,
+//! }
+//!
+//! impl actix::Actor for PlayerActor {
+//!     type Context = actix::Context<Self>;
+//!
+//!     fn started(&mut self, ctx: &mut Self::Context) {
+//!         self._media_watch = Some(woab::media::watch_video(&self.video, ctx.address()));
+//!         self.controller = Some(MediaController::for_video(&self.video).start());
+//!     }
+//! }
+//!
+//! impl actix::Handler<MediaEvent> for PlayerActor {
+//!     type Result = ();
+//!
+//!     fn handle(&mut self, msg: MediaEvent, _ctx: &mut Self::Context) -> Self::Result {
+//!         match msg {
+//!             MediaEvent::Timestamp(timestamp) => {
+//!                 self.position_label.set_text(&format!("{}s", timestamp / 1_000_000));
+//!             }
+//!             MediaEvent::Ended => {
+//!                 // Start over
+//!                 let controller = self.controller.as_ref().unwrap();
+//!                 controller.do_send(MediaCommand::Seek(0));
+//!                 controller.do_send(MediaCommand::Play);
+//!             }
+//!             _ => {}
+//!         }
+//!     }
+//! }
+//! ```
+
+use core::cell::RefCell;
+use std::rc::Rc;
+
+use gtk4::prelude::*;
+
+/// A change in the playback state of a media stream, sent by [`watch_media_stream`] and
+/// [`watch_video`].
+#[derive(Debug, Clone)]
+pub enum MediaEvent {
+    /// The stream is ready to be played.
+    Prepared,
+    /// The stream started (`true`) or stopped (`false`) playing.
+    Playing(bool),
+    /// The stream reached its end.
+    Ended,
+    /// The stream failed. Once a stream is in an error state, it cannot be played.
+    Error(glib::Error),
+    /// The current playback position, in microseconds.
+    Timestamp(i64),
+}
+
+impl actix::Message for MediaEvent {
+    type Result = ();
+}
+
+/// Keeps a watch created by [`watch_media_stream`] or [`watch_video`] active.
+///
+/// The stream stops being watched when this is dropped.
+#[must_use = "the media stream stops being watched when the MediaWatch is dropped"]
+pub struct MediaWatch {
+    object: glib::Object,
+    handler_ids: Vec<glib::SignalHandlerId>,
+    // For videos, the watch of the current stream.
+    _stream_watch: Option<Rc<RefCell<Option<MediaWatch>>>>,
+}
+
+impl Drop for MediaWatch {
+    fn drop(&mut self) {
+        for handler_id in self.handler_ids.drain(..) {
+            self.object.disconnect(handler_id);
+        }
+    }
+}
+
+/// Send a [`MediaEvent`] to an actor whenever the playback state of the stream changes.
+///
+/// Only changes are sent - the actor can query the stream for its current state.
+pub fn watch_media_stream(stream: &impl IsA<gtk4::MediaStream>, target: impl Into<actix::Recipient<MediaEvent>>) -> MediaWatch {
+    let stream = stream.upcast_ref::<gtk4::MediaStream>();
+    let target = target.into();
+    let handler_ids = vec![
+        stream.connect_prepared_notify({
+            let target = target.clone();
+            move |stream| {
+                if stream.is_prepared() {
+                    target.do_send(MediaEvent::Prepared);
+                }
+            }
+        }),
+        stream.connect_playing_notify({
+            let target = target.clone();
+            move |stream| target.do_send(MediaEvent::Playing(stream.is_playing()))
+        }),
+        stream.connect_ended_notify({
+            let target = target.clone();
+            move |stream| {
+                if stream.is_ended() {
+                    target.do_send(MediaEvent::Ended);
+                }
+            }
+        }),
+        stream.connect_error_notify({
+            let target = target.clone();
+            move |stream| {
+                if let Some(error) = stream.error() {
+                    target.do_send(MediaEvent::Error(error));
+                }
+            }
+        }),
+        stream.connect_timestamp_notify(move |stream| target.do_send(MediaEvent::Timestamp(stream.timestamp()))),
+    ];
+    MediaWatch {
+        object: stream.clone().upcast(),
+        handler_ids,
+        _stream_watch: None,
+    }
+}
+
+/// Send a [`MediaEvent`] to an actor whenever the playback state of the video's stream changes.
+///
+/// Like [`watch_media_stream`], but when the video's stream is replaced (e.g. when a different
+/// file is set) the new stream is watched instead.
+pub fn watch_video(video: &gtk4::Video, target: impl Into<actix::Recipient<MediaEvent>>) -> MediaWatch {
+    let target = target.into();
+    let stream_watch = Rc::new(RefCell::new(
+        video.media_stream().map(|stream| watch_media_stream(&stream, target.clone())),
+    ));
+    let handler_id = video.connect_media_stream_notify({
+        let stream_watch = stream_watch.clone();
+        move |video| {
+            // Drop the old watch before creating the new one, in case the stream is the same.
+            stream_watch.borrow_mut().take();
+            *stream_watch.borrow_mut() = video.media_stream().map(|stream| watch_media_stream(&stream, target.clone()));
+        }
+    });
+    MediaWatch {
+        object: video.clone().upcast(),
+        handler_ids: vec![handler_id],
+        _stream_watch: Some(stream_watch),
+    }
+}
+
+/// A playback command for a [`MediaController`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MediaCommand {
+    /// Start playing the stream.
+    Play,
+    /// Pause the stream.
+    Pause,
+    /// Play the stream if it is paused, and pause it if it is playing.
+    TogglePlaying,
+    /// Seek to a position, in microseconds. Ignored if the stream is not seekable.
+    Seek(i64),
+}
+
+impl actix::Message for MediaCommand {
+    type Result = ();
+}
+
+impl MediaCommand {
+    /// Run the command on a stream.
+    pub fn apply(self, stream: &impl IsA<gtk4::MediaStream>) {
+        let stream = stream.upcast_ref::<gtk4::MediaStream>();
+        match self {
+            MediaCommand::Play => stream.play(),
+            MediaCommand::Pause => stream.pause(),
+            MediaCommand::TogglePlaying => stream.set_playing(!stream.is_playing()),
+            MediaCommand::Seek(timestamp) => {
+                if stream.is_seekable() {
+                    stream.seek(timestamp);
+                }
+            }
+        }
+    }
+}
+
+enum MediaSource {
+    Stream(gtk4::MediaStream),
+    Video(gtk4::Video),
+}
+
+/// An actor that runs [`MediaCommand`]s on a media stream.
+///
+/// The actor stops once all its addresses are dropped.
+pub struct MediaController {
+    source: MediaSource,
+}
+
+impl MediaController {
+    /// Control a media stream.
+    pub fn new(stream: &impl IsA<gtk4::MediaStream>) -> Self {
+        Self {
+            source: MediaSource::Stream(stream.clone().upcast()),
+        }
+    }
+
+    /// Control whatever stream the video plays when the command arrives.
+    ///
+    /// Commands that arrive while the video has no stream are ignored.
+    pub fn for_video(video: &gtk4::Video) -> Self {
+        Self {
+            source: MediaSource::Video(video.clone()),
+        }
+    }
+}
+
+impl actix::Actor for MediaController {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<MediaCommand> for MediaController {
+    type Result = ();
+
+    fn handle(&mut self, msg: MediaCommand, _ctx: &mut Self::Context) -> Self::Result {
+        match &self.source {
+            MediaSource::Stream(stream) => msg.apply(stream),
+            MediaSource::Video(video) => {
+                if let Some(stream) = video.media_stream() {
+                    msg.apply(&stream);
+                }
+            }
+        }
+    }
+}
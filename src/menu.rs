@@ -0,0 +1,218 @@
+//! Build GIO menus in Rust and route their actions to actors.
+//!
+//! See [`MenuDef`] for usage instructions.
+
+use gtk4::prelude::*;
+
+enum MenuEntry {
+    Item {
+        label: String,
+        action: String,
+        target: Option<glib::Variant>,
+    },
+    Section {
+        label: Option<String>,
+        menu: MenuDef,
+    },
+    Submenu {
+        label: String,
+        menu: MenuDef,
+    },
+}
+
+/// A declarative definition of a `gio::Menu`.
+///
+/// Items refer to actions by their detailed names (e.g. `"win.save"` or `"app.quit"`).
+/// [`install`](MenuDef::install) builds the menu and also creates the actions it refers to and
+/// routes them to an actor, so that the action names only need to be written once.
+/// [`attach`](MenuDef::attach) does the same and also sets the menu on a [`MenuHolder`] - a menu
+/// button, a popover menu, or the application's menubar.
+///
+/// The actions are routed like [`woab::route_action`](crate::route_action) - the signal name is
+/// the action name without the prefix, and items with a target create actions with a parameter of
+/// the target's type (use [`action_param`](crate::Signal::action_param) to get it).
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// use woab::menu::MenuDef;
+///
+/// # struct WindowActor;
+/// # impl actix::Actor for WindowActor { type Context = actix::Context<Self>; }
+/// # impl actix::Handler<woab::Signal> for WindowActor {
+/// #     type Result = woab::SignalResult;
+/// #     fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+/// #         msg.cant_handle()
+/// #     }
+/// # }
+/// # let window: gtk4::ApplicationWindow = panic!();
+/// # let menu_button: gtk4::MenuButton = panic!();
+/// # let addr: actix::Addr<WindowActor> = panic!();
+/// let menu = MenuDef::new()
+///     .section(
+///         None,
+///         MenuDef::new().item("_Open", "win.open").item("_Save", "win.save"),
+///     )
+///     .submenu(
+///         "_Zoom",
+///         MenuDef::new()
+///             .item_with_target("50%", "win.zoom", 50)
+///             .item_with_target("100%", "win.zoom", 100)
+///             .item_with_target("200%", "win.zoom", 200),
+///     );
+/// menu.attach(&menu_button, "win", &window, addr).unwrap();
+/// ```
+#[derive(Default)]
+pub struct MenuDef {
+    entries: Vec<MenuEntry>,
+}
+
+impl MenuDef {
+    /// Create an empty menu definition.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an item that activates the action.
+    pub fn item(mut self, label: impl Into<String>, action: impl Into<String>) -> Self {
+        self.entries.push(MenuEntry::Item {
+            label: label.into(),
+            action: action.into(),
+            target: None,
+        });
+        self
+    }
+
+    /// Add an item that activates the action with a parameter.
+    pub fn item_with_target(mut self, label: impl Into<String>, action: impl Into<String>, target: impl ToVariant) -> Self {
+        self.entries.push(MenuEntry::Item {
+            label: label.into(),
+            action: action.into(),
+            target: Some(target.to_variant()),
+        });
+        self
+    }
+
+    /// Add a section - a group of items that is separated from the other items.
+    pub fn section(mut self, label: Option<&str>, menu: MenuDef) -> Self {
+        self.entries.push(MenuEntry::Section {
+            label: label.map(|label| label.to_owned()),
+            menu,
+        });
+        self
+    }
+
+    /// Add a submenu.
+    pub fn submenu(mut self, label: impl Into<String>, menu: MenuDef) -> Self {
+        self.entries.push(MenuEntry::Submenu {
+            label: label.into(),
+            menu,
+        });
+        self
+    }
+
+    /// Build the `gio::Menu`, without creating any actions.
+    pub fn build(&self) -> gio::Menu {
+        let menu = gio::Menu::new();
+        for entry in self.entries.iter() {
+            match entry {
+                MenuEntry::Item { label, action, target } => {
+                    let item = gio::MenuItem::new(Some(label), None);
+                    item.set_action_and_target_value(Some(action), target.as_ref());
+                    menu.append_item(&item);
+                }
+                MenuEntry::Section { label, menu: section } => {
+                    menu.append_section(label.as_deref(), &section.build());
+                }
+                MenuEntry::Submenu { label, menu: submenu } => {
+                    menu.append_submenu(Some(label), &submenu.build());
+                }
+            }
+        }
+        menu
+    }
+
+    fn visit_items<'a>(&'a self, visitor: &mut impl FnMut(&'a str, Option<&'a glib::Variant>)) {
+        for entry in self.entries.iter() {
+            match entry {
+                MenuEntry::Item { action, target, .. } => visitor(action, target.as_ref()),
+                MenuEntry::Section { menu, .. } | MenuEntry::Submenu { menu, .. } => menu.visit_items(visitor),
+            }
+        }
+    }
+
+    /// Build the `gio::Menu`, and create the actions it refers to under `prefix`.
+    ///
+    /// For each action named `<prefix>.<name>` in the menu, if `action_map` does not already have
+    /// an action named `<name>`, a `gio::SimpleAction` is added to it and routed to `target`.
+    /// Actions with other prefixes are ignored.
+    pub fn install(
+        &self,
+        prefix: &str,
+        action_map: &impl IsA<gio::ActionMap>,
+        target: impl crate::IntoGenerateRoutingGtkHandler,
+    ) -> crate::Result<gio::Menu> {
+        use crate::GenerateRoutingGtkHandler;
+
+        let generator = target.into_generate_routing_gtk_handler();
+        let mut actions = Vec::new();
+        self.visit_items(&mut |action, target| actions.push((action, target)));
+        for (action, target) in actions {
+            let Some(name) = action.strip_prefix(prefix).and_then(|name| name.strip_prefix('.')) else {
+                continue;
+            };
+            if action_map.lookup_action(name).is_some() {
+                continue;
+            }
+            if !gio::Action::name_is_valid(name) {
+                return Err(glib::bool_error!("Invalid action name {:?}", name).into());
+            }
+            let action = gio::SimpleAction::new(name, target.map(|target| target.type_()));
+            generator.connect_local(&action, "activate", name);
+            action_map.add_action(&action);
+        }
+        Ok(self.build())
+    }
+
+    /// [`install`](MenuDef::install) the menu and set it as the menu of `holder`.
+    pub fn attach(
+        &self,
+        holder: &impl MenuHolder,
+        prefix: &str,
+        action_map: &impl IsA<gio::ActionMap>,
+        target: impl crate::IntoGenerateRoutingGtkHandler,
+    ) -> crate::Result<gio::Menu> {
+        let menu = self.install(prefix, action_map, target)?;
+        holder.set_menu(&menu);
+        Ok(menu)
+    }
+}
+
+/// Something that can display a menu. See [`MenuDef::attach`].
+pub trait MenuHolder {
+    /// Set the menu model this holder displays.
+    fn set_menu(&self, menu: &gio::Menu);
+}
+
+impl MenuHolder for gtk4::MenuButton {
+    fn set_menu(&self, menu: &gio::Menu) {
+        self.set_menu_model(Some(menu));
+    }
+}
+
+impl MenuHolder for gtk4::PopoverMenu {
+    fn set_menu(&self, menu: &gio::Menu) {
+        self.set_menu_model(Some(menu));
+    }
+}
+
+impl MenuHolder for gtk4::PopoverMenuBar {
+    fn set_menu(&self, menu: &gio::Menu) {
+        self.set_menu_model(Some(menu));
+    }
+}
+
+impl MenuHolder for gtk4::Application {
+    fn set_menu(&self, menu: &gio::Menu) {
+        self.set_menubar(Some(menu));
+    }
+}
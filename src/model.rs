@@ -0,0 +1,168 @@
+//! Two-way binding between a model struct and a [`PropSync`](crate::PropSync) widgets struct.
+//!
+//! See [`#[derive(woab::Model)]`](crate::Model) for usage instructions.
+
+use std::cell::{Cell, Ref, RefCell};
+use std::rc::Rc;
+
+use glib::object::ObjectExt;
+
+/// A model that can be synced with a [`PropSync`](crate::PropSync) widgets struct.
+///
+/// Usually implemented with [`#[derive(woab::Model)]`](crate::Model).
+pub trait Model: 'static {
+    /// The widgets struct the model is synced with.
+    type Widgets: 'static + Clone;
+
+    /// Set the widgets' data from the model.
+    fn apply_to_widgets(&self, widgets: &Self::Widgets);
+
+    /// Update the model from the widgets' data.
+    fn update_from_widgets(&mut self, widgets: &Self::Widgets);
+
+    /// Connect a callback to the change signals of all the widgets that are synced with the
+    /// model.
+    fn connect_widgets_changes(widgets: &Self::Widgets, callback: Rc<dyn Fn()>) -> Vec<(glib::Object, glib::SignalHandlerId)>;
+}
+
+/// Convert a model field to the type of the matching field in a
+/// [`PropSync`](crate::PropSync)-generated setter.
+#[doc(hidden)] // for internal use by #[derive(Model)]
+pub trait AsSetter<'a, S> {
+    fn as_setter(&'a self) -> S;
+}
+
+impl<'a, T: Clone> AsSetter<'a, T> for T {
+    fn as_setter(&'a self) -> T {
+        self.clone()
+    }
+}
+
+impl<'a> AsSetter<'a, &'a str> for String {
+    fn as_setter(&'a self) -> &'a str {
+        self
+    }
+}
+
+/// A model bound to its widgets.
+///
+/// When the user edits the widgets, the model is updated automatically and the `on_change`
+/// callback is invoked. When the model is modified with [`modify`](ModelBinding::modify), the
+/// widgets are updated.
+///
+/// The signals are disconnected when the binding is dropped.
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// #[derive(woab::Model)]
+/// #[model(widgets = PersonWidgets)]
+/// struct Person {
+///     name: String,
+///     age: f64,
+/// }
+///
+/// #[derive(Clone, woab::WidgetsFromBuilder, woab::PropSync)]
+/// struct PersonWidgets {
+///     #[prop_sync(set, get)]
+///     name: gtk4::Entry,
+///     #[prop_sync("value" as f64, set, get)]
+///     age: gtk4::SpinButton,
+/// }
+///
+/// struct PersonActor {
+///     person: woab::model::ModelBinding<Person>,
+/// }
+/// # impl actix::Actor for PersonActor { type Context = actix::Context<Self>; }
+///
+/// struct PersonChanged;
+/// # impl actix::Message for PersonChanged { type Result = (); }
+///
+/// impl actix::Handler<PersonChanged> for PersonActor {
+///     type Result = ();
+///
+///     fn handle(&mut self, _msg: PersonChanged, _ctx: &mut Self::Context) -> Self::Result {
+///         println!("Person's name is now {:?}", self.person.model().name);
+///     }
+/// }
+///
+/// # let widgets: PersonWidgets = panic!();
+/// PersonActor::create(|ctx| {
+///     let addr = ctx.address();
+///     let person = woab::model::ModelBinding::new(
+///         Person { name: "John".to_owned(), age: 42.0 },
+///         widgets,
+///         move || addr.do_send(PersonChanged),
+///     );
+///     PersonActor { person }
+/// });
+/// ```
+pub struct ModelBinding<M: Model> {
+    model: Rc<RefCell<M>>,
+    widgets: M::Widgets,
+    applying: Rc<Cell<bool>>,
+    connections: Vec<(glib::Object, glib::SignalHandlerId)>,
+}
+
+impl<M: Model> ModelBinding<M> {
+    /// Bind the model to the widgets.
+    ///
+    /// The widgets are initially set from the model.
+    pub fn new(model: M, widgets: M::Widgets, on_change: impl 'static + Fn()) -> Self {
+        let model = Rc::new(RefCell::new(model));
+        let applying = Rc::new(Cell::new(false));
+        let callback = Rc::new({
+            let model = model.clone();
+            let widgets = widgets.clone();
+            let applying = applying.clone();
+            move || {
+                if applying.get() {
+                    return;
+                }
+                model.borrow_mut().update_from_widgets(&widgets);
+                on_change();
+            }
+        });
+        let connections = M::connect_widgets_changes(&widgets, callback);
+        let binding = Self {
+            model,
+            widgets,
+            applying,
+            connections,
+        };
+        binding.apply();
+        binding
+    }
+
+    fn apply(&self) {
+        self.applying.set(true);
+        self.model.borrow().apply_to_widgets(&self.widgets);
+        self.applying.set(false);
+    }
+
+    /// The current state of the model.
+    pub fn model(&self) -> Ref<'_, M> {
+        self.model.borrow()
+    }
+
+    /// The widgets the model is bound to.
+    pub fn widgets(&self) -> &M::Widgets {
+        &self.widgets
+    }
+
+    /// Modify the model, and update the widgets accordingly.
+    ///
+    /// This does not invoke the `on_change` callback.
+    pub fn modify<T>(&self, dlg: impl FnOnce(&mut M) -> T) -> T {
+        let result = dlg(&mut self.model.borrow_mut());
+        self.apply();
+        result
+    }
+}
+
+impl<M: Model> Drop for ModelBinding<M> {
+    fn drop(&mut self) {
+        for (obj, signal_handler_id) in self.connections.drain(..) {
+            obj.disconnect(signal_handler_id);
+        }
+    }
+}
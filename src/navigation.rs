@@ -0,0 +1,311 @@
+//! Navigate between the pages of a `gtk4::Stack`, with an actor per page.
+//!
+//! See [`Navigator`] for usage instructions.
+
+use core::any::Any;
+
+use hashbrown::HashMap;
+
+/// An actor that owns a page of a [`Navigator`].
+///
+/// Like a [`Component`](crate::component::Component), a page is unmounted with
+/// [`woab::Remove`](crate::Remove), which should remove its widget from the stack and stop the
+/// actor - usually by deriving [`woab::Removable`](crate::Removable) with `in gtk4::Stack`.
+pub trait Page: actix::Actor<Context = actix::Context<Self>> + actix::Handler<PageEvent> + actix::Handler<crate::Remove> {
+    /// The parameters passed to [`Push`] or [`Replace`] when navigating to the page.
+    type Params: Send + 'static;
+
+    /// Create the page's actor.
+    ///
+    /// This is called from inside the actor's context, so the page can route the signals of its
+    /// widgets to `ctx.address()`. `factory` is the factory the page was
+    /// [registered](Navigator::register) with.
+    fn create_page(params: Self::Params, factory: &crate::BuilderFactory, ctx: &mut Self::Context) -> Self;
+
+    /// The widget that should be added to the stack.
+    fn root_widget(&self) -> gtk4::Widget;
+}
+
+/// Sent to a [`Page`] when it becomes the visible page of the stack, and when another page
+/// covers it or it is popped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageEvent {
+    Shown,
+    Hidden,
+}
+
+impl actix::Message for PageEvent {
+    type Result = ();
+}
+
+/// Navigate to a new page, on top of the current one.
+///
+/// Resolves to an error if no page was registered with that ID, or if the params are not of the
+/// page's [`Params`](Page::Params) type.
+pub struct Push {
+    pub page_id: String,
+    pub params: Box<dyn Any + Send>,
+}
+
+impl Push {
+    pub fn new(page_id: impl Into<String>, params: impl Any + Send) -> Self {
+        Self {
+            page_id: page_id.into(),
+            params: Box::new(params),
+        }
+    }
+}
+
+impl actix::Message for Push {
+    type Result = crate::Result<()>;
+}
+
+/// Go back to the previous page, removing the current one.
+///
+/// Resolves to `false` if there is no previous page to go back to, in which case the current page
+/// stays.
+pub struct Pop;
+
+impl actix::Message for Pop {
+    type Result = bool;
+}
+
+/// Navigate to a new page, removing the current one.
+///
+/// Like [`Push`], but the new page takes the place of the current page in the history - so
+/// [`Pop`]ing it goes back to the page before the current one.
+pub struct Replace {
+    pub page_id: String,
+    pub params: Box<dyn Any + Send>,
+}
+
+impl Replace {
+    pub fn new(page_id: impl Into<String>, params: impl Any + Send) -> Self {
+        Self {
+            page_id: page_id.into(),
+            params: Box::new(params),
+        }
+    }
+}
+
+impl actix::Message for Replace {
+    type Result = crate::Result<()>;
+}
+
+struct PageEntry {
+    widget: gtk4::Widget,
+    events: actix::Recipient<PageEvent>,
+    remove: actix::Recipient<crate::Remove>,
+}
+
+impl PageEntry {
+    fn remove(self) {
+        self.events.do_send(PageEvent::Hidden);
+        self.remove.do_send(crate::Remove);
+    }
+}
+
+type PageCreator = Box<dyn Fn(&str, Box<dyn Any + Send>, &gtk4::Stack) -> crate::Result<PageEntry>>;
+
+fn page_creator<P: Page>(factory: crate::BuilderFactory) -> PageCreator {
+    Box::new(move |page_id, params, stack| {
+        let params = params
+            .downcast::<P::Params>()
+            .map_err(|_| crate::Error::IncorrectPageParams {
+                page_id: page_id.to_owned(),
+                expected_type: core::any::type_name::<P::Params>(),
+            })?;
+        let mut widget = None;
+        let addr = <P as actix::Actor>::create(|ctx| {
+            let page = P::create_page(*params, &factory, ctx);
+            widget = Some(page.root_widget());
+            page
+        });
+        let widget = widget.expect("create_page was called");
+        stack.add_child(&widget);
+        Ok(PageEntry {
+            widget,
+            events: addr.clone().recipient(),
+            remove: addr.recipient(),
+        })
+    })
+}
+
+/// An actor that manages the pages of a `gtk4::Stack` as a navigation history.
+///
+/// Pages are [`register`](Navigator::register)ed with an ID, a [`Page`] type and a factory.
+/// Navigation is done by sending the navigator messages:
+///
+/// * [`Push`] - create a page and show it on top of the current one.
+/// * [`Pop`] - remove the current page and show the one under it.
+/// * [`Replace`] - create a page and show it instead of the current one.
+///
+/// Each navigation sends [`PageEvent::Hidden`] to the page that was visible and
+/// [`PageEvent::Shown`] to the page that becomes visible. Popped and replaced pages are sent
+/// [`woab::Remove`](crate::Remove). The stack's transition type is used for all the navigations.
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// # use gtk4::prelude::*;
+/// use woab::navigation::{Navigator, Page, PageEvent, Pop, Push};
+///
+/// #[derive(woab::Removable)]
+/// #[removable(self.widgets.details_page in gtk4::Stack)]
+/// struct DetailsPage {
+///     widgets: DetailsWidgets,
+///     item_id: u64,
+/// }
+///
+/// #[derive(woab::WidgetsFromBuilder)]
+/// struct DetailsWidgets {
+///     details_page: gtk4::Box,
+///     title: gtk4::Label,
+/// }
+///
+/// impl actix::Actor for DetailsPage {
+///     type Context = actix::Context<Self>;
+/// }
+///
+/// impl Page for DetailsPage {
+///     type Params = u64;
+///
+///     fn create_page(item_id: u64, factory: &woab::BuilderFactory, ctx: &mut Self::Context) -> Self {
+///         let widgets = factory.instantiate_route_to(ctx.address()).widgets().unwrap();
+///         Self { widgets, item_id }
+///     }
+///
+///     fn root_widget(&self) -> gtk4::Widget {
+///         self.widgets.details_page.clone().upcast()
+///     }
+/// }
+///
+/// impl actix::Handler<PageEvent> for DetailsPage {
+///     type Result = ();
+///
+///     fn handle(&mut self, msg: PageEvent, _ctx: &mut Self::Context) -> Self::Result {
+///         if msg == PageEvent::Shown {
+///             self.widgets.title.set_text(&format!("Item {}", self.item_id));
+///         }
+///     }
+/// }
+///
+/// impl actix::Handler<woab::Signal> for DetailsPage {
+///     type Result = woab::SignalResult;
+///
+///     fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+///         msg.cant_handle()
+///     }
+/// }
+///
+/// # let stack: gtk4::Stack = panic!();
+/// # let details_factory: woab::BuilderFactory = panic!();
+/// let navigator = Navigator::new(&stack)
+///     .register::<DetailsPage>("details", details_factory)
+///     .start();
+/// navigator.do_send(Push::new("details", 42u64));
+/// navigator.do_send(Pop);
+/// ```
+pub struct Navigator {
+    stack: gtk4::Stack,
+    creators: HashMap<String, PageCreator>,
+    history: Vec<PageEntry>,
+}
+
+impl Navigator {
+    /// Create a navigator for the stack, with no pages registered.
+    ///
+    /// Existing children of the stack are not part of the navigation history, so they can be
+    /// used as placeholders until the first page is pushed.
+    pub fn new(stack: &gtk4::Stack) -> Self {
+        Self {
+            stack: stack.clone(),
+            creators: HashMap::new(),
+            history: Vec::new(),
+        }
+    }
+
+    /// Register a page type under an ID, to be used with [`Push`] and [`Replace`].
+    pub fn register<P: Page>(mut self, page_id: impl Into<String>, factory: crate::BuilderFactory) -> Self {
+        self.creators.insert(page_id.into(), page_creator::<P>(factory));
+        self
+    }
+
+    fn create_page(&self, page_id: &str, params: Box<dyn Any + Send>) -> crate::Result<PageEntry> {
+        let creator = self.creators.get(page_id).ok_or_else(|| crate::Error::NoSuchPage {
+            page_id: page_id.to_owned(),
+            known_ids: self.creators.keys().cloned().collect(),
+        })?;
+        creator(page_id, params, &self.stack)
+    }
+
+    fn show_top(&self) {
+        if let Some(top) = self.history.last() {
+            self.stack.set_visible_child(&top.widget);
+            top.events.do_send(PageEvent::Shown);
+        }
+    }
+}
+
+impl actix::Actor for Navigator {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<Push> for Navigator {
+    type Result = crate::Result<()>;
+
+    fn handle(&mut self, msg: Push, _ctx: &mut Self::Context) -> Self::Result {
+        let page = self.create_page(&msg.page_id, msg.params)?;
+        if let Some(previous) = self.history.last() {
+            previous.events.do_send(PageEvent::Hidden);
+        }
+        self.history.push(page);
+        self.show_top();
+        Ok(())
+    }
+}
+
+impl actix::Handler<Pop> for Navigator {
+    type Result = bool;
+
+    fn handle(&mut self, _msg: Pop, _ctx: &mut Self::Context) -> Self::Result {
+        if self.history.len() < 2 {
+            return false;
+        }
+        let popped = self.history.pop().expect("history has at least two pages");
+        // Show the previous page before removing the popped one, so that the stack does not
+        // switch to some other child in between.
+        self.show_top();
+        popped.remove();
+        true
+    }
+}
+
+impl actix::Handler<Replace> for Navigator {
+    type Result = crate::Result<()>;
+
+    fn handle(&mut self, msg: Replace, _ctx: &mut Self::Context) -> Self::Result {
+        let page = self.create_page(&msg.page_id, msg.params)?;
+        let replaced = self.history.pop();
+        self.history.push(page);
+        self.show_top();
+        if let Some(replaced) = replaced {
+            replaced.remove();
+        }
+        Ok(())
+    }
+}
+
+/// Resolves to the number of pages in the navigation history of a [`Navigator`].
+pub struct Depth;
+
+impl actix::Message for Depth {
+    type Result = usize;
+}
+
+impl actix::Handler<Depth> for Navigator {
+    type Result = usize;
+
+    fn handle(&mut self, _msg: Depth, _ctx: &mut Self::Context) -> Self::Result {
+        self.history.len()
+    }
+}
@@ -0,0 +1,91 @@
+use gio::prelude::*;
+
+/// The state of the network, sent by [`woab::watch_network`](watch_network).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NetworkStatus {
+    /// Whether or not the network is available (i.e. there is a default route).
+    pub available: bool,
+    /// Whether or not the network is metered (e.g. mobile data), in which case the application
+    /// should avoid heavy traffic.
+    pub metered: bool,
+    /// How much of the network (local network only, the internet...) is reachable.
+    pub connectivity: gio::NetworkConnectivity,
+}
+
+impl NetworkStatus {
+    /// The current state of a network monitor.
+    pub fn of(monitor: &gio::NetworkMonitor) -> Self {
+        Self {
+            available: monitor.is_network_available(),
+            metered: monitor.is_network_metered(),
+            connectivity: monitor.connectivity(),
+        }
+    }
+}
+
+impl actix::Message for NetworkStatus {
+    type Result = ();
+}
+
+/// Keeps a watch created by [`woab::watch_network`](watch_network) active.
+///
+/// The network stops being watched when this is dropped.
+#[must_use = "the network stops being watched when the NetworkWatch is dropped"]
+pub struct NetworkWatch {
+    monitor: gio::NetworkMonitor,
+    handler_ids: Vec<glib::SignalHandlerId>,
+}
+
+impl Drop for NetworkWatch {
+    fn drop(&mut self) {
+        for handler_id in self.handler_ids.drain(..) {
+            self.monitor.disconnect(handler_id);
+        }
+    }
+}
+
+/// Send the state of the network to an actor as a [`NetworkStatus`] message, and send it again
+/// whenever it changes.
+///
+/// The current state is sent immediately, so that the actor starts with the correct state.
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// struct SyncActor {
+///     _network_watch: Option<woab::NetworkWatch>,
+///     offline: bool,
+/// }
+///
+/// impl actix::Actor for SyncActor {
+///     type Context = actix::Context<Self>;
+///
+///     fn started(&mut self, ctx: &mut Self::Context) {
+///         self._network_watch = Some(woab::watch_network(ctx.address()));
+///     }
+/// }
+///
+/// impl actix::Handler<woab::NetworkStatus> for SyncActor {
+///     type Result = ();
+///
+///     fn handle(&mut self, msg: woab::NetworkStatus, _ctx: &mut Self::Context) -> Self::Result {
+///         self.offline = msg.connectivity != gio::NetworkConnectivity::Full;
+///     }
+/// }
+/// ```
+pub fn watch_network(target: impl Into<actix::Recipient<NetworkStatus>>) -> NetworkWatch {
+    let monitor = gio::NetworkMonitor::default();
+    let target = target.into();
+    target.do_send(NetworkStatus::of(&monitor));
+    let handler_ids = vec![
+        monitor.connect_network_changed({
+            let target = target.clone();
+            move |monitor, _| target.do_send(NetworkStatus::of(monitor))
+        }),
+        monitor.connect_network_metered_notify({
+            let target = target.clone();
+            move |monitor| target.do_send(NetworkStatus::of(monitor))
+        }),
+        monitor.connect_connectivity_notify(move |monitor| target.do_send(NetworkStatus::of(monitor))),
+    ];
+    NetworkWatch { monitor, handler_ids }
+}
@@ -0,0 +1,287 @@
+//! Lazily load huge lists page by page.
+//!
+//! See [`PagedList`] for usage instructions.
+
+use core::marker::PhantomData;
+
+use actix::prelude::*;
+use gio::prelude::*;
+use gtk4::prelude::*;
+use hashbrown::{HashMap, HashSet};
+
+/// An actor that fills a `gio::ListStore` on demand, fetching the items a page at a time.
+///
+/// The list store is filled with placeholders for all the items. Whenever the `gtk4::ListView`
+/// is scrolled, the pages that are visible (plus one page before and one page after them) are
+/// requested from the fetcher with a [`FetchPage`] message, and the placeholders are replaced
+/// with the fetched items. Fetches of pages that were scrolled out of view before they were
+/// resolved are cancelled.
+///
+/// The items are stored in the list store wrapped in `glib::BoxedAnyObject` - use [`item`] to get
+/// them back (e.g. inside the `bind` signal of a `gtk4::SignalListItemFactory`). [`item`] returns
+/// `None` for placeholders, which should be displayed as such (e.g. with a "Loading..." label).
+/// When a placeholder is replaced with the fetched item, the list view will emit `unbind` and
+/// `bind` again for its row.
+///
+/// The list view must already be inside a `gtk4::ScrolledWindow` when the actor is started. The
+/// list view does not keep the actor alive - it stops once all of its addresses are dropped.
+///
+/// The list is controlled with these messages:
+///
+/// * [`SetTotal`] - change the number of items. This resets all the items to placeholders and
+///   scrolls back to the top.
+/// * [`Reload`] - reset all the items to placeholders, keeping the scroll position. Use it when
+///   the data behind the list changes.
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// use woab::paged_list::{FetchPage, PagedList};
+///
+/// struct DatabaseActor;
+/// # impl actix::Actor for DatabaseActor { type Context = actix::Context<Self>; }
+/// # async fn query_database(_offset: usize, _limit: usize) -> Vec<String> { vec![] }
+///
+/// impl actix::Handler<FetchPage<String>> for DatabaseActor {
+///     type Result = ResponseFuture<Vec<String>>;
+///
+///     fn handle(&mut self, msg: FetchPage<String>, _ctx: &mut Self::Context) -> Self::Result {
+///         Box::pin(query_database(msg.offset, msg.limit))
+///     }
+/// }
+///
+/// # let list_view: gtk4::ListView = panic!();
+/// let database = DatabaseActor.start();
+/// let paged_list = PagedList::new(list_view.clone(), database.recipient(), 100, 1_000_000);
+/// list_view.set_model(Some(&gtk4::NoSelection::new(Some(paged_list.store().clone()))));
+/// // Keep the address (e.g. in the window's actor) - otherwise the paged list will stop.
+/// let paged_list = paged_list.start();
+/// ```
+pub struct PagedList<T: 'static + Send> {
+    list_view: gtk4::ListView,
+    store: gio::ListStore,
+    fetcher: Recipient<FetchPage<T>>,
+    page_size: usize,
+    total: usize,
+    loaded_pages: HashSet<usize>,
+    pending_fetches: HashMap<usize, SpawnHandle>,
+    adjustment_connections: Option<(gtk4::Adjustment, [glib::SignalHandlerId; 2])>,
+}
+
+impl<T: 'static + Send> PagedList<T> {
+    /// Create a paged list with `total` placeholders.
+    ///
+    /// * `list_view` is used for determining which pages are visible - it is not modified, so its
+    ///   model should be set to the list store (or a selection model that wraps it) manually.
+    /// * `fetcher` receives the [`FetchPage`] messages.
+    /// * `page_size` is the number of items fetched in a single `FetchPage`.
+    pub fn new(list_view: gtk4::ListView, fetcher: Recipient<FetchPage<T>>, page_size: usize, total: usize) -> Self {
+        assert!(0 < page_size, "page size must be positive");
+        let mut result = Self {
+            list_view,
+            store: gio::ListStore::new::<glib::BoxedAnyObject>(),
+            fetcher,
+            page_size,
+            total: 0,
+            loaded_pages: HashSet::new(),
+            pending_fetches: HashMap::new(),
+            adjustment_connections: None,
+        };
+        result.reset(total);
+        result
+    }
+
+    /// The list store that this paged list fills.
+    ///
+    /// This should be used as the model of the list widget. The list store should only be
+    /// modified by the paged list.
+    pub fn store(&self) -> &gio::ListStore {
+        &self.store
+    }
+
+    fn reset(&mut self, total: usize) {
+        self.total = total;
+        self.loaded_pages.clear();
+        let placeholders = (0..total).map(|_| glib::BoxedAnyObject::new(None::<T>)).collect::<Vec<_>>();
+        self.store.splice(0, self.store.n_items(), &placeholders);
+    }
+
+    fn cancel_all_fetches(&mut self, ctx: &mut actix::Context<Self>) {
+        for (_, handle) in self.pending_fetches.drain() {
+            ctx.cancel_future(handle);
+        }
+    }
+
+    fn wanted_pages(&self) -> core::ops::Range<usize> {
+        if self.total == 0 {
+            return 0..0;
+        }
+        let last_page = (self.total - 1) / self.page_size;
+        let Some(adjustment) = self.list_view.vadjustment() else {
+            return 0..1;
+        };
+        let upper = adjustment.upper();
+        if upper <= 0.0 {
+            return 0..1;
+        }
+        let index_at = |position: f64| ((position / upper) * self.total as f64).clamp(0.0, (self.total - 1) as f64) as usize;
+        let first_visible = index_at(adjustment.value());
+        let last_visible = index_at(adjustment.value() + adjustment.page_size());
+        let first_page = (first_visible / self.page_size).saturating_sub(1);
+        let last_page = (last_visible / self.page_size + 1).min(last_page);
+        first_page..last_page + 1
+    }
+
+    fn fetch_visible_pages(&mut self, ctx: &mut actix::Context<Self>) {
+        let wanted_pages = self.wanted_pages();
+        self.pending_fetches.retain(|page, handle| {
+            if wanted_pages.contains(page) {
+                true
+            } else {
+                ctx.cancel_future(*handle);
+                false
+            }
+        });
+        for page in wanted_pages {
+            if self.loaded_pages.contains(&page) || self.pending_fetches.contains_key(&page) {
+                continue;
+            }
+            let offset = page * self.page_size;
+            let limit = self.page_size.min(self.total - offset);
+            let fetch = self.fetcher.send(FetchPage {
+                offset,
+                limit,
+                _phantom: PhantomData,
+            });
+            let handle = ctx.spawn(fetch.into_actor(self).map(move |result, act, _ctx| {
+                act.pending_fetches.remove(&page);
+                if let Ok(items) = result {
+                    act.fill_page(page, items);
+                }
+            }));
+            self.pending_fetches.insert(page, handle);
+        }
+    }
+
+    fn fill_page(&mut self, page: usize, items: Vec<T>) {
+        let offset = page * self.page_size;
+        let limit = self.page_size.min(self.total - offset);
+        let items = items
+            .into_iter()
+            .take(limit)
+            .map(|item| glib::BoxedAnyObject::new(Some(item)))
+            .collect::<Vec<_>>();
+        self.store.splice(offset as u32, items.len() as u32, &items);
+        self.loaded_pages.insert(page);
+    }
+}
+
+impl<T: 'static + Send> actix::Actor for PagedList<T> {
+    type Context = actix::Context<Self>;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        if let Some(adjustment) = self.list_view.vadjustment() {
+            // A strong address would keep the actor alive for as long as the list view lives.
+            let addr = ctx.address().downgrade();
+            let notify = move || {
+                if let Some(addr) = addr.upgrade() {
+                    addr.do_send(VisibleRangeChanged);
+                }
+            };
+            let value_changed = adjustment.connect_value_changed({
+                let notify = notify.clone();
+                move |_| notify()
+            });
+            let changed = adjustment.connect_changed(move |_| notify());
+            self.adjustment_connections = Some((adjustment, [value_changed, changed]));
+        }
+        self.fetch_visible_pages(ctx);
+    }
+
+    fn stopped(&mut self, _ctx: &mut Self::Context) {
+        if let Some((adjustment, handler_ids)) = self.adjustment_connections.take() {
+            for handler_id in handler_ids {
+                adjustment.disconnect(handler_id);
+            }
+        }
+    }
+}
+
+/// Get the item of a [`PagedList`] from the object stored in its list store.
+///
+/// Returns `None` if the item was not fetched yet (or if the object was not created by a
+/// `PagedList<T>`).
+pub fn item<T: 'static>(obj: &glib::Object) -> Option<std::cell::Ref<'_, T>> {
+    let item = obj.downcast_ref::<glib::BoxedAnyObject>()?.try_borrow::<Option<T>>().ok()?;
+    std::cell::Ref::filter_map(item, |item| item.as_ref()).ok()
+}
+
+/// Request to fetch a page of a [`PagedList`].
+///
+/// The handler should resolve to the items in the range `offset..offset + limit` (usually with
+/// `ResponseFuture` or `ResponseActFuture`). If less items are returned, the rest of the page will
+/// remain as placeholders.
+pub struct FetchPage<T> {
+    /// The index of the first item in the page.
+    pub offset: usize,
+    /// The number of items in the page.
+    pub limit: usize,
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<T: 'static + Send> actix::Message for FetchPage<T> {
+    type Result = Vec<T>;
+}
+
+/// Change the number of items in a [`PagedList`].
+///
+/// All the items are reset to placeholders, pending fetches are cancelled, and the list is
+/// scrolled back to the top.
+pub struct SetTotal(pub usize);
+
+impl actix::Message for SetTotal {
+    type Result = ();
+}
+
+impl<T: 'static + Send> actix::Handler<SetTotal> for PagedList<T> {
+    type Result = ();
+
+    fn handle(&mut self, msg: SetTotal, ctx: &mut Self::Context) -> Self::Result {
+        self.cancel_all_fetches(ctx);
+        self.reset(msg.0);
+        if let Some(adjustment) = self.list_view.vadjustment() {
+            adjustment.set_value(adjustment.lower());
+        }
+        self.fetch_visible_pages(ctx);
+    }
+}
+
+/// Refetch all the items of a [`PagedList`], keeping the scroll position.
+pub struct Reload;
+
+impl actix::Message for Reload {
+    type Result = ();
+}
+
+impl<T: 'static + Send> actix::Handler<Reload> for PagedList<T> {
+    type Result = ();
+
+    fn handle(&mut self, _msg: Reload, ctx: &mut Self::Context) -> Self::Result {
+        self.cancel_all_fetches(ctx);
+        self.reset(self.total);
+        self.fetch_visible_pages(ctx);
+    }
+}
+
+struct VisibleRangeChanged;
+
+impl actix::Message for VisibleRangeChanged {
+    type Result = ();
+}
+
+impl<T: 'static + Send> actix::Handler<VisibleRangeChanged> for PagedList<T> {
+    type Result = ();
+
+    fn handle(&mut self, _msg: VisibleRangeChanged, ctx: &mut Self::Context) -> Self::Result {
+        self.fetch_visible_pages(ctx);
+    }
+}
@@ -0,0 +1,171 @@
+//! A dialog for displaying the progress of long running operations.
+//!
+//! See [`ProgressDialog`] for usage instructions.
+
+use actix::prelude::*;
+use gtk4::prelude::*;
+
+/// An actor that displays a progress bar in a modal window.
+///
+/// Spawn it with [`spawn`](ProgressDialog::spawn) and send it messages to update it:
+///
+/// * [`SetFraction`] to set the fraction of the progress bar.
+/// * [`SetText`] to set the text displayed on the progress bar.
+/// * [`Pulse`] to indicate progress was made without specifying how much.
+/// * [`Done`] to close the window and stop the actor.
+///
+/// If a recipient is given for the cancel button, clicking it will send a
+/// [`woab::Signal`](crate::Signal) named `"progress_dialog_cancel"` to that recipient. The dialog
+/// is not closed automatically when it is cancelled - the handler should stop the operation and
+/// send [`Done`] itself.
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// # use woab::progress_dialog::{ProgressDialog, SetFraction, Done};
+/// struct WorkerActor {
+///     progress_dialog: actix::Addr<ProgressDialog>,
+/// }
+/// # impl actix::Actor for WorkerActor { type Context = actix::Context<Self>; }
+///
+/// impl actix::Handler<woab::Signal> for WorkerActor {
+///     type Result = woab::SignalResult;
+///
+///     fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+///         Ok(match msg.name() {
+///             "progress_dialog_cancel" => {
+///                 // Stop the operation
+///                 self.progress_dialog.do_send(Done);
+///                 None
+///             }
+///             _ => msg.cant_handle()?,
+///         })
+///     }
+/// }
+///
+/// # let parent: gtk4::ApplicationWindow = panic!();
+/// WorkerActor::create(|ctx| {
+///     let progress_dialog = ProgressDialog::spawn(
+///         "Working...",
+///         Some(parent.upcast_ref()),
+///         Some(ctx.address().recipient()),
+///     );
+///     progress_dialog.do_send(SetFraction(0.5));
+///     WorkerActor { progress_dialog }
+/// });
+/// ```
+pub struct ProgressDialog {
+    window: gtk4::Window,
+    progress_bar: gtk4::ProgressBar,
+}
+
+impl actix::Actor for ProgressDialog {
+    type Context = actix::Context<Self>;
+
+    fn stopped(&mut self, _ctx: &mut Self::Context) {
+        self.window.destroy();
+    }
+}
+
+impl ProgressDialog {
+    /// Create the progress dialog's window, show it, and start its actor.
+    ///
+    /// * `parent`: the window the dialog will be modal to.
+    /// * `cancel_target`: where to route the cancel button's `"progress_dialog_cancel"` signal.
+    ///   If `None`, the dialog will not have a cancel button.
+    pub fn spawn(
+        title: &str,
+        parent: Option<&gtk4::Window>,
+        cancel_target: Option<actix::Recipient<crate::Signal>>,
+    ) -> actix::Addr<Self> {
+        let window = gtk4::Window::builder()
+            .title(title)
+            .modal(true)
+            .deletable(false)
+            .resizable(false)
+            .default_width(300)
+            .build();
+        window.set_transient_for(parent);
+
+        let layout = gtk4::Box::builder()
+            .orientation(gtk4::Orientation::Vertical)
+            .spacing(6)
+            .margin_top(12)
+            .margin_bottom(12)
+            .margin_start(12)
+            .margin_end(12)
+            .build();
+        let progress_bar = gtk4::ProgressBar::builder().show_text(true).build();
+        layout.append(&progress_bar);
+
+        if let Some(cancel_target) = cancel_target {
+            let cancel_button = gtk4::Button::builder().label("Cancel").halign(gtk4::Align::End).build();
+            crate::route_signal(&cancel_button, "clicked", "progress_dialog_cancel", cancel_target).unwrap();
+            layout.append(&cancel_button);
+        }
+
+        window.set_child(Some(&layout));
+        window.present();
+
+        ProgressDialog { window, progress_bar }.start()
+    }
+}
+
+/// Set the fraction (between 0.0 and 1.0) of the [`ProgressDialog`]'s progress bar.
+pub struct SetFraction(pub f64);
+
+impl actix::Message for SetFraction {
+    type Result = ();
+}
+
+impl actix::Handler<SetFraction> for ProgressDialog {
+    type Result = ();
+
+    fn handle(&mut self, msg: SetFraction, _ctx: &mut Self::Context) -> Self::Result {
+        self.progress_bar.set_fraction(msg.0);
+    }
+}
+
+/// Set the text displayed on the [`ProgressDialog`]'s progress bar.
+pub struct SetText(pub String);
+
+impl actix::Message for SetText {
+    type Result = ();
+}
+
+impl actix::Handler<SetText> for ProgressDialog {
+    type Result = ();
+
+    fn handle(&mut self, msg: SetText, _ctx: &mut Self::Context) -> Self::Result {
+        self.progress_bar.set_text(Some(msg.0.as_str()));
+    }
+}
+
+/// Pulse the [`ProgressDialog`]'s progress bar, for when the fraction of the progress is unknown.
+pub struct Pulse;
+
+impl actix::Message for Pulse {
+    type Result = ();
+}
+
+impl actix::Handler<Pulse> for ProgressDialog {
+    type Result = ();
+
+    fn handle(&mut self, _msg: Pulse, _ctx: &mut Self::Context) -> Self::Result {
+        self.progress_bar.pulse();
+    }
+}
+
+/// Close the [`ProgressDialog`] and stop its actor.
+pub struct Done;
+
+impl actix::Message for Done {
+    type Result = ();
+}
+
+impl actix::Handler<Done> for ProgressDialog {
+    type Result = ();
+
+    fn handle(&mut self, _msg: Done, ctx: &mut Self::Context) -> Self::Result {
+        ctx.stop();
+    }
+}
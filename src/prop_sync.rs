@@ -1,3 +1,11 @@
+// `gtk4::ComboBox` and `gtk4::ComboBoxText` are deprecated since GTK 4.10.
+#![cfg_attr(feature = "gtk_v4_10", allow(deprecated))]
+
+use std::cell::Cell;
+use std::future::Future;
+use std::rc::Rc;
+use std::time::Duration;
+
 use gtk4::prelude::*;
 
 /// Set widgets values from some setter type. See [`#[derive(woab::PropSync)]`](crate::PropSync).
@@ -22,6 +30,32 @@ pub trait GetProps {
 
     /// Get the widgets' data into the getter type.
     fn get_props(&self) -> Self::GetterType;
+
+    /// Like [`get_props`](GetProps::get_props), but returns an error instead of panicking when a
+    /// property cannot be converted to its declared type.
+    fn try_get_props(&self) -> crate::Result<Self::GetterType> {
+        Ok(self.get_props())
+    }
+}
+
+#[doc(hidden)]
+pub fn try_get_property<T>(object: &impl IsA<glib::Object>, property: &str) -> crate::Result<T>
+where
+    T: for<'b> glib::value::FromValue<'b> + glib::types::StaticType + 'static,
+{
+    let object = object.upcast_ref::<glib::Object>();
+    if object.find_property(property).is_none() {
+        return Err(crate::Error::NoSuchProperty {
+            object_type: object.type_(),
+            property: property.to_owned(),
+        });
+    }
+    let value = object.property_value(property);
+    value.get::<T>().map_err(|_| crate::Error::IncorrectPropertyType {
+        property: property.to_owned(),
+        expected_type: T::static_type(),
+        actual_type: value.type_(),
+    })
 }
 
 impl<'a> SetProps<'a> for gtk4::Label {
@@ -63,3 +97,164 @@ impl GetProps for gtk4::CheckButton {
         self.is_active()
     }
 }
+
+impl<'a> SetProps<'a> for gtk4::TextBuffer {
+    type SetterType = &'a str;
+
+    fn set_props(&self, setter: &Self::SetterType) {
+        self.set_text(setter);
+    }
+}
+
+impl GetProps for gtk4::TextBuffer {
+    type GetterType = String;
+
+    fn get_props(&self) -> Self::GetterType {
+        let (start, end) = self.bounds();
+        self.text(&start, &end, false).to_string()
+    }
+}
+
+impl<'a> SetProps<'a> for gtk4::TextView {
+    type SetterType = &'a str;
+
+    fn set_props(&self, setter: &Self::SetterType) {
+        self.buffer().set_props(setter);
+    }
+}
+
+impl GetProps for gtk4::TextView {
+    type GetterType = String;
+
+    fn get_props(&self) -> Self::GetterType {
+        self.buffer().get_props()
+    }
+}
+
+/// Connect a callback to the signals that indicate the widgets' data was changed.
+///
+/// Used by [`#[derive(woab::Model)]`](crate::Model) to update the model when the user edits the
+/// widgets.
+pub trait ConnectChanges {
+    /// Connect the callback to the change signals.
+    ///
+    /// Returns the objects the callback was connected to, along with the signal handler IDs, so
+    /// that the signals could later be disconnected.
+    fn connect_changes(&self, callback: Rc<dyn Fn()>) -> Vec<(glib::Object, glib::SignalHandlerId)>;
+}
+
+macro_rules! impl_connect_changes {
+    ($widget_type:ty, $signal:expr) => {
+        impl ConnectChanges for $widget_type {
+            fn connect_changes(&self, callback: Rc<dyn Fn()>) -> Vec<(glib::Object, glib::SignalHandlerId)> {
+                let signal_handler_id = self.connect_local($signal, false, move |_| {
+                    callback();
+                    None
+                });
+                vec![(self.clone().upcast(), signal_handler_id)]
+            }
+        }
+    };
+}
+
+impl_connect_changes!(gtk4::Entry, "changed");
+impl_connect_changes!(gtk4::CheckButton, "toggled");
+impl_connect_changes!(gtk4::ToggleButton, "toggled");
+impl_connect_changes!(gtk4::Switch, "notify::active");
+impl_connect_changes!(gtk4::SpinButton, "value-changed");
+impl_connect_changes!(gtk4::Scale, "value-changed");
+impl_connect_changes!(gtk4::Adjustment, "value-changed");
+impl_connect_changes!(gtk4::DropDown, "notify::selected");
+impl_connect_changes!(gtk4::ComboBox, "changed");
+impl_connect_changes!(gtk4::ComboBoxText, "changed");
+impl_connect_changes!(gtk4::TextBuffer, "changed");
+
+/// Text views are connected to the `changed` signal of their buffer. Note that if the buffer is
+/// replaced later, the new buffer will not be connected.
+impl ConnectChanges for gtk4::TextView {
+    fn connect_changes(&self, callback: Rc<dyn Fn()>) -> Vec<(glib::Object, glib::SignalHandlerId)> {
+        self.buffer().connect_changes(callback)
+    }
+}
+
+/// Labels cannot be edited by the user, so there are no changes to connect to.
+impl ConnectChanges for gtk4::Label {
+    fn connect_changes(&self, _callback: Rc<dyn Fn()>) -> Vec<(glib::Object, glib::SignalHandlerId)> {
+        Vec::new()
+    }
+}
+
+#[doc(hidden)]
+pub fn connect_changes_to_signal(
+    widget: &impl ConnectChanges,
+    callback: crate::RawSignalCallback,
+) -> Vec<(glib::Object, glib::SignalHandlerId)> {
+    widget.connect_changes(Rc::new(move || {
+        callback(&[]);
+    }))
+}
+
+/// Route the changes of a prop-synced widget to an actor, debounced.
+///
+/// The changes are detected using [`ConnectChanges`]. Instead of sending a signal for every
+/// change, WoAB waits until no changes were made for the `debounce` duration, and only then sends
+/// a single [`woab::Signal`](crate::Signal) named `actix_signal`. The only parameter of the signal
+/// is the widget itself.
+///
+/// This is mostly useful for multi-line editors (`gtk4::TextView`), which would otherwise flood the
+/// actor with a signal per keystroke:
+///
+/// ```no_run
+/// # use std::time::Duration;
+/// let text_view: gtk4::TextView;
+/// let target: actix::Recipient<woab::Signal>; // `actix::Addr` is also supported
+/// # text_view = panic!();
+/// # target = panic!();
+/// woab::prop_sync::route_changes(&text_view, "description_changed", Duration::from_millis(300), target);
+/// ```
+///
+/// Returns the objects the routing was connected to, along with the signal handler IDs, so that the
+/// routing could later be disconnected.
+pub fn route_changes<W: ConnectChanges + IsA<glib::Object>>(
+    widget: &W,
+    actix_signal: &str,
+    debounce: Duration,
+    target: impl crate::IntoGenerateRoutingGtkHandler,
+) -> Vec<(glib::Object, glib::SignalHandlerId)> {
+    use crate::GenerateRoutingGtkHandler;
+
+    let callback = Rc::new(target.into_generate_routing_gtk_handler().raw_signal_callback(actix_signal));
+    let widget_ref = widget.upcast_ref::<glib::Object>().downgrade();
+    let generation = Rc::new(Cell::new(0u64));
+    widget.connect_changes(Rc::new(move || {
+        let current_generation = generation.get() + 1;
+        generation.set(current_generation);
+        let callback = callback.clone();
+        let widget_ref = widget_ref.clone();
+        let generation = generation.clone();
+        // Use the Actix clock (and not a GLib timeout) so that tests can control it.
+        spawn_in_actix(async move {
+            actix::clock::sleep(debounce).await;
+            // A newer change restarted the debounce.
+            if generation.get() != current_generation {
+                return;
+            }
+            if let Some(widget) = widget_ref.upgrade() {
+                callback(&[widget.to_value()]);
+            }
+        });
+    }))
+}
+
+pub(crate) fn spawn_in_actix(fut: impl 'static + Future<Output = ()>) {
+    let fut = Cell::new(Some(fut));
+    let spawn = || {
+        if let Some(fut) = fut.take() {
+            actix::spawn(fut);
+        }
+    };
+    if crate::try_block_on(async { spawn() }).is_err() {
+        // Already inside the Actix runtime.
+        spawn();
+    }
+}
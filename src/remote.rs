@@ -0,0 +1,152 @@
+use tokio::sync::mpsc;
+
+/// Create a channel for sending messages to an actor from other threads.
+///
+/// Actors that own GTK widgets live on the GTK thread, and the Actix runtime WoAB runs there is
+/// single-threaded. The returned sender can be cloned and moved to background threads; the
+/// messages sent through it are received on the GTK thread's GLib main context and delivered to
+/// `recipient` from there.
+///
+/// Must be called from the GTK thread. Once the target actor stops, the channel is closed (when
+/// the next message arrives) and sending through it fails.
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// # struct WindowActor;
+/// # impl actix::Actor for WindowActor { type Context = actix::Context<Self>; }
+/// struct Progress(f64);
+///
+/// impl actix::Message for Progress {
+///     type Result = ();
+/// }
+/// # impl actix::Handler<Progress> for WindowActor {
+/// #     type Result = ();
+/// #     fn handle(&mut self, _msg: Progress, _ctx: &mut Self::Context) -> Self::Result {}
+/// # }
+///
+/// # let addr: actix::Addr<WindowActor> = panic!();
+/// let progress = woab::channel_to_actor(addr);
+/// std::thread::spawn(move || {
+///     for i in 0..100 {
+///         // ... do some heavy work ...
+///         if progress.send(Progress(i as f64 / 100.0)).is_err() {
+///             // The window was closed.
+///             return;
+///         }
+///     }
+/// });
+/// ```
+pub fn channel_to_actor<M>(recipient: impl Into<actix::Recipient<M>>) -> mpsc::UnboundedSender<M>
+where
+    M: actix::Message + Send + 'static,
+    M::Result: Send,
+{
+    let recipient = recipient.into();
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    glib::MainContext::ref_thread_default().spawn_local(async move {
+        while let Some(msg) = rx.recv().await {
+            if !recipient.connected() {
+                break;
+            }
+            recipient.do_send(msg);
+        }
+    });
+    tx
+}
+
+/// A thread-safe address of an actor that lives on the GTK thread.
+///
+/// `actix::Addr` is `Send`, but using it from other threads bypasses the GTK thread. A
+/// `RemoteAddr` enqueues the messages onto the GTK thread's GLib main context, and only there
+/// sends them to the actor. Use it to talk to UI actors from Tokio tasks, Rayon workers, or
+/// callbacks of other libraries that run on their own threads.
+///
+/// Must be created on the GTK thread.
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// # struct WindowActor;
+/// # impl actix::Actor for WindowActor { type Context = actix::Context<Self>; }
+/// struct GetSearchText;
+///
+/// impl actix::Message for GetSearchText {
+///     type Result = String;
+/// }
+/// # impl actix::Handler<GetSearchText> for WindowActor {
+/// #     type Result = String;
+/// #     fn handle(&mut self, _msg: GetSearchText, _ctx: &mut Self::Context) -> Self::Result {
+/// #         String::new()
+/// #     }
+/// # }
+///
+/// // Can run on any thread, in any async runtime.
+/// async fn search_in_background(remote: woab::RemoteAddr<WindowActor>) {
+///     let search_text = remote.send(GetSearchText).await.unwrap();
+///     // ...
+/// }
+///
+/// # let addr: actix::Addr<WindowActor> = panic!();
+/// let remote = woab::RemoteAddr::new(addr);
+/// # let _ = search_in_background(remote);
+/// ```
+pub struct RemoteAddr<A: actix::Actor> {
+    addr: actix::Addr<A>,
+    context: glib::MainContext,
+}
+
+impl<A: actix::Actor> Clone for RemoteAddr<A> {
+    fn clone(&self) -> Self {
+        Self {
+            addr: self.addr.clone(),
+            context: self.context.clone(),
+        }
+    }
+}
+
+impl<A: actix::Actor> RemoteAddr<A> {
+    /// Wrap the address of an actor that lives on the current thread.
+    pub fn new(addr: actix::Addr<A>) -> Self {
+        Self {
+            addr,
+            context: glib::MainContext::ref_thread_default(),
+        }
+    }
+
+    /// Send a message to the actor, without waiting for a response.
+    pub fn do_send<M>(&self, msg: M)
+    where
+        M: actix::Message + Send + 'static,
+        M::Result: Send,
+        A: actix::Handler<M>,
+        A::Context: actix::dev::ToEnvelope<A, M>,
+    {
+        let addr = self.addr.clone();
+        self.context.invoke(move || addr.do_send(msg));
+    }
+
+    /// Send a message to the actor, and wait for the response.
+    ///
+    /// The returned future can be awaited from any thread and any async runtime.
+    pub fn send<M>(&self, msg: M) -> impl core::future::Future<Output = Result<M::Result, actix::MailboxError>> + Send + 'static
+    where
+        M: actix::Message + Send + 'static,
+        M::Result: Send,
+        A: actix::Handler<M>,
+        A::Context: actix::dev::ToEnvelope<A, M>,
+    {
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        let addr = self.addr.clone();
+        self.context.invoke(move || {
+            let request = addr.send(msg);
+            glib::MainContext::ref_thread_default().spawn_local(async move {
+                let _ = tx.send(request.await);
+            });
+        });
+        async move { rx.await.unwrap_or(Err(actix::MailboxError::Closed)) }
+    }
+
+    /// The wrapped address. Only use it on the GTK thread.
+    pub fn local(&self) -> &actix::Addr<A> {
+        &self.addr
+    }
+}
@@ -1,3 +1,5 @@
+use gtk4::prelude::*;
+
 /// A message for removing actors along with their GUI
 ///
 /// Refer to [`#[derive(woab::Removable)]`](derive.Removable.html) docs for usage instructions.
@@ -24,3 +26,184 @@ pub struct Remove;
 impl actix::Message for Remove {
     type Result = ();
 }
+
+/// Like [`woab::Remove`](Remove), but resolves once the widget was removed.
+///
+/// Actors that derive [`woab::Removable`](crate::Removable) handle this message by removing the
+/// widget from its parent and stopping - so once the response arrives, the widget is no longer in
+/// the container and the actor no longer handles messages. The response is `true` if the widget
+/// was removed, and `false` if it had already been removed from its parent before.
+///
+/// Use it instead of `do_send(woab::Remove)` when the container is modified right after removing
+/// the actor:
+///
+/// ```no_run
+/// # #[derive(woab::Removable)]
+/// # #[removable(self.row in gtk4::ListBox)]
+/// # struct MyActor {
+/// #     row: gtk4::ListBoxRow,
+/// # }
+/// # impl actix::Actor for MyActor { type Context = actix::Context<Self>; }
+/// # async fn asyncfunc() -> Result<(), actix::MailboxError> {
+/// let my_actor: actix::Addr<MyActor>;
+/// # my_actor = panic!();
+/// my_actor.send(woab::RemoveAndConfirm).await?;
+/// // The row is no longer in the list box
+/// # Ok(())
+/// # }
+/// ```
+pub struct RemoveAndConfirm;
+
+impl actix::Message for RemoveAndConfirm {
+    type Result = bool;
+}
+
+/// Child actors that should be removed together with their parent actor.
+///
+/// Use the `children = ...` parameter of [`#[derive(woab::Removable)]`](crate::Removable) to
+/// make the parent forward the removal to its children - each child gets a
+/// [`woab::RemoveAndConfirm`](RemoveAndConfirm), and only after all of them are removed does
+/// the parent remove its own widget and stop. This is important when the children's widgets are
+/// inside the parent's widget - otherwise the child actors keep running after their widgets are
+/// gone.
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// #[derive(woab::Removable)]
+/// #[removable(self.list_box in gtk4::Box, children = self.rows)]
+/// struct ListActor {
+///     list_box: gtk4::ListBox,
+///     rows: woab::RemovableChildren,
+/// }
+/// # impl actix::Actor for ListActor { type Context = actix::Context<Self>; }
+///
+/// #[derive(woab::Removable)]
+/// #[removable(self.row in gtk4::ListBox)]
+/// struct RowActor {
+///     row: gtk4::ListBoxRow,
+/// }
+/// # impl actix::Actor for RowActor { type Context = actix::Context<Self>; }
+///
+/// impl ListActor {
+///     fn add_row(&mut self) {
+///         let row = gtk4::ListBoxRow::new();
+///         self.list_box.append(&row);
+///         self.rows.add(RowActor { row }.start());
+///     }
+/// }
+/// ```
+#[derive(Default)]
+pub struct RemovableChildren {
+    children: Vec<actix::Recipient<RemoveAndConfirm>>,
+}
+
+impl RemovableChildren {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a child actor.
+    ///
+    /// Children that already stopped are forgotten, so there is no need to explicitly unregister
+    /// them.
+    pub fn add(&mut self, child: impl Into<actix::Recipient<RemoveAndConfirm>>) {
+        self.children.retain(|child| child.connected());
+        self.children.push(child.into());
+    }
+
+    /// The number of registered children that are still running.
+    pub fn len(&self) -> usize {
+        self.children.iter().filter(|child| child.connected()).count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Send [`woab::RemoveAndConfirm`](RemoveAndConfirm) to all the children, and unregister them.
+    ///
+    /// The messages are sent immediately, and the returned future resolves once all the children
+    /// handled them.
+    pub fn remove_all(&mut self) -> impl core::future::Future<Output = ()> + 'static {
+        remove_children(std::mem::take(&mut self.children))
+    }
+}
+
+/// Send [`woab::RemoveAndConfirm`](RemoveAndConfirm) to all the children that are still running,
+/// and resolve once all of them handled it.
+pub(crate) fn remove_children(
+    children: Vec<actix::Recipient<RemoveAndConfirm>>,
+) -> impl core::future::Future<Output = ()> + 'static {
+    let requests = children
+        .into_iter()
+        .filter(|child| child.connected())
+        .map(|child| child.send(RemoveAndConfirm))
+        .collect::<Vec<_>>();
+    async move {
+        for request in requests {
+            // A child that stopped before handling the message does not need to be removed.
+            let _ = request.await;
+        }
+    }
+}
+
+/// Send [`woab::Remove`](Remove) to an actor when GTK removes its widget.
+///
+/// Actors usually hold their widgets, so if GTK removes a widget from its side (e.g. when a window
+/// is closed or when a parent container is cleared) the actor will keep running - and messages it
+/// receives will operate on widgets that are no longer displayed. This function makes the actor
+/// receive a `woab::Remove` message when the widget is destroyed, or when it is detached from its
+/// root (e.g. when it, or one of its ancestors, is removed from its container).
+///
+/// The `Remove` is sent only once. Actors that derive [`woab::Removable`](crate::Removable) will
+/// stop when they receive it. Other actors should implement `actix::Handler<woab::Remove>`
+/// and stop themselves.
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// #[derive(woab::Removable)]
+/// #[removable(self.widgets.row in gtk4::ListBox)]
+/// struct RowActor {
+///     widgets: RowWidgets,
+/// }
+/// # impl actix::Actor for RowActor { type Context = actix::Context<Self>; }
+///
+/// #[derive(woab::WidgetsFromBuilder)]
+/// struct RowWidgets {
+///     row: gtk4::ListBoxRow,
+/// }
+///
+/// # let widgets: RowWidgets = panic!();
+/// let row = widgets.row.clone();
+/// let addr = RowActor { widgets }.start();
+/// woab::bind_lifetime(&row, addr);
+/// ```
+///
+/// Note that moving the widget to a different container will also detach it from its root - so
+/// widgets that should be moved must not be bound (or should be unbound first, by disconnecting
+/// the returned signal handlers).
+pub fn bind_lifetime(
+    widget: &impl IsA<gtk4::Widget>,
+    target: impl Into<actix::Recipient<Remove>>,
+) -> Vec<(glib::Object, glib::SignalHandlerId)> {
+    let send_remove = {
+        let target = target.into();
+        let sent = std::cell::Cell::new(false);
+        std::rc::Rc::new(move || {
+            if !sent.replace(true) {
+                target.do_send(Remove);
+            }
+        })
+    };
+    let destroy_handler = widget.connect_destroy({
+        let send_remove = send_remove.clone();
+        move |_| send_remove()
+    });
+    let root_handler = widget.connect_root_notify(move |widget| {
+        if widget.root().is_none() {
+            send_remove();
+        }
+    });
+    let widget = widget.upcast_ref::<gtk4::Widget>().upcast_ref::<glib::Object>();
+    vec![(widget.clone(), destroy_handler), (widget.clone(), root_handler)]
+}
@@ -0,0 +1,149 @@
+use core::cell::RefCell;
+use std::path::{Path, PathBuf};
+
+/// Where to load a `gio::Resource` from. See [`woab::register_resources`](crate::register_resources).
+pub enum ResourceSource {
+    /// The contents of a compiled `.gresource` file - usually from `include_bytes!`.
+    Bytes(glib::Bytes),
+    /// The path of a compiled `.gresource` file.
+    Path(PathBuf),
+}
+
+impl From<&'static [u8]> for ResourceSource {
+    fn from(bytes: &'static [u8]) -> Self {
+        Self::Bytes(glib::Bytes::from_static(bytes))
+    }
+}
+
+impl<const N: usize> From<&'static [u8; N]> for ResourceSource {
+    fn from(bytes: &'static [u8; N]) -> Self {
+        Self::Bytes(glib::Bytes::from_static(bytes))
+    }
+}
+
+impl From<Vec<u8>> for ResourceSource {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::Bytes(glib::Bytes::from_owned(bytes))
+    }
+}
+
+impl From<glib::Bytes> for ResourceSource {
+    fn from(bytes: glib::Bytes) -> Self {
+        Self::Bytes(bytes)
+    }
+}
+
+impl From<PathBuf> for ResourceSource {
+    fn from(path: PathBuf) -> Self {
+        Self::Path(path)
+    }
+}
+
+impl From<&Path> for ResourceSource {
+    fn from(path: &Path) -> Self {
+        Self::Path(path.to_owned())
+    }
+}
+
+impl From<&str> for ResourceSource {
+    fn from(path: &str) -> Self {
+        Self::Path(path.into())
+    }
+}
+
+/// Load a compiled `.gresource` file and register it globally.
+///
+/// After registration, the resources can be referenced from the UI XML (e.g. with
+/// `resource:///com/example/app/image.png`) and loaded with `gtk4::Builder::from_resource` and
+/// friends. Icons placed under the `icons` directory of the application's resource base path are
+/// picked up by the icon theme automatically - for other locations use
+/// [`woab::add_icon_search_path`](crate::add_icon_search_path).
+///
+/// ```no_run
+/// fn main() -> woab::Result<()> {
+///     woab::register_resources("resources/app.gresource")?;
+///     woab::main(gtk4::Application::default(), |_app| {
+///         // ...
+///         Ok(())
+///     })
+/// }
+/// ```
+pub fn register_resources(source: impl Into<ResourceSource>) -> crate::Result<gio::Resource> {
+    let resource = match source.into() {
+        ResourceSource::Bytes(bytes) => gio::Resource::from_data(&bytes),
+        ResourceSource::Path(path) => gio::Resource::load(path),
+    }
+    .map_err(|err| crate::Error::GenericError(Box::new(err)))?;
+    gio::resources_register(&resource);
+    Ok(resource)
+}
+
+/// Where to look for icons. See [`woab::add_icon_search_path`](crate::add_icon_search_path).
+enum IconSearchPath {
+    Directory(PathBuf),
+    Resource(String),
+}
+
+impl IconSearchPath {
+    fn add_to(&self, display: &gdk4::Display) {
+        let icon_theme = gtk4::IconTheme::for_display(display);
+        match self {
+            Self::Directory(path) => icon_theme.add_search_path(path),
+            Self::Resource(path) => icon_theme.add_resource_path(path),
+        }
+    }
+}
+
+thread_local! {
+    static PENDING_ICON_SEARCH_PATHS: RefCell<Vec<IconSearchPath>> = const { RefCell::new(Vec::new()) };
+}
+
+fn default_display() -> Option<gdk4::Display> {
+    // `gdk4::Display::default` panics if GTK is not initialized yet.
+    if gtk4::is_initialized_main_thread() {
+        gdk4::Display::default()
+    } else {
+        None
+    }
+}
+
+fn add_or_defer(search_path: IconSearchPath) {
+    if let Some(display) = default_display() {
+        search_path.add_to(&display);
+    } else {
+        PENDING_ICON_SEARCH_PATHS.with_borrow_mut(|pending| pending.push(search_path));
+    }
+}
+
+/// Add a directory to the icon theme's search path, so that its icons can be used by name in the
+/// UI XML.
+///
+/// This can be called before GTK is initialized - in which case the directory will be added when
+/// [`woab::main`](crate::main) initializes it, before the application's `startup` and `activate`
+/// signals.
+pub fn add_icon_search_path(path: impl AsRef<Path>) {
+    add_or_defer(IconSearchPath::Directory(path.as_ref().to_owned()));
+}
+
+/// Add a resource path (e.g. `/com/example/app/icons`) to the icon theme's search path.
+///
+/// The resources must be registered - see [`woab::register_resources`](crate::register_resources).
+/// Like [`woab::add_icon_search_path`](crate::add_icon_search_path), this can be called before GTK
+/// is initialized.
+pub fn add_icon_resource_path(path: impl Into<String>) {
+    add_or_defer(IconSearchPath::Resource(path.into()));
+}
+
+pub(crate) fn apply_pending_icon_search_paths() {
+    let pending = PENDING_ICON_SEARCH_PATHS.with_borrow_mut(core::mem::take);
+    if pending.is_empty() {
+        return;
+    }
+    let Some(display) = default_display() else {
+        PENDING_ICON_SEARCH_PATHS.with_borrow_mut(|still_pending| still_pending.extend(pending));
+        return;
+    };
+    for search_path in pending {
+        search_path.add_to(&display);
+    }
+}
@@ -0,0 +1,302 @@
+//! Manage a container with an actor per row.
+//!
+//! See [`RowManager`] for usage instructions.
+
+use core::cmp::Ordering;
+use core::hash::Hash;
+
+use actix::prelude::*;
+use gtk4::prelude::*;
+
+/// An actor that manages a row in a [`RowManager`].
+///
+/// The actor must handle [`woab::Remove`](crate::Remove) by removing its widget from the container
+/// and stopping - usually by deriving [`woab::Removable`](crate::Removable).
+pub trait ManagedRow:
+    actix::Actor<Context = actix::Context<Self>> + actix::Handler<crate::Signal> + actix::Handler<crate::Remove>
+{
+    /// Identifies the row in the manager.
+    type Key: 'static + Eq + Hash + Clone + Send + Unpin;
+
+    /// Additional data for creating the row.
+    type Init: 'static + Send;
+
+    /// Create the row actor from the widgets instantiated from the manager's factory.
+    ///
+    /// The signals of the widgets are already routed to the row actor.
+    fn create_row(key: Self::Key, init: Self::Init, builder: crate::BuilderWidgets, ctx: &mut Self::Context) -> Self;
+
+    /// The widget that should be added to the container.
+    fn row_widget(&self) -> gtk4::Widget;
+}
+
+/// A container that a [`RowManager`] can insert rows into.
+pub trait RowContainer: 'static + Unpin {
+    /// Insert the widget right after the `sibling` widget, or as the first child if `sibling` is
+    /// `None`.
+    fn insert_row_after(&self, widget: &gtk4::Widget, sibling: Option<&gtk4::Widget>);
+}
+
+impl RowContainer for gtk4::ListBox {
+    fn insert_row_after(&self, widget: &gtk4::Widget, sibling: Option<&gtk4::Widget>) {
+        let position = sibling
+            .and_then(|sibling| {
+                // Widgets that are not `ListBoxRow` are wrapped in one by the `ListBox`.
+                sibling
+                    .downcast_ref::<gtk4::ListBoxRow>()
+                    .cloned()
+                    .or_else(|| sibling.parent()?.downcast().ok())
+            })
+            .map_or(0, |row| row.index() + 1);
+        self.insert(widget, position);
+    }
+}
+
+impl RowContainer for gtk4::FlowBox {
+    fn insert_row_after(&self, widget: &gtk4::Widget, sibling: Option<&gtk4::Widget>) {
+        let position = sibling
+            .and_then(|sibling| {
+                // Widgets that are not `FlowBoxChild` are wrapped in one by the `FlowBox`.
+                sibling
+                    .downcast_ref::<gtk4::FlowBoxChild>()
+                    .cloned()
+                    .or_else(|| sibling.parent()?.downcast().ok())
+            })
+            .map_or(0, |child| child.index() + 1);
+        self.insert(widget, position);
+    }
+}
+
+impl RowContainer for gtk4::Box {
+    fn insert_row_after(&self, widget: &gtk4::Widget, sibling: Option<&gtk4::Widget>) {
+        self.insert_child_after(widget, sibling);
+    }
+}
+
+type RowOrdering<K> = Box<dyn Fn(&K, &K) -> Ordering>;
+
+/// An actor that owns a container and manages an actor per row.
+///
+/// The rows are created from a [`woab::BuilderFactory`](crate::BuilderFactory), and their actors
+/// implement [`ManagedRow`]. The manager is controlled with these messages:
+///
+/// * [`Add`] - create a row with the given key, replacing the row with the same key if there is
+///   one. Resolves to the address of the new row actor.
+/// * [`Remove`] - remove the row with the given key.
+/// * [`Clear`] - remove all the rows.
+/// * [`Get`] - get the address of the row actor with the given key.
+/// * [`Rows`] - get the keys and addresses of all the rows, in the order they are displayed
+///   (`Rows::default()`).
+///
+/// Rows are displayed in insertion order, unless an ordering is set with
+/// [`sorted_by`](RowManager::sorted_by). Row actors may also stop themselves (after removing
+/// their widget) - the manager will forget them automatically.
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// use woab::row_manager::{Add, ManagedRow, RowManager};
+///
+/// #[derive(woab::Removable)]
+/// #[removable(self.widgets.row in gtk4::ListBox)]
+/// struct PersonRow {
+///     widgets: PersonRowWidgets,
+/// }
+///
+/// #[derive(woab::WidgetsFromBuilder)]
+/// struct PersonRowWidgets {
+///     row: gtk4::ListBoxRow,
+///     name: gtk4::Label,
+/// }
+///
+/// impl actix::Actor for PersonRow {
+///     type Context = actix::Context<Self>;
+/// }
+///
+/// # impl actix::Handler<woab::Signal> for PersonRow {
+/// #     type Result = woab::SignalResult;
+/// #     fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+/// #         msg.cant_handle()
+/// #     }
+/// # }
+/// impl ManagedRow for PersonRow {
+///     type Key = u64;
+///     type Init = String;
+///
+///     fn create_row(_id: u64, name: String, builder: woab::BuilderWidgets, _ctx: &mut Self::Context) -> Self {
+///         let widgets: PersonRowWidgets = builder.widgets().unwrap();
+///         widgets.name.set_text(&name);
+///         Self { widgets }
+///     }
+///
+///     fn row_widget(&self) -> gtk4::Widget {
+///         self.widgets.row.clone().upcast()
+///     }
+/// }
+///
+/// # let list_box: gtk4::ListBox = panic!();
+/// # let row_factory: woab::BuilderFactory = panic!();
+/// let manager = RowManager::<PersonRow>::new(list_box, row_factory).sorted_by(|a, b| a.cmp(b)).start();
+/// manager.do_send(Add(1, "Alice".to_owned()));
+/// ```
+pub struct RowManager<R: ManagedRow, C: RowContainer = gtk4::ListBox> {
+    container: C,
+    factory: crate::BuilderFactory,
+    rows: Vec<(R::Key, actix::Addr<R>, gtk4::Widget)>,
+    ordering: Option<RowOrdering<R::Key>>,
+}
+
+impl<R: ManagedRow, C: RowContainer> RowManager<R, C> {
+    /// Create a manager that adds rows instantiated from `factory` to `container`.
+    ///
+    /// The container should only contain rows added by the manager.
+    pub fn new(container: C, factory: crate::BuilderFactory) -> Self {
+        Self {
+            container,
+            factory,
+            rows: Vec::new(),
+            ordering: None,
+        }
+    }
+
+    /// Keep the rows sorted by their keys, instead of by insertion order.
+    pub fn sorted_by(mut self, ordering: impl 'static + Fn(&R::Key, &R::Key) -> Ordering) -> Self {
+        self.ordering = Some(Box::new(ordering));
+        self
+    }
+
+    /// The container the rows are added to.
+    pub fn container(&self) -> &C {
+        &self.container
+    }
+
+    fn prune_stopped_rows(&mut self) {
+        self.rows.retain(|(_, addr, _)| addr.connected());
+    }
+
+    fn remove_row(&mut self, key: &R::Key) -> bool {
+        if let Some(position) = self.rows.iter().position(|(k, _, _)| k == key) {
+            let (_, addr, _) = self.rows.remove(position);
+            addr.do_send(crate::Remove);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl<R: ManagedRow, C: RowContainer> actix::Actor for RowManager<R, C> {
+    type Context = actix::Context<Self>;
+}
+
+/// Create a row in a [`RowManager`].
+///
+/// If there is already a row with the same key, it is replaced.
+pub struct Add<R: ManagedRow>(pub R::Key, pub R::Init);
+
+impl<R: ManagedRow> actix::Message for Add<R> {
+    type Result = actix::Addr<R>;
+}
+
+impl<R: ManagedRow, C: RowContainer> actix::Handler<Add<R>> for RowManager<R, C> {
+    type Result = actix::MessageResult<Add<R>>;
+
+    fn handle(&mut self, msg: Add<R>, _ctx: &mut Self::Context) -> Self::Result {
+        let Add(key, init) = msg;
+        self.prune_stopped_rows();
+        self.remove_row(&key);
+        let position = if let Some(ordering) = &self.ordering {
+            self.rows.partition_point(|(k, _, _)| ordering(k, &key) != Ordering::Greater)
+        } else {
+            self.rows.len()
+        };
+        let mut widget = None;
+        let addr = R::create(|ctx| {
+            let builder = self.factory.instantiate_route_to(ctx.address());
+            let row = R::create_row(key.clone(), init, builder, ctx);
+            widget = Some(row.row_widget());
+            row
+        });
+        let widget = widget.unwrap();
+        let sibling = position.checked_sub(1).map(|previous| &self.rows[previous].2);
+        self.container.insert_row_after(&widget, sibling);
+        self.rows.insert(position, (key, addr.clone(), widget));
+        actix::MessageResult(addr)
+    }
+}
+
+/// Remove the row with the given key from a [`RowManager`].
+///
+/// Resolves to `false` if there was no row with that key.
+pub struct Remove<R: ManagedRow>(pub R::Key);
+
+impl<R: ManagedRow> actix::Message for Remove<R> {
+    type Result = bool;
+}
+
+impl<R: ManagedRow, C: RowContainer> actix::Handler<Remove<R>> for RowManager<R, C> {
+    type Result = bool;
+
+    fn handle(&mut self, msg: Remove<R>, _ctx: &mut Self::Context) -> Self::Result {
+        self.prune_stopped_rows();
+        self.remove_row(&msg.0)
+    }
+}
+
+/// Remove all the rows of a [`RowManager`].
+pub struct Clear;
+
+impl actix::Message for Clear {
+    type Result = ();
+}
+
+impl<R: ManagedRow, C: RowContainer> actix::Handler<Clear> for RowManager<R, C> {
+    type Result = ();
+
+    fn handle(&mut self, _msg: Clear, _ctx: &mut Self::Context) -> Self::Result {
+        for (_, addr, _) in self.rows.drain(..) {
+            addr.do_send(crate::Remove);
+        }
+    }
+}
+
+/// Get the address of the row actor with the given key from a [`RowManager`].
+pub struct Get<R: ManagedRow>(pub R::Key);
+
+impl<R: ManagedRow> actix::Message for Get<R> {
+    type Result = Option<actix::Addr<R>>;
+}
+
+impl<R: ManagedRow, C: RowContainer> actix::Handler<Get<R>> for RowManager<R, C> {
+    type Result = Option<actix::Addr<R>>;
+
+    fn handle(&mut self, msg: Get<R>, _ctx: &mut Self::Context) -> Self::Result {
+        self.prune_stopped_rows();
+        self.rows
+            .iter()
+            .find(|(k, _, _)| *k == msg.0)
+            .map(|(_, addr, _)| addr.clone())
+    }
+}
+
+/// Get the keys and addresses of all the rows of a [`RowManager`], in the order they are
+/// displayed.
+pub struct Rows<R: ManagedRow>(core::marker::PhantomData<fn() -> R>);
+
+impl<R: ManagedRow> Default for Rows<R> {
+    fn default() -> Self {
+        Self(core::marker::PhantomData)
+    }
+}
+
+impl<R: ManagedRow> actix::Message for Rows<R> {
+    type Result = Vec<(R::Key, actix::Addr<R>)>;
+}
+
+impl<R: ManagedRow, C: RowContainer> actix::Handler<Rows<R>> for RowManager<R, C> {
+    type Result = actix::MessageResult<Rows<R>>;
+
+    fn handle(&mut self, _msg: Rows<R>, _ctx: &mut Self::Context) -> Self::Result {
+        self.prune_stopped_rows();
+        actix::MessageResult(self.rows.iter().map(|(key, addr, _)| (key.clone(), addr.clone())).collect())
+    }
+}
@@ -0,0 +1,89 @@
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+/// The result of [`woab::send`](crate::send).
+///
+/// If the message was sent from outside the Actix runtime, the response is already available and
+/// can be taken with [`now`](SendResult::now). Either way, the `SendResult` can be `await`ed.
+#[must_use = "the response is lost unless the result is awaited or checked with `now`"]
+pub struct SendResult<R>(SendResultInner<R>);
+
+enum SendResultInner<R> {
+    Ready(Option<Result<R, actix::MailboxError>>),
+    Pending(Pin<Box<dyn Future<Output = Result<R, actix::MailboxError>>>>),
+}
+
+impl<R> SendResult<R> {
+    /// Get the response, if it is already available.
+    ///
+    /// Returns the `SendResult` back as the error if the message was sent from inside the Actix
+    /// runtime, so that it could be awaited instead.
+    pub fn now(self) -> Result<Result<R, actix::MailboxError>, Self> {
+        match self.0 {
+            SendResultInner::Ready(Some(result)) => Ok(result),
+            SendResultInner::Ready(None) => panic!("`SendResult` polled after completion"),
+            SendResultInner::Pending(_) => Err(self),
+        }
+    }
+}
+
+// The response is never pinned.
+impl<R> Unpin for SendResult<R> {}
+
+impl<R> Future for SendResult<R> {
+    type Output = Result<R, actix::MailboxError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match &mut self.get_mut().0 {
+            SendResultInner::Ready(result) => Poll::Ready(result.take().expect("`SendResult` polled after completion")),
+            SendResultInner::Pending(future) => future.as_mut().poll(cx),
+        }
+    }
+}
+
+/// Send a message to an actor, from either inside or outside the Actix runtime.
+///
+/// * When called from outside the Actix runtime (e.g. from a GTK callback that was not routed to
+///   an actor) the message is sent and its response is waited for with
+///   [`woab::try_block_on`](crate::try_block_on), so the response is available immediately with
+///   [`SendResult::now`].
+/// * When called from inside the Actix runtime (e.g. from an actor's handler) blocking is not
+///   possible, so the response must be `await`ed.
+///
+/// In both cases, the message is sent even if the result is dropped without being awaited.
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// # struct Counter;
+/// # impl actix::Actor for Counter { type Context = actix::Context<Self>; }
+/// # struct Increment;
+/// # impl actix::Message for Increment { type Result = usize; }
+/// # impl actix::Handler<Increment> for Counter {
+/// #     type Result = usize;
+/// #     fn handle(&mut self, _msg: Increment, _ctx: &mut Self::Context) -> usize { 0 }
+/// # }
+/// // Can be called from GTK callbacks and from actor handlers alike:
+/// async fn increment(counter: actix::Addr<Counter>) -> usize {
+///     woab::send(counter, Increment).await.unwrap()
+/// }
+///
+/// // Only when the caller is known to be outside the Actix runtime:
+/// fn increment_now(counter: actix::Addr<Counter>) -> usize {
+///     let Ok(result) = woab::send(counter, Increment).now() else {
+///         panic!("Called from inside the Actix runtime");
+///     };
+///     result.unwrap()
+/// }
+/// ```
+pub fn send<M>(target: impl Into<actix::Recipient<M>>, msg: M) -> SendResult<M::Result>
+where
+    M: actix::Message + Send + 'static,
+    M::Result: Send,
+{
+    let request = target.into().send(msg);
+    match crate::try_block_on(request) {
+        Ok(result) => SendResult(SendResultInner::Ready(Some(result))),
+        Err(request) => SendResult(SendResultInner::Pending(Box::pin(request))),
+    }
+}
@@ -0,0 +1,134 @@
+use glib::prelude::*;
+use gtk4::prelude::BuildableExt;
+use send_wrapper::SendWrapper;
+
+/// Find GTK objects by their IDs. Used by [`woab::SetWidgetProperty`](SetWidgetProperty).
+pub trait FindWidget {
+    /// The object with the given ID, if there is one.
+    fn find_widget(&self, id: &str) -> Option<glib::Object>;
+
+    /// The IDs of all the objects, for suggesting similar IDs when an object is not found.
+    fn widget_ids(&self) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+impl FindWidget for gtk4::Builder {
+    fn find_widget(&self, id: &str) -> Option<glib::Object> {
+        self.object(id)
+    }
+
+    fn widget_ids(&self) -> Vec<String> {
+        self.objects()
+            .iter()
+            .filter_map(|object| object.downcast_ref::<gtk4::Buildable>()?.buildable_id())
+            .map(|id| id.to_string())
+            .collect()
+    }
+}
+
+impl FindWidget for crate::BuilderWidgets {
+    fn find_widget(&self, id: &str) -> Option<glib::Object> {
+        self.builder.find_widget(id)
+    }
+
+    fn widget_ids(&self) -> Vec<String> {
+        self.builder.widget_ids()
+    }
+}
+
+enum WidgetTarget {
+    Id(String),
+    Object(SendWrapper<glib::Object>),
+}
+
+/// A generic message for setting a property of a widget.
+///
+/// Useful for actors that need to make simple UI updates (e.g. background workers reporting their
+/// status) without defining a message type for each such update. The widget can be specified by
+/// its builder ID (with [`by_id`](SetWidgetProperty::by_id), which can be created in any thread)
+/// or directly (with [`on`](SetWidgetProperty::on), which can only be created in the GTK thread).
+///
+/// The actor that owns the widgets handles the message by calling
+/// [`apply`](SetWidgetProperty::apply) with anything that implements [`FindWidget`] (like the
+/// [`BuilderWidgets`](crate::BuilderWidgets) it was created from):
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// struct WindowActor {
+///     builder: woab::BuilderWidgets,
+/// }
+/// # impl actix::Actor for WindowActor { type Context = actix::Context<Self>; }
+///
+/// impl actix::Handler<woab::SetWidgetProperty> for WindowActor {
+///     type Result = woab::Result<()>;
+///
+///     fn handle(&mut self, msg: woab::SetWidgetProperty, _ctx: &mut Self::Context) -> Self::Result {
+///         msg.apply(&self.builder)
+///     }
+/// }
+///
+/// # let window_actor: actix::Addr<WindowActor> = panic!();
+/// window_actor.do_send(woab::SetWidgetProperty::by_id("status_label", "label", "Done"));
+/// ```
+pub struct SetWidgetProperty {
+    target: WidgetTarget,
+    property: String,
+    value: glib::SendValue,
+}
+
+impl actix::Message for SetWidgetProperty {
+    type Result = crate::Result<()>;
+}
+
+impl SetWidgetProperty {
+    /// Set the property of the widget with the given builder ID.
+    pub fn by_id(id: impl Into<String>, property: impl Into<String>, value: impl glib::value::ToSendValue) -> Self {
+        Self {
+            target: WidgetTarget::Id(id.into()),
+            property: property.into(),
+            value: value.to_send_value(),
+        }
+    }
+
+    /// Set the property of the given widget.
+    ///
+    /// The message must be handled in the same thread it was created in.
+    pub fn on(widget: &impl IsA<glib::Object>, property: impl Into<String>, value: impl glib::value::ToSendValue) -> Self {
+        Self {
+            target: WidgetTarget::Object(SendWrapper::new(widget.clone().upcast())),
+            property: property.into(),
+            value: value.to_send_value(),
+        }
+    }
+
+    /// Set the property, using `finder` to find the widget if it was specified by ID.
+    pub fn apply(self, finder: &impl FindWidget) -> crate::Result<()> {
+        let object = match self.target {
+            WidgetTarget::Id(id) => finder.find_widget(&id).ok_or_else(|| crate::Error::WidgetMissingInBuilder {
+                suggestions: crate::error::suggestions_for(&id, finder.widget_ids()),
+                widget_id: id.clone(),
+            })?,
+            WidgetTarget::Object(object) => object.take(),
+        };
+        check_property(&object, &self.property, self.value.type_())?;
+        object.set_property_from_value(&self.property, &self.value);
+        Ok(())
+    }
+}
+
+/// Verify that the object has the property, and that it can be set to a value of the given type.
+pub(crate) fn check_property(object: &glib::Object, property: &str, value_type: glib::Type) -> crate::Result<()> {
+    let pspec = object.find_property(property).ok_or_else(|| crate::Error::NoSuchProperty {
+        object_type: object.type_(),
+        property: property.to_owned(),
+    })?;
+    if !value_type.is_a(pspec.value_type()) {
+        return Err(crate::Error::IncorrectPropertyType {
+            property: property.to_owned(),
+            expected_type: pspec.value_type(),
+            actual_type: value_type,
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use gio::prelude::*;
+
+/// Bind GSettings keys to properties of widgets (or any GObject), in both directions.
+///
+/// Each binding is a `(key, object, property)` tuple. Changes to the settings (e.g. from another
+/// instance of the application, or from `dconf-editor`) are reflected in the properties, and
+/// changes to the properties (e.g. because the user edited a widget) are written back to the
+/// settings. GIO does the syncing directly, so there is no need to route every edit through an
+/// actor - actors that need to know about changes can watch the settings or the widgets.
+///
+/// The keys and properties are checked before anything is bound, so an error means no binding
+/// was made. The bindings last until the objects are destroyed, or until they are removed with
+/// `gio::Settings::unbind`.
+///
+/// ```no_run
+/// # let settings: gio::Settings = panic!();
+/// # let font_size_spin_button: gtk4::SpinButton = panic!();
+/// # let dark_mode_switch: gtk4::Switch = panic!();
+/// woab::bind_settings(
+///     &settings,
+///     &[
+///         ("font-size", &font_size_spin_button, "value"),
+///         ("dark-mode", &dark_mode_switch, "active"),
+///     ],
+/// )
+/// .unwrap();
+/// ```
+///
+/// Structs that derive [`woab::PropSync`](crate::PropSync) can declare the setting of each field
+/// with `#[prop_sync(setting = "...")]`, and bind all of them with the generated `bind_settings`
+/// method.
+pub fn bind_settings(settings: &gio::Settings, bindings: &[(&str, &dyn AsRef<glib::Object>, &str)]) -> crate::Result<()> {
+    let schema = settings.settings_schema();
+    for (key, object, property) in bindings.iter() {
+        if let Some(schema) = &schema {
+            if !schema.has_key(key) {
+                return Err(crate::Error::NoSuchSettingsKey {
+                    key: key.to_string(),
+                    known_keys: schema.list_keys().into_iter().map(|key| key.to_string()).collect(),
+                });
+            }
+        }
+        let object = object.as_ref();
+        if object.find_property(property).is_none() {
+            return Err(crate::Error::NoSuchProperty {
+                object_type: object.type_(),
+                property: property.to_string(),
+            });
+        }
+    }
+    for (key, object, property) in bindings.iter() {
+        settings.bind(key, object.as_ref(), property).build();
+    }
+    Ok(())
+}
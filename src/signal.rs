@@ -19,32 +19,176 @@ impl<T> actix::Message for Signal<T> {
     type Result = SignalResult;
 }
 
+impl<T: Clone> Clone for Signal<T> {
+    /// Cloning a signal does not copy its parameters - they are reference-counted.
+    fn clone(&self) -> Self {
+        Signal(SendWrapper::new((*self.0).clone()))
+    }
+}
+
+/// Shows the name, the tag, and the type and value of each parameter.
+///
+/// Printing a signal from a thread other than the one that created it will not show its content,
+/// because GTK values cannot be accessed from other threads.
+impl<T: core::fmt::Debug> core::fmt::Debug for Signal<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if !self.0.valid() {
+            return f.write_str("Signal { <created in another thread> }");
+        }
+        f.debug_struct("Signal")
+            .field("name", &self.name())
+            .field("tag", self.tag())
+            .field("parameters", &self.raw_params())
+            .finish()
+    }
+}
+
+/// Shows the name and the type and value of each parameter, e.g. `clicked((GtkButton) ...)`.
+impl<T> core::fmt::Display for Signal<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if !self.0.valid() {
+            return f.write_str("<signal created in another thread>");
+        }
+        write!(f, "{}(", self.name())?;
+        for (i, parameter) in self.raw_params().iter().enumerate() {
+            if 0 < i {
+                f.write_str(", ")?;
+            }
+            write!(f, "{:?}", parameter)?;
+        }
+        f.write_str(")")
+    }
+}
+
 #[doc(hidden)]
+#[derive(Clone)]
 pub struct SignalData<T> {
-    name: Rc<String>,
-    parameters: Vec<glib::Value>,
+    name: glib::Quark,
+    // Reference-counted so that routing code can create signals from the borrowed parameters GTK
+    // passes with a single allocation, and so that signals can be cloned cheaply.
+    parameters: Rc<[glib::Value]>,
     tag: T,
 }
 
 impl<T: Clone> Signal<T> {
     pub fn creator(name: &str, tag: T) -> impl Fn(Vec<glib::Value>) -> Self {
-        let name = Rc::new(name.to_owned());
+        let name = glib::Quark::from_str(name);
         move |parameters| {
             Signal(SendWrapper::new(SignalData {
-                name: name.clone(),
-                parameters,
+                name,
+                parameters: parameters.into(),
                 tag: tag.clone(),
             }))
         }
     }
 }
 
+impl Signal {
+    /// Create a signal from plain Rust values, for unit-testing signal handlers.
+    ///
+    /// The parameters are a tuple of values that can be converted to `glib::Value` (or a
+    /// `Vec<glib::Value>`), and the signal has no tag. Use [`Signal::with_values`] for tagged
+    /// signals.
+    ///
+    /// Creating signals and extracting their parameters only needs GLib's type system, so signals
+    /// made of plain values (numbers, strings, booleans and the like) can be passed to handlers
+    /// in regular `#[test]` functions, without calling `gtk4::init()` or running a main loop. Note
+    /// that WoAB still links against GTK, so the GTK libraries must be installed to build the
+    /// tests - they just do not need a display.
+    ///
+    /// ```no_run
+    /// let signal = woab::Signal::for_test("counter_changed", (42i32, "forty two", true));
+    /// assert_eq!(signal.name(), "counter_changed");
+    /// let woab::params!(value: i32, text: String, done: bool) = signal.params()?;
+    /// # Ok::<(), woab::Error>(())
+    /// ```
+    ///
+    /// Parameters that are GTK objects, of course, need GTK to be initialized before they can be
+    /// created:
+    ///
+    /// ```no_run
+    /// # use actix::prelude::*;
+    /// # use gtk4::prelude::*;
+    /// # struct MyActor;
+    /// # impl actix::Actor for MyActor { type Context = actix::Context<Self>; }
+    /// # impl actix::Handler<woab::Signal> for MyActor {
+    /// #     type Result = woab::SignalResult;
+    /// #     fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+    /// #         msg.cant_handle()
+    /// #     }
+    /// # }
+    /// # let mut actor = MyActor;
+    /// # let mut ctx = actix::Context::<MyActor>::new();
+    /// let buffer = gtk4::TextBuffer::new(None);
+    /// buffer.set_text("42");
+    /// actor.handle(woab::Signal::for_test("addend_changed", (buffer,)), &mut ctx)?;
+    /// # Ok::<(), woab::Error>(())
+    /// ```
+    pub fn for_test(name: &str, parameters: impl IntoSignalParameters) -> Self {
+        Self::with_values(name, parameters.into_signal_parameters(), ())
+    }
+}
+
+impl<T> Signal<T> {
+    /// Create a signal from a list of `glib::Value`s and a tag.
+    ///
+    /// Mainly useful for unit-testing signal handlers without instantiating builders - see also
+    /// [`Signal::for_test`].
+    pub fn with_values(name: &str, parameters: Vec<glib::Value>, tag: T) -> Self {
+        Self::new(glib::Quark::from_str(name), parameters, tag)
+    }
+}
+
+/// Parameters for [`Signal::for_test`].
+///
+/// Implemented for `Vec<glib::Value>` and for tuples (of up to 12 elements) of values that implement
+/// `glib::value::ToValue`.
+pub trait IntoSignalParameters {
+    fn into_signal_parameters(self) -> Vec<glib::Value>;
+}
+
+impl IntoSignalParameters for Vec<glib::Value> {
+    fn into_signal_parameters(self) -> Vec<glib::Value> {
+        self
+    }
+}
+
+macro_rules! impl_into_signal_parameters_for_tuple {
+    ($($param:ident),*) => {
+        impl<$($param: glib::value::ToValue),*> IntoSignalParameters for ($($param,)*) {
+            #[allow(non_snake_case)]
+            fn into_signal_parameters(self) -> Vec<glib::Value> {
+                let ($($param,)*) = self;
+                vec![$($param.to_value()),*]
+            }
+        }
+    };
+}
+
+impl_into_signal_parameters_for_tuple!();
+impl_into_signal_parameters_for_tuple!(A);
+impl_into_signal_parameters_for_tuple!(A, B);
+impl_into_signal_parameters_for_tuple!(A, B, C);
+impl_into_signal_parameters_for_tuple!(A, B, C, D);
+impl_into_signal_parameters_for_tuple!(A, B, C, D, E);
+impl_into_signal_parameters_for_tuple!(A, B, C, D, E, F);
+impl_into_signal_parameters_for_tuple!(A, B, C, D, E, F, G);
+impl_into_signal_parameters_for_tuple!(A, B, C, D, E, F, G, H);
+impl_into_signal_parameters_for_tuple!(A, B, C, D, E, F, G, H, I);
+impl_into_signal_parameters_for_tuple!(A, B, C, D, E, F, G, H, I, J);
+impl_into_signal_parameters_for_tuple!(A, B, C, D, E, F, G, H, I, J, K);
+impl_into_signal_parameters_for_tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
+
 impl<T> SignalData<T> {
+    fn name(&self) -> &str {
+        self.name.as_str().as_str()
+    }
+
     fn raw_param(&self, index: usize) -> Result<&glib::Value, crate::Error> {
         self.parameters
             .get(index)
             .ok_or_else(|| crate::Error::SignalParameterIndexOutOfBound {
-                signal: self.name.as_str().to_owned(),
+                signal: self.name().to_owned(),
                 index,
                 num_parameters: self.parameters.len(),
             })
@@ -60,7 +204,28 @@ impl<T> SignalData<T> {
             Ok(value)
         } else {
             Err(crate::Error::IncorrectSignalParameterType {
-                signal: self.name.as_str().to_owned(),
+                signal: self.name().to_owned(),
+                index,
+                expected_type: <P as glib::types::StaticType>::static_type(),
+                actual_type: value.type_(),
+            })
+        }
+    }
+
+    fn optional_param<'a, P>(&'a self, index: usize) -> Result<Option<P>, crate::Error>
+    where
+        P: glib::value::FromValue<'a>,
+        P: glib::types::StaticType,
+    {
+        let value = self.raw_param(index)?;
+        if let Ok(value) = value.get() {
+            Ok(Some(value))
+        } else if value.type_().is_a(<P as glib::types::StaticType>::static_type()) {
+            // The type is correct, so the value could only fail to convert because it is NULL
+            Ok(None)
+        } else {
+            Err(crate::Error::IncorrectSignalParameterType {
+                signal: self.name().to_owned(),
                 index,
                 expected_type: <P as glib::types::StaticType>::static_type(),
                 actual_type: value.type_(),
@@ -70,8 +235,16 @@ impl<T> SignalData<T> {
 }
 
 impl<T> Signal<T> {
-    pub fn new(name: Rc<String>, parameters: Vec<glib::Value>, tag: T) -> Self {
-        Signal(SendWrapper::new(SignalData { name, parameters, tag }))
+    /// Create a signal.
+    ///
+    /// `parameters` can be a `Vec<glib::Value>`, but passing a `&[glib::Value]` saves an
+    /// allocation.
+    pub fn new(name: glib::Quark, parameters: impl Into<Rc<[glib::Value]>>, tag: T) -> Self {
+        Signal(SendWrapper::new(SignalData {
+            name,
+            parameters: parameters.into(),
+            tag,
+        }))
     }
 
     /// The name of the signal.
@@ -85,7 +258,20 @@ impl<T> Signal<T> {
     /// * If the signal comes from [`woab::route_action`](crate::route_action), it is the name of
     ///   the GIO action.
     pub fn name(&self) -> &str {
-        &self.0.name
+        self.0.name()
+    }
+
+    /// The name of the signal, interned as a `glib::Quark`.
+    ///
+    /// Comparing quarks is cheaper than comparing strings - useful for handlers that check the
+    /// name against a quark that was interned once (e.g. in a `static` or in the actor's state).
+    pub fn name_quark(&self) -> glib::Quark {
+        self.0.name
+    }
+
+    /// Check the name of the signal by comparing quarks.
+    pub fn is(&self, name: glib::Quark) -> bool {
+        self.0.name == name
     }
 
     /// The tag of the signal.
@@ -101,7 +287,15 @@ impl<T> Signal<T> {
         self.0.raw_param(index)
     }
 
+    /// All the parameters of the signal, as raw `glib::Value`s.
+    pub fn raw_params(&self) -> &[glib::Value] {
+        &self.0.parameters
+    }
+
     /// A parameter of the signal, converted to the appropriate type.
+    ///
+    /// GTK passes NULL for some parameters (e.g. optional objects, strings, or boxed types like
+    /// `gtk4::TreePath`). To accept them, request an `Option` - NULL will be converted to `None`.
     pub fn param<'a, P>(&'a self, index: usize) -> Result<P, crate::Error>
     where
         P: glib::value::FromValue<'a>,
@@ -124,6 +318,40 @@ impl<T> Signal<T> {
         })
     }
 
+    /// The target of an action routed with
+    /// [`woab::route_detailed_action`](crate::route_detailed_action) - e.g. `"center"` for
+    /// `app.alignment::center`.
+    ///
+    /// Unlike [`action_param`](Self::action_param), this fails with a
+    /// [`MissingActionTarget`](crate::Error::MissingActionTarget) error (and not a type error)
+    /// when the action was activated without a target.
+    pub fn detailed_target<P: glib::variant::FromVariant>(&self) -> Result<P, crate::Error> {
+        let value: Option<glib::Variant> = self.param(1)?;
+        let value = value.ok_or_else(|| crate::Error::MissingActionTarget {
+            signal: self.name().to_owned(),
+        })?;
+        value.get().ok_or_else(|| crate::Error::IncorrectActionParameter {
+            signal: self.name().to_owned(),
+            expected_type: <P as glib::variant::StaticVariantType>::static_variant_type().into_owned(),
+            actual_type: value.type_().to_owned(),
+        })
+    }
+
+    /// The selection data of a signal routed with [`woab::route_selection`](crate::route_selection).
+    pub fn selection_change(&self) -> Result<crate::SelectionChange, crate::Error> {
+        use gio::prelude::*;
+
+        let selected: gtk4::Bitset = self.param(3)?;
+        let items: gio::ListStore = self.param(4)?;
+        Ok(crate::SelectionChange {
+            model: self.param(0)?,
+            position: self.param(1)?,
+            n_items: self.param(2)?,
+            selected_positions: (0..selected.size()).map(|i| selected.nth(i as u32)).collect(),
+            selected_items: (0..items.n_items()).filter_map(|i| items.item(i)).collect(),
+        })
+    }
+
     /// An error indicating that an handler does not recognize the signal.
     ///
     /// To be used in the catch-all arm of a signal match:
@@ -154,15 +382,131 @@ impl<T> Signal<T> {
     ///     }
     /// }
     pub fn cant_handle(&self) -> SignalResult {
-        Err(crate::Error::NoSuchSignalError(self.0.name.as_str().to_owned()))
+        Err(crate::Error::NoSuchSignalError {
+            signal: self.name().to_owned(),
+            suggestions: Vec::new(),
+        })
+    }
+
+    /// Like [`cant_handle`](Self::cant_handle), but the error suggests the most similar names out
+    /// of the ones the handler does handle - which helps finding typos in the handler names in the
+    /// builder XML.
+    ///
+    /// ```no_run
+    /// # let msg: woab::Signal = panic!();
+    /// # let _: woab::SignalResult =
+    /// msg.cant_handle_expecting(&["signal1", "signal2", "signal3"])
+    /// # ;
+    /// ```
+    pub fn cant_handle_expecting(&self, handled: &[&str]) -> SignalResult {
+        Err(crate::Error::NoSuchSignalError {
+            signal: self.name().to_owned(),
+            suggestions: crate::error::suggestions_for(self.name(), handled),
+        })
     }
 
     /// To be used with the [`woab::params!`](crate::params!) macro to extract all the signal's parameters.
     pub fn params<'a, R: SignalParamReceiver<'a>>(&'a self) -> Result<R, crate::Error> {
         R::fill_from_index(&*self.0, 0)
     }
+
+    /// Extract all the signal's parameters as a tuple.
+    ///
+    /// Like [`params`](Self::params) with [`woab::params!`](crate::params!), but without the macro,
+    /// which is more convenient in generic code. Fails if the signal has a different number of
+    /// parameters or if they are not of the requested types.
+    ///
+    /// ```no_run
+    /// # let msg: woab::Signal = panic!();
+    /// let (button, count): (gtk4::Button, i32) = msg.params_as()?;
+    /// // Or, with a turbofish:
+    /// let (text, count) = msg.params_as::<(String, i32)>()?;
+    /// # Ok::<(), woab::Error>(())
+    /// ```
+    pub fn params_as<'a, P: FromSignalParams<'a>>(&'a self) -> Result<P, crate::Error> {
+        P::from_signal_params(&self.0)
+    }
+}
+
+/// Asks an actor whether it handles the signal with the given name, without emitting the signal.
+///
+/// Used by [`BuilderFactory::check_signals_handled`](crate::BuilderFactory::check_signals_handled)
+/// to find signals declared in the builder XML that no actor handles. Actors opt into it by
+/// implementing a handler for this message in addition to their [`Signal`] handler:
+///
+/// ```no_run
+/// # struct WindowActor;
+/// # impl actix::Actor for WindowActor { type Context = actix::Context<Self>; }
+/// impl actix::Handler<woab::ProbeSignal> for WindowActor {
+///     type Result = bool;
+///
+///     fn handle(&mut self, msg: woab::ProbeSignal, _ctx: &mut Self::Context) -> Self::Result {
+///         msg.is_one_of(&["save_clicked", "close_clicked"])
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ProbeSignal {
+    pub name: String,
+}
+
+impl actix::Message for ProbeSignal {
+    type Result = bool;
+}
+
+impl ProbeSignal {
+    /// Check if the probed signal is one of the names.
+    pub fn is_one_of(&self, names: &[&str]) -> bool {
+        names.contains(&self.name.as_str())
+    }
+}
+
+/// A tuple that can be extracted from the parameters of a signal with [`Signal::params_as`].
+///
+/// Implemented for tuples (of up to 12 elements) of types that can be converted from
+/// `glib::Value`.
+pub trait FromSignalParams<'a>: Sized {
+    #[doc(hidden)]
+    fn from_signal_params<SignalTag>(signal: &'a SignalData<SignalTag>) -> Result<Self, crate::Error>;
+}
+
+macro_rules! impl_from_signal_params_for_tuple {
+    ($($param:ident),*) => {
+        impl<'a, $($param),*> FromSignalParams<'a> for ($($param,)*)
+        where
+            $(
+                $param: glib::value::FromValue<'a> + glib::types::StaticType,
+            )*
+        {
+            #[allow(unused_mut, clippy::let_unit_value)]
+            fn from_signal_params<SignalTag>(signal: &'a SignalData<SignalTag>) -> Result<Self, crate::Error> {
+                let mut index = 0;
+                let result = ($({
+                    let value: $param = signal.param(index)?;
+                    index += 1;
+                    value
+                },)*);
+                <() as SignalParamReceiver>::fill_from_index(signal, index)?;
+                Ok(result)
+            }
+        }
+    };
 }
 
+impl_from_signal_params_for_tuple!();
+impl_from_signal_params_for_tuple!(A);
+impl_from_signal_params_for_tuple!(A, B);
+impl_from_signal_params_for_tuple!(A, B, C);
+impl_from_signal_params_for_tuple!(A, B, C, D);
+impl_from_signal_params_for_tuple!(A, B, C, D, E);
+impl_from_signal_params_for_tuple!(A, B, C, D, E, F);
+impl_from_signal_params_for_tuple!(A, B, C, D, E, F, G);
+impl_from_signal_params_for_tuple!(A, B, C, D, E, F, G, H);
+impl_from_signal_params_for_tuple!(A, B, C, D, E, F, G, H, I);
+impl_from_signal_params_for_tuple!(A, B, C, D, E, F, G, H, I, J);
+impl_from_signal_params_for_tuple!(A, B, C, D, E, F, G, H, I, J, K);
+impl_from_signal_params_for_tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
+
 #[doc(hidden)]
 pub trait SignalParamReceiver<'a>: Sized {
     fn fill_from_index<D>(signal: &'a SignalData<D>, from_index: usize) -> Result<Self, crate::Error>;
@@ -172,7 +516,7 @@ impl SignalParamReceiver<'_> for () {
     fn fill_from_index<D>(signal: &SignalData<D>, from_index: usize) -> Result<Self, crate::Error> {
         if from_index < signal.parameters.len() {
             return Err(crate::Error::NotAllParametersExtracted {
-                signal: signal.name.as_str().to_owned(),
+                signal: signal.name().to_owned(),
                 num_parameters: signal.parameters.len(),
                 num_extracted: from_index,
             });
@@ -196,6 +540,20 @@ where
     }
 }
 
+impl<'a, T, R> SignalParamReceiver<'a> for ((Option<T>, core::marker::PhantomData<T>), R)
+where
+    T: glib::value::FromValue<'a>,
+    T: glib::types::StaticType,
+    R: SignalParamReceiver<'a>,
+{
+    fn fill_from_index<D>(signal: &'a SignalData<D>, from_index: usize) -> Result<Self, crate::Error> {
+        Ok((
+            (signal.optional_param(from_index)?, core::marker::PhantomData),
+            R::fill_from_index(signal, from_index + 1)?,
+        ))
+    }
+}
+
 impl<'a, R> SignalParamReceiver<'a> for ((&'a glib::Value,), R)
 where
     R: SignalParamReceiver<'a>,
@@ -0,0 +1,108 @@
+use core::cell::{Cell, RefCell};
+
+use hashbrown::HashMap;
+
+/// How many times a signal was routed, and how many of these emissions hit the limitations of
+/// signals that are emitted while the Actix runtime is busy.
+///
+/// See [`woab::signal_diagnostics`](signal_diagnostics).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignalStats {
+    /// The name of the signal, as the actor sees it.
+    pub signal: String,
+    /// Emissions that were handled by the actor immediately.
+    pub handled_immediately: usize,
+    /// Emissions that had to be queued because they happened inside the Actix runtime (e.g. a
+    /// signal GTK emitted because an actor changed a widget).
+    pub queued: usize,
+    /// Queued emissions whose handler returned a propagation value. Since the signal already
+    /// returned to GTK by then, this causes a panic.
+    pub queued_with_propagation: usize,
+}
+
+#[derive(Default, Clone, Copy)]
+struct Counters {
+    handled_immediately: usize,
+    queued: usize,
+    queued_with_propagation: usize,
+}
+
+thread_local! {
+    static ENABLED: Cell<bool> = const { Cell::new(false) };
+    static COUNTERS: RefCell<Option<HashMap<glib::Quark, Counters>>> = const { RefCell::new(None) };
+}
+
+pub(crate) enum SignalOutcome {
+    HandledImmediately,
+    Queued,
+    QueuedWithPropagation,
+}
+
+pub(crate) fn record(signal_name: glib::Quark, outcome: SignalOutcome) {
+    if !ENABLED.with(Cell::get) {
+        return;
+    }
+    COUNTERS.with(|counters| {
+        let mut counters = counters.borrow_mut();
+        let counters = counters.get_or_insert_with(HashMap::new).entry(signal_name).or_default();
+        match outcome {
+            SignalOutcome::HandledImmediately => counters.handled_immediately += 1,
+            SignalOutcome::Queued => counters.queued += 1,
+            SignalOutcome::QueuedWithPropagation => counters.queued_with_propagation += 1,
+        }
+    });
+}
+
+/// A snapshot of the routing statistics of all the signals routed so far, sorted by name.
+///
+/// WoAB routes signals to actors synchronously - but if a signal is emitted while the Actix
+/// runtime is busy (usually because an actor did something that made GTK emit it) the signal has to
+/// be queued. Queued signals cannot have context parameters and cannot return a propagation value
+/// (see [`woab::outside`](crate::outside)), so these counters help finding the signals that need
+/// attention - e.g. when migrating an application that was written without WoAB.
+///
+/// The statistics are per thread (GTK only runs on one thread anyway), and the emissions are
+/// counted by signal name regardless of which actor they were routed to. Counting is disabled by
+/// default, so that routing signals does not pay for it - enable it with
+/// [`woab::set_signal_diagnostics_enabled`](set_signal_diagnostics_enabled).
+///
+/// ```no_run
+/// woab::set_signal_diagnostics_enabled(true);
+/// // Run the application...
+/// for stats in woab::signal_diagnostics() {
+///     if 0 < stats.queued {
+///         println!("{} was queued {} times", stats.signal, stats.queued);
+///     }
+/// }
+/// ```
+pub fn signal_diagnostics() -> Vec<SignalStats> {
+    let mut result = COUNTERS.with(|counters| {
+        counters
+            .borrow()
+            .iter()
+            .flatten()
+            .map(|(signal_name, counters)| SignalStats {
+                signal: signal_name.as_str().as_str().to_owned(),
+                handled_immediately: counters.handled_immediately,
+                queued: counters.queued,
+                queued_with_propagation: counters.queued_with_propagation,
+            })
+            .collect::<Vec<_>>()
+    });
+    result.sort_by(|a, b| a.signal.cmp(&b.signal));
+    result
+}
+
+/// Start (or stop) counting the emissions of routed signals for
+/// [`woab::signal_diagnostics`](signal_diagnostics).
+///
+/// Disabled by default. Stopping does not reset the counters - use
+/// [`woab::reset_signal_diagnostics`](reset_signal_diagnostics) for that.
+pub fn set_signal_diagnostics_enabled(enabled: bool) {
+    ENABLED.with(|cell| cell.set(enabled));
+}
+
+/// Reset all the counters of [`woab::signal_diagnostics`](signal_diagnostics).
+pub fn reset_signal_diagnostics() {
+    COUNTERS.with(|counters| counters.borrow_mut().take());
+}
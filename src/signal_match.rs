@@ -0,0 +1,38 @@
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+#[doc(hidden)] // for internal use by woab::match_signal!
+pub const fn signal_names_contain(names: &[&str], name: &str) -> bool {
+    let mut i = 0;
+    while i < names.len() {
+        if str_eq(names[i], name) {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+#[doc(hidden)] // for internal use by woab::match_signal!
+pub const fn signal_names_cover(names: &[&str], required: &[&str]) -> bool {
+    let mut i = 0;
+    while i < required.len() {
+        if !signal_names_contain(names, required[i]) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
@@ -0,0 +1,161 @@
+use core::cell::RefCell;
+use core::time::Duration;
+
+use hashbrown::HashMap;
+
+/// The upper bounds of the histogram buckets. The last bucket has no upper bound.
+const BUCKET_BOUNDS: [Duration; 10] = [
+    Duration::from_millis(1),
+    Duration::from_millis(2),
+    Duration::from_millis(5),
+    Duration::from_millis(10),
+    Duration::from_millis(20),
+    Duration::from_millis(50),
+    Duration::from_millis(100),
+    Duration::from_millis(200),
+    Duration::from_millis(500),
+    Duration::from_millis(1000),
+];
+
+/// How long the handlers of a signal took.
+///
+/// See [`woab::signal_metrics`](signal_metrics).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignalMetrics {
+    /// The name of the signal, as the actor sees it.
+    pub signal: String,
+    /// The number of handled emissions.
+    pub count: usize,
+    /// The total time of all the handled emissions.
+    pub total: Duration,
+    /// The time of the slowest emission.
+    pub max: Duration,
+    /// The number of emissions in each latency bucket, as `(upper_bound, count)`. The bounds are
+    /// 1ms, 2ms, 5ms, 10ms, ... 1s, and the last bucket's bound is `Duration::MAX`.
+    pub histogram: Vec<(Duration, usize)>,
+}
+
+impl SignalMetrics {
+    /// The average time of an emission.
+    pub fn mean(&self) -> Duration {
+        if self.count == 0 {
+            Duration::ZERO
+        } else {
+            self.total / self.count as u32
+        }
+    }
+
+    /// The number of emissions that took at least `threshold`.
+    ///
+    /// Since the times are bucketed, `threshold` is rounded up to the bucket bound above it.
+    pub fn count_at_least(&self, threshold: Duration) -> usize {
+        let mut lower_bound = Duration::ZERO;
+        let mut result = 0;
+        for (upper_bound, count) in self.histogram.iter() {
+            if threshold <= lower_bound {
+                result += count;
+            }
+            lower_bound = *upper_bound;
+        }
+        result
+    }
+}
+
+/// Shows the count, mean and max, and the non-empty buckets of the histogram.
+impl core::fmt::Display for SignalMetrics {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{}: {} emissions, mean {:?}, max {:?}",
+            self.signal,
+            self.count,
+            self.mean(),
+            self.max
+        )?;
+        for (upper_bound, count) in self.histogram.iter() {
+            if *count == 0 {
+                continue;
+            }
+            if *upper_bound == Duration::MAX {
+                write!(f, ", >{:?}: {}", BUCKET_BOUNDS[BUCKET_BOUNDS.len() - 1], count)?;
+            } else {
+                write!(f, ", <{:?}: {}", upper_bound, count)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default, Clone, Copy)]
+struct Timings {
+    count: usize,
+    total: Duration,
+    max: Duration,
+    buckets: [usize; BUCKET_BOUNDS.len() + 1],
+}
+
+thread_local! {
+    static TIMINGS: RefCell<Option<HashMap<glib::Quark, Timings>>> = const { RefCell::new(None) };
+}
+
+pub(crate) fn record_latency(signal_name: glib::Quark, latency: Duration) {
+    TIMINGS.with(|timings| {
+        let mut timings = timings.borrow_mut();
+        let timings = timings.get_or_insert_with(HashMap::new).entry(signal_name).or_default();
+        timings.count += 1;
+        timings.total += latency;
+        timings.max = timings.max.max(latency);
+        let bucket = BUCKET_BOUNDS
+            .iter()
+            .position(|upper_bound| latency < *upper_bound)
+            .unwrap_or(BUCKET_BOUNDS.len());
+        timings.buckets[bucket] += 1;
+    });
+}
+
+/// A snapshot of the handler latencies of all the signals routed so far, sorted by name.
+///
+/// Only available with the `metrics` feature. Signals handled immediately are timed from the
+/// moment GTK emitted them until their handler returned - which is the time the GTK thread was
+/// blocked. Queued signals are timed from the moment they were queued until their handler
+/// returned.
+///
+/// Like [`woab::signal_diagnostics`](crate::signal_diagnostics), the metrics are per thread and
+/// collected by signal name regardless of which actor handled them.
+///
+/// ```no_run
+/// # use core::time::Duration;
+/// for metrics in woab::signal_metrics() {
+///     if 0 < metrics.count_at_least(Duration::from_millis(100)) {
+///         println!("{}", metrics);
+///     }
+/// }
+/// ```
+pub fn signal_metrics() -> Vec<SignalMetrics> {
+    let mut result = TIMINGS.with(|timings| {
+        timings
+            .borrow()
+            .iter()
+            .flatten()
+            .map(|(signal_name, timings)| SignalMetrics {
+                signal: signal_name.as_str().as_str().to_owned(),
+                count: timings.count,
+                total: timings.total,
+                max: timings.max,
+                histogram: BUCKET_BOUNDS
+                    .iter()
+                    .copied()
+                    .chain([Duration::MAX])
+                    .zip(timings.buckets)
+                    .collect(),
+            })
+            .collect::<Vec<_>>()
+    });
+    result.sort_by(|a, b| a.signal.cmp(&b.signal));
+    result
+}
+
+/// Reset all the metrics of [`woab::signal_metrics`](signal_metrics).
+pub fn reset_signal_metrics() {
+    TIMINGS.with(|timings| timings.borrow_mut().take());
+}
@@ -1,5 +1,8 @@
 use std::rc::Rc;
 
+use crate::signal_diagnostics::{record, SignalOutcome};
+use crate::unmatched_signals::handle_unmatched_signal;
+
 /// Type of a gtk signal callback function that operates on uncast glib values.
 pub type RawSignalCallback = Box<dyn Fn(&[glib::Value]) -> Option<glib::Value>>;
 
@@ -50,6 +53,515 @@ pub fn route_action(
     route_signal(action, signal, action.name().as_str(), target)
 }
 
+/// Route a detailed action name, like `app.alignment::center`, to an Actix actor that can handle
+/// [`woab::Signal`](crate::Signal).
+///
+/// Detailed action names are how menus and buttons activate an action with a target value. If
+/// the action map does not have the action yet, a stateful `gio::SimpleAction` is created with the
+/// target as its initial state, added to the map, and routed to the target like
+/// [`woab::route_action`](route_action) does. Its state follows the target of the last activation -
+/// so menu items with the same action and different targets are displayed as radio items. Use
+/// [`Signal::detailed_target`](crate::Signal::detailed_target) to get the target value in the
+/// handler.
+///
+/// If the action already exists it is not routed again, so this function can be called for each
+/// of the action's targets. The `app.`/`win.` prefix of the detailed name is ignored - the action
+/// is added to `action_map`.
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// # struct AppActor;
+/// # impl actix::Actor for AppActor { type Context = actix::Context<Self>; }
+/// # impl AppActor { fn set_alignment(&mut self, _alignment: &str) {} }
+/// impl actix::Handler<woab::Signal> for AppActor {
+///     type Result = woab::SignalResult;
+///
+///     fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+///         Ok(match msg.name() {
+///             "alignment" => {
+///                 let alignment: String = msg.detailed_target()?;
+///                 self.set_alignment(&alignment);
+///                 None
+///             }
+///             _ => msg.cant_handle()?,
+///         })
+///     }
+/// }
+///
+/// # let app: gtk4::Application = panic!();
+/// let addr = AppActor.start();
+/// for detailed_name in ["app.alignment::left", "app.alignment::center", "app.alignment::right"] {
+///     woab::route_detailed_action(&app, detailed_name, addr.clone()).unwrap();
+/// }
+/// ```
+pub fn route_detailed_action(
+    action_map: &impl glib::object::IsA<gio::ActionMap>,
+    detailed_name: &str,
+    target: impl IntoGenerateRoutingGtkHandler,
+) -> Result<gio::Action, crate::Error> {
+    use gio::prelude::*;
+
+    let (name, action_target) = gio::Action::parse_detailed_name(detailed_name)
+        .ok()
+        .and_then(|(name, action_target)| Some((name, action_target?)))
+        .ok_or_else(|| crate::Error::InvalidDetailedActionName(detailed_name.to_owned()))?;
+    let name = name.rsplit('.').next().unwrap_or_default();
+    if let Some(action) = action_map.lookup_action(name) {
+        if let Some(expected_type) = action.state_type().or_else(|| action.parameter_type()) {
+            if *expected_type != *action_target.type_() {
+                return Err(crate::Error::IncorrectActionParameter {
+                    signal: name.to_owned(),
+                    expected_type,
+                    actual_type: action_target.type_().to_owned(),
+                });
+            }
+        }
+        return Ok(action);
+    }
+    let action = gio::SimpleAction::new_stateful(name, Some(action_target.type_()), &action_target);
+    action.connect_change_state(|action, state| {
+        if let Some(state) = state {
+            action.set_state(state);
+        }
+    });
+    route_signal(&action, "change-state", name, target)?;
+    action_map.add_action(&action);
+    Ok(action.upcast())
+}
+
+/// Create a `gtk4::SignalListItemFactory` that routes its signals to an Actix actor that can
+/// handle [`woab::Signal`](crate::Signal).
+///
+/// GTK4's list widgets (`GtkListView`, `GtkGridView` and `GtkColumnView`) create their rows using
+/// a list item factory instead of a GTK builder. The factory created by this function routes its
+/// `setup`, `bind`, `unbind` and `teardown` signals, using these names for the WoAB signals. The
+/// second parameter of each signal is the `gtk4::ListItem`.
+///
+/// The target can be anything that can be passed to
+/// [`instantiate_route_to`](crate::BuilderFactory::instantiate_route_to) - use a tag to
+/// differentiate between the factories if the same actor handles multiple list widgets.
+///
+/// A typical usage would be to use a [`woab::BuilderFactory`](crate::BuilderFactory) to create
+/// the row's widgets in `setup`, and fill them with the item's data in `bind`:
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// # use gtk4::prelude::*;
+/// struct ListActor {
+///     row_factory: woab::BuilderFactory,
+/// }
+/// # impl actix::Actor for ListActor { type Context = actix::Context<Self>; }
+///
+/// #[derive(woab::WidgetsFromBuilder)]
+/// struct RowWidgets {
+///     row: gtk4::Box,
+///     label: gtk4::Label,
+/// }
+///
+/// impl actix::Handler<woab::Signal> for ListActor {
+///     type Result = woab::SignalResult;
+///
+///     fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+///         Ok(match msg.name() {
+///             "setup" => {
+///                 let woab::params!(_, list_item: gtk4::ListItem) = msg.params()?;
+///                 let widgets: RowWidgets = self.row_factory.instantiate_without_routing_signals().widgets()?;
+///                 list_item.set_child(Some(&widgets.row));
+///                 None
+///             }
+///             "bind" => {
+///                 let woab::params!(_, list_item: gtk4::ListItem) = msg.params()?;
+///                 let label = list_item.child().unwrap().first_child().unwrap().downcast::<gtk4::Label>().unwrap();
+///                 let item = list_item.item().unwrap().downcast::<gtk4::StringObject>().unwrap();
+///                 label.set_text(&item.string());
+///                 None
+///             }
+///             "unbind" | "teardown" => None,
+///             _ => msg.cant_handle()?,
+///         })
+///     }
+/// }
+///
+/// # let list_view: gtk4::ListView = panic!();
+/// # let row_factory: woab::BuilderFactory = panic!();
+/// let actor = ListActor { row_factory }.start();
+/// list_view.set_factory(Some(&woab::route_list_item_factory(actor)));
+/// ```
+pub fn route_list_item_factory(target: impl IntoGenerateRoutingGtkHandler) -> gtk4::SignalListItemFactory {
+    let factory = gtk4::SignalListItemFactory::new();
+    let generator = target.into_generate_routing_gtk_handler();
+    for signal in ["setup", "bind", "unbind", "teardown"] {
+        generator.connect_local(&factory, signal, signal);
+    }
+    factory
+}
+
+/// Route the lifecycle signals of a widget to an Actix actor that can handle
+/// [`woab::Signal`](crate::Signal).
+///
+/// The signals `map`, `unmap`, `realize`, `unrealize` and `destroy` are routed as
+/// `lifecycle_map`, `lifecycle_unmap`, `lifecycle_realize`, `lifecycle_unrealize` and
+/// `lifecycle_destroy`, without having to declare them in the builder XML. Useful, for example,
+/// for suspending timers while the widget is hidden:
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// # struct MyActor;
+/// # impl actix::Actor for MyActor { type Context = actix::Context<Self>; }
+/// # impl MyActor { fn resume_timers(&mut self) {} fn suspend_timers(&mut self) {} }
+/// impl actix::Handler<woab::Signal> for MyActor {
+///     type Result = woab::SignalResult;
+///
+///     fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+///         Ok(match msg.name() {
+///             "lifecycle_map" => {
+///                 self.resume_timers();
+///                 None
+///             }
+///             "lifecycle_unmap" => {
+///                 self.suspend_timers();
+///                 None
+///             }
+///             "lifecycle_realize" | "lifecycle_unrealize" | "lifecycle_destroy" => None,
+///             _ => msg.cant_handle()?,
+///         })
+///     }
+/// }
+///
+/// # let widget: gtk4::Box = panic!();
+/// # let addr: actix::Addr<MyActor> = panic!();
+/// woab::route_lifecycle(&widget, addr);
+/// ```
+pub fn route_lifecycle(
+    widget: &impl glib::object::IsA<gtk4::Widget>,
+    target: impl IntoGenerateRoutingGtkHandler,
+) -> Vec<glib::SignalHandlerId> {
+    let generator = target.into_generate_routing_gtk_handler();
+    [
+        ("map", "lifecycle_map"),
+        ("unmap", "lifecycle_unmap"),
+        ("realize", "lifecycle_realize"),
+        ("unrealize", "lifecycle_unrealize"),
+        ("destroy", "lifecycle_destroy"),
+    ]
+    .into_iter()
+    .map(|(gtk_signal, actix_signal)| generator.connect_local(widget, gtk_signal, actix_signal))
+    .collect()
+}
+
+/// Route the `selection-changed` signal of a selection model (e.g. `gtk4::SingleSelection` or
+/// `gtk4::MultiSelection`) to an Actix actor that can handle [`woab::Signal`](crate::Signal).
+///
+/// In addition to the parameters of the original GTK signal (the model, the position of the first
+/// changed item, and the number of changed items) the routed signal also carries the selection as
+/// it was when the signal was emitted. Use [`Signal::selection_change`](crate::Signal::selection_change)
+/// to get all of it as a [`SelectionChange`]:
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// # struct MyActor;
+/// # impl actix::Actor for MyActor { type Context = actix::Context<Self>; }
+/// impl actix::Handler<woab::Signal> for MyActor {
+///     type Result = woab::SignalResult;
+///
+///     fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+///         Ok(match msg.name() {
+///             "items_selected" => {
+///                 let selection = msg.selection_change()?;
+///                 println!("Selected positions: {:?}", selection.selected_positions);
+///                 None
+///             }
+///             _ => msg.cant_handle()?,
+///         })
+///     }
+/// }
+///
+/// # let selection_model: gtk4::MultiSelection = panic!();
+/// # let addr: actix::Addr<MyActor> = panic!();
+/// woab::route_selection(&selection_model, "items_selected", addr);
+/// ```
+pub fn route_selection(
+    selection_model: &impl glib::object::IsA<gtk4::SelectionModel>,
+    actix_signal: &str,
+    target: impl IntoGenerateRoutingGtkHandler,
+) -> glib::SignalHandlerId {
+    use gtk4::prelude::*;
+
+    let callback = target.into_generate_routing_gtk_handler().raw_signal_callback(actix_signal);
+    selection_model.connect_local("selection-changed", false, move |parameters| {
+        let model: gtk4::SelectionModel = parameters[0].get().unwrap();
+        let selected = model.selection();
+        let items = gio::ListStore::new::<glib::Object>();
+        for i in 0..selected.size() {
+            if let Some(item) = model.item(selected.nth(i as u32)) {
+                items.append(&item);
+            }
+        }
+        let mut parameters = parameters.to_owned();
+        parameters.push(selected.to_value());
+        parameters.push(items.to_value());
+        callback(&parameters)
+    })
+}
+
+/// Route keyboard shortcuts to an Actix actor that can handle [`woab::Signal`](crate::Signal).
+///
+/// Each shortcut is a pair of a trigger (in the format of `gtk4::ShortcutTrigger::parse_string`,
+/// e.g. `"<Control>s"` or `"Escape"`) and the name of the signal it will be routed as. The only
+/// parameter of the signal is the widget the shortcut was activated on.
+///
+/// A `gtk4::ShortcutController` with the given `scope` is created, added to the widget, and
+/// returned so that it can be removed later:
+///
+/// * `gtk4::ShortcutScope::Local` - the shortcuts work only when the widget has the focus.
+/// * `gtk4::ShortcutScope::Managed` - the shortcuts are handled by the widget's nearest ancestor
+///   that manages shortcuts (usually the window).
+/// * `gtk4::ShortcutScope::Global` - the shortcuts are handled by the root widget.
+///
+/// If the handler returns `Ok(None)` or `Ok(Some(glib::Propagation::Stop))`, the shortcut is
+/// considered handled. If it returns `Ok(Some(glib::Propagation::Proceed))`, GTK will keep looking
+/// for other handlers of the key press.
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// # struct MyActor;
+/// # impl actix::Actor for MyActor { type Context = actix::Context<Self>; }
+/// # impl MyActor { fn save(&mut self) {} fn cancel(&mut self) {} }
+/// impl actix::Handler<woab::Signal> for MyActor {
+///     type Result = woab::SignalResult;
+///
+///     fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+///         Ok(match msg.name() {
+///             "save" => {
+///                 self.save();
+///                 None
+///             }
+///             "cancel" => {
+///                 self.cancel();
+///                 None
+///             }
+///             _ => msg.cant_handle()?,
+///         })
+///     }
+/// }
+///
+/// # let window: gtk4::ApplicationWindow = panic!();
+/// # let addr: actix::Addr<MyActor> = panic!();
+/// woab::route_shortcuts(
+///     &window,
+///     &[("<Control>s", "save"), ("Escape", "cancel")],
+///     gtk4::ShortcutScope::Managed,
+///     addr,
+/// )
+/// .unwrap();
+/// ```
+pub fn route_shortcuts(
+    widget: &impl glib::object::IsA<gtk4::Widget>,
+    shortcuts: &[(&str, &str)],
+    scope: gtk4::ShortcutScope,
+    target: impl IntoGenerateRoutingGtkHandler,
+) -> Result<gtk4::ShortcutController, crate::Error> {
+    use gtk4::prelude::*;
+
+    let generator = target.into_generate_routing_gtk_handler();
+    let controller = gtk4::ShortcutController::new();
+    controller.set_scope(scope);
+    for (trigger, actix_signal) in shortcuts {
+        let trigger = gtk4::ShortcutTrigger::parse_string(trigger)
+            .ok_or_else(|| crate::Error::InvalidShortcutTrigger(trigger.to_string()))?;
+        let callback = generator.raw_signal_callback(actix_signal);
+        let action = gtk4::CallbackAction::new(move |widget, _args| {
+            let proceed = callback(&[widget.to_value()]).map_or(false, |result| result.get::<bool>().unwrap());
+            if proceed {
+                glib::Propagation::Proceed
+            } else {
+                glib::Propagation::Stop
+            }
+        });
+        controller.add_shortcut(gtk4::Shortcut::new(Some(trigger), Some(action)));
+    }
+    widget.add_controller(controller.clone());
+    Ok(controller)
+}
+
+/// Route the changes of a `gtk4::Expression`'s value to an Actix actor that can handle
+/// [`woab::Signal`](crate::Signal).
+///
+/// The expression is watched (with `this` as the object it is evaluated on) and whenever its value
+/// changes, it is evaluated and sent to the actor as a signal named `actix_signal`, whose only
+/// parameter is the new value. Changes that the expression cannot be evaluated after (e.g.
+/// because an object in the middle of a chain became `None`) are not sent.
+///
+/// Returns the `gtk4::ExpressionWatch` - call `unwatch` on it to stop the routing.
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// # use gtk4::prelude::*;
+/// # struct MyActor;
+/// # impl actix::Actor for MyActor { type Context = actix::Context<Self>; }
+/// # impl MyActor { fn set_title(&mut self, _: &str) {} }
+/// impl actix::Handler<woab::Signal> for MyActor {
+///     type Result = woab::SignalResult;
+///
+///     fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+///         Ok(match msg.name() {
+///             "selected_title_changed" => {
+///                 let woab::params!(title: Option<String>) = msg.params()?;
+///                 self.set_title(title.as_deref().unwrap_or(""));
+///                 None
+///             }
+///             _ => msg.cant_handle()?,
+///         })
+///     }
+/// }
+///
+/// # let selection: gtk4::SingleSelection = panic!();
+/// # let addr: actix::Addr<MyActor> = panic!();
+/// // The `string` of the selected `gtk4::StringObject`
+/// let expression = gtk4::PropertyExpression::new(
+///     gtk4::StringObject::static_type(),
+///     Some(gtk4::PropertyExpression::new(
+///         gtk4::SingleSelection::static_type(),
+///         None::<gtk4::Expression>,
+///         "selected-item",
+///     )),
+///     "string",
+/// );
+/// woab::route_expression(&expression, Some(&selection), "selected_title_changed", addr);
+/// ```
+pub fn route_expression(
+    expression: &impl AsRef<gtk4::Expression>,
+    this: Option<&impl glib::object::IsA<glib::Object>>,
+    actix_signal: &str,
+    target: impl IntoGenerateRoutingGtkHandler,
+) -> gtk4::ExpressionWatch {
+    use gtk4::prelude::*;
+
+    let callback = target.into_generate_routing_gtk_handler().raw_signal_callback(actix_signal);
+    let expression = expression.as_ref().clone();
+    let this = this.map(|this| this.upcast_ref::<glib::Object>().clone());
+    let this_ref = this.as_ref().map(|this| this.downgrade());
+    expression.clone().watch(this.as_ref(), move || {
+        let this = this_ref.as_ref().map(|this_ref| this_ref.upgrade());
+        let value = match this {
+            // `this` was finalized - GTK will remove the watch.
+            Some(None) => return,
+            Some(Some(this)) => expression.evaluate(Some(&this)),
+            None => expression.evaluate(None::<&glib::Object>),
+        };
+        if let Some(value) = value {
+            callback(&[value]);
+        }
+    })
+}
+
+/// Route the signals of a `gtk4::SearchEntry` to an Actix actor that can handle
+/// [`woab::Signal`](crate::Signal), debouncing the changes of the search text.
+///
+/// Instead of routing a signal for every keystroke, WoAB waits until the text did not change for
+/// the `debounce` duration, and only then routes a single `search_changed` signal. Clearing the
+/// text is routed immediately, so that the actor can restore the unfiltered view without delay.
+/// GTK's own `search-delay` is not used, so this works the same on all GTK versions.
+///
+/// The signals are routed as:
+///
+/// * `search_changed` - the search text changed (debounced).
+/// * `search_stopped` - the user pressed Escape (`stop-search`).
+/// * `search_activated` - the user pressed Enter (`activate`).
+///
+/// The only parameter of all of them is the search entry.
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// # use gtk4::prelude::*;
+/// # use std::time::Duration;
+/// # struct MyActor;
+/// # impl actix::Actor for MyActor { type Context = actix::Context<Self>; }
+/// # impl MyActor { fn filter(&mut self, _: &str) {} fn open_first_result(&mut self) {} }
+/// impl actix::Handler<woab::Signal> for MyActor {
+///     type Result = woab::SignalResult;
+///
+///     fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+///         Ok(match msg.name() {
+///             "search_changed" => {
+///                 let woab::params!(search_entry: gtk4::SearchEntry) = msg.params()?;
+///                 self.filter(&search_entry.text());
+///                 None
+///             }
+///             "search_stopped" => {
+///                 let woab::params!(search_entry: gtk4::SearchEntry) = msg.params()?;
+///                 search_entry.set_text("");
+///                 None
+///             }
+///             "search_activated" => {
+///                 self.open_first_result();
+///                 None
+///             }
+///             _ => msg.cant_handle()?,
+///         })
+///     }
+/// }
+///
+/// # let search_entry: gtk4::SearchEntry = panic!();
+/// # let addr: actix::Addr<MyActor> = panic!();
+/// woab::route_search(&search_entry, addr, Duration::from_millis(300)).unwrap();
+/// ```
+pub fn route_search(
+    search_entry: &gtk4::SearchEntry,
+    target: impl IntoGenerateRoutingGtkHandler,
+    debounce: core::time::Duration,
+) -> Result<Vec<glib::SignalHandlerId>, crate::Error> {
+    use std::cell::Cell;
+
+    use gtk4::prelude::*;
+
+    let generator = target.into_generate_routing_gtk_handler();
+    let callback = Rc::new(generator.raw_signal_callback("search_changed"));
+    let generation = Rc::new(Cell::new(0u64));
+    let changed_handler = search_entry.connect_changed(move |search_entry| {
+        let current_generation = generation.get() + 1;
+        generation.set(current_generation);
+        if search_entry.text().is_empty() {
+            callback(&[search_entry.to_value()]);
+            return;
+        }
+        let callback = callback.clone();
+        let search_entry_ref = search_entry.downgrade();
+        let generation = generation.clone();
+        // Use the Actix clock (and not a GLib timeout) so that tests can control it.
+        crate::prop_sync::spawn_in_actix(async move {
+            actix::clock::sleep(debounce).await;
+            // A newer change restarted the debounce.
+            if generation.get() != current_generation {
+                return;
+            }
+            if let Some(search_entry) = search_entry_ref.upgrade() {
+                callback(&[search_entry.to_value()]);
+            }
+        });
+    });
+    Ok(vec![
+        changed_handler,
+        generator.connect_local(search_entry, "stop-search", "search_stopped"),
+        generator.connect_local(search_entry, "activate", "search_activated"),
+    ])
+}
+
+/// The selection data of a signal routed with [`woab::route_selection`](route_selection).
+#[derive(Debug, Clone)]
+pub struct SelectionChange {
+    /// The selection model that emitted the signal.
+    pub model: gtk4::SelectionModel,
+    /// The position of the first item whose selection state may have changed.
+    pub position: u32,
+    /// The number of items whose selection state may have changed.
+    pub n_items: u32,
+    /// The positions of all the selected items.
+    pub selected_positions: Vec<u32>,
+    /// All the selected items.
+    pub selected_items: Vec<glib::Object>,
+}
+
 fn panic_if_signal_cannot_be_queued(signal_name: &str, parameters: &[glib::Value]) {
     for (i, param) in parameters.iter().enumerate() {
         let param_type = param.type_();
@@ -66,14 +578,34 @@ fn panic_if_signal_cannot_be_queued(signal_name: &str, parameters: &[glib::Value
     }
 }
 
-fn run_signal_routing_future(
+// The tag and the actor type are only used for tracing.
+#[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+fn run_signal_routing_future<T: 'static>(
     future: impl core::future::Future<Output = Result<Result<Option<glib::Propagation>, crate::Error>, actix::MailboxError>> + 'static,
-    signal_name: &Rc<String>,
-    parameters: &[glib::Value],
+    signal_name: glib::Quark,
+    tag: &T,
+    actor_type: Option<&'static str>,
+    parameters: Rc<[glib::Value]>,
 ) -> Option<glib::Value> {
+    #[cfg(feature = "metrics")]
+    let start = std::time::Instant::now();
+    #[cfg(feature = "tracing")]
+    let span = crate::signal_tracing::signal_span(signal_name, tag, actor_type);
+    #[cfg(feature = "tracing")]
+    let _entered = span.enter();
     match crate::try_block_on(future) {
         Ok(result) => {
-            let result = result.unwrap().unwrap();
+            record(signal_name, SignalOutcome::HandledImmediately);
+            #[cfg(feature = "tracing")]
+            tracing::trace!("signal handled inline");
+            #[cfg(feature = "metrics")]
+            crate::signal_metrics::record_latency(signal_name, start.elapsed());
+            let result = match result.unwrap() {
+                Err(error @ crate::Error::NoSuchSignalError { .. }) => {
+                    return handle_unmatched_signal(error, signal_name, &parameters);
+                }
+                result => result.unwrap(),
+            };
             if let Some(propagation) = result {
                 use glib::value::ToValue;
                 Some(propagation.is_proceed().to_value())
@@ -82,22 +614,39 @@ fn run_signal_routing_future(
             }
         }
         Err(future) => {
-            panic_if_signal_cannot_be_queued(signal_name, parameters);
-            let signal_name = signal_name.clone();
-            actix::spawn(async move {
-                let result = future.await.unwrap().unwrap();
+            record(signal_name, SignalOutcome::Queued);
+            #[cfg(feature = "tracing")]
+            tracing::debug!("signal queued because the Actix runtime is busy");
+            panic_if_signal_cannot_be_queued(signal_name.as_str(), &parameters);
+            let task = async move {
+                let result = future.await;
+                #[cfg(feature = "tracing")]
+                tracing::trace!("queued signal handled");
+                #[cfg(feature = "metrics")]
+                crate::signal_metrics::record_latency(signal_name, start.elapsed());
+                let result = match result.unwrap() {
+                    Err(error @ crate::Error::NoSuchSignalError { .. }) => {
+                        handle_unmatched_signal(error, signal_name, &parameters);
+                        return;
+                    }
+                    result => result.unwrap(),
+                };
                 if let Some(result) = result {
+                    record(signal_name, SignalOutcome::QueuedWithPropagation);
                     panic!(
                         concat!(
                             "Signal {:?}, was invoked inside the Actix runtime and had to be queued, ",
                             "but it returned {:?} - which is not supported for queued signals. ",
                             "Try running whatever triggered it with `woab::outside()` or `woab::spawn_outside()",
                         ),
-                        signal_name.as_str(),
+                        signal_name.as_str().as_str(),
                         result,
                     );
                 }
-            });
+            };
+            #[cfg(feature = "tracing")]
+            let task = tracing::Instrument::instrument(task, span.clone());
+            actix::spawn(task);
             None
         }
     }
@@ -107,29 +656,80 @@ fn run_signal_routing_future(
 pub trait GenerateRoutingGtkHandler {
     fn connect_local(&self, obj: &impl glib::object::ObjectExt, gtk_signal: &str, actix_signal: &str) -> glib::SignalHandlerId;
     fn register_into_builder_rust_scope(&self, scope: &gtk4::BuilderRustScope, signal_name: &str);
+    fn raw_signal_callback(&self, actix_signal: &str) -> RawSignalCallback;
 }
 
 fn route_with_tag_generate_impl<T: Clone + 'static>(
     signal_name: &str,
     tag: T,
     recipient: actix::Recipient<crate::Signal<T>>,
+    actor_type: Option<&'static str>,
 ) -> impl Fn(&[glib::Value]) -> Option<glib::Value> {
-    let signal_name = Rc::new(signal_name.to_owned());
+    let signal_name = glib::Quark::from_str(signal_name);
     move |parameters| {
-        let signal = crate::Signal::new(signal_name.clone(), parameters.to_owned(), tag.clone());
-        run_signal_routing_future(recipient.send(signal), &signal_name, parameters)
+        let parameters: Rc<[glib::Value]> = parameters.into();
+        let signal = crate::Signal::new(signal_name, parameters.clone(), tag.clone());
+        run_signal_routing_future(recipient.send(signal), signal_name, &tag, actor_type, parameters)
     }
 }
 
 impl<T: Clone + 'static> GenerateRoutingGtkHandler for (T, actix::Recipient<crate::Signal<T>>) {
     fn register_into_builder_rust_scope(&self, scope: &gtk4::BuilderRustScope, signal_name: &str) {
         let (tag, recipient) = self.clone();
-        scope.add_callback(signal_name, route_with_tag_generate_impl(signal_name, tag, recipient));
+        scope.add_callback(signal_name, route_with_tag_generate_impl(signal_name, tag, recipient, None));
     }
 
     fn connect_local(&self, obj: &impl glib::object::ObjectExt, gtk_signal: &str, actix_signal: &str) -> glib::SignalHandlerId {
         let (tag, recipient) = self.clone();
-        obj.connect_local(gtk_signal, false, route_with_tag_generate_impl(actix_signal, tag, recipient))
+        obj.connect_local(
+            gtk_signal,
+            false,
+            route_with_tag_generate_impl(actix_signal, tag, recipient, None),
+        )
+    }
+
+    fn raw_signal_callback(&self, actix_signal: &str) -> RawSignalCallback {
+        let (tag, recipient) = self.clone();
+        Box::new(route_with_tag_generate_impl(actix_signal, tag, recipient, None))
+    }
+}
+
+/// Routes signals with the builder ID of the emitting object as the tag. See
+/// [`BuilderFactory::instantiate_route_tagged_by_id`](crate::BuilderFactory::instantiate_route_tagged_by_id).
+pub(crate) struct TagByBuilderId(pub actix::Recipient<crate::Signal<String>>);
+
+impl TagByBuilderId {
+    fn generate_impl(&self, signal_name: &str) -> impl Fn(&[glib::Value]) -> Option<glib::Value> {
+        use gtk4::prelude::*;
+
+        let signal_name = glib::Quark::from_str(signal_name);
+        let recipient = self.0.clone();
+        move |parameters| {
+            let id = parameters
+                .first()
+                .and_then(|emitter| emitter.get::<glib::Object>().ok())
+                .and_then(|emitter| emitter.downcast::<gtk4::Buildable>().ok())
+                .and_then(|emitter| emitter.buildable_id())
+                .map(|id| id.to_string())
+                .unwrap_or_default();
+            let parameters: Rc<[glib::Value]> = parameters.into();
+            let signal = crate::Signal::new(signal_name, parameters.clone(), id.clone());
+            run_signal_routing_future(recipient.send(signal), signal_name, &id, None, parameters)
+        }
+    }
+}
+
+impl GenerateRoutingGtkHandler for TagByBuilderId {
+    fn register_into_builder_rust_scope(&self, scope: &gtk4::BuilderRustScope, signal_name: &str) {
+        scope.add_callback(signal_name, self.generate_impl(signal_name));
+    }
+
+    fn connect_local(&self, obj: &impl glib::object::ObjectExt, gtk_signal: &str, actix_signal: &str) -> glib::SignalHandlerId {
+        obj.connect_local(gtk_signal, false, self.generate_impl(actix_signal))
+    }
+
+    fn raw_signal_callback(&self, actix_signal: &str) -> RawSignalCallback {
+        Box::new(self.generate_impl(actix_signal))
     }
 }
 
@@ -156,17 +756,49 @@ impl IntoGenerateRoutingGtkHandler for actix::Recipient<crate::Signal> {
     }
 }
 
+// Unlike a `Recipient`, an `Addr` knows the type of its actor - which is used for tracing.
+impl<T: Clone + 'static, A: actix::Actor> GenerateRoutingGtkHandler for (T, actix::Addr<A>)
+where
+    A: actix::Actor,
+    A: actix::Handler<crate::Signal<T>>,
+    <A as actix::Actor>::Context: actix::dev::ToEnvelope<A, crate::Signal<T>>,
+{
+    fn register_into_builder_rust_scope(&self, scope: &gtk4::BuilderRustScope, signal_name: &str) {
+        let (tag, actor) = self.clone();
+        let actor_type = Some(core::any::type_name::<A>());
+        scope.add_callback(
+            signal_name,
+            route_with_tag_generate_impl(signal_name, tag, actor.recipient(), actor_type),
+        );
+    }
+
+    fn connect_local(&self, obj: &impl glib::object::ObjectExt, gtk_signal: &str, actix_signal: &str) -> glib::SignalHandlerId {
+        let (tag, actor) = self.clone();
+        let actor_type = Some(core::any::type_name::<A>());
+        obj.connect_local(
+            gtk_signal,
+            false,
+            route_with_tag_generate_impl(actix_signal, tag, actor.recipient(), actor_type),
+        )
+    }
+
+    fn raw_signal_callback(&self, actix_signal: &str) -> RawSignalCallback {
+        let (tag, actor) = self.clone();
+        let actor_type = Some(core::any::type_name::<A>());
+        Box::new(route_with_tag_generate_impl(actix_signal, tag, actor.recipient(), actor_type))
+    }
+}
+
 impl<T: Clone + 'static, A: actix::Actor> IntoGenerateRoutingGtkHandler for (T, actix::Addr<A>)
 where
     A: actix::Actor,
     A: actix::Handler<crate::Signal<T>>,
     <A as actix::Actor>::Context: actix::dev::ToEnvelope<A, crate::Signal<T>>,
 {
-    type Generator = (T, actix::Recipient<crate::Signal<T>>);
+    type Generator = Self;
 
     fn into_generate_routing_gtk_handler(self) -> Self::Generator {
-        let (tag, actor) = self;
-        (tag, actor.recipient())
+        self
     }
 }
 
@@ -176,10 +808,10 @@ where
     A: actix::Handler<crate::Signal>,
     <A as actix::Actor>::Context: actix::dev::ToEnvelope<A, crate::Signal>,
 {
-    type Generator = ((), actix::Recipient<crate::Signal>);
+    type Generator = ((), Self);
 
     fn into_generate_routing_gtk_handler(self) -> Self::Generator {
-        ((), self.recipient())
+        ((), self)
     }
 }
 
@@ -193,6 +825,7 @@ pub struct NamespacedSignalRouter<T> {
 struct NamespacedSignalRouterTarget<T> {
     recipient: actix::Recipient<crate::Signal<T>>,
     strip_namespace: bool,
+    actor_type: Option<&'static str>,
 }
 
 /// Split signals from the same builder to multiple actors, based on namespaces.
@@ -272,6 +905,7 @@ impl<T> NamespacedSignalRouter<T> {
             NamespacedSignalRouterTarget {
                 recipient,
                 strip_namespace: false,
+                actor_type: None,
             },
         );
         self
@@ -284,6 +918,7 @@ impl<T> NamespacedSignalRouter<T> {
             NamespacedSignalRouterTarget {
                 recipient,
                 strip_namespace: true,
+                actor_type: None,
             },
         );
         self
@@ -310,6 +945,7 @@ impl<T> NamespacedSignalRouter<T> {
             NamespacedSignalRouterTarget {
                 recipient: actor.recipient(),
                 strip_namespace: true,
+                actor_type: Some(core::any::type_name::<A>()),
             },
         );
         self
@@ -337,19 +973,23 @@ impl<T: Clone + 'static> NamespacedSignalRouter<T> {
             panic!("Unknown namespace {:?}", signal_namespace)
         };
 
-        let signal_name = Rc::new(
-            if target.strip_namespace {
-                let (_, without_namespace) = signal_name.split_at(signal_namespace.len() + 2);
-                without_namespace
-            } else {
-                signal_name
-            }
-            .to_owned(),
-        );
+        let signal_name = glib::Quark::from_str(if target.strip_namespace {
+            let (_, without_namespace) = signal_name.split_at(signal_namespace.len() + 2);
+            without_namespace
+        } else {
+            signal_name
+        });
         let tag = tag.clone();
         move |parameters| {
-            let signal = crate::Signal::new(signal_name.clone(), parameters.to_owned(), tag.clone());
-            run_signal_routing_future(target.recipient.send(signal), &signal_name, parameters)
+            let parameters: Rc<[glib::Value]> = parameters.into();
+            let signal = crate::Signal::new(signal_name, parameters.clone(), tag.clone());
+            run_signal_routing_future(
+                target.recipient.send(signal),
+                signal_name,
+                &tag,
+                target.actor_type,
+                parameters,
+            )
         }
     }
 }
@@ -364,6 +1004,11 @@ impl<T: Clone + 'static> crate::GenerateRoutingGtkHandler for (T, NamespacedSign
         let (tag, router) = self;
         obj.connect_local(gtk_signal, false, router.generate_impl(actix_signal, tag.clone()))
     }
+
+    fn raw_signal_callback(&self, actix_signal: &str) -> RawSignalCallback {
+        let (tag, router) = self;
+        Box::new(router.generate_impl(actix_signal, tag.clone()))
+    }
 }
 
 impl<T: Clone + 'static> IntoGenerateRoutingGtkHandler for (T, NamespacedSignalRouter<T>) {
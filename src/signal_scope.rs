@@ -0,0 +1,102 @@
+use glib::object::{Cast, IsA, ObjectExt};
+
+/// A collection of signal connections that are disconnected together.
+///
+/// Signals connected with [`woab::route_signal`](crate::route_signal) (or directly with
+/// `connect_local`) keep a reference to their target for as long as the GTK object lives. An
+/// actor that connects signals of objects that outlive it (e.g. of a shared model, or of the
+/// application) should keep the connections in a `SignalScope` - which disconnects them when it
+/// is dropped (usually when the actor stops) or when [`clear`](SignalScope::clear) is called.
+///
+/// When the actor derives [`woab::Removable`](crate::Removable), the `signal_scope = ...`
+/// parameter makes the removal clear the scope as well.
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// struct MyActor {
+///     signals: woab::SignalScope,
+/// }
+/// # impl actix::Actor for MyActor { type Context = actix::Context<Self>; }
+/// # impl actix::Handler<woab::Signal> for MyActor {
+/// #     type Result = woab::SignalResult;
+/// #     fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+/// #         msg.cant_handle()
+/// #     }
+/// # }
+///
+/// # let app: gtk4::Application = panic!();
+/// # let quit_action: gio::SimpleAction = panic!();
+/// MyActor::create(|ctx| {
+///     let mut signals = woab::SignalScope::new();
+///     signals.route_signal(&app, "window-removed", "window_removed", ctx.address()).unwrap();
+///     signals.route_action(&quit_action, ctx.address()).unwrap();
+///     MyActor { signals }
+/// });
+/// ```
+#[derive(Default)]
+pub struct SignalScope {
+    connections: Vec<(glib::WeakRef<glib::Object>, glib::SignalHandlerId)>,
+}
+
+impl SignalScope {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a signal connection to the scope.
+    pub fn add(&mut self, obj: &impl IsA<glib::Object>, handler_id: glib::SignalHandlerId) {
+        self.connections
+            .push((obj.upcast_ref::<glib::Object>().downgrade(), handler_id));
+    }
+
+    /// Like [`woab::route_signal`](crate::route_signal), but the connection is added to the scope.
+    pub fn route_signal(
+        &mut self,
+        obj: &impl IsA<glib::Object>,
+        gtk_signal: &str,
+        actix_signal: &str,
+        target: impl crate::IntoGenerateRoutingGtkHandler,
+    ) -> crate::Result<()> {
+        let handler_id = crate::route_signal(obj, gtk_signal, actix_signal, target)?;
+        self.add(obj, handler_id);
+        Ok(())
+    }
+
+    /// Like [`woab::route_action`](crate::route_action), but the connection is added to the scope.
+    pub fn route_action(
+        &mut self,
+        action: &(impl IsA<glib::Object> + gio::prelude::ActionExt),
+        target: impl crate::IntoGenerateRoutingGtkHandler,
+    ) -> crate::Result<()> {
+        let handler_id = crate::route_action(action, target)?;
+        self.add(action, handler_id);
+        Ok(())
+    }
+
+    /// The number of connections in the scope.
+    pub fn len(&self) -> usize {
+        self.connections.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.connections.is_empty()
+    }
+
+    /// Disconnect all the signals in the scope.
+    ///
+    /// The scope can still be used for connecting new signals afterwards.
+    pub fn clear(&mut self) {
+        for (obj, handler_id) in self.connections.drain(..) {
+            // If the object was already destroyed, its signals were disconnected with it.
+            if let Some(obj) = obj.upgrade() {
+                obj.disconnect(handler_id);
+            }
+        }
+    }
+}
+
+impl Drop for SignalScope {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
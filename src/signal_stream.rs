@@ -0,0 +1,154 @@
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use glib::object::{Cast, IsA, ObjectExt};
+use tokio::sync::mpsc;
+
+/// A stream of GTK signal emissions.
+///
+/// Created by [`woab::signal_stream`](signal_stream),
+/// [`woab::route_signal_stream`](route_signal_stream) or
+/// [`woab::signal_stream_from_builder`](signal_stream_from_builder). The signal handler stays
+/// connected for as long as the stream lives, and is disconnected when the stream is dropped
+/// (except for signals streamed from a builder).
+pub struct SignalStream<T = ()> {
+    rx: mpsc::UnboundedReceiver<crate::Signal<T>>,
+    connection: Option<(glib::WeakRef<glib::Object>, glib::SignalHandlerId)>,
+}
+
+/// Stream the emissions of a GTK signal as [`woab::Signal`](crate::Signal)s.
+///
+/// Unlike [`woab::wake_from_signal`](crate::wake_from_signal), which resolves once and
+/// disconnects, the returned stream yields every emission of the signal until it is dropped. The
+/// name of the yielded signals is the name of the GTK signal.
+///
+/// This is useful for adding a widget's events to an actor with `add_stream`, without having to
+/// route them:
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// struct MyActor;
+///
+/// impl actix::Actor for MyActor {
+///     type Context = actix::Context<Self>;
+/// }
+///
+/// impl actix::StreamHandler<woab::Signal> for MyActor {
+///     fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) {
+///         // ...
+///     }
+/// }
+///
+/// # let button: gtk4::Button = panic!();
+/// MyActor::create(|ctx| {
+///     ctx.add_stream(woab::signal_stream(&button, "clicked"));
+///     MyActor
+/// });
+/// ```
+///
+/// # Pitfalls
+///
+/// * The signal is not handled while it is being emitted - it is only queued to the stream - so
+///   it cannot be used with signals that require a return value (like a propagation decision).
+/// * If the object is destroyed, the stream will end.
+pub fn signal_stream(obj: &impl IsA<glib::Object>, gtk_signal: &str) -> SignalStream {
+    route_signal_stream(obj, gtk_signal, gtk_signal)
+}
+
+/// Like [`woab::route_signal`](crate::route_signal), but instead of sending the signals to an
+/// actor, return them as a stream.
+///
+/// This allows consuming routed signals without implementing `actix::Handler<woab::Signal>` -
+/// e.g. in a plain async task, or in another framework embedded in the same application. Like in
+/// [`woab::signal_stream`](signal_stream), the signal handler stays connected for as long as the
+/// stream lives.
+///
+/// ```no_run
+/// # use futures_util::StreamExt;
+/// # async fn asyncfunc() {
+/// # let button: gtk4::Button = panic!();
+/// let mut stream = woab::route_signal_stream(&button, "clicked", "button_clicked");
+/// while let Some(signal) = stream.next().await {
+///     assert_eq!(signal.name(), "button_clicked");
+/// }
+/// # }
+/// ```
+///
+/// * The `actix_signal` argument is the signal name used for identifying the signal in the
+///   stream.
+pub fn route_signal_stream(obj: &impl IsA<glib::Object>, gtk_signal: &str, actix_signal: &str) -> SignalStream {
+    let (tx, rx) = mpsc::unbounded_channel();
+    let obj = obj.upcast_ref::<glib::Object>();
+    let handler_id = obj.connect_local(gtk_signal, false, send_to_stream(tx, actix_signal));
+    SignalStream {
+        rx,
+        connection: Some((obj.downgrade(), handler_id)),
+    }
+}
+
+/// Create the widgets of a builder factory, and stream the signals declared in its XML.
+///
+/// This is the stream equivalent of
+/// [`BuilderFactory::instantiate_route_to`](crate::BuilderFactory::instantiate_route_to) - the
+/// signals have the handler names from the XML, just like when they are routed to an actor.
+///
+/// ```no_run
+/// # use futures_util::StreamExt;
+/// # async fn asyncfunc() {
+/// # let builder_factory: woab::BuilderFactory = panic!();
+/// let (bld, mut stream) = woab::signal_stream_from_builder(&builder_factory);
+/// bld.get_object::<gtk4::ApplicationWindow>("main_window").unwrap().present();
+/// while let Some(signal) = stream.next().await {
+///     match signal.name() {
+///         "button_clicked" => {
+///             // ...
+///         }
+///         _ => {}
+///     }
+/// }
+/// # }
+/// ```
+///
+/// The signal handlers are owned by the builder's widgets, so dropping the stream does not
+/// disconnect them - their emissions are simply discarded. The stream ends once all the widgets
+/// that can emit the signals are destroyed.
+///
+/// # Pitfalls
+///
+/// * Like with [`woab::signal_stream`](signal_stream), the signals are only queued to the stream,
+///   so signals that require a return value are not supported.
+pub fn signal_stream_from_builder(factory: &crate::BuilderFactory) -> (crate::BuilderWidgets, SignalStream) {
+    let (tx, rx) = mpsc::unbounded_channel();
+    let scope = gtk4::BuilderRustScope::new();
+    for signal_name in factory.signals.iter() {
+        scope.add_callback(signal_name, send_to_stream(tx.clone(), signal_name));
+    }
+    let bld = factory.instantiate_with_scope(&scope);
+    (bld, SignalStream { rx, connection: None })
+}
+
+fn send_to_stream(tx: mpsc::UnboundedSender<crate::Signal>, signal_name: &str) -> impl Fn(&[glib::Value]) -> Option<glib::Value> {
+    let signal_name = glib::Quark::from_str(signal_name);
+    move |parameters| {
+        let _ = tx.send(crate::Signal::new(signal_name, parameters, ()));
+        None
+    }
+}
+
+impl<T> futures_core::Stream for SignalStream<T> {
+    type Item = crate::Signal<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx)
+    }
+}
+
+impl<T> Drop for SignalStream<T> {
+    fn drop(&mut self) {
+        if let Some((obj, handler_id)) = self.connection.take() {
+            if let Some(obj) = obj.upgrade() {
+                obj.disconnect(handler_id);
+            }
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use core::any::Any;
+
+/// The span of a routed signal, from the moment GTK emitted it until its handler returned.
+///
+/// The actor type is only known when the signal was routed to an `Addr` (and not to a
+/// `Recipient`). The tag is only recorded when it is a string or an integer - for other tags only
+/// their type is recorded.
+pub(crate) fn signal_span<T: 'static>(signal_name: glib::Quark, tag: &T, actor_type: Option<&'static str>) -> tracing::Span {
+    let span = tracing::debug_span!(
+        "woab_signal",
+        signal = signal_name.as_str().as_str(),
+        actor = tracing::field::Empty,
+        tag_type = core::any::type_name::<T>(),
+        tag = tracing::field::Empty,
+    );
+    if let Some(actor_type) = actor_type {
+        span.record("actor", actor_type);
+    }
+    let tag = tag as &dyn Any;
+    if let Some(tag) = tag.downcast_ref::<String>() {
+        span.record("tag", tag.as_str());
+    } else if let Some(tag) = tag.downcast_ref::<&'static str>() {
+        span.record("tag", *tag);
+    } else if let Some(tag) = tag.downcast_ref::<usize>() {
+        span.record("tag", *tag as u64);
+    } else if let Some(tag) = tag.downcast_ref::<u64>() {
+        span.record("tag", *tag);
+    } else if let Some(tag) = tag.downcast_ref::<u32>() {
+        span.record("tag", *tag);
+    } else if let Some(tag) = tag.downcast_ref::<i64>() {
+        span.record("tag", *tag);
+    } else if let Some(tag) = tag.downcast_ref::<i32>() {
+        span.record("tag", *tag);
+    }
+    span
+}
@@ -0,0 +1,220 @@
+//! Show status messages in a label, from anywhere in the application.
+//!
+//! See [`StatusBar`] for usage instructions.
+
+use core::cell::RefCell;
+use core::time::Duration;
+
+use actix::prelude::*;
+
+/// Identifies a message pushed to a [`StatusBar`], for [`PopStatus`]-ing it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StatusId(u64);
+
+struct StatusMessage {
+    id: StatusId,
+    text: String,
+    context: Option<String>,
+    priority: i32,
+}
+
+/// An actor that shows status messages in a `gtk4::Label`.
+///
+/// * [`PushStatus`] - add a message. Resolves to a [`StatusId`].
+/// * [`PopStatus`] - remove a message using its `StatusId`.
+/// * [`ClearStatusContext`] - remove all the messages of a context.
+///
+/// Only one message is shown at a time - the one with the highest priority, and among messages
+/// with the same priority the one pushed last. When that message is removed (explicitly or because
+/// it expired) the next one is shown. Pushing a message with a context replaces the previous
+/// message of the same context, which is useful for messages like "Saving..." that should only
+/// appear once.
+///
+/// If a `gtk4::Revealer` is set, it is revealed only while there are messages to show.
+///
+/// A status bar can be made [global](StatusBar::start_global), so that any actor can push
+/// messages to it without having its address:
+///
+/// ```no_run
+/// # use core::time::Duration;
+/// use woab::status_bar::{PushStatus, StatusBar};
+///
+/// # let status_label: gtk4::Label = panic!();
+/// # let status_revealer: gtk4::Revealer = panic!();
+/// StatusBar::new(&status_label).revealer(&status_revealer).start_global();
+///
+/// // Elsewhere:
+/// if let Some(status_bar) = StatusBar::global() {
+///     status_bar.do_send(PushStatus::new("File saved").expire_after(Duration::from_secs(3)));
+/// }
+/// ```
+pub struct StatusBar {
+    label: gtk4::Label,
+    revealer: Option<gtk4::Revealer>,
+    messages: Vec<StatusMessage>,
+    last_id: u64,
+}
+
+thread_local! {
+    static GLOBAL_STATUS_BAR: RefCell<Option<actix::Addr<StatusBar>>> = const { RefCell::new(None) };
+}
+
+impl StatusBar {
+    /// Create a status bar that shows its messages in the label.
+    pub fn new(label: &gtk4::Label) -> Self {
+        Self {
+            label: label.clone(),
+            revealer: None,
+            messages: Vec::new(),
+            last_id: 0,
+        }
+    }
+
+    /// Reveal the revealer only while there are messages to show.
+    pub fn revealer(mut self, revealer: &gtk4::Revealer) -> Self {
+        self.revealer = Some(revealer.clone());
+        self
+    }
+
+    /// Start the actor, and make it the status bar returned by [`StatusBar::global`].
+    pub fn start_global(self) -> actix::Addr<Self> {
+        let addr = self.start();
+        GLOBAL_STATUS_BAR.with(|global| *global.borrow_mut() = Some(addr.clone()));
+        addr
+    }
+
+    /// The status bar started with [`start_global`](StatusBar::start_global).
+    ///
+    /// Returns `None` if there is no global status bar, or if it was stopped.
+    pub fn global() -> Option<actix::Addr<Self>> {
+        GLOBAL_STATUS_BAR.with(|global| global.borrow().clone().filter(|addr| addr.connected()))
+    }
+
+    fn update(&self) {
+        let current = self.messages.iter().max_by_key(|message| (message.priority, message.id));
+        self.label.set_text(current.map_or("", |message| message.text.as_str()));
+        if let Some(revealer) = &self.revealer {
+            revealer.set_reveal_child(current.is_some());
+        }
+    }
+
+    fn remove(&mut self, id: StatusId) -> bool {
+        let len_before = self.messages.len();
+        self.messages.retain(|message| message.id != id);
+        let removed = self.messages.len() < len_before;
+        if removed {
+            self.update();
+        }
+        removed
+    }
+}
+
+impl actix::Actor for StatusBar {
+    type Context = actix::Context<Self>;
+
+    fn started(&mut self, _ctx: &mut Self::Context) {
+        self.update();
+    }
+}
+
+/// Add a message to a [`StatusBar`].
+pub struct PushStatus {
+    pub text: String,
+    /// A message with a context replaces the previous message of the same context.
+    pub context: Option<String>,
+    /// Messages with higher priority are shown before messages with lower priority. The default
+    /// is 0.
+    pub priority: i32,
+    /// Remove the message automatically after this duration.
+    pub expire_after: Option<Duration>,
+}
+
+impl PushStatus {
+    /// A message with no context, a priority of 0, and no expiry.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            context: None,
+            priority: 0,
+            expire_after: None,
+        }
+    }
+
+    pub fn context(mut self, context: impl Into<String>) -> Self {
+        self.context = Some(context.into());
+        self
+    }
+
+    pub fn priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    pub fn expire_after(mut self, duration: Duration) -> Self {
+        self.expire_after = Some(duration);
+        self
+    }
+}
+
+impl actix::Message for PushStatus {
+    type Result = StatusId;
+}
+
+impl actix::Handler<PushStatus> for StatusBar {
+    type Result = actix::MessageResult<PushStatus>;
+
+    fn handle(&mut self, msg: PushStatus, ctx: &mut Self::Context) -> Self::Result {
+        self.last_id += 1;
+        let id = StatusId(self.last_id);
+        if let Some(context) = &msg.context {
+            self.messages.retain(|message| message.context.as_ref() != Some(context));
+        }
+        self.messages.push(StatusMessage {
+            id,
+            text: msg.text,
+            context: msg.context,
+            priority: msg.priority,
+        });
+        self.update();
+        if let Some(expire_after) = msg.expire_after {
+            ctx.run_later(expire_after, move |act, _ctx| {
+                act.remove(id);
+            });
+        }
+        actix::MessageResult(id)
+    }
+}
+
+/// Remove a message from a [`StatusBar`].
+///
+/// Resolves to `false` if there was no such message (e.g. because it already expired).
+pub struct PopStatus(pub StatusId);
+
+impl actix::Message for PopStatus {
+    type Result = bool;
+}
+
+impl actix::Handler<PopStatus> for StatusBar {
+    type Result = bool;
+
+    fn handle(&mut self, msg: PopStatus, _ctx: &mut Self::Context) -> Self::Result {
+        self.remove(msg.0)
+    }
+}
+
+/// Remove all the messages of a context from a [`StatusBar`].
+pub struct ClearStatusContext(pub String);
+
+impl actix::Message for ClearStatusContext {
+    type Result = ();
+}
+
+impl actix::Handler<ClearStatusContext> for StatusBar {
+    type Result = ();
+
+    fn handle(&mut self, msg: ClearStatusContext, _ctx: &mut Self::Context) -> Self::Result {
+        self.messages
+            .retain(|message| message.context.as_deref() != Some(msg.0.as_str()));
+        self.update();
+    }
+}
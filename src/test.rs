@@ -0,0 +1,319 @@
+//! Utilities for testing WoAB applications.
+//!
+//! Requires the `test-util` feature:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! woab = { version = "*", features = ["test-util"] }
+//! ```
+
+use core::cell::Cell;
+use core::future::Future;
+use core::time::Duration;
+use std::rc::Rc;
+use std::time::Instant;
+
+use gtk4::prelude::*;
+
+pub mod clock;
+pub mod dialogs;
+mod fake_widgets;
+pub mod simulate;
+pub mod snapshot;
+
+pub use fake_widgets::{FakeWidget, FakeWidgetCall, FakeWidgets};
+
+/// How many GTK loop iterations [`drain_pending_events`] runs.
+const DRAIN_ITERATIONS: usize = 10;
+
+/// Run a test inside GTK and Actix.
+///
+/// This function:
+///
+/// 1. Initializes GTK and starts the Actix runtime inside the GTK event loop.
+/// 2. Runs the future inside a `gtk4::Application`.
+/// 3. Processes the GTK events that are still pending after the future is done (see
+///    [`drain_pending_events`]).
+/// 4. Closes the Actix runtime.
+///
+/// ```no_run
+/// #[test]
+/// fn test_something() -> woab::Result<()> {
+///     woab::test::test_main(async {
+///         // Start actors, emit signals, check the results...
+///         Ok(())
+///     })
+/// }
+/// ```
+///
+/// Because GTK can only be used from a single thread, and because the Actix runtime is
+/// thread-local, each test that uses this function must run in its own process. The simplest way
+/// to achieve this is to have a single test function in each file in the `tests` directory.
+pub fn test_main<E>(fut: impl 'static + Future<Output = Result<(), E>>) -> Result<(), E>
+where
+    E: 'static + From<crate::Error>,
+{
+    gtk4::init().map_err(crate::Error::from)?;
+    crate::run_actix_inside_gtk_event_loop();
+    let app = gtk4::Application::default();
+
+    let fut = Cell::new(Some(fut));
+    let res = Rc::new(Cell::new(None));
+    app.connect_activate({
+        let res = res.clone();
+        move |app| {
+            let fut = fut.take().unwrap();
+            res.set(Some(crate::block_on(fut)));
+            drain_pending_events();
+            app.quit();
+        }
+    });
+    let exit_code = app.run_with_args::<&str>(&[]);
+    crate::close_actix_runtime()
+        .map_err(crate::Error::from)?
+        .map_err(crate::Error::from)?;
+    res.take()
+        .unwrap_or_else(|| Err(crate::Error::GtkBadExitCode(exit_code).into()))
+}
+
+/// Run a test inside Actix and a GLib event loop, without initializing GTK.
+///
+/// Useful for testing the logic of the application (actors, GIO actions, signal routing of
+/// non-widget objects...) in environments that have no display, like CI containers. Widgets
+/// cannot be created inside such tests - use [`has_display`] to skip them, or run them with
+/// [`test_main`] under a virtual display (e.g. `xvfb-run`, or `GDK_BACKEND=broadway` with a
+/// running `gtk4-broadwayd`).
+///
+/// ```no_run
+/// #[test]
+/// fn test_logic() -> woab::Result<()> {
+///     woab::test::headless_main(async {
+///         let action = gio::SimpleAction::new("action", None);
+///         // Route the action to an actor, activate it, check the results...
+///         Ok(())
+///     })
+/// }
+/// ```
+pub fn headless_main<E>(fut: impl 'static + Future<Output = Result<(), E>>) -> Result<(), E>
+where
+    E: 'static + From<crate::Error>,
+{
+    crate::run_actix_inside_gtk_event_loop();
+    let main_loop = glib::MainLoop::new(None, false);
+
+    let res = Rc::new(Cell::new(None));
+    glib::idle_add_local_once({
+        let res = res.clone();
+        let main_loop = main_loop.clone();
+        move || {
+            res.set(Some(crate::block_on(fut)));
+            drain_pending_events();
+            main_loop.quit();
+        }
+    });
+    main_loop.run();
+    crate::close_actix_runtime()
+        .map_err(crate::Error::from)?
+        .map_err(crate::Error::from)?;
+    res.take().expect("the main loop exited before the test was done")
+}
+
+/// Like [`test_main`], but for tests that need the GLib main loop to run while they wait (e.g.
+/// for GLib timers, or for futures spawned outside the Actix runtime).
+///
+/// `setup` runs inside the Actix runtime, and then the main loop runs until `condition` is true.
+/// If it is still false after the timeout, the test fails with [`TimedOut`].
+///
+/// ```no_run
+/// # use std::cell::Cell;
+/// # use std::rc::Rc;
+/// #[test]
+/// fn test_timer() -> anyhow::Result<()> {
+///     let fired = Rc::new(Cell::new(false));
+///     woab::test::test_main_loop_until(
+///         || {
+///             let fired = fired.clone();
+///             glib::timeout_add_local_once(std::time::Duration::from_millis(10), move || fired.set(true));
+///         },
+///         || fired.get(),
+///         std::time::Duration::from_secs(1),
+///     )
+/// }
+/// ```
+pub fn test_main_loop_until<E>(setup: impl FnOnce(), mut condition: impl FnMut() -> bool, timeout: Duration) -> Result<(), E>
+where
+    E: From<crate::Error> + From<TimedOut>,
+{
+    gtk4::init().map_err(crate::Error::from)?;
+    crate::run_actix_inside_gtk_event_loop();
+    crate::block_on(async { setup() });
+    let started = Instant::now();
+    let context = glib::MainContext::default();
+    let result = loop {
+        if condition() {
+            break Ok(());
+        } else if timeout < started.elapsed() {
+            break Err(TimedOut {
+                condition: None,
+                waited: started.elapsed(),
+                last_state: None,
+            });
+        }
+        context.iteration(false);
+    };
+    crate::close_actix_runtime()
+        .map_err(crate::Error::from)?
+        .map_err(crate::Error::from)?;
+    Ok(result?)
+}
+
+/// Check if GTK can be initialized - that is, if there is a display to connect to.
+///
+/// Note that this initializes GTK if it can be initialized. Tests that use [`headless_main`] can
+/// use it to skip the parts that require widgets.
+pub fn has_display() -> bool {
+    gtk4::is_initialized() || gtk4::init().is_ok()
+}
+
+/// Process the GTK events that are already pending, without waiting for new ones.
+///
+/// Useful after triggering something that queues GTK events (e.g. emitting a signal from inside
+/// the Actix runtime), to let these events run before checking their results.
+pub fn drain_pending_events() {
+    let context = glib::MainContext::default();
+    // WoAB's Actix runtime is cranked by an idle source, so there are always pending events and we
+    // cannot wait until there are none.
+    for _ in 0..DRAIN_ITERATIONS {
+        if !context.iteration(false) {
+            break;
+        }
+    }
+}
+
+async fn sleep_briefly() {
+    actix::clock::sleep(Duration::new(0, 1_000)).await;
+}
+
+/// Raised by [`wait_until`] and [`wait_for!`](crate::wait_for) when the condition does not
+/// become true in time.
+#[derive(Debug)]
+pub struct TimedOut {
+    /// The condition that was waited for, as written in the code (only when using
+    /// [`wait_for!`](crate::wait_for)).
+    pub condition: Option<&'static str>,
+    /// How long the condition was polled.
+    pub waited: Duration,
+    /// The `Debug` representation of the last state reported by the condition (only when using
+    /// [`wait_until`]).
+    pub last_state: Option<String>,
+}
+
+impl std::fmt::Display for TimedOut {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Timed out after {:?}", self.waited)?;
+        if let Some(condition) = self.condition {
+            write!(f, ": {}", condition)?;
+        }
+        if let Some(last_state) = &self.last_state {
+            write!(f, " (last state: {})", last_state)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for TimedOut {}
+
+async fn poll_condition<T>(mut condition: impl FnMut() -> Result<T, Option<String>>, timeout: Duration) -> Result<T, TimedOut> {
+    let started = Instant::now();
+    loop {
+        let is_over = timeout < started.elapsed();
+        match condition() {
+            Ok(result) => return Ok(result),
+            Err(last_state) if is_over => {
+                return Err(TimedOut {
+                    condition: None,
+                    waited: started.elapsed(),
+                    last_state,
+                });
+            }
+            Err(_) => {}
+        }
+        sleep_briefly().await;
+    }
+}
+
+/// Wait (asynchronously) until a condition is met, letting GTK and Actix process events in the
+/// meantime.
+///
+/// The condition returns `Ok` when it is met - and `wait_until` resolves to that value - or `Err`
+/// with the current state when it is not. If the condition is not met within the timeout,
+/// `wait_until` resolves to [`TimedOut`], which includes the last state (formatted with `Debug`)
+/// to help figuring out why the test failed.
+///
+/// ```no_run
+/// # use std::cell::RefCell;
+/// # use std::time::Duration;
+/// # async fn asyncfunc() -> Result<(), woab::test::TimedOut> {
+/// # let output = RefCell::new(Vec::<&str>::new());
+/// woab::test::wait_until(
+///     || {
+///         let output = output.borrow();
+///         if *output == ["init", "clicked"] {
+///             Ok(())
+///         } else {
+///             Err(output.clone())
+///         }
+///     },
+///     Duration::from_secs(1),
+/// )
+/// .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn wait_until<T, S: std::fmt::Debug>(
+    mut condition: impl FnMut() -> Result<T, S>,
+    timeout: Duration,
+) -> Result<T, TimedOut> {
+    poll_condition(|| condition().map_err(|state| Some(format!("{:?}", state))), timeout).await
+}
+
+#[doc(hidden)]
+pub async fn wait_for_impl(
+    condition_text: &'static str,
+    mut condition: impl FnMut() -> bool,
+    timeout: Duration,
+) -> Result<(), TimedOut> {
+    poll_condition(|| if condition() { Ok(()) } else { Err(None) }, timeout)
+        .await
+        .map_err(|err| TimedOut {
+            condition: Some(condition_text),
+            ..err
+        })
+}
+
+/// Wait (asynchronously) until a condition becomes true.
+///
+/// Resolves to `Ok(())` once the condition is true, or to `Err(woab::test::TimedOut)` if the
+/// condition is still false after a second (or after the timeout given as a second argument).
+/// Must be used inside an async block (e.g. the one passed to
+/// [`woab::test::test_main`](crate::test::test_main)). Use [`wait_until`] for more detailed
+/// errors.
+///
+/// ```no_run
+/// # use std::cell::Cell;
+/// # async fn asyncfunc() -> Result<(), woab::test::TimedOut> {
+/// # let counter = Cell::new(0);
+/// woab::wait_for!(counter.get() == 2)?;
+/// woab::wait_for!(counter.get() == 3, std::time::Duration::from_secs(5))?;
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! wait_for {
+    ($pred:expr) => {
+        $crate::wait_for!($pred, core::time::Duration::from_secs(1))
+    };
+    ($pred:expr, $timeout:expr) => {
+        $crate::test::wait_for_impl(stringify!($pred), || $pred, $timeout).await
+    };
+}
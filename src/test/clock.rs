@@ -0,0 +1,58 @@
+//! Control the time of the Actix runtime in tests.
+//!
+//! While the clock is paused, time only moves forward with [`advance`] - so tests of debounced
+//! signals (like [`woab::prop_sync::route_changes`](crate::prop_sync::route_changes)), animations
+//! and timeouts can check their behavior at exact points in time instead of sleeping. This
+//! affects everything that uses `actix::clock` (including `AsyncContext::run_later` and
+//! `AsyncContext::run_interval`) but not GLib timeouts or `std::time::Instant`.
+//!
+//! ```no_run
+//! # async fn asyncfunc() {
+//! use core::time::Duration;
+//!
+//! woab::test::clock::pause();
+//! // Change a text that's routed with a debounce of 300ms...
+//! woab::test::clock::advance(Duration::from_millis(299)).await;
+//! // Check that the signal was not routed yet...
+//! woab::test::clock::advance(Duration::from_millis(1)).await;
+//! // Check that the signal was routed...
+//! woab::test::clock::resume();
+//! # }
+//! ```
+//!
+//! Like `tokio::time::pause`, when the runtime has no work to do while the clock is paused, the
+//! clock jumps forward to the next pending timer. [`advance`] is still the only way to move the
+//! clock while the test is running a busy loop (e.g. in [`wait_until`](crate::test::wait_until)).
+
+use core::time::Duration;
+
+/// Stop the clock of the Actix runtime.
+///
+/// Must be called from inside the Actix runtime (e.g. from inside the future passed to
+/// [`test_main`](crate::test::test_main)), and the clock must not already be paused.
+pub fn pause() {
+    tokio::time::pause();
+    crate::event_loops_bridge::CRANK_WITHOUT_SLEEPING.with(|flag| flag.set(true));
+}
+
+/// Let the clock of the Actix runtime run again.
+///
+/// Must be called from inside the Actix runtime, and the clock must be paused.
+pub fn resume() {
+    crate::event_loops_bridge::CRANK_WITHOUT_SLEEPING.with(|flag| flag.set(false));
+    tokio::time::resume();
+}
+
+/// Check if the clock of the Actix runtime is paused.
+pub fn is_paused() -> bool {
+    crate::event_loops_bridge::CRANK_WITHOUT_SLEEPING.with(|flag| flag.get())
+}
+
+/// Move the paused clock forward, firing all the timers that expire in the meantime.
+///
+/// Must be called from inside the Actix runtime, and the clock must be paused.
+pub async fn advance(duration: Duration) {
+    tokio::time::advance(duration).await;
+    // Let the tasks woken by the expired timers run.
+    tokio::task::yield_now().await;
+}
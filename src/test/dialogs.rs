@@ -0,0 +1,86 @@
+//! Respond automatically to dialogs in tests.
+//!
+//! See [`auto_respond`] for usage instructions.
+
+use core::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+use gtk4::prelude::*;
+
+struct Script {
+    responses: VecDeque<gtk4::ResponseType>,
+    dialog_titles: Vec<Option<String>>,
+}
+
+thread_local! {
+    static ACTIVE_SCRIPT: RefCell<Option<Rc<RefCell<Script>>>> = const { RefCell::new(None) };
+}
+
+#[cfg_attr(feature = "gtk_v4_10", allow(deprecated))]
+pub(crate) fn scripted_response(dialog: &gtk4::Dialog) -> Option<gtk4::ResponseType> {
+    let script = ACTIVE_SCRIPT.with(|active_script| active_script.borrow().clone())?;
+    let mut script = script.borrow_mut();
+    let title = dialog.title().map(|title| title.to_string());
+    let Some(response) = script.responses.pop_front() else {
+        panic!(
+            "Dialog {:?} was launched but the auto-responder has no more responses (already responded to {:?})",
+            title, script.dialog_titles,
+        );
+    };
+    script.dialog_titles.push(title);
+    Some(response)
+}
+
+/// Respond to the dialogs launched with [`woab::run_dialog`](crate::run_dialog) with a scripted
+/// sequence of responses, instead of showing them.
+///
+/// Each dialog gets the next response in the sequence, and its `response` signal is emitted with
+/// it - so the flow after the dialog runs just like it would if the user responded to it. Launching
+/// more dialogs than there are responses is a panic.
+///
+/// The auto-responder is active until the returned [`AutoResponder`] is dropped.
+///
+/// ```no_run
+/// # async fn asyncfunc() -> Result<(), woab::test::TimedOut> {
+/// let auto_responder = woab::test::dialogs::auto_respond([gtk4::ResponseType::Yes]);
+/// // Click the delete button, which asks for confirmation with `woab::run_dialog`...
+/// woab::wait_for!(auto_responder.remaining() == 0)?;
+/// // Check that the row was deleted...
+/// # Ok(())
+/// # }
+/// ```
+pub fn auto_respond(responses: impl IntoIterator<Item = gtk4::ResponseType>) -> AutoResponder {
+    let script = Rc::new(RefCell::new(Script {
+        responses: responses.into_iter().collect(),
+        dialog_titles: Vec::new(),
+    }));
+    let previous = ACTIVE_SCRIPT.with(|active_script| active_script.borrow_mut().replace(script.clone()));
+    AutoResponder { script, previous }
+}
+
+/// Keeps the auto-responder installed by [`auto_respond`] active.
+///
+/// Dropping it restores the auto-responder that was active before (if there was one).
+pub struct AutoResponder {
+    script: Rc<RefCell<Script>>,
+    previous: Option<Rc<RefCell<Script>>>,
+}
+
+impl AutoResponder {
+    /// The number of scripted responses that were not used yet.
+    pub fn remaining(&self) -> usize {
+        self.script.borrow().responses.len()
+    }
+
+    /// The titles of the dialogs that were responded to, in order.
+    pub fn dialog_titles(&self) -> Vec<Option<String>> {
+        self.script.borrow().dialog_titles.clone()
+    }
+}
+
+impl Drop for AutoResponder {
+    fn drop(&mut self) {
+        ACTIVE_SCRIPT.with(|active_script| *active_script.borrow_mut() = self.previous.take());
+    }
+}
@@ -0,0 +1,157 @@
+use core::cell::RefCell;
+use std::rc::Rc;
+
+use hashbrown::HashMap;
+
+/// A call made on a [`FakeWidget`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FakeWidgetCall {
+    SetText { widget: String, text: String },
+    SetSensitive { widget: String, sensitive: bool },
+}
+
+struct FakeWidgetState {
+    text: String,
+    sensitive: bool,
+}
+
+impl Default for FakeWidgetState {
+    fn default() -> Self {
+        Self {
+            text: String::new(),
+            sensitive: true,
+        }
+    }
+}
+
+#[derive(Default)]
+struct FakeWidgetsInner {
+    states: HashMap<String, FakeWidgetState>,
+    calls: Vec<FakeWidgetCall>,
+}
+
+/// In-memory stand-ins for widgets, for testing the logic of signal handlers without GTK.
+///
+/// Logic that is written against [`woab::WidgetOps`](crate::WidgetOps) instead of concrete widget
+/// types can be given [`FakeWidget`]s in tests. The fake widgets keep their text and sensitivity,
+/// and record the calls made on them (in the order they were made, across all the widgets of the
+/// same `FakeWidgets`), so tests can check both the final state and the updates that led to it.
+/// No widgets are created, so GTK does not need to be initialized.
+///
+/// ```no_run
+/// # use woab::WidgetOps;
+/// # struct CounterView<W> { count_label: W, decrement_button: W }
+/// # fn show_count(view: &CounterView<impl woab::WidgetOps>, count: u32) {
+/// #     view.count_label.set_text(&count.to_string());
+/// #     view.decrement_button.set_sensitive(0 < count);
+/// # }
+/// use woab::test::{FakeWidgetCall, FakeWidgets};
+///
+/// let fakes = FakeWidgets::new();
+/// let view = CounterView {
+///     count_label: fakes.widget("count_label"),
+///     decrement_button: fakes.widget("decrement_button"),
+/// };
+/// show_count(&view, 0);
+/// assert_eq!(
+///     fakes.take_calls(),
+///     [
+///         FakeWidgetCall::SetText {
+///             widget: "count_label".to_owned(),
+///             text: "0".to_owned(),
+///         },
+///         FakeWidgetCall::SetSensitive {
+///             widget: "decrement_button".to_owned(),
+///             sensitive: false,
+///         },
+///     ]
+/// );
+/// assert_eq!(view.count_label.text(), "0");
+/// ```
+#[derive(Clone, Default)]
+pub struct FakeWidgets {
+    inner: Rc<RefCell<FakeWidgetsInner>>,
+}
+
+impl FakeWidgets {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A fake widget with the given name.
+    ///
+    /// Fake widgets with the same name share their state. A new widget has no text and is
+    /// sensitive.
+    pub fn widget(&self, name: &str) -> FakeWidget {
+        self.inner.borrow_mut().states.entry_ref(name).or_default();
+        FakeWidget {
+            name: name.into(),
+            widgets: self.clone(),
+        }
+    }
+
+    /// The calls made on the fake widgets so far.
+    pub fn calls(&self) -> Vec<FakeWidgetCall> {
+        self.inner.borrow().calls.clone()
+    }
+
+    /// The calls made on the fake widgets so far, clearing them so that the next check only sees
+    /// newer calls.
+    pub fn take_calls(&self) -> Vec<FakeWidgetCall> {
+        core::mem::take(&mut self.inner.borrow_mut().calls)
+    }
+}
+
+/// An in-memory stand-in for a widget. Created by [`FakeWidgets::widget`].
+#[derive(Clone)]
+pub struct FakeWidget {
+    name: Rc<str>,
+    widgets: FakeWidgets,
+}
+
+impl FakeWidget {
+    /// The name the widget was created with.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Change the text, as if the user typed it, without recording a call.
+    pub fn simulate_text(&self, text: &str) {
+        self.with_state(|state| state.text = text.to_owned());
+    }
+
+    fn with_state<R>(&self, dlg: impl FnOnce(&mut FakeWidgetState) -> R) -> R {
+        let mut inner = self.widgets.inner.borrow_mut();
+        dlg(inner.states.entry_ref(&*self.name).or_default())
+    }
+
+    fn record(&self, call: FakeWidgetCall) {
+        self.widgets.inner.borrow_mut().calls.push(call);
+    }
+}
+
+impl crate::WidgetOps for FakeWidget {
+    fn text(&self) -> String {
+        self.with_state(|state| state.text.clone())
+    }
+
+    fn set_text(&self, text: &str) {
+        self.with_state(|state| state.text = text.to_owned());
+        self.record(FakeWidgetCall::SetText {
+            widget: self.name.to_string(),
+            text: text.to_owned(),
+        });
+    }
+
+    fn is_sensitive(&self) -> bool {
+        self.with_state(|state| state.sensitive)
+    }
+
+    fn set_sensitive(&self, sensitive: bool) {
+        self.with_state(|state| state.sensitive = sensitive);
+        self.record(FakeWidgetCall::SetSensitive {
+            widget: self.name.to_string(),
+            sensitive,
+        });
+    }
+}
@@ -0,0 +1,72 @@
+//! Simulate user interactions with widgets.
+//!
+//! These helpers emit the same GTK signals that are emitted when the user interacts with the
+//! widgets, so tests that use them go through the real signal routing of the application.
+
+use gtk4::prelude::*;
+
+/// Click a button.
+pub fn click(button: &impl IsA<gtk4::Button>) {
+    button.emit_clicked();
+}
+
+/// Type text into an editable widget (like `gtk4::Entry`) at the cursor position.
+///
+/// The text is inserted character by character, so signal handlers will be invoked once per
+/// character - just like when the user types it.
+pub fn type_text(editable: &impl IsA<gtk4::Editable>, text: &str) {
+    let mut position = editable.position();
+    let mut buf = [0; 4];
+    for character in text.chars() {
+        editable.insert_text(character.encode_utf8(&mut buf), &mut position);
+        editable.set_position(position);
+    }
+}
+
+/// Press Enter in an entry (emit its `activate` signal).
+pub fn press_enter(entry: &impl IsA<gtk4::Entry>) {
+    entry.emit_activate();
+}
+
+/// Select the row at the given index of a list box.
+///
+/// Returns `false` if there is no such row.
+pub fn select_row(list_box: &impl IsA<gtk4::ListBox>, index: i32) -> bool {
+    let list_box = list_box.upcast_ref::<gtk4::ListBox>();
+    if let Some(row) = list_box.row_at_index(index) {
+        list_box.select_row(Some(&row));
+        true
+    } else {
+        false
+    }
+}
+
+/// Select the item at the given position of a list view, column view or grid view.
+///
+/// Returns `false` if the widget has no model or if the model did not select the item.
+pub fn select_item(list: &impl IsA<glib::Object>, position: u32) -> bool {
+    let Some(model) = list.property::<Option<gtk4::SelectionModel>>("model") else {
+        return false;
+    };
+    model.select_item(position, true)
+}
+
+/// Activate an action by its detailed name (e.g. `"app.quit"` or `"win.save"`).
+///
+/// `app.` actions are looked up in the application, and `win.` actions are looked up in the
+/// application's active window.
+pub fn activate_action(app: &impl IsA<gtk4::Application>, name: &str, parameter: Option<&glib::Variant>) -> crate::Result<()> {
+    let app = app.upcast_ref::<gtk4::Application>();
+    if let Some(app_action) = name.strip_prefix("app.") {
+        if app.lookup_action(app_action).is_none() {
+            return Err(glib::bool_error!("Application has no action named {:?}", app_action).into());
+        }
+        app.activate_action(app_action, parameter);
+        Ok(())
+    } else {
+        let window = app
+            .active_window()
+            .ok_or_else(|| glib::bool_error!("Application has no active window to activate {:?} on", name))?;
+        Ok(window.activate_action(name, parameter)?)
+    }
+}
@@ -0,0 +1,150 @@
+//! Compare the rendering of widgets against stored baseline images.
+//!
+//! The widgets must be realized and have a size - so they should be inside a window that is
+//! shown.
+
+use std::path::{Path, PathBuf};
+
+use gtk4::prelude::*;
+
+/// Set this environment variable (to any value) to overwrite the baselines with the current
+/// rendering instead of comparing against them.
+pub const UPDATE_SNAPSHOTS_ENV_VAR: &str = "WOAB_UPDATE_SNAPSHOTS";
+
+#[derive(thiserror::Error, Debug)]
+pub enum SnapshotError {
+    #[error(transparent)]
+    WoabError(#[from] crate::Error),
+
+    /// When the widget cannot be rendered (e.g. because it is not realized yet).
+    #[error("Cannot render the widget: {0}")]
+    CannotRender(&'static str),
+
+    /// When the rendering of the widget differs from the baseline.
+    #[error("Rendering differs from {baseline:?} in {differing_pixels} pixels (see {actual:?} and {diff:?})")]
+    Mismatch {
+        baseline: PathBuf,
+        /// The current rendering of the widget.
+        actual: PathBuf,
+        /// The differing pixels, marked in red.
+        diff: PathBuf,
+        differing_pixels: usize,
+    },
+}
+
+/// Render a widget (with its children) into a texture.
+pub fn render_widget(widget: &impl IsA<gtk4::Widget>) -> Result<gdk4::Texture, SnapshotError> {
+    let renderer = widget
+        .native()
+        .and_then(|native| native.renderer())
+        .ok_or(SnapshotError::CannotRender("the widget is not realized"))?;
+    let (width, height) = (widget.width(), widget.height());
+    if width <= 0 || height <= 0 {
+        return Err(SnapshotError::CannotRender("the widget has no size"));
+    }
+    let paintable = gtk4::WidgetPaintable::new(Some(widget));
+    let snapshot = gtk4::Snapshot::new();
+    paintable.snapshot(&snapshot, width as f64, height as f64);
+    let node = snapshot
+        .to_node()
+        .ok_or(SnapshotError::CannotRender("the widget did not draw anything"))?;
+    let viewport = gtk4::graphene::Rect::new(0.0, 0.0, width as f32, height as f32);
+    Ok(renderer.render_texture(node, Some(&viewport)))
+}
+
+struct Pixels {
+    width: usize,
+    height: usize,
+    data: Vec<u8>,
+}
+
+impl Pixels {
+    fn download(texture: &gdk4::Texture) -> Self {
+        let width = texture.width() as usize;
+        let height = texture.height() as usize;
+        let mut data = vec![0; width * height * 4];
+        texture.download(&mut data, width * 4);
+        Self { width, height, data }
+    }
+}
+
+/// Compare the rendering of a widget against a baseline PNG image.
+///
+/// * If the baseline does not exist (or if the [`UPDATE_SNAPSHOTS_ENV_VAR`] environment variable
+///   is set) the current rendering is saved as the baseline.
+/// * If the rendering differs from the baseline, the current rendering is saved next to the
+///   baseline with an `.actual.png` suffix, and an image with the differing pixels marked in red
+///   is saved with a `.diff.png` suffix.
+///
+/// `tolerance` is the maximal difference allowed in each color channel of each pixel.
+///
+/// ```no_run
+/// # fn test() -> Result<(), woab::test::snapshot::SnapshotError> {
+/// # let widget: gtk4::Box = panic!();
+/// woab::test::snapshot::compare_snapshot(&widget, "tests/snapshots/my_widget.png", 0)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn compare_snapshot(widget: &impl IsA<gtk4::Widget>, baseline: impl AsRef<Path>, tolerance: u8) -> Result<(), SnapshotError> {
+    let baseline = baseline.as_ref();
+    let texture = render_widget(widget)?;
+    if std::env::var_os(UPDATE_SNAPSHOTS_ENV_VAR).is_some() || !baseline.exists() {
+        if let Some(parent) = baseline.parent() {
+            std::fs::create_dir_all(parent).map_err(crate::Error::from)?;
+        }
+        texture.save_to_png(baseline).map_err(crate::Error::from)?;
+        return Ok(());
+    }
+    let expected =
+        gdk4::Texture::from_file(&gio::File::for_path(baseline)).map_err(|err| crate::Error::GenericError(Box::new(err)))?;
+
+    let actual_pixels = Pixels::download(&texture);
+    let expected_pixels = Pixels::download(&expected);
+    let differing_pixels;
+    let mut diff_data = actual_pixels.data.clone();
+    if (actual_pixels.width, actual_pixels.height) == (expected_pixels.width, expected_pixels.height) {
+        let mut count = 0;
+        for (i, (actual, expected)) in actual_pixels
+            .data
+            .chunks_exact(4)
+            .zip(expected_pixels.data.chunks_exact(4))
+            .enumerate()
+        {
+            if actual.iter().zip(expected).any(|(a, e)| tolerance < a.abs_diff(*e)) {
+                count += 1;
+                // The downloaded format is BGRA.
+                diff_data[i * 4..i * 4 + 4].copy_from_slice(&[0, 0, 255, 255]);
+            }
+        }
+        differing_pixels = count;
+    } else {
+        differing_pixels = actual_pixels.width * actual_pixels.height;
+        diff_data.iter_mut().for_each(|byte| *byte = 0);
+    }
+    if differing_pixels == 0 {
+        return Ok(());
+    }
+
+    let with_suffix = |suffix: &str| {
+        let mut path = baseline.to_owned().into_os_string();
+        path.push(suffix);
+        PathBuf::from(path)
+    };
+    let actual = with_suffix(".actual.png");
+    let diff = with_suffix(".diff.png");
+    texture.save_to_png(&actual).map_err(crate::Error::from)?;
+    let diff_texture = gdk4::MemoryTexture::new(
+        actual_pixels.width as i32,
+        actual_pixels.height as i32,
+        gdk4::MemoryFormat::B8g8r8a8Premultiplied,
+        &glib::Bytes::from_owned(diff_data),
+        actual_pixels.width * 4,
+    );
+    diff_texture.save_to_png(&diff).map_err(crate::Error::from)?;
+    Err(SnapshotError::Mismatch {
+        baseline: baseline.to_owned(),
+        actual,
+        diff,
+        differing_pixels,
+    })
+}
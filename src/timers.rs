@@ -0,0 +1,162 @@
+use core::cell::{Cell, RefCell};
+use core::time::Duration;
+use std::rc::Rc;
+
+use gtk4::prelude::*;
+
+struct TimerState {
+    source_id: Cell<Option<glib::SourceId>>,
+    only_while_mapped: RefCell<Option<glib::WeakRef<gtk4::Widget>>>,
+}
+
+impl TimerState {
+    /// `None` if the widget set with `only_while_mapped` was destroyed.
+    fn should_send(&self) -> Option<bool> {
+        match &*self.only_while_mapped.borrow() {
+            None => Some(true),
+            Some(widget) => Some(widget.upgrade()?.is_mapped()),
+        }
+    }
+}
+
+/// A guard for a timer created with [`woab::interval`](interval) or
+/// [`woab::timeout_once`](timeout_once).
+///
+/// The timer is cancelled when the guard is dropped, unless [`detach`](Timer::detach) is called.
+#[must_use = "the timer is cancelled when the guard is dropped - use `detach` to keep it running"]
+pub struct Timer(Rc<TimerState>);
+
+impl Timer {
+    fn start(duration: Duration, mut tick: impl FnMut(&TimerState) -> glib::ControlFlow + 'static) -> Self {
+        let state = Rc::new(TimerState {
+            source_id: Cell::new(None),
+            only_while_mapped: RefCell::new(None),
+        });
+        let source_id = glib::timeout_add_local(duration, {
+            let state = state.clone();
+            move || {
+                let result = tick(&state);
+                if result == glib::ControlFlow::Break {
+                    // GLib removes the source by itself, so the guard must not remove it again.
+                    state.source_id.take();
+                }
+                result
+            }
+        });
+        state.source_id.set(Some(source_id));
+        Self(state)
+    }
+
+    /// Only send messages while the widget is mapped (i.e. shown on the screen).
+    ///
+    /// Ticks of a [`woab::interval`](interval) that happen while the widget is not mapped (e.g.
+    /// when its window is minimized or when it is in a hidden page of a `gtk4::Stack`) are
+    /// skipped. A [`woab::timeout_once`](timeout_once) that expires while the widget is not mapped
+    /// is postponed by another `duration`. If the widget is destroyed, the timer stops.
+    pub fn only_while_mapped(self, widget: &impl IsA<gtk4::Widget>) -> Self {
+        *self.0.only_while_mapped.borrow_mut() = Some(widget.upcast_ref().downgrade());
+        self
+    }
+
+    /// Check if the timer is still running.
+    pub fn is_active(&self) -> bool {
+        let source_id = self.0.source_id.take();
+        let is_active = source_id.is_some();
+        self.0.source_id.set(source_id);
+        is_active
+    }
+
+    /// Stop the timer.
+    pub fn cancel(self) {
+        drop(self);
+    }
+
+    /// Keep the timer running after the guard is dropped.
+    ///
+    /// The timer will stop by itself once the target actor stops.
+    pub fn detach(self) {
+        self.0.source_id.take();
+    }
+}
+
+impl Drop for Timer {
+    fn drop(&mut self) {
+        if let Some(source_id) = self.0.source_id.take() {
+            source_id.remove();
+        }
+    }
+}
+
+/// Send a message to an actor periodically.
+///
+/// The timer runs on the GLib main loop, so it does not need to be started from inside the Actix
+/// runtime. `msg_factory` is called on each tick to create the message. The timer stops when the
+/// returned [`Timer`] guard is dropped, or when the target actor stops.
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// # struct WindowActor { refresh_timer: woab::Timer }
+/// # impl actix::Actor for WindowActor { type Context = actix::Context<Self>; }
+/// struct Refresh;
+///
+/// impl actix::Message for Refresh {
+///     type Result = ();
+/// }
+/// # impl actix::Handler<Refresh> for WindowActor {
+/// #     type Result = ();
+/// #     fn handle(&mut self, _msg: Refresh, _ctx: &mut Self::Context) -> Self::Result {}
+/// # }
+///
+/// # let window: gtk4::ApplicationWindow = panic!();
+/// WindowActor::create(|ctx| {
+///     let refresh_timer = woab::interval(std::time::Duration::from_secs(1), ctx.address(), || Refresh)
+///         .only_while_mapped(&window);
+///     WindowActor { refresh_timer }
+/// });
+/// ```
+pub fn interval<M>(
+    duration: Duration,
+    recipient: impl Into<actix::Recipient<M>>,
+    mut msg_factory: impl FnMut() -> M + 'static,
+) -> Timer
+where
+    M: actix::Message<Result = ()> + Send + 'static,
+{
+    let recipient = recipient.into();
+    Timer::start(duration, move |state| {
+        if !recipient.connected() {
+            return glib::ControlFlow::Break;
+        }
+        match state.should_send() {
+            None => return glib::ControlFlow::Break,
+            Some(true) => recipient.do_send(msg_factory()),
+            Some(false) => {}
+        }
+        glib::ControlFlow::Continue
+    })
+}
+
+/// Send a message to an actor once, after a delay.
+///
+/// Like [`woab::interval`](interval), but the message is only sent once. Dropping the returned
+/// [`Timer`] guard before the delay passes cancels it.
+pub fn timeout_once<M>(
+    duration: Duration,
+    recipient: impl Into<actix::Recipient<M>>,
+    msg_factory: impl FnOnce() -> M + 'static,
+) -> Timer
+where
+    M: actix::Message<Result = ()> + Send + 'static,
+{
+    let recipient = recipient.into();
+    let mut msg_factory = Some(msg_factory);
+    Timer::start(duration, move |state| {
+        if state.should_send() == Some(false) {
+            return glib::ControlFlow::Continue;
+        }
+        if let (Some(true), Some(msg_factory)) = (state.should_send(), msg_factory.take()) {
+            recipient.do_send(msg_factory());
+        }
+        glib::ControlFlow::Break
+    })
+}
@@ -0,0 +1,180 @@
+use core::cell::RefCell;
+use std::rc::Rc;
+
+use gtk4::prelude::*;
+use hashbrown::HashMap;
+
+#[derive(PartialEq, Eq, Hash)]
+enum UpdateKey {
+    Property(glib::Object, String),
+    Custom(String),
+}
+
+enum Update {
+    Property(glib::Object, String, glib::Value),
+    Closure(Box<dyn FnOnce()>),
+}
+
+enum ScheduledFlush {
+    OnFrame {
+        tick_callback: gtk4::TickCallbackId,
+        unmap_handler: glib::SignalHandlerId,
+    },
+    OnIdle,
+}
+
+#[derive(Default)]
+struct Pending {
+    updates: Vec<Update>,
+    keys: HashMap<UpdateKey, usize>,
+    scheduled: Option<ScheduledFlush>,
+}
+
+/// Apply UI updates once per frame, coalescing repeated updates.
+///
+/// Actors that receive data faster than the screen refreshes (stock tickers, logs...) can submit
+/// their UI updates to a batcher instead of applying them directly. The batcher applies all the
+/// pending updates together on the next frame of the widget it was created with, and if the same
+/// property of the same object (or the same custom key) was updated multiple times since the last
+/// frame only the last update is applied.
+///
+/// Updates are applied in the order they were first submitted. The batcher is a cheap handle -
+/// clone it to share it between actors (in the GTK thread).
+///
+/// ```no_run
+/// # use actix::prelude::*;
+/// struct TickerActor {
+///     batcher: woab::UiBatcher,
+///     price_label: gtk4::Label,
+/// }
+/// # impl actix::Actor for TickerActor { type Context = actix::Context<Self>; }
+/// # struct PriceUpdate(f64);
+/// # impl actix::Message for PriceUpdate { type Result = woab::Result<()>; }
+///
+/// impl actix::Handler<PriceUpdate> for TickerActor {
+///     type Result = woab::Result<()>;
+///
+///     fn handle(&mut self, msg: PriceUpdate, _ctx: &mut Self::Context) -> Self::Result {
+///         self.batcher.set_property(&self.price_label, "label", format!("{:.2}", msg.0))
+///     }
+/// }
+/// ```
+#[derive(Clone)]
+pub struct UiBatcher {
+    widget: gtk4::Widget,
+    pending: Rc<RefCell<Pending>>,
+}
+
+impl UiBatcher {
+    /// Create a batcher that applies the updates on the frames of the window `widget` is in.
+    ///
+    /// If the widget is not mapped when updates are submitted, they are applied when GTK is idle
+    /// instead. If it gets unmapped before the next frame, they are applied when it is unmapped.
+    pub fn new(widget: &impl IsA<gtk4::Widget>) -> Self {
+        Self {
+            widget: widget.clone().upcast(),
+            pending: Default::default(),
+        }
+    }
+
+    /// Set a property of an object on the next frame.
+    ///
+    /// Returns an error (without submitting the update) if the object does not have such property
+    /// or if the value is of the wrong type.
+    pub fn set_property(
+        &self,
+        object: &impl IsA<glib::Object>,
+        property: &str,
+        value: impl glib::value::ToValue,
+    ) -> crate::Result<()> {
+        let object = object.upcast_ref::<glib::Object>();
+        let value = value.to_value();
+        crate::set_widget_property::check_property(object, property, value.type_())?;
+        self.submit(
+            Some(UpdateKey::Property(object.clone(), property.to_owned())),
+            Update::Property(object.clone(), property.to_owned(), value),
+        );
+        Ok(())
+    }
+
+    /// Run a closure on the next frame.
+    pub fn run(&self, update: impl 'static + FnOnce()) {
+        self.submit(None, Update::Closure(Box::new(update)));
+    }
+
+    /// Run a closure on the next frame, unless another closure is submitted with the same key
+    /// before that - in which case only the latter is ran.
+    pub fn run_keyed(&self, key: impl Into<String>, update: impl 'static + FnOnce()) {
+        self.submit(Some(UpdateKey::Custom(key.into())), Update::Closure(Box::new(update)));
+    }
+
+    /// Apply all the pending updates now, without waiting for the next frame.
+    pub fn flush(&self) {
+        flush(&self.widget, &self.pending);
+    }
+
+    fn submit(&self, key: Option<UpdateKey>, update: Update) {
+        let mut pending = self.pending.borrow_mut();
+        if let Some(key) = key {
+            if let Some(&index) = pending.keys.get(&key) {
+                pending.updates[index] = update;
+            } else {
+                let index = pending.updates.len();
+                pending.updates.push(update);
+                pending.keys.insert(key, index);
+            }
+        } else {
+            pending.updates.push(update);
+        }
+        if pending.scheduled.is_none() {
+            pending.scheduled = Some(if self.widget.is_mapped() {
+                let tick_callback = self.widget.add_tick_callback({
+                    let pending = self.pending.clone();
+                    move |widget, _| {
+                        flush(widget, &pending);
+                        glib::ControlFlow::Break
+                    }
+                });
+                // The tick callback will not be called if the widget is unmapped before the next
+                // frame.
+                let unmap_handler = self.widget.connect_unmap({
+                    let pending = self.pending.clone();
+                    move |widget| flush(widget, &pending)
+                });
+                ScheduledFlush::OnFrame {
+                    tick_callback,
+                    unmap_handler,
+                }
+            } else {
+                let widget = self.widget.clone();
+                let pending = self.pending.clone();
+                glib::idle_add_local_once(move || flush(&widget, &pending));
+                ScheduledFlush::OnIdle
+            });
+        }
+    }
+}
+
+fn flush(widget: &gtk4::Widget, pending: &Rc<RefCell<Pending>>) {
+    let (updates, scheduled) = {
+        let mut pending = pending.borrow_mut();
+        pending.keys.clear();
+        (core::mem::take(&mut pending.updates), pending.scheduled.take())
+    };
+    if let Some(ScheduledFlush::OnFrame {
+        tick_callback,
+        unmap_handler,
+    }) = scheduled
+    {
+        tick_callback.remove();
+        widget.disconnect(unmap_handler);
+    }
+    // The updates may emit signals whose handlers submit more updates - these will be applied on
+    // the next frame.
+    for update in updates {
+        match update {
+            Update::Property(object, property, value) => object.set_property_from_value(&property, &value),
+            Update::Closure(closure) => closure(),
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use core::cell::RefCell;
+
+/// What to do with routed signals that their actor cannot handle.
+///
+/// See [`woab::set_unmatched_signal_policy`](set_unmatched_signal_policy).
+#[derive(Clone, Default)]
+pub enum UnmatchedSignalPolicy {
+    /// Panic. This is the default, so that typos in the handler names of the builder XML are
+    /// caught early.
+    #[default]
+    Panic,
+    /// Log a GLib warning and ignore the signal.
+    Warn,
+    /// Send the signal to another actor. The signal keeps its name and parameters, but not its
+    /// tag.
+    ///
+    /// If the fallback actor cannot handle the signal either, a GLib warning is logged.
+    Fallback(actix::Recipient<crate::Signal>),
+}
+
+thread_local! {
+    static POLICY: RefCell<UnmatchedSignalPolicy> = RefCell::new(UnmatchedSignalPolicy::default());
+}
+
+/// Set what WoAB does when an actor cannot handle a signal routed to it.
+///
+/// A signal is unmatched when its handler returns a
+/// [`woab::Error::NoSuchSignalError`](crate::Error::NoSuchSignalError) - which is what
+/// [`Signal::cant_handle`](crate::Signal::cant_handle) does. By default this panics, which is
+/// usually what one wants during development. Production builds may prefer a policy that does not
+/// crash the application because of a misspelled handler name:
+///
+/// ```no_run
+/// if !cfg!(debug_assertions) {
+///     woab::set_unmatched_signal_policy(woab::UnmatchedSignalPolicy::Warn);
+/// }
+/// ```
+///
+/// Other errors returned by signal handlers always panic.
+///
+/// The policy is per thread - it should be set on the thread that runs GTK.
+pub fn set_unmatched_signal_policy(policy: UnmatchedSignalPolicy) {
+    POLICY.with(|current| *current.borrow_mut() = policy);
+}
+
+pub(crate) fn handle_unmatched_signal(
+    error: crate::Error,
+    signal_name: glib::Quark,
+    parameters: &[glib::Value],
+) -> Option<glib::Value> {
+    let policy = POLICY.with(|policy| policy.borrow().clone());
+    match policy {
+        UnmatchedSignalPolicy::Panic => panic!("{}", error),
+        UnmatchedSignalPolicy::Warn => {
+            glib::g_warning!("woab", "Ignoring unmatched signal: {}", error);
+            None
+        }
+        UnmatchedSignalPolicy::Fallback(fallback) => {
+            let signal = crate::Signal::new(signal_name, parameters, ());
+            match crate::try_block_on(fallback.send(signal)) {
+                Ok(Ok(Ok(propagation))) => {
+                    propagation.map(|propagation| glib::value::ToValue::to_value(&propagation.is_proceed()))
+                }
+                Ok(result) => {
+                    warn_about_fallback_failure(signal_name, result);
+                    None
+                }
+                Err(future) => {
+                    actix::spawn(async move {
+                        match future.await {
+                            Ok(Ok(None)) => {}
+                            result => warn_about_fallback_failure(signal_name, result),
+                        }
+                    });
+                    None
+                }
+            }
+        }
+    }
+}
+
+fn warn_about_fallback_failure(
+    signal_name: glib::Quark,
+    result: Result<Result<Option<glib::Propagation>, crate::Error>, actix::MailboxError>,
+) {
+    match result {
+        Ok(Ok(_)) => {
+            glib::g_warning!(
+                "woab",
+                "Fallback handler of {:?} returned a propagation value for a queued signal",
+                signal_name.as_str().as_str(),
+            );
+        }
+        Ok(Err(error)) => {
+            glib::g_warning!("woab", "Fallback handler failed: {}", error);
+        }
+        Err(error) => {
+            glib::g_warning!(
+                "woab",
+                "Could not send {:?} to the fallback handler: {}",
+                signal_name.as_str().as_str(),
+                error,
+            );
+        }
+    }
+}
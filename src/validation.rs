@@ -0,0 +1,199 @@
+//! Validate the data of [`PropSync`](crate::PropSync) widgets structs.
+//!
+//! See [`woab::Form`](Form) and [`#[derive(woab::Validate)]`](crate::Validate) for usage
+//! instructions.
+
+use gtk4::prelude::*;
+use hashbrown::HashMap;
+
+/// A validation error of a single field.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldError {
+    /// The name of the field in the widgets struct.
+    pub field: String,
+    /// A human readable description of the error.
+    pub message: String,
+}
+
+impl FieldError {
+    pub fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+}
+
+/// A widgets struct that can validate its data.
+///
+/// Usually implemented with [`#[derive(woab::Validate)]`](crate::Validate).
+pub trait Validate {
+    /// Check the widgets' data and return all the errors.
+    fn validate(&self) -> Vec<FieldError>;
+
+    /// The widget of the field, for marking it when it has errors.
+    fn field_widget(&self, field: &str) -> Option<gtk4::Widget>;
+}
+
+/// Values that can be checked by the `required` rule of
+/// [`#[derive(woab::Validate)]`](crate::Validate).
+pub trait IsBlank {
+    /// Whether or not the value should be considered as missing.
+    fn is_blank(&self) -> bool;
+}
+
+impl IsBlank for str {
+    fn is_blank(&self) -> bool {
+        self.trim().is_empty()
+    }
+}
+
+impl IsBlank for String {
+    fn is_blank(&self) -> bool {
+        self.as_str().is_blank()
+    }
+}
+
+impl<T> IsBlank for Option<T> {
+    fn is_blank(&self) -> bool {
+        self.is_none()
+    }
+}
+
+/// An unchecked check button is considered blank - so `required` can be used for "I agree"
+/// checkboxes.
+impl IsBlank for bool {
+    fn is_blank(&self) -> bool {
+        !*self
+    }
+}
+
+/// Check if the value matches the regular expression (using GLib's regular expressions)
+///
+/// Used by the `regex` rule of [`#[derive(woab::Validate)]`](crate::Validate).
+pub fn matches_regex(value: &str, pattern: &str) -> bool {
+    glib::Regex::match_simple(
+        pattern,
+        value,
+        glib::RegexCompileFlags::empty(),
+        glib::RegexMatchFlags::empty(),
+    )
+}
+
+type Validator<W> = Box<dyn Fn(&W) -> Vec<FieldError>>;
+
+/// Validate the data of a widgets struct and mark the widgets that have errors.
+///
+/// Widgets with errors get the `error` CSS class, and their tooltip is set to the error message.
+/// Once the errors are fixed and the form is validated again, the CSS class is removed and the
+/// original tooltip is restored.
+///
+/// In addition to the rules declared with [`#[derive(woab::Validate)]`](crate::Validate), custom
+/// validators can be added with [`with_validator`](Form::with_validator) (e.g. for rules that
+/// involve multiple fields).
+///
+/// ```no_run
+/// #[derive(woab::WidgetsFromBuilder, woab::PropSync, woab::Validate)]
+/// struct SignupWidgets {
+///     #[prop_sync(get)]
+///     #[validate(required, regex = "^[a-z_]+$")]
+///     user_name: gtk4::Entry,
+///     #[prop_sync(get)]
+///     password: gtk4::Entry,
+///     #[prop_sync(get)]
+///     repeat_password: gtk4::Entry,
+///     #[prop_sync("value" as f64, get)]
+///     #[validate(range = 13.0..=120.0)]
+///     age: gtk4::SpinButton,
+/// }
+///
+/// # let widgets: SignupWidgets = panic!();
+/// let mut form = woab::Form::new(widgets).with_validator(|widgets| {
+///     let SignupWidgetsPropGetter { password, repeat_password, .. } = widgets.get_props();
+///     if password == repeat_password {
+///         vec![]
+///     } else {
+///         vec![woab::validation::FieldError::new("repeat_password", "Passwords do not match")]
+///     }
+/// });
+/// if form.validate() {
+///     // Submit the form
+/// } else {
+///     for error in form.errors() {
+///         println!("{}: {}", error.field, error.message);
+///     }
+/// }
+/// ```
+pub struct Form<W: Validate> {
+    widgets: W,
+    validators: Vec<Validator<W>>,
+    errors: Vec<FieldError>,
+    original_tooltips: HashMap<String, (gtk4::Widget, Option<glib::GString>)>,
+}
+
+impl<W: Validate> Form<W> {
+    pub fn new(widgets: W) -> Self {
+        Self {
+            widgets,
+            validators: Vec::new(),
+            errors: Vec::new(),
+            original_tooltips: HashMap::new(),
+        }
+    }
+
+    /// Add a custom validator, that will run after the rules declared in the derive.
+    pub fn with_validator(mut self, validator: impl 'static + Fn(&W) -> Vec<FieldError>) -> Self {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// The widgets struct.
+    pub fn widgets(&self) -> &W {
+        &self.widgets
+    }
+
+    /// Run all the validations, mark the widgets that have errors, and return whether or not the
+    /// form is valid.
+    pub fn validate(&mut self) -> bool {
+        let mut errors = self.widgets.validate();
+        for validator in self.validators.iter() {
+            errors.extend(validator(&self.widgets));
+        }
+        self.clear_errors();
+        self.errors = errors;
+
+        let mut messages = HashMap::<&str, Vec<&str>>::new();
+        for error in self.errors.iter() {
+            messages.entry(&error.field).or_default().push(&error.message);
+        }
+        for (field, messages) in messages {
+            let Some(widget) = self.widgets.field_widget(field) else {
+                continue;
+            };
+            self.original_tooltips
+                .insert(field.to_owned(), (widget.clone(), widget.tooltip_text()));
+            widget.add_css_class("error");
+            widget.set_tooltip_text(Some(&messages.join("\n")));
+        }
+        self.is_valid()
+    }
+
+    /// Whether or not the last [`validate`](Form::validate) found no errors.
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// The errors found by the last [`validate`](Form::validate).
+    pub fn errors(&self) -> &[FieldError] {
+        &self.errors
+    }
+
+    /// Remove all the errors, and unmark the widgets that had them.
+    pub fn clear_errors(&mut self) {
+        self.errors.clear();
+        for (_, (widget, tooltip)) in self.original_tooltips.drain() {
+            widget.remove_css_class("error");
+            widget.set_tooltip_text(tooltip.as_deref());
+        }
+    }
+}
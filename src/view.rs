@@ -0,0 +1,33 @@
+use gtk4::prelude::*;
+
+/// A set of widgets with a single root widget.
+///
+/// Generic code that puts widgets into containers - tabs, rows, windows - can use this to get the
+/// widget it needs to insert from any widgets struct.
+/// [`woab::WidgetsFromBuilder`](crate::WidgetsFromBuilder) implements it when one of the fields is
+/// marked with `#[widget(root)]`:
+///
+/// ```no_run
+/// #[derive(woab::WidgetsFromBuilder)]
+/// struct PageWidgets {
+///     #[widget(root)]
+///     page: gtk4::Box,
+///     title: gtk4::Label,
+/// }
+///
+/// fn add_page(notebook: &gtk4::Notebook, view: &impl woab::View) {
+///     notebook.append_page(view.root(), None::<&gtk4::Widget>);
+/// }
+/// ```
+pub trait View {
+    /// The type of the root widget.
+    type Root: IsA<gtk4::Widget>;
+
+    /// The root widget.
+    fn root(&self) -> &Self::Root;
+
+    /// The root widget, upcast to `gtk4::Widget`.
+    fn root_widget(&self) -> gtk4::Widget {
+        self.root().clone().upcast()
+    }
+}
@@ -1,5 +1,8 @@
 use core::future::Future;
+use core::pin::Pin;
+use core::task::Poll;
 
+use gio::prelude::*;
 use tokio::sync::mpsc;
 
 use crate::WakerPerished;
@@ -85,6 +88,215 @@ pub async fn wake_from_signal<T>(
     result.ok_or(WakerPerished)
 }
 
+/// Run a future until it either finishes or the cancellable is cancelled.
+///
+/// If the cancellable is cancelled (or was already cancelled before this function was called),
+/// the future will be dropped and [`woab::Error::Cancelled`](crate::Error::Cancelled) will be
+/// returned.
+///
+/// This allows an actor to programmatically abort a pending operation - for example, to stop
+/// waiting for the user to respond to a dialog if the window is closing:
+///
+/// ```no_run
+/// # use gtk4::prelude::*;
+/// # async fn asyncfunc() -> woab::Result<()> {
+/// let dialog: gtk4::Dialog;
+/// let cancellable: gio::Cancellable;
+/// # dialog = panic!();
+/// # cancellable = panic!();
+/// // Some other code can call `cancellable.cancel()` to stop waiting for the response.
+/// let response = woab::until_cancelled(&cancellable, dialog.run_future()).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn until_cancelled<T>(cancellable: &gio::Cancellable, fut: impl Future<Output = T>) -> crate::Result<T> {
+    let (tx, mut rx) = tokio::sync::oneshot::channel();
+    let Some(cancelled_handler_id) = cancellable.connect_cancelled_local(move |_| {
+        let _ = tx.send(());
+    }) else {
+        return Err(crate::Error::Cancelled);
+    };
+    let mut fut = core::pin::pin!(fut);
+    let result = core::future::poll_fn(|cx| {
+        if let Poll::Ready(result) = fut.as_mut().poll(cx) {
+            Poll::Ready(Ok(result))
+        } else if let Poll::Ready(Ok(())) = Pin::new(&mut rx).poll(cx) {
+            Poll::Ready(Err(crate::Error::Cancelled))
+        } else {
+            Poll::Pending
+        }
+    })
+    .await;
+    cancellable.disconnect_cancelled(cancelled_handler_id);
+    result
+}
+
+/// Asynchronously wait for something to happen somewhere, unless cancelled.
+///
+/// Similar to [`woab::wake_from`](wake_from), but if the cancellable is cancelled before the
+/// sender is used, the future will resolve with
+/// [`woab::Error::Cancelled`](crate::Error::Cancelled).
+pub async fn wake_from_cancellable<T>(
+    cancellable: &gio::Cancellable,
+    setup_dlg: impl FnOnce(mpsc::Sender<T>),
+) -> crate::Result<T> {
+    let (tx, mut rx) = mpsc::channel(1);
+    setup_dlg(tx);
+    let result = until_cancelled(cancellable, rx.recv()).await;
+    rx.close();
+    Ok(result?.ok_or(WakerPerished)?)
+}
+
+/// Asynchronously wait for a signal to be called, unless cancelled.
+///
+/// Similar to [`woab::wake_from_signal`](wake_from_signal), but if the cancellable is cancelled
+/// before the sender is used, the signal will be removed from the object and the future will
+/// resolve with [`woab::Error::Cancelled`](crate::Error::Cancelled).
+pub async fn wake_from_signal_cancellable<T>(
+    obj: &impl glib::object::ObjectExt,
+    cancellable: &gio::Cancellable,
+    setup_dlg: impl FnOnce(mpsc::Sender<T>) -> glib::SignalHandlerId,
+) -> crate::Result<T> {
+    let (tx, mut rx) = mpsc::channel(1);
+    let signal_handler_id = setup_dlg(tx);
+    let result = until_cancelled(cancellable, rx.recv()).await;
+    rx.close();
+    obj.disconnect(signal_handler_id);
+    Ok(result?.ok_or(WakerPerished)?)
+}
+
+/// The callback [`woab::gio_task`](gio_task) passes to a gio `*_async` method.
+pub type GioTaskCallback<T> = Box<dyn FnOnce(Result<T, glib::Error>)>;
+
+/// Run one of gio's callback-based `*_async` operations as a future.
+///
+/// `start` is called immediately with the cancellable and a callback, and must pass both of them
+/// to the `*_async` method:
+///
+/// ```no_run
+/// # async fn asyncfunc() -> woab::Result<()> {
+/// use gio::prelude::*;
+///
+/// let file: gio::File;
+/// let cancellable: gio::Cancellable;
+/// # file = panic!();
+/// # cancellable = panic!();
+/// let info = woab::gio_task(&cancellable, |cancellable, callback| {
+///     file.query_info_async(
+///         gio::FILE_ATTRIBUTE_STANDARD_SIZE,
+///         gio::FileQueryInfoFlags::NONE,
+///         glib::Priority::DEFAULT,
+///         Some(cancellable),
+///         callback,
+///     )
+/// })
+/// .await?;
+/// println!("File size is {}", info.size());
+/// # Ok(())
+/// # }
+/// ```
+///
+/// If the operation is cancelled, the future resolves to
+/// [`woab::Error::Cancelled`](crate::Error::Cancelled). Other GIO errors are returned as
+/// [`woab::Error::GenericError`](crate::Error::GenericError).
+///
+/// If the future is dropped before the operation finishes - for example, because it was spawned
+/// in the context of an actor that stopped - the cancellable is cancelled, aborting the operation.
+/// Note that this also cancels any other operation that shares the same cancellable. Use
+/// [`ActixContextExt::spawn_gio_task`] to run the operation with its own cancellable and handle
+/// the result with access to the actor.
+pub fn gio_task<T: 'static>(
+    cancellable: &gio::Cancellable,
+    start: impl FnOnce(&gio::Cancellable, GioTaskCallback<T>),
+) -> impl Future<Output = crate::Result<T>> + 'static {
+    struct CancelOnDrop(Option<gio::Cancellable>);
+
+    impl Drop for CancelOnDrop {
+        fn drop(&mut self) {
+            if let Some(cancellable) = self.0.take() {
+                cancellable.cancel();
+            }
+        }
+    }
+
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    start(
+        cancellable,
+        Box::new(move |result| {
+            let _ = tx.send(result);
+        }),
+    );
+    let mut _cancel_on_drop = CancelOnDrop(Some(cancellable.clone()));
+    async move {
+        let result = rx.await;
+        _cancel_on_drop.0 = None;
+        match result.map_err(|_| WakerPerished)? {
+            Ok(result) => Ok(result),
+            Err(err) if err.matches(gio::IOErrorEnum::Cancelled) => Err(crate::Error::Cancelled),
+            Err(err) => Err(crate::Error::GenericError(Box::new(err))),
+        }
+    }
+}
+
+/// Asynchronously wait for the first of several signals to be called.
+///
+/// Accepts a list of GLib objects and the names of the signals to wait for on them. Once any of
+/// these signals is emitted, `wait_for_any` will be woken, disconnect all the signals, and return
+/// the emitted signal as a [`woab::Signal`](crate::Signal) tagged with the index of its source in
+/// the list.
+///
+/// ```no_run
+/// # use gtk4::prelude::*;
+/// # async fn asyncfunc() {
+/// let dialog: gtk4::Dialog;
+/// let window: gtk4::ApplicationWindow;
+/// # dialog = panic!();
+/// # window = panic!();
+/// let signal = woab::wait_for_any(&[
+///     (dialog.upcast_ref(), "response"),
+///     (window.upcast_ref(), "destroy"),
+/// ]).await.unwrap();
+/// match signal.tag() {
+///     0 => {
+///         let woab::params!(_, response: gtk4::ResponseType) = signal.params().unwrap();
+///         println!("Dialog responded with {:?}", response);
+///     }
+///     1 => {
+///         println!("Window was destroyed");
+///     }
+///     _ => unreachable!(),
+/// }
+/// # }
+/// ```
+///
+/// The signals are not handled while they are being emitted, so they cannot be signals that
+/// require a return value (like a propagation decision).
+pub async fn wait_for_any(sources: &[(&glib::Object, &str)]) -> Result<crate::Signal<usize>, WakerPerished> {
+    let (tx, mut rx) = mpsc::channel(1);
+    let connections = sources
+        .iter()
+        .enumerate()
+        .map(|(index, (obj, gtk_signal))| {
+            let tx = tx.clone();
+            let signal_name = glib::Quark::from_str(*gtk_signal);
+            let signal_handler_id = obj.connect_local(gtk_signal, false, move |parameters| {
+                let _ = tx.try_send(crate::Signal::new(signal_name, parameters, index));
+                None
+            });
+            (obj.downgrade(), signal_handler_id)
+        })
+        .collect::<Vec<_>>();
+    drop(tx);
+    let result = rx.recv().await;
+    rx.close();
+    for (obj, signal_handler_id) in connections {
+        if let Some(obj) = obj.upgrade() {
+            obj.disconnect(signal_handler_id);
+        }
+    }
+    result.ok_or(WakerPerished)
+}
+
 /// Run a future outside the Actix system.
 ///
 /// If operation that generate GTK signals are executed inside the Actix runtime, they'll be
@@ -203,11 +415,141 @@ pub fn spawn_outside(fut: impl Future<Output = ()> + 'static) {
 ///     }
 /// }
 /// ```
-pub async fn outside<T: 'static>(fut: impl Future<Output = T> + 'static) -> Result<T, WakerPerished> {
+///
+/// If the future panics, the panic will not propagate into the GLib main loop. Instead, `outside`
+/// will return [`woab::Error::OutsidePanicked`](crate::Error::OutsidePanicked) with the panic's
+/// message.
+pub async fn outside<T: 'static>(fut: impl Future<Output = T> + 'static) -> crate::Result<T> {
     let (tx, rx) = tokio::sync::oneshot::channel();
     glib::MainContext::ref_thread_default().spawn_local(async move {
-        let result = fut.await;
-        tx.send(result).map_err(|_| "Unable to send future result").unwrap();
+        let mut fut = core::pin::pin!(fut);
+        let result = core::future::poll_fn(|cx| poll_catching_panic(fut.as_mut(), cx)).await;
+        let _ = tx.send(result);
     });
-    rx.await.map_err(|_| WakerPerished)
+    match rx.await.map_err(|_| WakerPerished)? {
+        Ok(result) => Ok(result),
+        Err(panic) => Err(crate::Error::OutsidePanicked(panic_message(&*panic))),
+    }
+}
+
+/// Extension methods for Actix actor contexts, for running futures outside the Actix runtime.
+///
+/// These combine [`woab::outside`](outside) with the actor's context, so that the result of the
+/// future can be handled with access to the actor - instead of wrapping the `outside` call in an
+/// `async` block, converting it with `into_actor`, and `map`ing it:
+///
+/// ```no_run
+/// # use gtk4::prelude::*;
+/// use woab::ActixContextExt;
+///
+/// # struct WindowActor {
+/// #     widgets: WindowWidgets,
+/// #     removed: bool,
+/// # }
+/// #
+/// # struct WindowWidgets {
+/// #     some_container: gtk4::ListBox,
+/// #     some_widget: gtk4::ListBoxRow,
+/// # }
+/// #
+/// # impl actix::Actor for WindowActor {
+/// #     type Context = actix::Context<Self>;
+/// # }
+/// impl actix::Handler<woab::Signal> for WindowActor {
+///     type Result = woab::SignalResult;
+///
+///     fn handle(&mut self, msg: woab::Signal, ctx: &mut Self::Context) -> Self::Result {
+///         Ok(match msg.name() {
+///             "remove_button_clicked" => {
+///                 let container = self.widgets.some_container.clone();
+///                 let widget = self.widgets.some_widget.clone();
+///                 ctx.spawn_outside(
+///                     async move { container.remove(&widget) },
+///                     |result, act, _ctx| {
+///                         result.unwrap();
+///                         act.removed = true;
+///                     },
+///                 );
+///                 None
+///             }
+///             _ => msg.cant_handle()?
+///         })
+///     }
+/// }
+/// ```
+pub trait ActixContextExt<A>: actix::AsyncContext<A>
+where
+    A: actix::Actor<Context = Self>,
+{
+    /// Run a future outside the Actix runtime, and then call `then` with its result, the actor,
+    /// and the context.
+    ///
+    /// The actor keeps handling messages while the future runs. If the actor stops before the
+    /// future finishes, `then` will not be called.
+    fn spawn_outside<T: 'static>(
+        &mut self,
+        fut: impl Future<Output = T> + 'static,
+        then: impl FnOnce(crate::Result<T>, &mut A, &mut Self) + 'static,
+    ) -> actix::SpawnHandle {
+        use actix::prelude::*;
+
+        self.spawn(actix::fut::wrap_future::<_, A>(outside(fut)).map(move |result, act, ctx| then(result, act, ctx)))
+    }
+
+    /// Like [`spawn_outside`](Self::spawn_outside), but the actor does not handle other messages
+    /// until the future finishes and `then` is called.
+    fn wait_outside<T: 'static>(
+        &mut self,
+        fut: impl Future<Output = T> + 'static,
+        then: impl FnOnce(crate::Result<T>, &mut A, &mut Self) + 'static,
+    ) {
+        use actix::prelude::*;
+
+        self.wait(actix::fut::wrap_future::<_, A>(outside(fut)).map(move |result, act, ctx| then(result, act, ctx)));
+    }
+
+    /// Run one of gio's callback-based `*_async` operations with [`woab::gio_task`](gio_task),
+    /// and then call `then` with its result, the actor, and the context.
+    ///
+    /// The operation gets its own `gio::Cancellable`, which is cancelled if the actor stops (or the
+    /// returned handle is passed to `cancel_future`) before the operation finishes. In that case,
+    /// `then` will not be called.
+    fn spawn_gio_task<T: 'static>(
+        &mut self,
+        start: impl FnOnce(&gio::Cancellable, GioTaskCallback<T>),
+        then: impl FnOnce(crate::Result<T>, &mut A, &mut Self) + 'static,
+    ) -> actix::SpawnHandle {
+        use actix::prelude::*;
+
+        let fut = gio_task(&gio::Cancellable::new(), start);
+        self.spawn(actix::fut::wrap_future::<_, A>(fut).map(move |result, act, ctx| then(result, act, ctx)))
+    }
+}
+
+impl<A, C> ActixContextExt<A> for C
+where
+    A: actix::Actor<Context = C>,
+    C: actix::AsyncContext<A>,
+{
+}
+
+fn poll_catching_panic<F: Future>(
+    fut: Pin<&mut F>,
+    cx: &mut core::task::Context,
+) -> Poll<Result<F::Output, Box<dyn std::any::Any + Send>>> {
+    match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| fut.poll(cx))) {
+        Ok(Poll::Ready(result)) => Poll::Ready(Ok(result)),
+        Ok(Poll::Pending) => Poll::Pending,
+        Err(panic) => Poll::Ready(Err(panic)),
+    }
+}
+
+fn panic_message(panic: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = panic.downcast_ref::<&str>() {
+        (*message).to_owned()
+    } else if let Some(message) = panic.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Box<dyn Any>".to_owned()
+    }
 }
@@ -0,0 +1,74 @@
+use gtk4::prelude::*;
+
+/// The basic operations signal handlers do on widgets.
+///
+/// Writing the logic of an actor against this trait (instead of against concrete widget types)
+/// lets tests replace the widgets with [`woab::test::FakeWidget`](crate::test::FakeWidget)s
+/// (requires the `test-util` feature), which record the calls made on them:
+///
+/// ```no_run
+/// struct CounterView<W> {
+///     count_label: W,
+///     decrement_button: W,
+/// }
+///
+/// fn show_count(view: &CounterView<impl woab::WidgetOps>, count: u32) {
+///     view.count_label.set_text(&count.to_string());
+///     view.decrement_button.set_sensitive(0 < count);
+/// }
+/// ```
+///
+/// Implemented for all GTK widgets. The text is the text of `gtk4::Editable`s (e.g.
+/// `gtk4::Entry`), the buffer of `gtk4::TextView`s, or the `label` property of widgets that have
+/// one (e.g. `gtk4::Label` and `gtk4::Button`). Setting the text of other widgets does nothing,
+/// and their text is empty.
+///
+/// Generic code only needs the `W: woab::WidgetOps` bound - there is no need to import the
+/// trait, and importing it along with `gtk4::prelude::*` makes calls like `label.set_text(...)` on
+/// concrete widgets ambiguous.
+pub trait WidgetOps {
+    /// The text the widget displays (or the user entered into it).
+    fn text(&self) -> String;
+
+    /// Replace the text the widget displays.
+    fn set_text(&self, text: &str);
+
+    /// Whether the widget responds to input.
+    fn is_sensitive(&self) -> bool;
+
+    /// Make the widget respond, or not respond, to input.
+    fn set_sensitive(&self, sensitive: bool);
+}
+
+impl<W: IsA<gtk4::Widget>> WidgetOps for W {
+    fn text(&self) -> String {
+        if let Some(editable) = self.dynamic_cast_ref::<gtk4::Editable>() {
+            EditableExt::text(editable).into()
+        } else if let Some(text_view) = self.dynamic_cast_ref::<gtk4::TextView>() {
+            let buffer = text_view.buffer();
+            buffer.text(&buffer.start_iter(), &buffer.end_iter(), false).into()
+        } else if self.find_property("label").is_some() {
+            self.property::<Option<String>>("label").unwrap_or_default()
+        } else {
+            String::new()
+        }
+    }
+
+    fn set_text(&self, text: &str) {
+        if let Some(editable) = self.dynamic_cast_ref::<gtk4::Editable>() {
+            EditableExt::set_text(editable, text);
+        } else if let Some(text_view) = self.dynamic_cast_ref::<gtk4::TextView>() {
+            text_view.buffer().set_text(text);
+        } else if self.find_property("label").is_some() {
+            self.set_property("label", text);
+        }
+    }
+
+    fn is_sensitive(&self) -> bool {
+        WidgetExt::is_sensitive(self)
+    }
+
+    fn set_sensitive(&self, sensitive: bool) {
+        WidgetExt::set_sensitive(self, sensitive);
+    }
+}
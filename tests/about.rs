@@ -0,0 +1,25 @@
+use gtk4::prelude::*;
+
+#[test]
+fn test_show_about() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let window = gtk4::Window::new();
+        let info = woab::AboutInfo {
+            name: "WoAB Test".to_owned(),
+            version: Some("1.2.3".to_owned()),
+            ..Default::default()
+        };
+
+        let about = woab::show_about(Some(&window), &info);
+        assert_eq!(about.transient_for().as_ref(), Some(&window));
+        assert!(about.is_modal());
+
+        // Showing it again presents the same window instead of opening another one
+        assert_eq!(woab::show_about(Some(&window), &info), about);
+        assert_eq!(woab::show_about(Some(&about), &info), about);
+
+        about.destroy();
+        window.destroy();
+        Ok(())
+    })
+}
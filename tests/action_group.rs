@@ -0,0 +1,96 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use actix::prelude::*;
+use gio::prelude::*;
+use woab::action_group::{ActionGroup, RoutedActions};
+
+#[derive(woab::ActionGroup)]
+struct TestActions {
+    plain_action: gio::SimpleAction,
+    #[action(name = "with-param", parameter = String)]
+    with_param: gio::SimpleAction,
+    #[action(state = 1i32)]
+    stateful: gio::SimpleAction,
+}
+
+struct TestActor {
+    actions: Rc<RefCell<Option<RoutedActions<TestActions>>>>,
+    output: Rc<RefCell<Vec<String>>>,
+}
+
+impl actix::Actor for TestActor {
+    type Context = actix::Context<Self>;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        *self.actions.borrow_mut() = Some(TestActions::route_to(ctx.address()));
+    }
+}
+
+impl actix::Handler<woab::Signal> for TestActor {
+    type Result = woab::SignalResult;
+
+    fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+        Ok(match msg.name() {
+            "plain-action" => {
+                self.output.borrow_mut().push("plain".to_owned());
+                None
+            }
+            "with-param" => {
+                let param: String = msg.action_param()?;
+                self.output.borrow_mut().push(param);
+                None
+            }
+            "stateful" => {
+                let state: i32 = msg.action_param()?;
+                self.output.borrow_mut().push(format!("state {}", state));
+                None
+            }
+            _ => msg.cant_handle()?,
+        })
+    }
+}
+
+#[test]
+fn test_action_group() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let actions = Rc::new(RefCell::new(None));
+        let output = Rc::new(RefCell::new(Vec::<String>::new()));
+        TestActor {
+            actions: actions.clone(),
+            output: output.clone(),
+        }
+        .start();
+        woab::wait_for!(actions.borrow().is_some())?;
+        let group = actions.borrow().as_ref().unwrap().group().clone();
+        let mut action_names = group.list_actions().into_iter().map(String::from).collect::<Vec<_>>();
+        action_names.sort();
+        assert_eq!(action_names, ["plain-action", "stateful", "with-param"]);
+        assert_eq!(group.action_state("stateful").and_then(|state| state.get::<i32>()), Some(1));
+
+        group.activate_action("plain-action", None);
+        woab::wait_for!(*output.borrow() == ["plain"])?;
+        group.activate_action("with-param", Some(&"hello".to_variant()));
+        woab::wait_for!(*output.borrow() == ["plain", "hello"])?;
+        group.change_action_state("stateful", &2i32.to_variant());
+        woab::wait_for!(*output.borrow() == ["plain", "hello", "state 2"])?;
+
+        {
+            let actions = actions.borrow();
+            let actions = actions.as_ref().unwrap();
+            actions.block(&actions.plain_action);
+        }
+        group.activate_action("plain-action", None);
+        group.activate_action("with-param", Some(&"world".to_variant()));
+        woab::wait_for!(*output.borrow() == ["plain", "hello", "state 2", "world"])?;
+
+        {
+            let actions = actions.borrow();
+            let actions = actions.as_ref().unwrap();
+            actions.unblock(&actions.plain_action);
+        }
+        group.activate_action("plain-action", None);
+        woab::wait_for!(*output.borrow() == ["plain", "hello", "state 2", "world", "plain"])?;
+        Ok(())
+    })
+}
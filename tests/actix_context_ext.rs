@@ -0,0 +1,44 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use actix::prelude::*;
+use woab::ActixContextExt;
+
+struct TestActor {
+    output: Rc<RefCell<Vec<&'static str>>>,
+}
+
+impl actix::Actor for TestActor {
+    type Context = actix::Context<Self>;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        let output = self.output.clone();
+        ctx.wait_outside(
+            async move {
+                output.borrow_mut().push("waited outside");
+                1
+            },
+            |result, act, ctx| {
+                assert_eq!(result.unwrap(), 1);
+                act.output.borrow_mut().push("after wait");
+                ctx.spawn_outside(async { panic!("oops") }, |result, act, _ctx| {
+                    assert!(matches!(result, Err(woab::Error::OutsidePanicked(message)) if message == "oops"));
+                    act.output.borrow_mut().push("after panic");
+                });
+            },
+        );
+    }
+}
+
+#[test]
+fn test_actix_context_ext() -> anyhow::Result<()> {
+    // The futures run outside the Actix runtime, so the GLib main loop must be running.
+    let output = Rc::new(RefCell::new(Vec::<&'static str>::new()));
+    woab::test::test_main_loop_until(
+        || {
+            TestActor { output: output.clone() }.start();
+        },
+        || *output.borrow() == ["waited outside", "after wait", "after panic"],
+        std::time::Duration::from_secs(1),
+    )
+}
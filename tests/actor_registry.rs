@@ -0,0 +1,35 @@
+use actix::prelude::*;
+use gtk4::prelude::*;
+
+struct TestActor;
+
+impl actix::Actor for TestActor {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<woab::Signal> for TestActor {
+    type Result = woab::SignalResult;
+
+    fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+        msg.cant_handle()
+    }
+}
+
+#[test]
+fn test_actor_registry() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let container = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+        let label = gtk4::Label::new(None);
+        container.append(&label);
+
+        assert!(woab::actor_for(&label).is_none());
+
+        woab::register_actor(&container, TestActor.start().recipient());
+        assert!(woab::actor_for(&container).is_some());
+        assert!(woab::actor_for(&label).is_some());
+
+        assert!(woab::unregister_actor(&container).is_some());
+        assert!(woab::actor_for(&label).is_none());
+        Ok(())
+    })
+}
@@ -0,0 +1,51 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use actix::prelude::*;
+
+struct TestActor {
+    output: Rc<RefCell<Vec<usize>>>,
+}
+
+impl actix::Actor for TestActor {
+    type Context = actix::Context<Self>;
+}
+
+struct Number(usize);
+
+impl actix::Message for Number {
+    type Result = ();
+}
+
+impl actix::Handler<Number> for TestActor {
+    type Result = ();
+
+    fn handle(&mut self, msg: Number, _ctx: &mut Self::Context) -> Self::Result {
+        self.output.borrow_mut().push(msg.0);
+    }
+}
+
+#[test]
+fn test_adaptive_cranking() -> anyhow::Result<()> {
+    woab::set_cranking_mode(woab::CrankingMode::Adaptive {
+        min_interval: Duration::from_millis(10),
+        max_interval: Duration::from_millis(50),
+    });
+    let output = Rc::new(RefCell::new(Vec::new()));
+    woab::test::test_main_loop_until(
+        || {
+            let addr = TestActor { output: output.clone() }.start();
+            let tx = woab::channel_to_actor(addr);
+            std::thread::spawn(move || {
+                // Long enough for the cranking interval to back off to its maximum.
+                std::thread::sleep(Duration::from_millis(200));
+                tx.send(Number(1)).unwrap();
+                std::thread::sleep(Duration::from_millis(200));
+                tx.send(Number(2)).unwrap();
+            });
+        },
+        || *output.borrow() == [1, 2],
+        Duration::from_secs(2),
+    )
+}
@@ -0,0 +1,38 @@
+// `gtk4::MessageDialog` is deprecated since GTK 4.10.
+#![cfg_attr(feature = "gtk_v4_10", allow(deprecated))]
+
+use gtk4::prelude::*;
+
+fn visible_message_dialogs() -> usize {
+    gtk4::Window::list_toplevels()
+        .into_iter()
+        .filter(|window| window.is::<gtk4::MessageDialog>() && window.is_visible())
+        .count()
+}
+
+#[test]
+fn test_alerts() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let window = gtk4::Window::new();
+
+        let auto_responder = woab::test::dialogs::auto_respond([
+            gtk4::ResponseType::Ok,
+            gtk4::ResponseType::Ok,
+            gtk4::ResponseType::Ok,
+            gtk4::ResponseType::Cancel,
+            gtk4::ResponseType::DeleteEvent,
+        ]);
+        woab::alert_info(&window, "Information").await;
+        woab::alert_error(&window, "Error").await;
+        assert!(woab::confirm(&window, "Are you sure?").await);
+        assert!(!woab::confirm(&window, "Are you sure?").await);
+        // Closing the dialog counts as not confirming.
+        assert!(!woab::confirm(&window, "Are you sure?").await);
+        assert_eq!(auto_responder.remaining(), 0);
+        assert_eq!(auto_responder.dialog_titles().len(), 5);
+
+        // The dialogs are closed after the response.
+        assert_eq!(visible_message_dialogs(), 0);
+        Ok(())
+    })
+}
@@ -0,0 +1,45 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use actix::prelude::*;
+
+struct TestActor {
+    steps: Rc<RefCell<Vec<woab::Step>>>,
+}
+
+impl actix::Actor for TestActor {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<woab::Step> for TestActor {
+    type Result = ();
+
+    fn handle(&mut self, msg: woab::Step, _ctx: &mut Self::Context) -> Self::Result {
+        self.steps.borrow_mut().push(msg);
+    }
+}
+
+#[test]
+fn test_animator_pause_and_resume() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let steps = Rc::new(RefCell::new(Vec::new()));
+        let addr = TestActor { steps: steps.clone() }.start();
+        let label = gtk4::Label::new(None);
+        let animator = woab::Animator::new(&label, addr).max_fps(30.0);
+        assert!(!animator.is_paused());
+        animator.pause();
+        assert!(animator.is_paused());
+        animator.resume();
+        assert!(!animator.is_paused());
+
+        // The label is not mapped, so no steps should be sent
+        actix::clock::sleep(core::time::Duration::from_millis(50)).await;
+        assert!(steps.borrow().is_empty());
+
+        drop(label);
+        animator.pause();
+        animator.resume();
+        assert!(animator.is_paused(), "Cannot resume once the widget is destroyed");
+        Ok(())
+    })
+}
@@ -0,0 +1,66 @@
+use gtk4::prelude::*;
+
+use woab::auto_form::{AutoForm, FormWidgets};
+
+#[derive(woab::Choices, Debug, PartialEq)]
+enum Theme {
+    Light,
+    Dark,
+}
+
+#[derive(woab::AutoForm, Debug, PartialEq)]
+struct Settings {
+    user_name: String,
+    #[form(label = "Volume (%)", widget = gtk4::Scale, range = 0.0..=100.0)]
+    volume: f64,
+    #[form(range = 0..=10)]
+    retries: u32,
+    #[form(widget = gtk4::CheckButton)]
+    autosave: bool,
+    #[form(widget = gtk4::DropDown)]
+    theme: Theme,
+    #[form(skip)]
+    counter: usize,
+}
+
+#[test]
+fn test_auto_form() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let form = Settings::build_form();
+        let labels = (0..5)
+            .map(|row| {
+                form.grid()
+                    .child_at(0, row)
+                    .and_then(|widget| widget.downcast::<gtk4::Label>().ok())
+                    .map(|label| label.text().to_string())
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(labels, ["User name", "Volume (%)", "Retries", "Autosave", "Theme"]);
+
+        form.set(&Settings {
+            user_name: "John".to_owned(),
+            volume: 50.0,
+            retries: 20,
+            autosave: true,
+            theme: Theme::Dark,
+            counter: 3,
+        });
+        assert_eq!(form.user_name.text(), "John");
+        assert!(form.autosave.is_active());
+
+        // The range is enforced, and the skipped field gets its default value.
+        assert_eq!(
+            form.get(),
+            Settings {
+                user_name: "John".to_owned(),
+                volume: 50.0,
+                retries: 10,
+                autosave: true,
+                theme: Theme::Dark,
+                counter: 0,
+            }
+        );
+        Ok(())
+    })
+}
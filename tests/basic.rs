@@ -1,9 +1,6 @@
 use actix::prelude::*;
 use gtk4::prelude::*;
 
-#[macro_use]
-mod util;
-
 struct TestActor {
     widgets: TestWidgets,
 }
@@ -46,7 +43,7 @@ impl actix::Handler<woab::Signal> for TestActor {
 
 #[test]
 fn test_basic() -> anyhow::Result<()> {
-    util::test_main(async {
+    woab::test::test_main(async {
         let factory = woab::BuilderFactory::from(std::fs::read_to_string("tests/basic.ui")?);
         let ctx = Context::<TestActor>::new();
         let bld = factory.instantiate_route_to(ctx.address());
@@ -55,13 +52,13 @@ fn test_basic() -> anyhow::Result<()> {
             widgets: widgets.clone(),
         });
         widgets.buf_left.set_text("test left");
-        wait_for!(get_text(&widgets.buf_right).is_empty())?;
+        woab::wait_for!(get_text(&widgets.buf_right).is_empty())?;
         widgets.btn_copy_left_to_right.emit_clicked();
-        wait_for!(get_text(&widgets.buf_right) == "test left")?;
+        woab::wait_for!(get_text(&widgets.buf_right) == "test left")?;
         widgets.buf_left.set_text("");
         widgets.buf_right.set_text("test right");
         widgets.btn_copy_right_to_left.emit_clicked();
-        wait_for!(get_text(&widgets.buf_left) == "test right")?;
+        woab::wait_for!(get_text(&widgets.buf_left) == "test right")?;
         Ok(())
     })
 }
@@ -0,0 +1,58 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use actix::prelude::*;
+use gtk4::prelude::*;
+
+struct LabelActor {
+    removals: Rc<Cell<usize>>,
+}
+
+impl actix::Actor for LabelActor {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<woab::Remove> for LabelActor {
+    type Result = ();
+
+    fn handle(&mut self, _msg: woab::Remove, ctx: &mut Self::Context) -> Self::Result {
+        self.removals.set(self.removals.get() + 1);
+        ctx.stop();
+    }
+}
+
+#[test]
+fn test_bind_lifetime() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let window = gtk4::Window::new();
+        let container = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+        window.set_child(Some(&container));
+
+        let removals = Rc::new(Cell::new(0));
+        let labels = (0..2)
+            .map(|i| {
+                let label = gtk4::Label::new(Some(&format!("Label {}", i)));
+                container.append(&label);
+                let addr = LabelActor {
+                    removals: removals.clone(),
+                }
+                .start();
+                woab::bind_lifetime(&label, addr.clone());
+                (label, addr)
+            })
+            .collect::<Vec<_>>();
+
+        // Detaching the widget from its root removes its actor.
+        container.remove(&labels[0].0);
+        woab::wait_for!(!labels[0].1.connected())?;
+        assert_eq!(removals.get(), 1);
+        assert!(labels[1].1.connected());
+
+        // So does destroying the whole window - but `Remove` is only sent once per actor.
+        window.destroy();
+        woab::wait_for!(!labels[1].1.connected())?;
+        actix::clock::sleep(core::time::Duration::from_millis(10)).await;
+        assert_eq!(removals.get(), 2);
+        Ok(())
+    })
+}
@@ -0,0 +1,63 @@
+use gio::prelude::*;
+use gtk4::prelude::*;
+
+const SCHEMA_XML: &str = r#"
+<schemalist>
+  <schema id="org.woab.test-bind-settings">
+    <key name="font-size" type="d">
+      <default>12.0</default>
+    </key>
+    <key name="username" type="s">
+      <default>""</default>
+    </key>
+  </schema>
+</schemalist>
+"#;
+
+fn test_settings() -> anyhow::Result<gio::Settings> {
+    let schema_dir = std::env::temp_dir().join(format!("woab-test-bind-settings-{}", std::process::id()));
+    std::fs::create_dir_all(&schema_dir)?;
+    std::fs::write(schema_dir.join("org.woab.test-bind-settings.gschema.xml"), SCHEMA_XML)?;
+    let status = std::process::Command::new("glib-compile-schemas").arg(&schema_dir).status()?;
+    anyhow::ensure!(status.success(), "glib-compile-schemas failed");
+    let source = gio::SettingsSchemaSource::from_directory(&schema_dir, None, false)?;
+    let schema = source
+        .lookup("org.woab.test-bind-settings", false)
+        .ok_or_else(|| anyhow::Error::msg("schema not found"))?;
+    Ok(gio::Settings::new_full(&schema, None::<&gio::SettingsBackend>, None))
+}
+
+#[derive(woab::PropSync)]
+struct PreferencesWidgets {
+    #[prop_sync("value" as f64, setting = "font-size")]
+    font_size: gtk4::SpinButton,
+}
+
+#[test]
+fn test_bind_settings() -> anyhow::Result<()> {
+    std::env::set_var("GSETTINGS_BACKEND", "memory");
+    woab::test::test_main(async {
+        let settings = test_settings()?;
+
+        let username_entry = gtk4::Entry::new();
+        woab::bind_settings(&settings, &[("username", &username_entry, "text")])?;
+        settings.set_string("username", "alice")?;
+        assert_eq!(username_entry.text(), "alice");
+        username_entry.set_text("bob");
+        assert_eq!(settings.string("username"), "bob");
+
+        let widgets = PreferencesWidgets {
+            font_size: gtk4::SpinButton::with_range(1.0, 100.0, 1.0),
+        };
+        widgets.bind_settings(&settings)?;
+        assert_eq!(widgets.font_size.value_as_int(), 12);
+        widgets.font_size.set_value(14.0);
+        assert_eq!(settings.double("font-size") as i64, 14);
+
+        let result = woab::bind_settings(&settings, &[("font-siz", &widgets.font_size, "value")]);
+        assert!(matches!(result, Err(woab::Error::NoSuchSettingsKey { .. })));
+        let result = woab::bind_settings(&settings, &[("font-size", &widgets.font_size, "valu")]);
+        assert!(matches!(result, Err(woab::Error::NoSuchProperty { .. })));
+        Ok(())
+    })
+}
@@ -0,0 +1,49 @@
+use actix::prelude::*;
+
+struct TestActor {
+    handles: &'static [&'static str],
+}
+
+impl actix::Actor for TestActor {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<woab::Signal> for TestActor {
+    type Result = woab::SignalResult;
+
+    fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+        msg.cant_handle_expecting(self.handles)
+    }
+}
+
+impl actix::Handler<woab::ProbeSignal> for TestActor {
+    type Result = bool;
+
+    fn handle(&mut self, msg: woab::ProbeSignal, _ctx: &mut Self::Context) -> Self::Result {
+        msg.is_one_of(self.handles)
+    }
+}
+
+#[test]
+fn test_check_signals_handled() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let factory = woab::BuilderFactory::from(std::fs::read_to_string("tests/basic.ui")?);
+        let left = TestActor {
+            handles: &["copy_right_to_left"],
+        }
+        .start();
+
+        let result = factory.check_signals_handled(&[left.clone().recipient()]).await;
+        let Err(woab::Error::UnhandledSignals { signals }) = result else {
+            panic!("Expected UnhandledSignals, got {:?}", result);
+        };
+        assert_eq!(signals, ["copy_left_to_right"]);
+
+        let right = TestActor {
+            handles: &["copy_left_to_right"],
+        }
+        .start();
+        factory.check_signals_handled(&[left.recipient(), right.recipient()]).await?;
+        Ok(())
+    })
+}
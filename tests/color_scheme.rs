@@ -0,0 +1,52 @@
+// With the `adw` feature the color scheme comes from `adw::StyleManager`, which follows the
+// system preference and cannot be changed from the test.
+#![cfg(not(feature = "adw"))]
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use actix::prelude::*;
+
+struct TestActor {
+    output: Rc<RefCell<Vec<woab::ColorScheme>>>,
+    watch: Option<woab::ColorSchemeWatch>,
+}
+
+impl actix::Actor for TestActor {
+    type Context = actix::Context<Self>;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        self.watch = Some(woab::watch_color_scheme(ctx.address()));
+    }
+}
+
+impl actix::Handler<woab::ColorScheme> for TestActor {
+    type Result = ();
+
+    fn handle(&mut self, msg: woab::ColorScheme, _ctx: &mut Self::Context) -> Self::Result {
+        self.output.borrow_mut().push(msg);
+    }
+}
+
+#[test]
+fn test_watch_color_scheme() -> anyhow::Result<()> {
+    use woab::ColorScheme::{Dark, Light};
+
+    woab::test::test_main(async {
+        let settings = gtk4::Settings::default().unwrap();
+        settings.set_gtk_application_prefer_dark_theme(false);
+
+        let output = Rc::new(RefCell::new(Vec::new()));
+        TestActor {
+            output: output.clone(),
+            watch: None,
+        }
+        .start();
+        woab::wait_for!(*output.borrow() == [Light])?;
+
+        settings.set_gtk_application_prefer_dark_theme(true);
+        woab::wait_for!(*output.borrow() == [Light, Dark])?;
+        assert_eq!(woab::ColorScheme::current(), Dark);
+        Ok(())
+    })
+}
@@ -0,0 +1,123 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use actix::prelude::*;
+use gtk4::prelude::*;
+use woab::columns::Columns;
+
+#[derive(woab::Columns)]
+struct Task {
+    #[column(title = "Description")]
+    description: String,
+    #[column(title = "Priority", numeric, sort)]
+    priority: u32,
+    #[column(title = "Done", check, edit = "task_done_toggled")]
+    done: bool,
+}
+
+struct TasksActor {
+    output: Rc<RefCell<Vec<(String, bool)>>>,
+}
+
+impl actix::Actor for TasksActor {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<woab::Signal> for TasksActor {
+    type Result = woab::SignalResult;
+
+    fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+        Ok(match msg.name() {
+            "task_done_toggled" => {
+                let woab::params!(item: glib::BoxedAnyObject, done: bool) = msg.params()?;
+                let task = item.borrow::<Task>();
+                self.output.borrow_mut().push((task.description.clone(), done));
+                None
+            }
+            _ => msg.cant_handle()?,
+        })
+    }
+}
+
+fn descendants(widget: &gtk4::Widget) -> Vec<gtk4::Widget> {
+    let mut result = Vec::new();
+    let mut child = widget.first_child();
+    while let Some(widget) = child {
+        result.extend(descendants(&widget));
+        child = widget.next_sibling();
+        result.push(widget);
+    }
+    result
+}
+
+#[test]
+fn test_columns() -> anyhow::Result<()> {
+    let output = Rc::new(RefCell::new(Vec::new()));
+    let column_view = Rc::new(RefCell::new(None::<gtk4::ColumnView>));
+    let toggled = core::cell::Cell::new(false);
+    woab::test::test_main_loop_until::<anyhow::Error>(
+        || {
+            let columns = Task::columns(None);
+            let titles = columns.iter().map(|column| column.title().unwrap()).collect::<Vec<_>>();
+            assert_eq!(titles, ["Description", "Priority", "Done"]);
+            let sorters = columns.iter().map(|column| column.sorter().is_some()).collect::<Vec<_>>();
+            assert_eq!(sorters, [false, true, false]);
+
+            let task = |description: &str, priority, done| {
+                glib::BoxedAnyObject::new(Task {
+                    description: description.to_owned(),
+                    priority,
+                    done,
+                })
+            };
+            let urgent = task("Fix the bug", 1, false);
+            let later = task("Write the docs", 5, true);
+            let sorter = columns[1].sorter().unwrap();
+            assert_eq!(sorter.compare(&urgent, &later), gtk4::Ordering::Smaller);
+            assert_eq!(sorter.compare(&later, &urgent), gtk4::Ordering::Larger);
+
+            let store = gio::ListStore::new::<glib::BoxedAnyObject>();
+            store.append(&urgent);
+            store.append(&later);
+            let view = gtk4::ColumnView::new(Some(gtk4::NoSelection::new(Some(store))));
+            let actor = TasksActor { output: output.clone() }.start();
+            Task::append_columns(&view, Some(actor.recipient()));
+            let window = gtk4::Window::builder().child(&view).default_height(200).build();
+            window.present();
+            *column_view.borrow_mut() = Some(view);
+        },
+        || {
+            let column_view = column_view.borrow();
+            let widgets = descendants(column_view.as_ref().unwrap().upcast_ref());
+            let check_buttons = widgets
+                .iter()
+                .filter_map(|widget| widget.downcast_ref::<gtk4::CheckButton>())
+                .collect::<Vec<_>>();
+            if check_buttons.len() < 2 {
+                return false;
+            }
+            if !toggled.replace(true) {
+                let texts = widgets
+                    .iter()
+                    .filter_map(|widget| widget.downcast_ref::<gtk4::Label>())
+                    .map(|label| label.text().to_string())
+                    .collect::<Vec<_>>();
+                for text in ["Fix the bug", "1", "Write the docs", "5"] {
+                    assert!(texts.iter().any(|t| t == text), "{:?} not in {:?}", text, texts);
+                }
+                assert_eq!(
+                    check_buttons
+                        .iter()
+                        .map(|check_button| check_button.is_active())
+                        .collect::<Vec<_>>(),
+                    [false, true]
+                );
+                check_buttons[0].set_active(true);
+            }
+            !output.borrow().is_empty()
+        },
+        std::time::Duration::from_secs(5),
+    )?;
+    assert_eq!(*output.borrow(), [("Fix the bug".to_owned(), true)]);
+    Ok(())
+}
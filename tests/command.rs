@@ -0,0 +1,70 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use actix::prelude::*;
+use gtk4::prelude::*;
+
+struct TestActor {
+    output: Rc<RefCell<Vec<&'static str>>>,
+}
+
+impl actix::Actor for TestActor {
+    type Context = actix::Context<Self>;
+}
+
+struct Done(&'static str);
+
+impl actix::Message for Done {
+    type Result = ();
+}
+
+impl actix::Handler<Done> for TestActor {
+    type Result = ();
+
+    fn handle(&mut self, msg: Done, _ctx: &mut Self::Context) -> Self::Result {
+        self.output.borrow_mut().push(msg.0);
+    }
+}
+
+#[test]
+fn test_command() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let output = Rc::new(RefCell::new(Vec::new()));
+        let addr = TestActor { output: output.clone() }.start();
+
+        let button = gtk4::Button::new();
+        let already_insensitive = gtk4::Entry::new();
+        already_insensitive.set_sensitive(false);
+        let spinner = gtk4::Spinner::new();
+        let command = woab::Command::new()
+            .disable(&button)
+            .disable(&already_insensitive)
+            .spinner(&spinner);
+        assert!(!spinner.is_visible());
+
+        let (tx, rx) = tokio::sync::oneshot::channel::<()>();
+        assert!(command.run(addr.clone(), async move {
+            rx.await.unwrap();
+            Done("first")
+        }));
+        assert!(command.is_running());
+        assert!(!button.is_sensitive());
+        assert!(spinner.is_visible());
+        assert!(spinner.is_spinning());
+
+        assert!(!command.run(addr.clone(), async { Done("second") }));
+
+        tx.send(()).unwrap();
+        woab::wait_for!(*output.borrow() == ["first"])?;
+        assert!(!command.is_running());
+        assert!(button.is_sensitive());
+        assert!(!already_insensitive.is_sensitive());
+        assert!(!spinner.is_visible());
+        assert!(!spinner.is_spinning());
+
+        assert!(command.run(addr, async { Done("third") }));
+        woab::wait_for!(*output.borrow() == ["first", "third"])?;
+
+        Ok(())
+    })
+}
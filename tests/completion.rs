@@ -0,0 +1,53 @@
+use core::time::Duration;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use actix::prelude::*;
+use gtk4::prelude::*;
+
+struct TestActor {
+    queries: Rc<RefCell<Vec<String>>>,
+}
+
+impl actix::Actor for TestActor {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<woab::CompletionQuery> for TestActor {
+    type Result = Vec<String>;
+
+    fn handle(&mut self, msg: woab::CompletionQuery, _ctx: &mut Self::Context) -> Self::Result {
+        self.queries.borrow_mut().push(msg.prefix.clone());
+        ["apple", "apricot", "banana"]
+            .into_iter()
+            .filter(|fruit| fruit.starts_with(&msg.prefix))
+            .map(|fruit| fruit.to_owned())
+            .collect()
+    }
+}
+
+#[test]
+fn test_completion() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let queries = Rc::new(RefCell::new(Vec::new()));
+        let addr = TestActor {
+            queries: queries.clone(),
+        }
+        .start();
+        let entry = gtk4::Entry::new();
+        let completion = woab::Completion::new(&entry, addr).debounce(Duration::from_millis(10));
+
+        // Only the last text is queried
+        entry.set_text("b");
+        entry.set_text("a");
+        entry.set_text("ap");
+        woab::wait_for!(!completion.suggestions().is_empty())?;
+        assert_eq!(completion.suggestions(), ["apple", "apricot"]);
+        assert_eq!(*queries.borrow(), ["ap"]);
+
+        entry.set_text("");
+        assert!(completion.suggestions().is_empty());
+        assert_eq!(*queries.borrow(), ["ap"]);
+        Ok(())
+    })
+}
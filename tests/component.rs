@@ -0,0 +1,115 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use actix::prelude::*;
+use gtk4::prelude::*;
+
+use woab::component::{Children, Component};
+
+#[derive(woab::Removable)]
+#[removable(self.label in gtk4::Box)]
+struct LabelComponent {
+    label: gtk4::Label,
+    output: actix::Recipient<Echo>,
+}
+
+impl actix::Actor for LabelComponent {
+    type Context = actix::Context<Self>;
+}
+
+#[derive(Clone)]
+struct SetText(String);
+
+impl actix::Message for SetText {
+    type Result = ();
+}
+
+struct Echo(String);
+
+impl actix::Message for Echo {
+    type Result = ();
+}
+
+impl Component for LabelComponent {
+    type Init = &'static str;
+    type Input = SetText;
+    type Output = Echo;
+
+    fn create_component(init: Self::Init, output: actix::Recipient<Echo>, _ctx: &mut Self::Context) -> Self {
+        Self {
+            label: gtk4::Label::new(Some(init)),
+            output,
+        }
+    }
+
+    fn root_widget(&self) -> gtk4::Widget {
+        self.label.clone().upcast()
+    }
+}
+
+impl actix::Handler<SetText> for LabelComponent {
+    type Result = ();
+
+    fn handle(&mut self, msg: SetText, _ctx: &mut Self::Context) -> Self::Result {
+        self.label.set_text(&msg.0);
+        self.output.do_send(Echo(msg.0));
+    }
+}
+
+struct ParentActor {
+    output: Rc<RefCell<Vec<String>>>,
+}
+
+impl actix::Actor for ParentActor {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<Echo> for ParentActor {
+    type Result = ();
+
+    fn handle(&mut self, msg: Echo, _ctx: &mut Self::Context) -> Self::Result {
+        self.output.borrow_mut().push(msg.0);
+    }
+}
+
+fn labels(container: &gtk4::Box) -> Vec<String> {
+    let mut result = Vec::new();
+    let mut child = container.first_child();
+    while let Some(widget) = child {
+        result.push(widget.downcast_ref::<gtk4::Label>().unwrap().text().into());
+        child = widget.next_sibling();
+    }
+    result
+}
+
+#[test]
+fn test_children() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let output = Rc::new(RefCell::new(Vec::new()));
+        let parent = ParentActor { output: output.clone() }.start();
+        let container = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+
+        let mut children = Children::<LabelComponent>::new(&container, parent);
+        let first = children.mount("first");
+        let second = children.mount("second");
+        assert_eq!(labels(&container), ["first", "second"]);
+        assert_eq!(children.len(), 2);
+
+        second.do_send(SetText("changed".to_owned()));
+        woab::wait_for!(*output.borrow() == ["changed"])?;
+        assert_eq!(labels(&container), ["first", "changed"]);
+
+        children.unmount(&first);
+        woab::wait_for!(!first.connected())?;
+        assert_eq!(labels(&container), ["changed"]);
+        assert_eq!(children.len(), 1);
+
+        children.send_all(SetText("all".to_owned()));
+        woab::wait_for!(*output.borrow() == ["changed", "all"])?;
+
+        children.unmount_all().await;
+        assert!(children.is_empty());
+        assert_eq!(labels(&container), Vec::<String>::new());
+        Ok(())
+    })
+}
@@ -0,0 +1,85 @@
+use actix::prelude::*;
+use gtk4::prelude::*;
+
+use woab::component::{Children, Component};
+
+#[derive(woab::Removable)]
+#[removable(self.label in gtk4::ListBox)]
+struct LabelComponent {
+    label: gtk4::Label,
+}
+
+impl actix::Actor for LabelComponent {
+    type Context = actix::Context<Self>;
+}
+
+struct Nothing;
+
+impl actix::Message for Nothing {
+    type Result = ();
+}
+
+impl Component for LabelComponent {
+    type Init = &'static str;
+    type Input = Nothing;
+    type Output = Nothing;
+
+    fn create_component(init: Self::Init, _output: actix::Recipient<Nothing>, _ctx: &mut Self::Context) -> Self {
+        Self {
+            label: gtk4::Label::new(Some(init)),
+        }
+    }
+
+    fn root_widget(&self) -> gtk4::Widget {
+        self.label.clone().upcast()
+    }
+}
+
+impl actix::Handler<Nothing> for LabelComponent {
+    type Result = ();
+
+    fn handle(&mut self, _msg: Nothing, _ctx: &mut Self::Context) -> Self::Result {}
+}
+
+struct ParentActor;
+
+impl actix::Actor for ParentActor {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<Nothing> for ParentActor {
+    type Result = ();
+
+    fn handle(&mut self, _msg: Nothing, _ctx: &mut Self::Context) -> Self::Result {}
+}
+
+fn labels(list_box: &gtk4::ListBox) -> Vec<String> {
+    let mut result = Vec::new();
+    let mut child = list_box.first_child();
+    while let Some(widget) = child {
+        let row = widget.downcast_ref::<gtk4::ListBoxRow>().unwrap();
+        result.push(row.child().and_downcast::<gtk4::Label>().unwrap().text().into());
+        child = widget.next_sibling();
+    }
+    result
+}
+
+#[test]
+fn test_component_in_list_box() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let list_box = gtk4::ListBox::new();
+
+        let mut children = Children::<LabelComponent>::new(&list_box, ParentActor.start());
+        let first = children.mount("first");
+        children.mount("second");
+        assert_eq!(labels(&list_box), ["first", "second"]);
+
+        // The labels are wrapped in rows, so removing them removes the rows.
+        assert!(first.send(woab::RemoveAndConfirm).await?);
+        assert_eq!(labels(&list_box), ["second"]);
+
+        children.unmount_all().await;
+        assert_eq!(labels(&list_box), Vec::<String>::new());
+        Ok(())
+    })
+}
@@ -5,9 +5,6 @@ use actix::prelude::*;
 use gio::prelude::*;
 use hashbrown::HashMap;
 
-#[macro_use]
-mod util;
-
 struct TestActor {
     action_group: gio::SimpleActionGroup,
     output: Rc<RefCell<Vec<&'static str>>>,
@@ -80,7 +77,7 @@ impl actix::Handler<woab::Signal> for TestActor {
 
 #[test]
 fn test_connect_nonbuilder_signals() -> anyhow::Result<()> {
-    util::test_main(async {
+    woab::test::test_main(async {
         let output = Rc::new(RefCell::new(Vec::new()));
 
         let action_group = gio::SimpleActionGroup::new();
@@ -91,28 +88,28 @@ fn test_connect_nonbuilder_signals() -> anyhow::Result<()> {
         }
         .start();
 
-        wait_for!(*output.borrow() == ["init"])?;
+        woab::wait_for!(*output.borrow() == ["init"])?;
         action_group.activate_action("action1", None);
-        wait_for!(*output.borrow() == ["init", "action1"])?;
+        woab::wait_for!(*output.borrow() == ["init", "action1"])?;
         action_group.activate_action("action2", None);
-        wait_for!(*output.borrow() == ["init", "action1", "action2"])?;
+        woab::wait_for!(*output.borrow() == ["init", "action1", "action2"])?;
         action_group.activate_action("block", Some(&"action1".to_variant()));
-        wait_for!(*output.borrow() == ["init", "action1", "action2", "block"])?;
+        woab::wait_for!(*output.borrow() == ["init", "action1", "action2", "block"])?;
 
         // We send both action1 and action2, but action1 is blocked
         action_group.activate_action("action1", None);
         action_group.activate_action("action2", None);
-        wait_for!(*output.borrow() == ["init", "action1", "action2", "block", "action2"])?;
+        woab::wait_for!(*output.borrow() == ["init", "action1", "action2", "block", "action2"])?;
 
         action_group.activate_action("unblock", Some(&"action1".to_variant()));
-        wait_for!(*output.borrow() == ["init", "action1", "action2", "block", "action2", "unblock"])?;
+        woab::wait_for!(*output.borrow() == ["init", "action1", "action2", "block", "action2", "unblock"])?;
         action_group.activate_action("disconnect", Some(&"action2".to_variant()));
-        wait_for!(*output.borrow() == ["init", "action1", "action2", "block", "action2", "unblock", "disconnect"])?;
+        woab::wait_for!(*output.borrow() == ["init", "action1", "action2", "block", "action2", "unblock", "disconnect"])?;
 
         // We send both action2 and action1, but action2 is disconnected
         action_group.activate_action("action2", None);
         action_group.activate_action("action1", None);
-        wait_for!(
+        woab::wait_for!(
             *output.borrow()
                 == [
                     "init",
@@ -0,0 +1,51 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use actix::prelude::*;
+use glib::translate::IntoGlib;
+
+struct TestActor {
+    priorities: Rc<RefCell<Vec<Option<i32>>>>,
+}
+
+impl actix::Actor for TestActor {
+    type Context = actix::Context<Self>;
+}
+
+struct RecordPriority;
+
+impl actix::Message for RecordPriority {
+    type Result = ();
+}
+
+impl actix::Handler<RecordPriority> for TestActor {
+    type Result = ();
+
+    fn handle(&mut self, _msg: RecordPriority, _ctx: &mut Self::Context) -> Self::Result {
+        // The source currently dispatched by GLib is the one cranking the Actix runtime.
+        let priority = glib::main_current_source().map(|source| source.priority());
+        self.priorities.borrow_mut().push(priority);
+    }
+}
+
+#[test]
+fn test_cranking_priority() -> anyhow::Result<()> {
+    woab::set_cranking_priority(Some(glib::Priority::HIGH));
+    let priorities = Rc::new(RefCell::new(Vec::new()));
+    woab::test::test_main_loop_until::<anyhow::Error>(
+        || {
+            let addr = TestActor {
+                priorities: priorities.clone(),
+            }
+            .start();
+            let tx = woab::channel_to_actor(addr);
+            std::thread::spawn(move || {
+                tx.send(RecordPriority).unwrap();
+            });
+        },
+        || !priorities.borrow().is_empty(),
+        std::time::Duration::from_secs(1),
+    )?;
+    assert_eq!(*priorities.borrow(), [Some(glib::Priority::HIGH.into_glib())]);
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use actix::prelude::*;
+use gio::prelude::*;
+
+struct TestActor {
+    output: Rc<RefCell<Vec<String>>>,
+}
+
+impl actix::Actor for TestActor {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<woab::Signal> for TestActor {
+    type Result = woab::SignalResult;
+
+    fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+        Ok(match msg.name() {
+            "alignment" => {
+                let alignment: String = msg.detailed_target()?;
+                self.output.borrow_mut().push(alignment);
+                None
+            }
+            _ => msg.cant_handle()?,
+        })
+    }
+}
+
+#[test]
+fn test_route_detailed_action() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let output = Rc::new(RefCell::new(Vec::<String>::new()));
+        let addr = TestActor { output: output.clone() }.start();
+        let group = gio::SimpleActionGroup::new();
+
+        let action = woab::route_detailed_action(&group, "app.alignment::left", addr.clone())?;
+        for detailed_name in ["app.alignment::center", "app.alignment::right"] {
+            let same_action = woab::route_detailed_action(&group, detailed_name, addr.clone())?;
+            assert_eq!(same_action, action);
+        }
+        assert_eq!(action.state(), Some("left".to_variant()));
+
+        assert!(woab::route_detailed_action(&group, "app.alignment", addr.clone()).is_err());
+        assert!(woab::route_detailed_action(&group, "app.alignment(42)", addr).is_err());
+
+        group.activate_action("alignment", Some(&"center".to_variant()));
+        woab::wait_for!(*output.borrow() == ["center"])?;
+        assert_eq!(action.state(), Some("center".to_variant()));
+        Ok(())
+    })
+}
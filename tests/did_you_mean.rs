@@ -0,0 +1,19 @@
+#[test]
+fn test_did_you_mean_suggestions() {
+    let signal = woab::Signal::for_test("buton_clicked", ());
+    match signal.cant_handle_expecting(&["button_clicked", "button_pressed", "window_closed"]) {
+        Err(woab::Error::NoSuchSignalError { signal, suggestions }) => {
+            assert_eq!(signal, "buton_clicked");
+            assert_eq!(suggestions, ["button_clicked"]);
+        }
+        _ => panic!("Expected NoSuchSignalError"),
+    }
+
+    let signal = woab::Signal::for_test("something_else", ());
+    match signal.cant_handle_expecting(&["button_clicked", "button_pressed"]) {
+        Err(woab::Error::NoSuchSignalError { suggestions, .. }) => {
+            assert!(suggestions.is_empty());
+        }
+        _ => panic!("Expected NoSuchSignalError"),
+    }
+}
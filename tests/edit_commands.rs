@@ -0,0 +1,46 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use actix::prelude::*;
+use gtk4::prelude::*;
+
+struct TestActor {
+    output: Rc<RefCell<Vec<String>>>,
+}
+
+impl actix::Actor for TestActor {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<woab::Signal> for TestActor {
+    type Result = woab::SignalResult;
+
+    fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+        let woab::params!(_widget: gtk4::Widget) = msg.params()?;
+        self.output.borrow_mut().push(msg.name().to_owned());
+        Ok(None)
+    }
+}
+
+#[test]
+fn test_edit_commands() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let entry = gtk4::Entry::new();
+        entry.set_text("hello");
+        assert!(woab::dispatch_edit_command(&entry, woab::EditCommand::SelectAll));
+        assert_eq!(entry.selection_bounds(), Some((0, 5)));
+
+        let output = Rc::new(RefCell::new(Vec::<String>::new()));
+        let container = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+        let button = gtk4::Button::new();
+        container.append(&button);
+
+        assert!(!woab::dispatch_edit_command(&button, woab::EditCommand::Copy));
+
+        woab::register_actor(&container, TestActor { output: output.clone() }.start().recipient());
+        assert!(woab::dispatch_edit_command(&button, woab::EditCommand::Copy));
+        assert!(woab::dispatch_edit_command(&button, woab::EditCommand::Paste));
+        woab::wait_for!(*output.borrow() == ["edit_copy", "edit_paste"])?;
+        Ok(())
+    })
+}
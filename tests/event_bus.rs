@@ -0,0 +1,63 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use actix::prelude::*;
+
+use woab::event_bus::{EventBus, Publish, Subscribe, Unsubscribe};
+
+#[derive(Clone)]
+struct TestEvent(&'static str);
+
+impl actix::Message for TestEvent {
+    type Result = ();
+}
+
+struct TestActor {
+    name: &'static str,
+    output: Rc<RefCell<Vec<String>>>,
+}
+
+impl actix::Actor for TestActor {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<TestEvent> for TestActor {
+    type Result = ();
+
+    fn handle(&mut self, msg: TestEvent, _ctx: &mut Self::Context) -> Self::Result {
+        self.output.borrow_mut().push(format!("{} got {}", self.name, msg.0));
+    }
+}
+
+#[test]
+fn test_event_bus() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let output = Rc::new(RefCell::new(Vec::<String>::new()));
+        let bus = EventBus::<TestEvent>::new().start();
+
+        let actor1 = TestActor {
+            name: "actor1",
+            output: output.clone(),
+        }
+        .start();
+        let actor2 = TestActor {
+            name: "actor2",
+            output: output.clone(),
+        }
+        .start();
+        let subscription1 = bus.send(Subscribe(actor1.recipient())).await?;
+        bus.send(Subscribe(actor2.recipient())).await?;
+
+        bus.send(Publish(TestEvent("first"))).await?;
+        woab::wait_for!(output.borrow().len() == 2)?;
+        output.borrow_mut().sort();
+        assert_eq!(*output.borrow(), ["actor1 got first", "actor2 got first"]);
+        output.borrow_mut().clear();
+
+        assert!(bus.send(Unsubscribe(subscription1)).await?);
+        assert!(!bus.send(Unsubscribe(subscription1)).await?);
+        bus.send(Publish(TestEvent("second"))).await?;
+        woab::wait_for!(*output.borrow() == ["actor2 got second"])?;
+        Ok(())
+    })
+}
@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+
+#[derive(woab::Factories)]
+struct Factories {
+    win_app: String,
+    #[factory(extra(buf_text))]
+    row: String,
+}
+
+#[derive(woab::Factories)]
+struct SelfClosingFactories {
+    buf_text: String,
+    row: String,
+}
+
+// Never read successfully - only used to check the error.
+#[allow(dead_code)]
+#[derive(woab::Factories)]
+struct MisspelledFactories {
+    win_app: String,
+    rows: String,
+}
+
+const UI_XML: &str = r#"
+<interface>
+  <object class="GtkApplicationWindow" id="win_app"/>
+  <object class="GtkTextBuffer" id="buf_text"/>
+  <object class="GtkListBoxRow" id="row">
+    <child>
+      <object class="GtkTextView">
+        <property name="buffer">buf_text</property>
+      </object>
+    </child>
+  </object>
+</interface>
+"#;
+
+#[test]
+fn test_factories_read() -> anyhow::Result<()> {
+    let factories = Factories::read(UI_XML.as_bytes())?;
+    assert!(factories.win_app.contains(r#"id="win_app""#));
+    assert!(!factories.win_app.contains(r#"id="row""#));
+    assert!(factories.row.contains(r#"id="buf_text""#));
+    assert!(factories.row.contains(r#"id="row""#));
+    assert!(!factories.row.contains(r#"id="win_app""#));
+    Ok(())
+}
+
+#[test]
+fn test_factories_self_closing_object() -> anyhow::Result<()> {
+    let factories = SelfClosingFactories::read(UI_XML.as_bytes())?;
+    assert!(factories.buf_text.contains(r#"id="buf_text""#));
+    assert!(!factories.buf_text.contains(r#"id="row""#));
+    assert!(!factories.buf_text.contains(r#"id="win_app""#));
+    // Self-closing objects are not copied to the other factories like the top-level elements that
+    // are not objects.
+    assert!(!factories.row.contains(r#"id="buf_text""#));
+    assert!(!factories.row.contains(r#"id="win_app""#));
+    Ok(())
+}
+
+#[test]
+fn test_factories_id_not_found() {
+    let Err(err) = MisspelledFactories::read(UI_XML.as_bytes()) else {
+        panic!("Reading should have failed");
+    };
+    match &err {
+        woab::Error::FactoryIdNotFound { field, known_ids } => {
+            assert_eq!(field, "rows");
+            assert_eq!(known_ids, &["win_app", "buf_text", "row"]);
+        }
+        _ => panic!("Unexpected error {:?}", err),
+    }
+    assert_eq!(
+        err.to_string(),
+        r#"No object in the UI XML has the ID "rows" - did you mean "row"?"#
+    );
+}
+
+#[derive(woab::Factories)]
+struct FactoriesWithRest {
+    #[factory(extra(buf_text))]
+    row: String,
+    #[factory(rest)]
+    other: HashMap<String, String>,
+}
+
+#[test]
+fn test_factories_rest() -> anyhow::Result<()> {
+    let factories = FactoriesWithRest::read(UI_XML.as_bytes())?;
+    assert!(factories.row.contains(r#"id="row""#));
+    assert_eq!(factories.other.keys().collect::<Vec<_>>(), ["win_app"]);
+    let win_app = &factories.other["win_app"];
+    assert!(win_app.starts_with("\n<interface>"));
+    assert!(win_app.contains(r#"id="win_app""#));
+    assert!(!win_app.contains(r#"id="row""#));
+    assert!(!win_app.contains(r#"id="buf_text""#));
+    assert!(win_app.trim_end().ends_with("</interface>"));
+    Ok(())
+}
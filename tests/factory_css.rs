@@ -0,0 +1,64 @@
+use std::cell::RefCell;
+
+use gtk4::prelude::*;
+
+#[derive(woab::Factories)]
+struct Factories {
+    #[factory(css = "factory_css.css")]
+    wide_label: woab::BuilderFactory,
+    tall_label: woab::BuilderFactory,
+}
+
+const UI_XML: &str = r#"
+<interface>
+  <object class="GtkLabel" id="wide_label">
+    <style>
+      <class name="woab-test-wide"/>
+    </style>
+  </object>
+  <object class="GtkLabel" id="tall_label">
+    <style>
+      <class name="woab-test-tall"/>
+    </style>
+  </object>
+</interface>
+"#;
+
+#[test]
+fn test_factory_css() -> anyhow::Result<()> {
+    let factories = Factories::read(UI_XML.as_bytes())?;
+    let tall_label_factory = factories.tall_label.with_css("label.woab-test-tall { min-height: 45px; }");
+    let labels = RefCell::new(None::<(gtk4::Label, gtk4::Label)>);
+    woab::test::test_main_loop_until::<anyhow::Error>(
+        || {
+            // The CSS is loaded when the factories are instantiated.
+            let wide_label: gtk4::Label = factories
+                .wide_label
+                .instantiate_without_routing_signals()
+                .get_object("wide_label")
+                .unwrap();
+            let tall_label: gtk4::Label = tall_label_factory
+                .instantiate_without_routing_signals()
+                .get_object("tall_label")
+                .unwrap();
+            let vbox = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+            vbox.append(&wide_label);
+            vbox.append(&tall_label);
+            gtk4::Window::builder().child(&vbox).build().present();
+            *labels.borrow_mut() = Some((wide_label, tall_label));
+        },
+        || {
+            labels
+                .borrow()
+                .as_ref()
+                .is_some_and(|(wide_label, tall_label)| wide_label.is_mapped() && tall_label.is_mapped())
+        },
+        std::time::Duration::from_secs(5),
+    )?;
+    let (wide_label, tall_label) = labels.take().unwrap();
+    let (min_width, ..) = wide_label.measure(gtk4::Orientation::Horizontal, -1);
+    assert!(123 <= min_width);
+    let (min_height, ..) = tall_label.measure(gtk4::Orientation::Vertical, -1);
+    assert!(45 <= min_height);
+    Ok(())
+}
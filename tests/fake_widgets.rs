@@ -0,0 +1,83 @@
+use actix::prelude::*;
+use woab::test::{FakeWidgetCall, FakeWidgets};
+use woab::WidgetOps;
+
+struct CounterView<W> {
+    count_label: W,
+    step_entry: W,
+    decrement_button: W,
+}
+
+struct CounterActor<W> {
+    view: CounterView<W>,
+    count: u32,
+}
+
+impl<W: 'static + Unpin> actix::Actor for CounterActor<W> {
+    type Context = actix::Context<Self>;
+}
+
+impl<W: 'static + Unpin + WidgetOps> actix::Handler<woab::Signal> for CounterActor<W> {
+    type Result = woab::SignalResult;
+
+    fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+        let step = self.view.step_entry.text().parse().unwrap_or(1);
+        Ok(match msg.name() {
+            "increment" => {
+                self.count += step;
+                self.view.count_label.set_text(&self.count.to_string());
+                self.view.decrement_button.set_sensitive(0 < self.count);
+                None
+            }
+            "decrement" => {
+                self.count = self.count.saturating_sub(step);
+                self.view.count_label.set_text(&self.count.to_string());
+                self.view.decrement_button.set_sensitive(0 < self.count);
+                None
+            }
+            _ => msg.cant_handle()?,
+        })
+    }
+}
+
+#[test]
+fn test_fake_widgets() -> anyhow::Result<()> {
+    let fakes = FakeWidgets::new();
+    let mut actor = CounterActor {
+        view: CounterView {
+            count_label: fakes.widget("count_label"),
+            step_entry: fakes.widget("step_entry"),
+            decrement_button: fakes.widget("decrement_button"),
+        },
+        count: 0,
+    };
+    let mut ctx = actix::Context::new();
+
+    actor.handle(woab::Signal::for_test("increment", ()), &mut ctx)?;
+    assert_eq!(
+        fakes.take_calls(),
+        [
+            FakeWidgetCall::SetText {
+                widget: "count_label".to_owned(),
+                text: "1".to_owned(),
+            },
+            FakeWidgetCall::SetSensitive {
+                widget: "decrement_button".to_owned(),
+                sensitive: true,
+            },
+        ]
+    );
+
+    actor.view.step_entry.simulate_text("5");
+    actor.handle(woab::Signal::for_test("decrement", ()), &mut ctx)?;
+    assert_eq!(actor.view.count_label.text(), "0");
+    assert!(!actor.view.decrement_button.is_sensitive());
+    assert_eq!(fakes.calls().len(), 2);
+    assert!(fakes
+        .calls()
+        .iter()
+        .all(|call| !matches!(call, FakeWidgetCall::SetText { widget, .. } if widget == "step_entry")));
+
+    assert!(!gtk4::is_initialized());
+    Ok(())
+}
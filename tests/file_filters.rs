@@ -0,0 +1,24 @@
+use gtk4::prelude::*;
+
+#[test]
+fn test_file_filters() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let filters = woab::FileFilters::new()
+            .filter("Text files", ["*.txt"])
+            .mime_filter("Images", ["image/*"])
+            .as_default()
+            .all_files("All files");
+
+        let list_store = filters.list_store();
+        let names = list_store
+            .iter::<gtk4::FileFilter>()
+            .map(|filter| Ok(filter?.name().unwrap().to_string()))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        assert_eq!(names, ["Text files", "Images", "All files"]);
+        assert_eq!(
+            filters.default_filter().and_then(|filter| filter.name()).as_deref(),
+            Some("Images")
+        );
+        Ok(())
+    })
+}
@@ -0,0 +1,73 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use actix::prelude::*;
+use gio::prelude::*;
+
+fn query_size(file: &gio::File, cancellable: &gio::Cancellable, callback: woab::GioTaskCallback<gio::FileInfo>) {
+    file.query_info_async(
+        gio::FILE_ATTRIBUTE_STANDARD_SIZE,
+        gio::FileQueryInfoFlags::NONE,
+        glib::Priority::DEFAULT,
+        Some(cancellable),
+        callback,
+    )
+}
+
+struct TestActor {
+    file: gio::File,
+    output: Rc<RefCell<Vec<String>>>,
+}
+
+impl actix::Actor for TestActor {
+    type Context = actix::Context<Self>;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        use woab::ActixContextExt;
+
+        let file = self.file.clone();
+        ctx.spawn_gio_task(
+            move |cancellable, callback| query_size(&file, cancellable, callback),
+            |result, act, _ctx| {
+                act.output.borrow_mut().push(format!("size {}", result.unwrap().size()));
+            },
+        );
+
+        let cancellable = gio::Cancellable::new();
+        cancellable.cancel();
+        let file = self.file.clone();
+        ctx.spawn(
+            woab::gio_task(&cancellable, move |cancellable, callback| {
+                query_size(&file, cancellable, callback)
+            })
+            .into_actor(self)
+            .map(|result, act, _ctx| {
+                act.output.borrow_mut().push(format!("{:?}", result.map(|_| ())));
+            }),
+        );
+    }
+}
+
+#[test]
+fn test_gio_task() -> anyhow::Result<()> {
+    let path = std::env::temp_dir().join(format!("woab-test-gio-task-{}", std::process::id()));
+    std::fs::write(&path, b"hello")?;
+    let output = Rc::new(RefCell::new(Vec::<String>::new()));
+    let result: anyhow::Result<()> = woab::test::test_main_loop_until(
+        || {
+            TestActor {
+                file: gio::File::for_path(&path),
+                output: output.clone(),
+            }
+            .start();
+        },
+        || output.borrow().len() == 2,
+        std::time::Duration::from_secs(1),
+    );
+    std::fs::remove_file(&path)?;
+    result?;
+    let mut output = output.borrow().clone();
+    output.sort();
+    assert_eq!(output, ["Err(Cancelled)", "size 5"]);
+    Ok(())
+}
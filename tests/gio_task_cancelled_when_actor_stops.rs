@@ -0,0 +1,47 @@
+use actix::prelude::*;
+use gio::prelude::*;
+
+fn query_size(file: &gio::File, cancellable: &gio::Cancellable, callback: woab::GioTaskCallback<gio::FileInfo>) {
+    file.query_info_async(
+        gio::FILE_ATTRIBUTE_STANDARD_SIZE,
+        gio::FileQueryInfoFlags::NONE,
+        glib::Priority::DEFAULT,
+        Some(cancellable),
+        callback,
+    )
+}
+
+struct StoppingActor {
+    cancellable: gio::Cancellable,
+}
+
+impl actix::Actor for StoppingActor {
+    type Context = actix::Context<Self>;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        let file = gio::File::for_path(std::env::temp_dir());
+        ctx.spawn(
+            woab::gio_task(&self.cancellable, move |cancellable, callback| {
+                query_size(&file, cancellable, callback)
+            })
+            .into_actor(self)
+            .map(|_, _, _| panic!("The task should have been dropped")),
+        );
+        ctx.stop();
+    }
+}
+
+#[test]
+fn test_gio_task_cancelled_when_actor_stops() -> anyhow::Result<()> {
+    let cancellable = gio::Cancellable::new();
+    woab::test::test_main_loop_until(
+        || {
+            StoppingActor {
+                cancellable: cancellable.clone(),
+            }
+            .start();
+        },
+        || cancellable.is_cancelled(),
+        std::time::Duration::from_secs(1),
+    )
+}
@@ -0,0 +1,47 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use actix::prelude::*;
+use gio::prelude::*;
+
+struct TestActor {
+    output: Rc<RefCell<Vec<String>>>,
+}
+
+impl actix::Actor for TestActor {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<woab::Signal> for TestActor {
+    type Result = woab::SignalResult;
+
+    fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+        self.output.borrow_mut().push(msg.name().to_owned());
+        Ok(None)
+    }
+}
+
+#[test]
+fn test_headless_main() -> anyhow::Result<()> {
+    let output = Rc::new(RefCell::new(Vec::new()));
+    woab::test::headless_main::<anyhow::Error>({
+        let output = output.clone();
+        async move {
+            let action = gio::SimpleAction::new("headless_action", None);
+            woab::route_action(&action, TestActor { output: output.clone() }.start())?;
+            action.activate(None);
+            action.activate(None);
+            woab::wait_for!(output.borrow().len() == 2)?;
+            assert!(!gtk4::is_initialized());
+
+            // Only create widgets when there is a display to create them on.
+            if woab::test::has_display() {
+                let label = gtk4::Label::new(Some("displayed"));
+                assert_eq!(label.text(), "displayed");
+            }
+            Ok(())
+        }
+    })?;
+    assert_eq!(*output.borrow(), ["headless_action", "headless_action"]);
+    Ok(())
+}
@@ -0,0 +1,36 @@
+#![cfg(feature = "i18n")]
+
+const UI_XML: &str = r#"
+<interface>
+  <object class="GtkLabel" id="greeting">
+    <property name="label" translatable="yes">Hello</property>
+  </object>
+</interface>
+"#;
+
+#[test]
+fn test_i18n() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let before_init = woab::BuilderFactory::from(UI_XML.to_owned());
+        woab::init_i18n("woab-test", std::env::temp_dir())?;
+        let after_init = woab::BuilderFactory::from(UI_XML.to_owned());
+        let with_own_domain = after_init.clone().with_translation_domain("woab-test-other");
+
+        let domain_of = |factory: &woab::BuilderFactory| {
+            factory
+                .instantiate_without_routing_signals()
+                .builder
+                .translation_domain()
+                .map(|domain| domain.to_string())
+        };
+        // The default domain is taken when the factory is created, not when it is instantiated.
+        assert_eq!(domain_of(&before_init), None);
+        assert_eq!(domain_of(&after_init).as_deref(), Some("woab-test"));
+        assert_eq!(domain_of(&with_own_domain).as_deref(), Some("woab-test-other"));
+
+        // There is no catalog for the domain, so the strings are left untranslated.
+        let label: gtk4::Label = after_init.instantiate_without_routing_signals().get_object("greeting")?;
+        assert_eq!(label.label(), "Hello");
+        Ok(())
+    })
+}
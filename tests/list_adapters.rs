@@ -0,0 +1,99 @@
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use actix::prelude::*;
+use gio::prelude::*;
+use woab::list_adapters::{FilterAdapter, Matches, Refilter, SortAdapter, SortKey};
+use woab::list_binding::{ListBinding, ListBindingItem, Reset};
+
+struct Item {
+    key: u32,
+    name: &'static str,
+}
+
+impl ListBindingItem for Item {
+    type Key = u32;
+
+    fn key(&self) -> Self::Key {
+        self.key
+    }
+}
+
+const ITEMS: [(u32, &str); 3] = [(1, "c"), (2, "a"), (3, "b")];
+
+struct PredicateActor {
+    min_key: Rc<Cell<u32>>,
+}
+
+impl actix::Actor for PredicateActor {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<Matches<u32>> for PredicateActor {
+    type Result = bool;
+
+    fn handle(&mut self, msg: Matches<u32>, _ctx: &mut Self::Context) -> Self::Result {
+        self.min_key.get() <= msg.key
+    }
+}
+
+impl actix::Handler<SortKey<u32, String>> for PredicateActor {
+    type Result = String;
+
+    fn handle(&mut self, msg: SortKey<u32, String>, _ctx: &mut Self::Context) -> Self::Result {
+        let (_, name) = ITEMS.iter().find(|(key, _)| *key == msg.key).unwrap();
+        name.to_string()
+    }
+}
+
+fn names(model: &gtk4::SortListModel) -> Vec<&'static str> {
+    model
+        .iter::<glib::Object>()
+        .map(|obj| woab::list_binding::item::<Item>(&obj.unwrap()).unwrap().name)
+        .collect()
+}
+
+#[test]
+fn test_list_adapters() -> anyhow::Result<()> {
+    let min_key = Rc::new(Cell::new(0));
+    let state = Rc::new(RefCell::new(None));
+    let refiltered = Cell::new(false);
+    woab::test::test_main_loop_until::<anyhow::Error>(
+        || {
+            let binding = ListBinding::<Item>::new();
+            let predicate = PredicateActor {
+                min_key: min_key.clone(),
+            }
+            .start();
+            let filter = FilterAdapter::<Item>::new(predicate.clone().recipient());
+            let sorter = SortAdapter::<Item, String>::new(predicate.recipient());
+            let model = gtk4::SortListModel::new(
+                Some(gtk4::FilterListModel::new(
+                    Some(binding.store().clone()),
+                    Some(filter.filter().clone()),
+                )),
+                Some(sorter.sorter().clone()),
+            );
+            binding
+                .start()
+                .do_send(Reset(ITEMS.iter().map(|&(key, name)| Item { key, name }).collect()));
+            *state.borrow_mut() = Some((model, filter.start(), sorter.start()));
+        },
+        || {
+            let state = state.borrow();
+            let (model, filter, _sorter) = state.as_ref().unwrap();
+            if !refiltered.get() {
+                if names(model) == ["a", "b", "c"] {
+                    // The predicate's result changed, so the cache must be cleared.
+                    min_key.set(2);
+                    filter.do_send(Refilter);
+                    refiltered.set(true);
+                }
+                false
+            } else {
+                names(model) == ["a", "b"]
+            }
+        },
+        std::time::Duration::from_secs(5),
+    )
+}
@@ -0,0 +1,72 @@
+use actix::prelude::*;
+use gio::prelude::*;
+use woab::list_binding::{Insert, ListBinding, ListBindingItem, Remove, Reset, Update};
+
+#[derive(Debug, PartialEq)]
+struct Item {
+    key: u32,
+    value: &'static str,
+}
+
+impl ListBindingItem for Item {
+    type Key = u32;
+
+    fn key(&self) -> Self::Key {
+        self.key
+    }
+}
+
+fn store_content(store: &gio::ListStore) -> Vec<(u32, &'static str)> {
+    store
+        .iter::<glib::Object>()
+        .map(|obj| {
+            let item = woab::list_binding::item::<Item>(&obj.unwrap()).map(|item| (item.key, item.value));
+            item.unwrap()
+        })
+        .collect()
+}
+
+#[test]
+fn test_list_binding() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let binding = ListBinding::<Item>::new();
+        let store = binding.store().clone();
+        let binding = binding.start();
+
+        binding.send(Insert(Item { key: 1, value: "one" })).await?;
+        binding.send(Insert(Item { key: 2, value: "two" })).await?;
+        assert_eq!(store_content(&store), [(1, "one"), (2, "two")]);
+
+        assert!(binding.send(Update(Item { key: 1, value: "uno" })).await?);
+        assert!(!binding.send(Update(Item { key: 3, value: "tres" })).await?);
+        assert_eq!(store_content(&store), [(1, "uno"), (2, "two")]);
+
+        binding.send(Insert(Item { key: 2, value: "dos" })).await?;
+        assert_eq!(store_content(&store), [(1, "uno"), (2, "dos")]);
+
+        assert!(binding.send(Remove(1)).await?);
+        assert!(!binding.send(Remove(1)).await?);
+        assert_eq!(store_content(&store), [(2, "dos")]);
+
+        binding
+            .send(Reset(vec![
+                Item { key: 3, value: "three" },
+                Item { key: 2, value: "two" },
+                Item { key: 4, value: "four" },
+            ]))
+            .await??;
+        assert_eq!(store_content(&store), [(3, "three"), (2, "two"), (4, "four")]);
+
+        binding
+            .send(Reset(vec![Item { key: 4, value: "four" }, Item { key: 3, value: "three" }]))
+            .await??;
+        assert_eq!(store_content(&store), [(4, "four"), (3, "three")]);
+
+        let result = binding
+            .send(Reset(vec![Item { key: 5, value: "five" }, Item { key: 5, value: "cinco" }]))
+            .await?;
+        assert!(matches!(result, Err(woab::Error::DuplicateListBindingKeys)));
+        assert_eq!(store_content(&store), [(4, "four"), (3, "three")]);
+        Ok(())
+    })
+}
@@ -0,0 +1,6 @@
+#[test]
+fn test_main_returns_startup_value() -> anyhow::Result<()> {
+    let value = woab::main(gtk4::Application::default(), |_app| Ok(42))?;
+    assert_eq!(value, 42);
+    Ok(())
+}
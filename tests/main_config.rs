@@ -0,0 +1,14 @@
+#[test]
+fn test_main_config() -> anyhow::Result<()> {
+    let app = gtk4::Application::default();
+    woab::MainConfig::new()
+        .application_name("WoAB Test")
+        .resource_base_path("/org/example/WoabTest")
+        .main(app.clone(), |_app| Ok(()))?;
+    assert_eq!(glib::application_name().as_deref(), Some("WoAB Test"));
+    assert_eq!(
+        gio::prelude::ApplicationExt::resource_base_path(&app).as_deref(),
+        Some("/org/example/WoabTest")
+    );
+    Ok(())
+}
@@ -0,0 +1,17 @@
+#[test]
+fn test_main_new_window() -> anyhow::Result<()> {
+    let new_windows = std::rc::Rc::new(std::cell::Cell::new(0));
+    let app = gtk4::Application::default();
+    woab::MainConfig::new()
+        .on_new_window({
+            let new_windows = new_windows.clone();
+            move |_app| new_windows.set(new_windows.get() + 1)
+        })
+        .main(app.clone(), |app| {
+            assert!(gio::prelude::ActionGroupExt::has_action(app, "new-window"));
+            Ok(())
+        })?;
+    // Only the initial activation, since the test does not activate the application again
+    assert_eq!(new_windows.get(), 1);
+    Ok(())
+}
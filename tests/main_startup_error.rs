@@ -0,0 +1,16 @@
+#[test]
+fn test_main_startup_error() {
+    let handled_error = std::rc::Rc::new(std::cell::RefCell::new(None));
+    woab::set_startup_error_handler({
+        let handled_error = handled_error.clone();
+        move |_app, error| {
+            *handled_error.borrow_mut() = Some(error.to_string());
+            None
+        }
+    });
+    let result = woab::main(gtk4::Application::default(), |_app| -> woab::Result<()> {
+        Err(woab::Error::Cancelled)
+    });
+    assert!(matches!(result, Err(woab::Error::Cancelled)));
+    assert_eq!(*handled_error.borrow(), Some(woab::Error::Cancelled.to_string()));
+}
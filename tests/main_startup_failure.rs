@@ -0,0 +1,26 @@
+use gtk4::prelude::*;
+
+#[test]
+fn test_no_new_window_after_startup_failure() {
+    woab::set_startup_error_handler(|app, _error| {
+        let window = gtk4::Window::new();
+        window.set_application(Some(app));
+        // Close the error window once the application was activated, as if the user closed it.
+        glib::idle_add_local_once({
+            let window = window.clone();
+            move || window.close()
+        });
+        Some(window)
+    });
+    let new_windows = std::rc::Rc::new(std::cell::Cell::new(0));
+    let result = woab::MainConfig::new()
+        .on_new_window({
+            let new_windows = new_windows.clone();
+            move |_app| new_windows.set(new_windows.get() + 1)
+        })
+        .main(gtk4::Application::default(), |_app| -> woab::Result<()> {
+            Err(woab::Error::Cancelled)
+        });
+    assert!(matches!(result, Err(woab::Error::Cancelled)));
+    assert_eq!(new_windows.get(), 0);
+}
@@ -0,0 +1,14 @@
+#![cfg(feature = "i18n")]
+
+#[test]
+fn test_main_translations() -> anyhow::Result<()> {
+    woab::MainConfig::new()
+        .translations("woab-test", std::env::temp_dir())
+        .main(gtk4::Application::default(), |_app| {
+            let factory = woab::BuilderFactory::from("<interface/>".to_owned());
+            let builder = factory.instantiate_without_routing_signals().builder;
+            assert_eq!(builder.translation_domain().as_deref(), Some("woab-test"));
+            Ok(())
+        })?;
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use actix::prelude::*;
+use gtk4::prelude::*;
+
+#[derive(woab::Factories)]
+#[factory(ui = "tests/basic.ui")]
+struct Factories {
+    win_test: woab::BuilderFactory,
+}
+
+#[derive(woab::WidgetsFromBuilder)]
+struct TestWidgets {
+    btn_copy_right_to_left: gtk4::Button,
+    btn_copy_left_to_right: gtk4::Button,
+}
+
+struct TestActor {
+    output: Rc<RefCell<Vec<String>>>,
+}
+
+impl actix::Actor for TestActor {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<woab::Signal> for TestActor {
+    type Result = woab::SignalResult;
+
+    fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+        Ok(woab::match_signal!(msg, from factory Factories::win_test {
+            "copy_right_to_left" | "copy_left_to_right" => {
+                self.output.borrow_mut().push(msg.name().to_owned());
+                None
+            }
+        }))
+    }
+}
+
+#[test]
+fn test_match_signal() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let factories = Factories::read(include_bytes!("basic.ui") as &[u8])?;
+        let output = Rc::new(RefCell::new(Vec::new()));
+        let addr = TestActor { output: output.clone() }.start();
+        let widgets: TestWidgets = factories.win_test.instantiate_route_to(addr.clone()).widgets()?;
+        widgets.btn_copy_left_to_right.emit_clicked();
+        woab::wait_for!(*output.borrow() == ["copy_left_to_right"])?;
+        widgets.btn_copy_right_to_left.emit_clicked();
+        woab::wait_for!(*output.borrow() == ["copy_left_to_right", "copy_right_to_left"])?;
+
+        let error = addr
+            .send(woab::Signal::for_test("copy_right_to_lfet", ()))
+            .await?
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            woab::Error::NoSuchSignalError { ref suggestions, .. } if suggestions == &["copy_right_to_left"]
+        ));
+        Ok(())
+    })
+}
@@ -0,0 +1,47 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use actix::prelude::*;
+use gtk4::prelude::*;
+
+use woab::media::MediaEvent;
+
+struct TestActor {
+    output: Rc<RefCell<Vec<String>>>,
+}
+
+impl actix::Actor for TestActor {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<MediaEvent> for TestActor {
+    type Result = ();
+
+    fn handle(&mut self, msg: MediaEvent, _ctx: &mut Self::Context) -> Self::Result {
+        self.output.borrow_mut().push(format!("{:?}", msg));
+    }
+}
+
+#[test]
+fn test_watch_video() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let output = Rc::new(RefCell::new(Vec::new()));
+        let addr = TestActor { output: output.clone() }.start();
+
+        let video = gtk4::Video::new();
+        let _watch = woab::media::watch_video(&video, addr);
+
+        let stream1 = gtk4::MediaFile::new();
+        video.set_media_stream(Some(&stream1));
+        stream1.update(1_000);
+        woab::wait_for!(*output.borrow() == ["Timestamp(1000)"])?;
+
+        let stream2 = gtk4::MediaFile::new();
+        video.set_media_stream(Some(&stream2));
+        stream1.update(2_000);
+        stream2.update(3_000);
+        woab::wait_for!(*output.borrow() == ["Timestamp(1000)", "Timestamp(3000)"])?;
+
+        Ok(())
+    })
+}
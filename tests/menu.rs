@@ -0,0 +1,63 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use actix::prelude::*;
+use gtk4::prelude::*;
+
+use woab::menu::MenuDef;
+
+struct TestActor {
+    output: Rc<RefCell<Vec<String>>>,
+}
+
+impl actix::Actor for TestActor {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<woab::Signal> for TestActor {
+    type Result = woab::SignalResult;
+
+    fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+        Ok(match msg.name() {
+            "open" => {
+                self.output.borrow_mut().push("open".to_owned());
+                None
+            }
+            "zoom" => {
+                let zoom: i32 = msg.action_param()?;
+                self.output.borrow_mut().push(format!("zoom {}", zoom));
+                None
+            }
+            _ => msg.cant_handle()?,
+        })
+    }
+}
+
+#[test]
+fn test_menu_def() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let output = Rc::new(RefCell::new(Vec::new()));
+        let addr = TestActor { output: output.clone() }.start();
+
+        let action_group = gio::SimpleActionGroup::new();
+        let menu = MenuDef::new()
+            .section(None, MenuDef::new().item("_Open", "win.open").item("_Quit", "app.quit"))
+            .submenu(
+                "_Zoom",
+                MenuDef::new()
+                    .item_with_target("50%", "win.zoom", 50)
+                    .item_with_target("100%", "win.zoom", 100),
+            )
+            .install("win", &action_group, addr)?;
+
+        assert_eq!(menu.n_items(), 2);
+        assert!(action_group.has_action("open"));
+        assert!(action_group.has_action("zoom"));
+        assert!(!action_group.has_action("quit"));
+
+        action_group.activate_action("zoom", Some(&100.to_variant()));
+        action_group.activate_action("open", None);
+        woab::wait_for!(*output.borrow() == ["zoom 100", "open"])?;
+        Ok(())
+    })
+}
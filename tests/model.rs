@@ -0,0 +1,80 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use gtk4::prelude::*;
+use woab::model::ModelBinding;
+
+#[derive(woab::Model)]
+#[model(widgets = PersonWidgets)]
+struct Person {
+    name: String,
+    age: f64,
+    subscribed: bool,
+    #[model(skip)]
+    dirty: bool,
+}
+
+#[derive(Clone, woab::PropSync)]
+struct PersonWidgets {
+    #[prop_sync(set, get)]
+    name: gtk4::Entry,
+    #[prop_sync("value" as f64, set, get)]
+    age: gtk4::SpinButton,
+    #[prop_sync(set, get)]
+    subscribed: gtk4::CheckButton,
+}
+
+#[test]
+fn test_model() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let widgets = PersonWidgets {
+            name: gtk4::Entry::new(),
+            age: gtk4::SpinButton::with_range(0.0, 150.0, 1.0),
+            subscribed: gtk4::CheckButton::new(),
+        };
+        let changes = Rc::new(Cell::new(0));
+        let binding = ModelBinding::new(
+            Person {
+                name: "John".to_owned(),
+                age: 42.0,
+                subscribed: false,
+                dirty: false,
+            },
+            widgets.clone(),
+            {
+                let changes = changes.clone();
+                move || changes.set(changes.get() + 1)
+            },
+        );
+
+        // The widgets are set from the model, without invoking the callback.
+        assert_eq!(widgets.name.text(), "John");
+        assert_eq!(widgets.age.value_as_int(), 42);
+        assert!(!widgets.subscribed.is_active());
+        assert_eq!(changes.get(), 0);
+
+        // Editing the widgets updates the model.
+        widgets.name.set_text("Jane");
+        assert_eq!(binding.model().name, "Jane");
+        let changes_after_name = changes.get();
+        assert!(0 < changes_after_name);
+        widgets.subscribed.set_active(true);
+        assert!(binding.model().subscribed);
+        assert_eq!(changes.get(), changes_after_name + 1);
+
+        // Modifying the model updates the widgets, without invoking the callback.
+        binding.modify(|person| {
+            person.age = 30.0;
+            person.dirty = true;
+        });
+        assert_eq!(widgets.age.value_as_int(), 30);
+        assert!(binding.model().dirty);
+        assert_eq!(changes.get(), changes_after_name + 1);
+
+        // Dropping the binding disconnects it from the widgets.
+        drop(binding);
+        widgets.name.set_text("Someone else");
+        assert_eq!(changes.get(), changes_after_name + 1);
+        Ok(())
+    })
+}
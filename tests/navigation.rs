@@ -0,0 +1,96 @@
+use std::cell::RefCell;
+
+use actix::prelude::*;
+use gtk4::prelude::*;
+
+use woab::navigation::{Depth, Navigator, Page, PageEvent, Pop, Push, Replace};
+
+thread_local! {
+    static EVENTS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+fn events_of(name: &str) -> Vec<String> {
+    let prefix = format!("{}:", name);
+    EVENTS.with(|events| {
+        events
+            .borrow()
+            .iter()
+            .filter_map(|event| Some(event.strip_prefix(&prefix)?.to_owned()))
+            .collect()
+    })
+}
+
+#[derive(woab::Removable)]
+#[removable(self.label in gtk4::Stack)]
+struct LabelPage {
+    label: gtk4::Label,
+}
+
+impl actix::Actor for LabelPage {
+    type Context = actix::Context<Self>;
+}
+
+impl Page for LabelPage {
+    type Params = &'static str;
+
+    fn create_page(params: Self::Params, _factory: &woab::BuilderFactory, _ctx: &mut Self::Context) -> Self {
+        Self {
+            label: gtk4::Label::new(Some(params)),
+        }
+    }
+
+    fn root_widget(&self) -> gtk4::Widget {
+        self.label.clone().upcast()
+    }
+}
+
+impl actix::Handler<PageEvent> for LabelPage {
+    type Result = ();
+
+    fn handle(&mut self, msg: PageEvent, _ctx: &mut Self::Context) -> Self::Result {
+        EVENTS.with(|events| events.borrow_mut().push(format!("{}:{:?}", self.label.text(), msg)));
+    }
+}
+
+fn visible_label(stack: &gtk4::Stack) -> Option<String> {
+    Some(stack.visible_child()?.downcast::<gtk4::Label>().ok()?.text().into())
+}
+
+#[test]
+fn test_navigator() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let stack = gtk4::Stack::new();
+        let navigator = Navigator::new(&stack)
+            .register::<LabelPage>("label", woab::BuilderFactory::from("<interface/>".to_owned()))
+            .start();
+
+        assert!(matches!(
+            navigator.send(Push::new("lable", "a")).await?,
+            Err(woab::Error::NoSuchPage { .. })
+        ));
+        assert!(matches!(
+            navigator.send(Push::new("label", 1)).await?,
+            Err(woab::Error::IncorrectPageParams { .. })
+        ));
+
+        navigator.send(Push::new("label", "a")).await??;
+        navigator.send(Push::new("label", "b")).await??;
+        assert_eq!(visible_label(&stack).as_deref(), Some("b"));
+        assert_eq!(navigator.send(Depth).await?, 2);
+
+        assert!(navigator.send(Pop).await?);
+        assert_eq!(visible_label(&stack).as_deref(), Some("a"));
+        woab::wait_for!(stack.observe_children().n_items() == 1)?;
+        assert!(!navigator.send(Pop).await?);
+
+        navigator.send(Replace::new("label", "c")).await??;
+        assert_eq!(visible_label(&stack).as_deref(), Some("c"));
+        woab::wait_for!(stack.observe_children().n_items() == 1)?;
+        assert_eq!(navigator.send(Depth).await?, 1);
+
+        woab::wait_for!(events_of("a") == ["Shown", "Hidden", "Shown", "Hidden"])?;
+        assert_eq!(events_of("b"), ["Shown", "Hidden"]);
+        assert_eq!(events_of("c"), ["Shown"]);
+        Ok(())
+    })
+}
@@ -3,9 +3,6 @@ use std::rc::Rc;
 
 use actix::prelude::*;
 
-#[macro_use]
-mod util;
-
 #[derive(woab::Factories)]
 struct Factories {
     win_test: woab::BuilderFactory,
@@ -43,13 +40,13 @@ pub struct TestWidgets {}
 
 #[test]
 fn test_no_signals() -> anyhow::Result<()> {
-    util::test_main(async {
+    woab::test::test_main(async {
         let factories = Factories::read(include_bytes!("no_signals.ui") as &[u8])?;
         let output = Rc::new(RefCell::new(Vec::new()));
         factories
             .win_test
             .instantiate_route_to(TestActor { output: output.clone() }.start());
-        wait_for!(*output.borrow() == ["before spawned future", "inside spawned future",])?;
+        woab::wait_for!(*output.borrow() == ["before spawned future", "inside spawned future",])?;
         Ok(())
     })
 }
@@ -0,0 +1,68 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use actix::prelude::*;
+use gio::prelude::*;
+use woab::paged_list::{FetchPage, PagedList, Reload, SetTotal};
+
+struct FetcherActor {
+    requests: Rc<RefCell<Vec<(usize, usize)>>>,
+}
+
+impl actix::Actor for FetcherActor {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<FetchPage<String>> for FetcherActor {
+    type Result = Vec<String>;
+
+    fn handle(&mut self, msg: FetchPage<String>, _ctx: &mut Self::Context) -> Self::Result {
+        self.requests.borrow_mut().push((msg.offset, msg.limit));
+        (msg.offset..msg.offset + msg.limit).map(|i| format!("item {}", i)).collect()
+    }
+}
+
+fn loaded_items(store: &gio::ListStore) -> Vec<Option<String>> {
+    store
+        .iter::<glib::Object>()
+        .map(|obj| woab::paged_list::item::<String>(&obj.unwrap()).map(|item| item.clone()))
+        .collect()
+}
+
+#[test]
+fn test_paged_list() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let requests = Rc::new(RefCell::new(Vec::new()));
+        let fetcher = FetcherActor {
+            requests: requests.clone(),
+        }
+        .start();
+        // Not inside a scrolled window, so only the first page is considered visible.
+        let list_view = gtk4::ListView::new(None::<gtk4::SelectionModel>, None::<gtk4::ListItemFactory>);
+        let paged_list = PagedList::new(list_view, fetcher.recipient(), 10, 25);
+        let store = paged_list.store().clone();
+        assert_eq!(loaded_items(&store), vec![None; 25]);
+
+        let paged_list = paged_list.start();
+        woab::wait_for!(loaded_items(&store)[0].is_some())?;
+        let items = loaded_items(&store);
+        assert_eq!(items.len(), 25);
+        assert_eq!(items[0].as_deref(), Some("item 0"));
+        assert_eq!(items[9].as_deref(), Some("item 9"));
+        assert_eq!(items[10], None);
+        assert_eq!(*requests.borrow(), [(0, 10)]);
+
+        // The last page is shorter than the page size.
+        paged_list.send(SetTotal(5)).await?;
+        assert_eq!(store.n_items(), 5);
+        woab::wait_for!(loaded_items(&store).iter().all(|item| item.is_some()))?;
+        assert_eq!(loaded_items(&store)[4].as_deref(), Some("item 4"));
+        assert_eq!(*requests.borrow(), [(0, 10), (0, 5)]);
+
+        paged_list.send(Reload).await?;
+        woab::wait_for!(requests.borrow().len() == 3)?;
+        woab::wait_for!(loaded_items(&store).iter().all(|item| item.is_some()))?;
+        assert_eq!(store.n_items(), 5);
+        Ok(())
+    })
+}
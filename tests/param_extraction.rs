@@ -1,5 +1,3 @@
-use std::rc::Rc;
-
 use glib::prelude::ToValue;
 
 #[test]
@@ -7,7 +5,7 @@ fn test_param_extraction() -> anyhow::Result<()> {
     macro_rules! signal {
         ($($param:expr),*) => {
             woab::Signal::new(
-                Rc::new("signal".to_owned()),
+                glib::Quark::from_str("signal"),
                 vec![$(
                     $param.to_value()
                 ),*],
@@ -42,3 +40,76 @@ fn test_param_extraction() -> anyhow::Result<()> {
     assert_eq!(d, ["seven", "eight"]);
     Ok(())
 }
+
+#[test]
+fn test_optional_param_extraction() -> anyhow::Result<()> {
+    let signal = woab::Signal::for_test("signal", (None::<String>, None::<gio::SimpleAction>, "three", 4i32));
+    let woab::params!(a: Option<String>, b: Option<gio::SimpleAction>, c: Option<String>, d: Option<i32>) = signal.params()?;
+    assert_eq!(a, None);
+    assert!(b.is_none());
+    assert_eq!(c.as_deref(), Some("three"));
+    assert_eq!(d, Some(4));
+
+    assert_eq!(signal.param::<Option<String>>(0)?, None);
+    assert_eq!(signal.param::<Option<String>>(2)?.as_deref(), Some("three"));
+
+    match signal.params() {
+        Err(woab::Error::IncorrectSignalParameterType { index, .. }) => {
+            assert_eq!(index, 2);
+        }
+        Ok(woab::params!(_, _, _c: Option<i32>, _)) => {
+            panic!("Should have failed");
+        }
+        Err(err) => return Err(err.into()),
+    }
+    Ok(())
+}
+
+#[test]
+fn test_signal_for_test() -> anyhow::Result<()> {
+    let signal = woab::Signal::for_test("signal", (1i32, "two"));
+    assert_eq!(signal.name(), "signal");
+    let woab::params!(a: i32, b: String) = signal.params()?;
+    assert_eq!(a, 1);
+    assert_eq!(b, "two");
+
+    let signal = woab::Signal::with_values("tagged", vec![3i32.to_value()], "tag");
+    assert_eq!(*signal.tag(), "tag");
+    let woab::params!(c: i32) = signal.params()?;
+    assert_eq!(c, 3);
+    Ok(())
+}
+
+#[test]
+fn test_signal_formatting() {
+    let signal = woab::Signal::with_values("tagged", vec![1i32.to_value(), "two".to_value()], "tag");
+    let display = signal.to_string();
+    assert!(display.starts_with("tagged("), "{}", display);
+    assert!(display.contains("gint"), "{}", display);
+    assert!(display.contains("gchararray"), "{}", display);
+    let debug = format!("{:?}", signal);
+    assert!(debug.contains(r#"name: "tagged""#), "{}", debug);
+    assert!(debug.contains(r#"tag: "tag""#), "{}", debug);
+    assert!(debug.contains("gint"), "{}", debug);
+}
+
+#[test]
+fn test_params_as() -> anyhow::Result<()> {
+    let signal = woab::Signal::for_test("signal", (1i32, "two"));
+    let (a, b): (i32, String) = signal.params_as()?;
+    assert_eq!(a, 1);
+    assert_eq!(b, "two");
+    assert!(matches!(
+        signal.params_as::<(i32,)>(),
+        Err(woab::Error::NotAllParametersExtracted {
+            num_parameters: 2,
+            num_extracted: 1,
+            ..
+        })
+    ));
+    assert!(matches!(
+        signal.params_as::<(String, String)>(),
+        Err(woab::Error::IncorrectSignalParameterType { .. })
+    ));
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use actix::prelude::*;
+use gtk4::prelude::*;
+use woab::progress_dialog::{Done, ProgressDialog, Pulse, SetFraction, SetText};
+
+struct WorkerActor {
+    output: Rc<RefCell<Vec<String>>>,
+}
+
+impl actix::Actor for WorkerActor {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<woab::Signal> for WorkerActor {
+    type Result = woab::SignalResult;
+
+    fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+        self.output.borrow_mut().push(msg.name().to_owned());
+        Ok(None)
+    }
+}
+
+fn find_progress_window() -> Option<gtk4::Window> {
+    gtk4::Window::list_toplevels()
+        .into_iter()
+        .filter_map(|window| window.downcast::<gtk4::Window>().ok())
+        .find(|window| window.title().as_deref() == Some("Working..."))
+}
+
+#[test]
+fn test_progress_dialog() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let output = Rc::new(RefCell::new(Vec::new()));
+        let worker = WorkerActor { output: output.clone() }.start();
+        let progress_dialog = ProgressDialog::spawn("Working...", None, Some(worker.recipient()));
+
+        let window = find_progress_window().unwrap();
+        assert!(window.is_modal());
+        let layout = window.child().unwrap();
+        let progress_bar = layout.first_child().unwrap().downcast::<gtk4::ProgressBar>().unwrap();
+        let cancel_button = layout.last_child().unwrap().downcast::<gtk4::Button>().unwrap();
+
+        progress_dialog.send(SetFraction(0.5)).await?;
+        assert_eq!(progress_bar.fraction(), 0.5);
+        progress_dialog.send(SetText("Halfway".to_owned())).await?;
+        assert_eq!(progress_bar.text().as_deref(), Some("Halfway"));
+        progress_dialog.send(Pulse).await?;
+
+        // Cancelling does not close the dialog by itself.
+        cancel_button.emit_clicked();
+        woab::wait_for!(*output.borrow() == ["progress_dialog_cancel"])?;
+        assert!(progress_dialog.connected());
+
+        progress_dialog.send(Done).await?;
+        woab::wait_for!(!progress_dialog.connected())?;
+        assert!(find_progress_window().is_none());
+        Ok(())
+    })
+}
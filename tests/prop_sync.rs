@@ -1,8 +1,5 @@
 use gtk4::prelude::*;
 
-#[macro_use]
-mod util;
-
 #[derive(woab::WidgetsFromBuilder, woab::PropSync)]
 struct TestWidgets {
     #[prop_sync(set, get)]
@@ -31,7 +28,7 @@ struct WidgetsGroup2 {
 
 #[test]
 fn test_prop_sync() -> anyhow::Result<()> {
-    util::test_main(async {
+    woab::test::test_main(async {
         let factory = woab::BuilderFactory::from(std::fs::read_to_string("tests/various_widgets.ui")?);
 
         let widgets: TestWidgets = factory.instantiate_without_routing_signals().widgets()?;
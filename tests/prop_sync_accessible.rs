@@ -0,0 +1,39 @@
+#[derive(woab::WidgetsFromBuilder, woab::PropSync)]
+struct AccessibleWidgets {
+    #[prop_sync("value" as f64, set, get, accessible_value_text)]
+    spin_button: gtk4::SpinButton,
+    #[prop_sync(set, accessible_label, accessible_description)]
+    label: gtk4::Label,
+}
+
+#[test]
+fn test_prop_sync_accessible() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let factory = woab::BuilderFactory::from(std::fs::read_to_string("tests/various_widgets.ui")?);
+        let widgets: AccessibleWidgets = factory.instantiate_without_routing_signals().widgets()?;
+
+        widgets.set_props(&AccessibleWidgetsPropSetter {
+            spin_button: 3.0,
+            spin_button_accessible_value_text: "three",
+            label: "four",
+            label_accessible_label: "Count",
+            label_accessible_description: "How many there are",
+        });
+
+        assert_eq!(widgets.get_props().spin_button as i64, 3);
+        assert_eq!(widgets.label.text(), "four");
+        assert!(gtk4::test_accessible_has_property(
+            &widgets.spin_button,
+            gtk4::AccessibleProperty::ValueText
+        ));
+        assert!(gtk4::test_accessible_has_property(
+            &widgets.label,
+            gtk4::AccessibleProperty::Label
+        ));
+        assert!(gtk4::test_accessible_has_property(
+            &widgets.label,
+            gtk4::AccessibleProperty::Description
+        ));
+        Ok(())
+    })
+}
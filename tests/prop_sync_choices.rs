@@ -0,0 +1,53 @@
+// `gtk4::ComboBox` and `gtk4::ComboBoxText` are deprecated since GTK 4.10.
+#![cfg_attr(feature = "gtk_v4_10", allow(deprecated))]
+
+use gtk4::prelude::*;
+
+#[derive(Debug, PartialEq, woab::Choices)]
+enum Color {
+    Red,
+    #[choice(label = "Green (default)")]
+    Green,
+    #[choice(id = "b")]
+    Blue,
+}
+
+#[derive(woab::PropSync)]
+struct ChoicesWidgets {
+    #[prop_sync(choice as Color, set, get)]
+    drop_down: gtk4::DropDown,
+    #[prop_sync(choice as Color, set, get)]
+    combo_box: gtk4::ComboBoxText,
+}
+
+#[test]
+fn test_prop_sync_choices() -> anyhow::Result<()> {
+    use woab::choices::Choices;
+
+    woab::test::test_main(async {
+        assert_eq!(Color::iter().collect::<Vec<_>>(), [Color::Red, Color::Green, Color::Blue]);
+        assert_eq!(Color::from_id("b"), Some(Color::Blue));
+        assert_eq!(Color::Green.label(), "Green (default)");
+
+        let widgets = ChoicesWidgets {
+            drop_down: gtk4::DropDown::builder().model(&Color::string_list()).build(),
+            combo_box: gtk4::ComboBoxText::new(),
+        };
+        Color::fill_combo_box_text(&widgets.combo_box);
+
+        let ChoicesWidgetsPropGetter { combo_box, .. } = widgets.get_props();
+        assert_eq!(combo_box, None);
+
+        widgets.set_props(&ChoicesWidgetsPropSetter {
+            drop_down: Color::Green,
+            combo_box: Color::Blue,
+        });
+        assert_eq!(widgets.drop_down.selected(), 1);
+        assert_eq!(widgets.combo_box.active_id().as_deref(), Some("b"));
+
+        let ChoicesWidgetsPropGetter { drop_down, combo_box } = widgets.get_props();
+        assert_eq!(drop_down, Some(Color::Green));
+        assert_eq!(combo_box, Some(Color::Blue));
+        Ok(())
+    })
+}
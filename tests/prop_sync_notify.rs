@@ -0,0 +1,73 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use actix::prelude::*;
+use gtk4::prelude::*;
+
+#[derive(woab::WidgetsFromBuilder, woab::PropSync)]
+#[prop_sync(notify)]
+struct NotifyingWidgets {
+    #[prop_sync(get)]
+    #[widget(nested)]
+    group: NotifyingGroup,
+    #[prop_sync("value" as f64, get)]
+    spin_button: gtk4::SpinButton,
+    #[prop_sync(set)]
+    label: gtk4::Label,
+}
+
+#[derive(woab::WidgetsFromBuilder, woab::PropSync)]
+#[prop_sync(notify)]
+struct NotifyingGroup {
+    #[prop_sync(set, get)]
+    text_entry: gtk4::Entry,
+    #[prop_sync(set, get)]
+    check_button: gtk4::CheckButton,
+}
+
+struct ChangesActor {
+    output: Rc<RefCell<Vec<String>>>,
+}
+
+impl actix::Actor for ChangesActor {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<woab::Signal> for ChangesActor {
+    type Result = woab::SignalResult;
+
+    fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+        self.output.borrow_mut().push(msg.name().to_owned());
+        Ok(None)
+    }
+}
+
+#[test]
+fn test_prop_sync_notify() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let factory = woab::BuilderFactory::from(std::fs::read_to_string("tests/various_widgets.ui")?);
+        let widgets: NotifyingWidgets = factory.instantiate_without_routing_signals().widgets()?;
+
+        let output = Rc::new(RefCell::new(Vec::new()));
+        let addr = ChangesActor { output: output.clone() }.start();
+        let connections = widgets.connect_changes(addr);
+        assert_eq!(connections.len(), 3);
+
+        widgets.group.text_entry.set_text("one");
+        woab::wait_for!(*output.borrow() == ["group"])?;
+        widgets.spin_button.set_value(2.0);
+        woab::wait_for!(*output.borrow() == ["group", "spin_button"])?;
+        widgets.group.check_button.set_active(!widgets.group.check_button.is_active());
+        woab::wait_for!(*output.borrow() == ["group", "spin_button", "group"])?;
+
+        let NotifyingWidgetsPropGetter { group, spin_button } = widgets.get_props();
+        let NotifyingGroupPropGetter {
+            text_entry,
+            check_button,
+        } = group;
+        assert_eq!(text_entry, "one");
+        assert_eq!(check_button, widgets.group.check_button.is_active());
+        assert_eq!(spin_button as i64, 2);
+        Ok(())
+    })
+}
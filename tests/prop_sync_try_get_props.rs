@@ -0,0 +1,39 @@
+// `gtk4::ComboBoxText` is deprecated since GTK 4.10.
+#![cfg_attr(feature = "gtk_v4_10", allow(deprecated))]
+
+use gtk4::prelude::*;
+
+#[derive(woab::PropSync)]
+struct ActiveIdWidgets {
+    #[prop_sync("active-id" as Option<&str>, set, get)]
+    optional: gtk4::ComboBoxText,
+    #[prop_sync("active-id" as String, get)]
+    required: gtk4::ComboBoxText,
+}
+
+#[test]
+fn test_prop_sync_try_get_props() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let widgets = ActiveIdWidgets {
+            optional: gtk4::ComboBoxText::new(),
+            required: gtk4::ComboBoxText::new(),
+        };
+        for combo_box in [&widgets.optional, &widgets.required] {
+            combo_box.append(Some("a"), "A");
+            combo_box.append(Some("b"), "B");
+        }
+
+        let err = widgets.try_get_props().err().unwrap();
+        assert!(matches!(err, woab::Error::IncorrectPropertyType { property, .. } if property == "active-id"));
+
+        widgets.required.set_active_id(Some("a"));
+        let ActiveIdWidgetsPropGetter { optional, required } = widgets.try_get_props()?;
+        assert_eq!(optional, None);
+        assert_eq!(required, "a");
+
+        widgets.set_props(&ActiveIdWidgetsPropSetter { optional: Some("b") });
+        let ActiveIdWidgetsPropGetter { optional, .. } = widgets.get_props();
+        assert_eq!(optional.as_deref(), Some("b"));
+        Ok(())
+    })
+}
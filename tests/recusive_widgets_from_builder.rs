@@ -1,8 +1,5 @@
 use gtk4::prelude::*;
 
-#[macro_use]
-mod util;
-
 #[derive(woab::WidgetsFromBuilder)]
 pub struct FlatWidgets {
     text1: gtk4::Entry,
@@ -33,7 +30,7 @@ pub struct GroupB {
 
 #[test]
 fn test_recusive_widgets_from_builder() -> anyhow::Result<()> {
-    util::test_main(async {
+    woab::test::test_main(async {
         let factory = woab::BuilderFactory::from(include_str!("four_texts.ui").to_owned());
         let bld = factory.instantiate_without_routing_signals();
 
@@ -50,10 +47,10 @@ fn test_recusive_widgets_from_builder() -> anyhow::Result<()> {
         flat_widgets.text3.set_text("Text 3");
         flat_widgets.text4.set_text("Text 4");
 
-        wait_for!(flat_widgets.text1.text() == "Text 1")?;
-        wait_for!(flat_widgets.text2.text() == "Text 2")?;
-        wait_for!(flat_widgets.text3.text() == "Text 3")?;
-        wait_for!(flat_widgets.text4.text() == "Text 4")?;
+        woab::wait_for!(flat_widgets.text1.text() == "Text 1")?;
+        woab::wait_for!(flat_widgets.text2.text() == "Text 2")?;
+        woab::wait_for!(flat_widgets.text3.text() == "Text 3")?;
+        woab::wait_for!(flat_widgets.text4.text() == "Text 4")?;
 
         assert!(grouped_widgets.group_a.text1.text() == "Text 1");
         assert!(grouped_widgets.group_a.text2.text() == "Text 2");
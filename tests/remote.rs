@@ -0,0 +1,44 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use actix::prelude::*;
+
+struct TestActor {
+    output: Rc<RefCell<Vec<usize>>>,
+}
+
+impl actix::Actor for TestActor {
+    type Context = actix::Context<Self>;
+}
+
+struct Number(usize);
+
+impl actix::Message for Number {
+    type Result = ();
+}
+
+impl actix::Handler<Number> for TestActor {
+    type Result = ();
+
+    fn handle(&mut self, msg: Number, _ctx: &mut Self::Context) -> Self::Result {
+        self.output.borrow_mut().push(msg.0);
+    }
+}
+
+#[test]
+fn test_channel_to_actor() -> anyhow::Result<()> {
+    let output = Rc::new(RefCell::new(Vec::new()));
+    woab::test::test_main_loop_until(
+        || {
+            let addr = TestActor { output: output.clone() }.start();
+            let tx = woab::channel_to_actor(addr);
+            std::thread::spawn(move || {
+                for i in 0..5 {
+                    tx.send(Number(i)).unwrap();
+                }
+            });
+        },
+        || *output.borrow() == [0, 1, 2, 3, 4],
+        std::time::Duration::from_secs(1),
+    )
+}
@@ -0,0 +1,60 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use actix::prelude::*;
+
+struct TestActor {
+    output: Rc<RefCell<Vec<usize>>>,
+}
+
+impl actix::Actor for TestActor {
+    type Context = actix::Context<Self>;
+}
+
+struct Number(usize);
+
+impl actix::Message for Number {
+    type Result = ();
+}
+
+impl actix::Handler<Number> for TestActor {
+    type Result = ();
+
+    fn handle(&mut self, msg: Number, _ctx: &mut Self::Context) -> Self::Result {
+        self.output.borrow_mut().push(msg.0);
+    }
+}
+
+struct Sum;
+
+impl actix::Message for Sum {
+    type Result = usize;
+}
+
+impl actix::Handler<Sum> for TestActor {
+    type Result = usize;
+
+    fn handle(&mut self, _msg: Sum, _ctx: &mut Self::Context) -> Self::Result {
+        self.output.borrow().iter().sum()
+    }
+}
+
+#[test]
+fn test_remote_addr() -> anyhow::Result<()> {
+    let output = Rc::new(RefCell::new(Vec::new()));
+    let sum = std::sync::Arc::new(std::sync::Mutex::new(None));
+    woab::test::test_main_loop_until(
+        || {
+            let remote = woab::RemoteAddr::new(TestActor { output: output.clone() }.start());
+            let sum = sum.clone();
+            std::thread::spawn(move || {
+                remote.do_send(Number(1));
+                remote.do_send(Number(2));
+                let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+                *sum.lock().unwrap() = Some(runtime.block_on(remote.send(Sum)).unwrap());
+            });
+        },
+        || *sum.lock().unwrap() == Some(3),
+        std::time::Duration::from_secs(1),
+    )
+}
@@ -0,0 +1,57 @@
+use actix::prelude::*;
+use gtk4::prelude::*;
+
+#[derive(woab::Removable)]
+#[removable(self.container in gtk4::Box, children = self.children)]
+struct ParentActor {
+    container: gtk4::Box,
+    children: woab::RemovableChildren,
+}
+
+impl actix::Actor for ParentActor {
+    type Context = actix::Context<Self>;
+}
+
+#[derive(woab::Removable)]
+#[removable(self.label in gtk4::Box)]
+struct ChildActor {
+    label: gtk4::Label,
+}
+
+impl actix::Actor for ChildActor {
+    type Context = actix::Context<Self>;
+}
+
+#[test]
+fn test_cascading_remove() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let outer = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+        let container = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+        outer.append(&container);
+
+        let mut children = woab::RemovableChildren::new();
+        let child_addrs = (0..2)
+            .map(|i| {
+                let label = gtk4::Label::new(Some(&format!("Child {}", i)));
+                container.append(&label);
+                let addr = ChildActor { label }.start();
+                children.add(addr.clone());
+                addr
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(children.len(), 2);
+
+        let parent = ParentActor {
+            container: container.clone(),
+            children,
+        }
+        .start();
+
+        assert!(parent.send(woab::RemoveAndConfirm).await?);
+        // The children were removed before the parent removed its own widget.
+        assert!(container.first_child().is_none());
+        assert!(outer.first_child().is_none());
+        woab::wait_for!(child_addrs.iter().all(|addr| !addr.connected()) && !parent.connected())?;
+        Ok(())
+    })
+}
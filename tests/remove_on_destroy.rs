@@ -0,0 +1,29 @@
+use actix::prelude::*;
+use gtk4::prelude::*;
+
+#[derive(woab::Removable)]
+#[removable(self.window in gtk4::Box, on_destroy)]
+struct WindowActor {
+    window: gtk4::Window,
+}
+
+impl actix::Actor for WindowActor {
+    type Context = actix::Context<Self>;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        self.remove_on_destroy(ctx);
+    }
+}
+
+#[test]
+fn test_remove_on_destroy() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let window = gtk4::Window::new();
+        let addr = WindowActor { window: window.clone() }.start();
+        // Let the actor start, so that it connects to the `destroy` signal.
+        actix::clock::sleep(core::time::Duration::from_millis(10)).await;
+        window.destroy();
+        woab::wait_for!(!addr.connected())?;
+        Ok(())
+    })
+}
@@ -0,0 +1,34 @@
+use actix::prelude::*;
+use gtk4::prelude::*;
+
+#[derive(woab::Removable)]
+#[removable(self.label in gtk4::Box, on_destroy)]
+struct LabelActor {
+    label: gtk4::Label,
+}
+
+impl actix::Actor for LabelActor {
+    type Context = actix::Context<Self>;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        self.remove_on_destroy(ctx);
+    }
+}
+
+#[test]
+fn test_remove_on_destroy_of_window() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let window = gtk4::Window::new();
+        let container = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+        window.set_child(Some(&container));
+        let label = gtk4::Label::new(None);
+        container.append(&label);
+        // The actor keeps the label alive, so destroying the window does not destroy the label -
+        // only detaches it from the window.
+        let addr = LabelActor { label }.start();
+        actix::clock::sleep(core::time::Duration::from_millis(10)).await;
+        window.destroy();
+        woab::wait_for!(!addr.connected())?;
+        Ok(())
+    })
+}
@@ -0,0 +1,39 @@
+use std::path::PathBuf;
+
+#[test]
+fn test_register_invalid_resources() {
+    assert!(matches!(
+        woab::register_resources(b"not a gresource file"),
+        Err(woab::Error::GenericError(_))
+    ));
+    assert!(matches!(
+        woab::register_resources("no/such/file.gresource"),
+        Err(woab::Error::GenericError(_))
+    ));
+}
+
+#[test]
+fn test_icon_search_paths() -> anyhow::Result<()> {
+    // Added before GTK is initialized, so they must be deferred.
+    woab::add_icon_search_path("/woab/test/early/icons");
+    woab::add_icon_resource_path("/org/example/WoabTest/early/icons");
+    woab::main(gtk4::Application::default(), |_app| {
+        let icon_theme = gtk4::IconTheme::for_display(&gdk4::Display::default().unwrap());
+        assert!(icon_theme.search_path().contains(&PathBuf::from("/woab/test/early/icons")));
+        assert!(icon_theme
+            .resource_path()
+            .iter()
+            .any(|path| path == "/org/example/WoabTest/early/icons"));
+
+        // Once GTK is initialized, they are added immediately.
+        woab::add_icon_search_path("/woab/test/late/icons");
+        woab::add_icon_resource_path("/org/example/WoabTest/late/icons");
+        assert!(icon_theme.search_path().contains(&PathBuf::from("/woab/test/late/icons")));
+        assert!(icon_theme
+            .resource_path()
+            .iter()
+            .any(|path| path == "/org/example/WoabTest/late/icons"));
+        Ok(())
+    })?;
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use actix::prelude::*;
+use gtk4::prelude::*;
+
+struct TestActor {
+    output: Rc<RefCell<Vec<String>>>,
+}
+
+impl actix::Actor for TestActor {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<woab::Signal> for TestActor {
+    type Result = woab::SignalResult;
+
+    fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+        Ok(match msg.name() {
+            "label_changed" => {
+                let woab::params!(label: String) = msg.params()?;
+                self.output.borrow_mut().push(label);
+                None
+            }
+            _ => msg.cant_handle()?,
+        })
+    }
+}
+
+#[test]
+fn test_route_expression() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let output = Rc::new(RefCell::new(Vec::<String>::new()));
+        let addr = TestActor { output: output.clone() }.start();
+
+        let label = gtk4::Label::new(Some("one"));
+        let expression = gtk4::PropertyExpression::new(gtk4::Label::static_type(), None::<gtk4::Expression>, "label");
+        let watch = woab::route_expression(&expression, Some(&label), "label_changed", addr);
+
+        label.set_label("two");
+        woab::wait_for!(*output.borrow() == ["two"])?;
+        label.set_label("three");
+        woab::wait_for!(*output.borrow() == ["two", "three"])?;
+
+        watch.unwatch();
+        label.set_label("four");
+        actix::clock::sleep(core::time::Duration::from_millis(10)).await;
+        assert_eq!(*output.borrow(), ["two", "three"]);
+        Ok(())
+    })
+}
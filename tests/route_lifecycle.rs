@@ -0,0 +1,58 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use actix::prelude::*;
+use gtk4::prelude::*;
+
+struct TestActor {
+    output: Rc<RefCell<Vec<String>>>,
+}
+
+impl actix::Actor for TestActor {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<woab::Signal> for TestActor {
+    type Result = woab::SignalResult;
+
+    fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+        self.output.borrow_mut().push(msg.name().to_owned());
+        Ok(None)
+    }
+}
+
+#[test]
+fn test_route_lifecycle() -> anyhow::Result<()> {
+    let output = Rc::new(RefCell::new(Vec::new()));
+    let window = Rc::new(RefCell::new(None::<gtk4::Window>));
+    woab::test::test_main_loop_until::<anyhow::Error>(
+        || {
+            // Only the window holds the label, so that destroying the window destroys the label.
+            let label = gtk4::Label::new(Some("Hello"));
+            woab::route_lifecycle(&label, TestActor { output: output.clone() }.start());
+            let new_window = gtk4::Window::builder().child(&label).build();
+            new_window.present();
+            *window.borrow_mut() = Some(new_window);
+        },
+        || {
+            if output.borrow().iter().any(|signal| signal == "lifecycle_map") {
+                if let Some(window) = window.take() {
+                    window.destroy();
+                }
+            }
+            output.borrow().iter().any(|signal| signal == "lifecycle_destroy")
+        },
+        std::time::Duration::from_secs(5),
+    )?;
+    assert_eq!(
+        *output.borrow(),
+        [
+            "lifecycle_realize",
+            "lifecycle_map",
+            "lifecycle_unmap",
+            "lifecycle_unrealize",
+            "lifecycle_destroy",
+        ]
+    );
+    Ok(())
+}
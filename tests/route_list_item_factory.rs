@@ -0,0 +1,71 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use actix::prelude::*;
+use gtk4::prelude::*;
+
+struct ListActor {
+    output: Rc<RefCell<Vec<String>>>,
+}
+
+impl actix::Actor for ListActor {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<woab::Signal> for ListActor {
+    type Result = woab::SignalResult;
+
+    fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+        let woab::params!(_, list_item: gtk4::ListItem) = msg.params()?;
+        Ok(match msg.name() {
+            "setup" => {
+                list_item.set_child(Some(&gtk4::Label::new(None)));
+                None
+            }
+            "bind" => {
+                let label = list_item.child().unwrap().downcast::<gtk4::Label>().unwrap();
+                let item = list_item.item().unwrap().downcast::<gtk4::StringObject>().unwrap();
+                label.set_text(&item.string());
+                self.output.borrow_mut().push(format!("bind {}", item.string()));
+                None
+            }
+            "unbind" | "teardown" => None,
+            _ => msg.cant_handle()?,
+        })
+    }
+}
+
+#[test]
+fn test_route_list_item_factory() -> anyhow::Result<()> {
+    let output = Rc::new(RefCell::new(Vec::new()));
+    let list_view = Rc::new(RefCell::new(None::<gtk4::ListView>));
+    woab::test::test_main_loop_until::<anyhow::Error>(
+        || {
+            let actor = ListActor { output: output.clone() }.start();
+            let model = gtk4::NoSelection::new(Some(gtk4::StringList::new(&["a", "b", "c"])));
+            let view = gtk4::ListView::new(Some(model), Some(woab::route_list_item_factory(actor)));
+            let window = gtk4::Window::builder().child(&view).default_height(200).build();
+            window.present();
+            *list_view.borrow_mut() = Some(view);
+        },
+        || output.borrow().len() == 3,
+        std::time::Duration::from_secs(5),
+    )?;
+
+    let mut bound = output.borrow().clone();
+    bound.sort();
+    assert_eq!(bound, ["bind a", "bind b", "bind c"]);
+
+    let list_view = list_view.take().unwrap();
+    let mut texts = Vec::new();
+    let mut child = list_view.first_child();
+    while let Some(row) = child {
+        if let Some(label) = row.first_child().and_then(|label| label.downcast::<gtk4::Label>().ok()) {
+            texts.push(label.text().to_string());
+        }
+        child = row.next_sibling();
+    }
+    texts.sort();
+    assert_eq!(texts, ["a", "b", "c"]);
+    Ok(())
+}
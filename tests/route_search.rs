@@ -0,0 +1,47 @@
+use core::time::Duration;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use actix::prelude::*;
+use gtk4::prelude::*;
+
+struct TestActor {
+    output: Rc<RefCell<Vec<String>>>,
+}
+
+impl actix::Actor for TestActor {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<woab::Signal> for TestActor {
+    type Result = woab::SignalResult;
+
+    fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+        let woab::params!(search_entry: gtk4::SearchEntry) = msg.params()?;
+        self.output
+            .borrow_mut()
+            .push(format!("{}({})", msg.name(), search_entry.text()));
+        Ok(None)
+    }
+}
+
+#[test]
+fn test_route_search() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let output = Rc::new(RefCell::new(Vec::<String>::new()));
+        let addr = TestActor { output: output.clone() }.start();
+
+        let search_entry = gtk4::SearchEntry::new();
+        woab::route_search(&search_entry, addr, Duration::from_millis(10))?;
+
+        search_entry.set_text("a");
+        search_entry.set_text("ab");
+        woab::wait_for!(*output.borrow() == ["search_changed(ab)"])?;
+
+        // Clearing the search is not debounced
+        search_entry.set_text("");
+        search_entry.emit_by_name::<()>("stop-search", &[]);
+        woab::wait_for!(*output.borrow() == ["search_changed(ab)", "search_changed()", "search_stopped()"])?;
+        Ok(())
+    })
+}
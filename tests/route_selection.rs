@@ -0,0 +1,53 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use actix::prelude::*;
+use gtk4::prelude::*;
+
+struct TestActor {
+    output: Rc<RefCell<Vec<Vec<String>>>>,
+}
+
+impl actix::Actor for TestActor {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<woab::Signal> for TestActor {
+    type Result = woab::SignalResult;
+
+    fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+        Ok(match msg.name() {
+            "items_selected" => {
+                let selection = msg.selection_change()?;
+                let selected = selection
+                    .selected_items
+                    .into_iter()
+                    .map(|item| item.downcast::<gtk4::StringObject>().unwrap().string().to_string())
+                    .collect();
+                self.output.borrow_mut().push(selected);
+                None
+            }
+            _ => msg.cant_handle()?,
+        })
+    }
+}
+
+#[test]
+fn test_route_selection() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let output = Rc::new(RefCell::new(Vec::<Vec<String>>::new()));
+        let addr = TestActor { output: output.clone() }.start();
+
+        let model = gtk4::StringList::new(&["one", "two", "three"]);
+        let selection = gtk4::MultiSelection::new(Some(model));
+        woab::route_selection(&selection, "items_selected", addr);
+
+        selection.select_item(1, true);
+        woab::wait_for!(*output.borrow() == [vec!["two"]])?;
+        selection.select_item(2, false);
+        woab::wait_for!(*output.borrow() == [vec!["two"], vec!["two", "three"]])?;
+        selection.unselect_item(1);
+        woab::wait_for!(*output.borrow() == [vec!["two"], vec!["two", "three"], vec!["three"]])?;
+        Ok(())
+    })
+}
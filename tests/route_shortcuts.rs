@@ -0,0 +1,67 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use actix::prelude::*;
+use gtk4::prelude::*;
+
+struct TestActor {
+    output: Rc<RefCell<Vec<&'static str>>>,
+}
+
+impl actix::Actor for TestActor {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<woab::Signal> for TestActor {
+    type Result = woab::SignalResult;
+
+    fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+        Ok(match msg.name() {
+            "save" => {
+                let _: gtk4::Widget = msg.param(0)?;
+                self.output.borrow_mut().push("save");
+                None
+            }
+            "cancel" => {
+                self.output.borrow_mut().push("cancel");
+                None
+            }
+            _ => msg.cant_handle()?,
+        })
+    }
+}
+
+#[test]
+fn test_route_shortcuts() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let output = Rc::new(RefCell::new(Vec::new()));
+        let addr = TestActor { output: output.clone() }.start();
+
+        let widget = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+        assert!(matches!(
+            woab::route_shortcuts(&widget, &[("not a trigger", "save")], gtk4::ShortcutScope::Local, addr.clone()),
+            Err(woab::Error::InvalidShortcutTrigger(trigger)) if trigger == "not a trigger"
+        ));
+
+        let controller = woab::route_shortcuts(
+            &widget,
+            &[("<Control>s", "save"), ("Escape", "cancel")],
+            gtk4::ShortcutScope::Managed,
+            addr,
+        )?;
+        assert_eq!(controller.scope(), gtk4::ShortcutScope::Managed);
+        assert_eq!(controller.n_items(), 2);
+
+        let activate = |i: u32| {
+            let shortcut: gtk4::Shortcut = controller.item(i).unwrap().downcast().unwrap();
+            assert!(shortcut
+                .action()
+                .unwrap()
+                .activate(gtk4::ShortcutActionFlags::empty(), &widget, None));
+        };
+        activate(1);
+        activate(0);
+        woab::wait_for!(*output.borrow() == ["cancel", "save"])?;
+        Ok(())
+    })
+}
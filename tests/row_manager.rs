@@ -0,0 +1,94 @@
+use actix::prelude::*;
+use gtk4::prelude::*;
+use woab::row_manager::{Add, Remove, RowManager, Rows};
+
+const ROW_XML: &str = r#"
+<interface>
+  <object class="GtkLabel" id="row_label"/>
+</interface>
+"#;
+
+#[derive(woab::WidgetsFromBuilder)]
+struct RowWidgets {
+    row_label: gtk4::Label,
+}
+
+#[derive(woab::Removable)]
+#[removable(self.widgets.row_label in gtk4::Box)]
+struct RowActor {
+    widgets: RowWidgets,
+}
+
+impl actix::Actor for RowActor {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<woab::Signal> for RowActor {
+    type Result = woab::SignalResult;
+
+    fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+        msg.cant_handle()
+    }
+}
+
+impl woab::row_manager::ManagedRow for RowActor {
+    type Key = u32;
+    type Init = String;
+
+    fn create_row(key: u32, init: String, builder: woab::BuilderWidgets, _ctx: &mut Self::Context) -> Self {
+        let widgets: RowWidgets = builder.widgets().unwrap();
+        widgets.row_label.set_text(&format!("{}: {}", key, init));
+        Self { widgets }
+    }
+
+    fn row_widget(&self) -> gtk4::Widget {
+        self.widgets.row_label.clone().upcast()
+    }
+}
+
+fn labels(container: &gtk4::Box) -> Vec<String> {
+    let mut result = Vec::new();
+    let mut child = container.first_child();
+    while let Some(widget) = child {
+        result.push(widget.downcast_ref::<gtk4::Label>().unwrap().text().into());
+        child = widget.next_sibling();
+    }
+    result
+}
+
+#[test]
+fn test_row_manager() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let container = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
+        let factory = woab::BuilderFactory::from(ROW_XML.to_owned());
+        let manager = RowManager::<RowActor, gtk4::Box>::new(container.clone(), factory)
+            .sorted_by(|a, b| a.cmp(b))
+            .start();
+
+        // Insert
+        manager.send(Add(2, "two".to_owned())).await?;
+        manager.send(Add(1, "one".to_owned())).await?;
+        manager.send(Add(3, "three".to_owned())).await?;
+        assert_eq!(labels(&container), ["1: one", "2: two", "3: three"]);
+
+        // Update
+        let old_addr = manager.send(woab::row_manager::Get(2)).await?.unwrap();
+        let new_addr = manager.send(Add(2, "TWO".to_owned())).await?;
+        woab::wait_for!(labels(&container) == ["1: one", "2: TWO", "3: three"])?;
+        woab::wait_for!(!old_addr.connected())?;
+        assert!(new_addr.connected());
+
+        // Remove
+        assert!(manager.send(Remove(1)).await?);
+        assert!(!manager.send(Remove(1)).await?);
+        woab::wait_for!(labels(&container) == ["2: TWO", "3: three"])?;
+        let keys = manager
+            .send(Rows::default())
+            .await?
+            .into_iter()
+            .map(|(key, _)| key)
+            .collect::<Vec<_>>();
+        assert_eq!(keys, [2, 3]);
+        Ok(())
+    })
+}
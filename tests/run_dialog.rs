@@ -0,0 +1,68 @@
+// `gtk4::Dialog` is deprecated since GTK 4.10.
+#![cfg_attr(feature = "gtk_v4_10", allow(deprecated))]
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use gtk4::prelude::*;
+
+#[test]
+fn test_run_dialog() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let responses = Rc::new(RefCell::new(Vec::new()));
+        let dialog = |title: &str| {
+            let dialog = gtk4::Dialog::builder().title(title).build();
+            dialog.connect_response({
+                let responses = responses.clone();
+                move |_, response| responses.borrow_mut().push(response)
+            });
+            dialog
+        };
+
+        let outer = woab::test::dialogs::auto_respond([gtk4::ResponseType::Cancel]);
+        {
+            let inner = woab::test::dialogs::auto_respond([gtk4::ResponseType::Yes, gtk4::ResponseType::No]);
+            assert_eq!(woab::run_dialog(&dialog("First")).await, gtk4::ResponseType::Yes);
+            assert_eq!(inner.remaining(), 1);
+            assert_eq!(woab::run_dialog(&dialog("Second")).await, gtk4::ResponseType::No);
+            assert_eq!(inner.remaining(), 0);
+            assert_eq!(inner.dialog_titles(), [Some("First".to_owned()), Some("Second".to_owned())]);
+        }
+
+        // Dropping the inner auto-responder restores the outer one.
+        assert_eq!(woab::run_dialog(&dialog("Third")).await, gtk4::ResponseType::Cancel);
+        assert_eq!(outer.remaining(), 0);
+        assert_eq!(outer.dialog_titles(), [Some("Third".to_owned())]);
+
+        // The `response` signal is emitted, just like when the user responds.
+        assert_eq!(
+            *responses.borrow(),
+            [gtk4::ResponseType::Yes, gtk4::ResponseType::No, gtk4::ResponseType::Cancel]
+        );
+
+        // An already cancelled cancellable does not even use a scripted response.
+        let cancellable = gio::Cancellable::new();
+        cancellable.cancel();
+        let auto_responder = woab::test::dialogs::auto_respond([gtk4::ResponseType::Yes]);
+        let result = woab::run_dialog_cancellable(&dialog("Fourth"), &cancellable).await;
+        assert!(matches!(result, Err(woab::Error::Cancelled)));
+        assert_eq!(auto_responder.remaining(), 1);
+        drop(auto_responder);
+        drop(outer);
+
+        // Cancelling while the dialog is shown closes it.
+        let cancellable = gio::Cancellable::new();
+        actix::spawn({
+            let cancellable = cancellable.clone();
+            async move {
+                actix::clock::sleep(core::time::Duration::from_millis(10)).await;
+                cancellable.cancel();
+            }
+        });
+        let fifth = dialog("Fifth");
+        let result = woab::run_dialog_cancellable(&fifth, &cancellable).await;
+        assert!(matches!(result, Err(woab::Error::Cancelled)));
+        assert!(!fifth.is_visible());
+        Ok(())
+    })
+}
@@ -0,0 +1,41 @@
+use actix::prelude::*;
+
+struct Counter(usize);
+
+impl actix::Actor for Counter {
+    type Context = actix::Context<Self>;
+}
+
+struct Increment;
+
+impl actix::Message for Increment {
+    type Result = usize;
+}
+
+impl actix::Handler<Increment> for Counter {
+    type Result = usize;
+
+    fn handle(&mut self, _msg: Increment, _ctx: &mut Self::Context) -> Self::Result {
+        self.0 += 1;
+        self.0
+    }
+}
+
+#[test]
+fn test_send_from_inside_actix() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let counter = Counter(0).start();
+        assert_eq!(woab::send(counter.clone(), Increment).await?, 1);
+
+        // Inside the Actix runtime the response cannot be available immediately.
+        let Err(pending) = woab::send(counter.clone().recipient(), Increment).now() else {
+            panic!("`send` should not block inside the Actix runtime");
+        };
+        assert_eq!(pending.await?, 2);
+
+        // The message is sent even if the result is dropped.
+        drop(woab::send(counter.clone(), Increment));
+        assert_eq!(woab::send(counter, Increment).await?, 4);
+        Ok(())
+    })
+}
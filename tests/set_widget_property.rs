@@ -0,0 +1,71 @@
+use actix::prelude::*;
+
+const UI_XML: &str = r#"
+<interface>
+  <object class="GtkBox" id="status_box">
+    <child>
+      <object class="GtkLabel" id="status_label"/>
+    </child>
+  </object>
+</interface>
+"#;
+
+struct WindowActor {
+    builder: woab::BuilderWidgets,
+}
+
+impl actix::Actor for WindowActor {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<woab::SetWidgetProperty> for WindowActor {
+    type Result = woab::Result<()>;
+
+    fn handle(&mut self, msg: woab::SetWidgetProperty, _ctx: &mut Self::Context) -> Self::Result {
+        msg.apply(&self.builder)
+    }
+}
+
+#[test]
+fn test_set_widget_property() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let factory = woab::BuilderFactory::from(UI_XML.to_owned());
+        let builder = factory.instantiate_without_routing_signals();
+        let label: gtk4::Label = builder.get_object("status_label")?;
+        let addr = WindowActor { builder }.start();
+
+        // Messages that specify the widget by ID can be created in other threads.
+        let msg = std::thread::spawn(|| woab::SetWidgetProperty::by_id("status_label", "label", "Done"))
+            .join()
+            .unwrap();
+        addr.send(msg).await??;
+        assert_eq!(label.text(), "Done");
+
+        addr.send(woab::SetWidgetProperty::on(&label, "label", "Direct")).await??;
+        assert_eq!(label.text(), "Direct");
+
+        let err = addr
+            .send(woab::SetWidgetProperty::by_id("status_labl", "label", "Typo"))
+            .await?
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            woab::Error::WidgetMissingInBuilder { widget_id, suggestions }
+                if widget_id == "status_labl" && suggestions == ["status_label"]
+        ));
+
+        let err = addr
+            .send(woab::SetWidgetProperty::by_id("status_label", "no-such-property", "value"))
+            .await?
+            .unwrap_err();
+        assert!(matches!(err, woab::Error::NoSuchProperty { property, .. } if property == "no-such-property"));
+
+        let err = addr
+            .send(woab::SetWidgetProperty::by_id("status_label", "label", 5i32))
+            .await?
+            .unwrap_err();
+        assert!(matches!(err, woab::Error::IncorrectPropertyType { property, .. } if property == "label"));
+        assert_eq!(label.text(), "Direct");
+        Ok(())
+    })
+}
@@ -4,9 +4,6 @@ use std::rc::Rc;
 use actix::prelude::*;
 use gtk4::prelude::*;
 
-#[macro_use]
-mod util;
-
 #[derive(woab::Factories)]
 struct Factories {
     win_test: woab::BuilderFactory,
@@ -59,7 +56,7 @@ fn test_teardown() -> anyhow::Result<()> {
     assert!(!woab::is_runtime_running());
 
     let output = Rc::new(RefCell::new(Vec::new()));
-    util::test_main({
+    woab::test::test_main::<anyhow::Error>({
         let output = output.clone();
         async move {
             let factories = Factories::read(include_bytes!("just_a_button.ui") as &[u8])?;
@@ -68,7 +65,7 @@ fn test_teardown() -> anyhow::Result<()> {
                 .instantiate_route_to(TestActor { output: output.clone() }.start());
             let widgets = bld.widgets::<TestWidgets>().unwrap();
             widgets.btn_button.emit_clicked();
-            wait_for!(*output.borrow() == ["click"])?;
+            woab::wait_for!(*output.borrow() == ["click"])?;
             assert!(woab::is_runtime_running());
             Ok(())
         }
@@ -0,0 +1,62 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use actix::prelude::*;
+use gtk4::prelude::*;
+
+#[derive(woab::Factories)]
+#[factory(ui = "tests/basic.ui")]
+struct Factories {
+    win_test: woab::BuilderFactory,
+}
+
+#[derive(woab::WidgetsFromBuilder)]
+struct TestWidgets {
+    btn_copy_right_to_left: gtk4::Button,
+    btn_copy_left_to_right: gtk4::Button,
+}
+
+struct TestActor {
+    output: Rc<RefCell<Vec<&'static str>>>,
+}
+
+impl actix::Actor for TestActor {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<woab::Signal> for TestActor {
+    type Result = woab::SignalResult;
+
+    fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+        Ok(match msg.name() {
+            factories_signals::COPY_RIGHT_TO_LEFT => {
+                self.output.borrow_mut().push("right to left");
+                None
+            }
+            factories_signals::COPY_LEFT_TO_RIGHT => {
+                self.output.borrow_mut().push("left to right");
+                None
+            }
+            _ => msg.cant_handle()?,
+        })
+    }
+}
+
+#[test]
+fn test_signal_constants() -> anyhow::Result<()> {
+    assert_eq!(factories_signals::COPY_RIGHT_TO_LEFT, "copy_right_to_left");
+    assert_eq!(factories_signals::COPY_LEFT_TO_RIGHT, "copy_left_to_right");
+    woab::test::test_main(async {
+        let factories = Factories::read(include_bytes!("basic.ui") as &[u8])?;
+        let output = Rc::new(RefCell::new(Vec::new()));
+        let bld = factories
+            .win_test
+            .instantiate_route_to(TestActor { output: output.clone() }.start());
+        let widgets: TestWidgets = bld.widgets()?;
+        widgets.btn_copy_left_to_right.emit_clicked();
+        woab::wait_for!(*output.borrow() == ["left to right"])?;
+        widgets.btn_copy_right_to_left.emit_clicked();
+        woab::wait_for!(*output.borrow() == ["left to right", "right to left"])?;
+        Ok(())
+    })
+}
@@ -0,0 +1,55 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use actix::prelude::*;
+use gtk4::prelude::*;
+
+struct TestActor {
+    clicks: Rc<Cell<usize>>,
+}
+
+impl actix::Actor for TestActor {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<woab::Signal> for TestActor {
+    type Result = woab::SignalResult;
+
+    fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+        Ok(match msg.name() {
+            "diagnosed_click" => {
+                self.clicks.set(self.clicks.get() + 1);
+                None
+            }
+            _ => msg.cant_handle()?,
+        })
+    }
+}
+
+#[test]
+fn test_signal_diagnostics() -> anyhow::Result<()> {
+    woab::set_signal_diagnostics_enabled(true);
+    let clicks = Rc::new(Cell::new(0));
+    woab::test::test_main_loop_until::<anyhow::Error>(
+        || {
+            let addr = TestActor { clicks: clicks.clone() }.start();
+            let button = gtk4::Button::new();
+            woab::route_signal(&button, "clicked", "diagnosed_click", addr).unwrap();
+            // Emitted inside the Actix runtime, so it must be queued
+            button.emit_clicked();
+            // Emitted from the main loop, so it can be handled immediately
+            glib::idle_add_local_once(move || button.emit_clicked());
+        },
+        || clicks.get() == 2,
+        std::time::Duration::from_secs(1),
+    )?;
+    let stats = woab::signal_diagnostics();
+    let stats = stats.iter().find(|stats| stats.signal == "diagnosed_click").unwrap();
+    assert_eq!(stats.handled_immediately, 1);
+    assert_eq!(stats.queued, 1);
+    assert_eq!(stats.queued_with_propagation, 0);
+
+    woab::reset_signal_diagnostics();
+    assert!(woab::signal_diagnostics().is_empty());
+    Ok(())
+}
@@ -0,0 +1,60 @@
+#![cfg(feature = "metrics")]
+
+use core::time::Duration;
+use std::cell::Cell;
+use std::rc::Rc;
+
+use actix::prelude::*;
+use gtk4::prelude::*;
+
+struct TestActor {
+    clicks: Rc<Cell<usize>>,
+}
+
+impl actix::Actor for TestActor {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<woab::Signal> for TestActor {
+    type Result = woab::SignalResult;
+
+    fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+        Ok(match msg.name() {
+            "slow_click" => {
+                std::thread::sleep(Duration::from_millis(5));
+                self.clicks.set(self.clicks.get() + 1);
+                None
+            }
+            _ => msg.cant_handle()?,
+        })
+    }
+}
+
+#[test]
+fn test_signal_metrics() -> anyhow::Result<()> {
+    let clicks = Rc::new(Cell::new(0));
+    woab::test::test_main_loop_until::<anyhow::Error>(
+        || {
+            let addr = TestActor { clicks: clicks.clone() }.start();
+            let button = gtk4::Button::new();
+            woab::route_signal(&button, "clicked", "slow_click", addr).unwrap();
+            glib::idle_add_local_once(move || {
+                button.emit_clicked();
+                button.emit_clicked();
+            });
+        },
+        || clicks.get() == 2,
+        std::time::Duration::from_secs(1),
+    )?;
+    let metrics = woab::signal_metrics();
+    let metrics = metrics.iter().find(|metrics| metrics.signal == "slow_click").unwrap();
+    assert_eq!(metrics.count, 2);
+    assert!(Duration::from_millis(5) <= metrics.max);
+    assert!(Duration::from_millis(5) <= metrics.mean());
+    assert_eq!(metrics.count_at_least(Duration::from_millis(5)), 2);
+    assert_eq!(metrics.histogram.iter().map(|(_, count)| count).sum::<usize>(), 2);
+
+    woab::reset_signal_metrics();
+    assert!(woab::signal_metrics().is_empty());
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use actix::prelude::*;
+use gtk4::prelude::*;
+
+struct TestActor {
+    output: Rc<RefCell<Vec<&'static str>>>,
+}
+
+impl actix::Actor for TestActor {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<woab::Signal> for TestActor {
+    type Result = woab::SignalResult;
+
+    fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+        Ok(match msg.name() {
+            "scoped" => {
+                self.output.borrow_mut().push("scoped");
+                None
+            }
+            "unscoped" => {
+                self.output.borrow_mut().push("unscoped");
+                None
+            }
+            _ => msg.cant_handle()?,
+        })
+    }
+}
+
+#[test]
+fn test_signal_scope() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let output = Rc::new(RefCell::new(Vec::new()));
+        let addr = TestActor { output: output.clone() }.start();
+        let scoped_button = gtk4::Button::new();
+        let unscoped_button = gtk4::Button::new();
+
+        let mut scope = woab::SignalScope::new();
+        scope.route_signal(&scoped_button, "clicked", "scoped", addr.clone())?;
+        woab::route_signal(&unscoped_button, "clicked", "unscoped", addr)?;
+        assert_eq!(scope.len(), 1);
+
+        scoped_button.emit_clicked();
+        woab::wait_for!(*output.borrow() == ["scoped"])?;
+
+        scope.clear();
+        assert!(scope.is_empty());
+        scoped_button.emit_clicked();
+        unscoped_button.emit_clicked();
+        woab::wait_for!(*output.borrow() == ["scoped", "unscoped"])?;
+        Ok(())
+    })
+}
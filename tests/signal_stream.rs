@@ -0,0 +1,24 @@
+use futures_util::StreamExt;
+use gio::prelude::*;
+
+#[test]
+fn test_signal_stream() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let action = gio::SimpleAction::new("action", Some(glib::VariantTy::INT32));
+        let mut stream = woab::signal_stream(&action, "activate");
+
+        action.activate(Some(&1.to_variant()));
+        action.activate(Some(&2.to_variant()));
+
+        let signal = stream.next().await.unwrap();
+        assert_eq!(signal.name(), "activate");
+        assert_eq!(signal.action_param::<i32>()?, 1);
+        let signal = stream.next().await.unwrap();
+        assert_eq!(signal.action_param::<i32>()?, 2);
+
+        drop(stream);
+        // Make sure the signal was disconnected and does not try to send to the dropped stream.
+        action.activate(Some(&3.to_variant()));
+        Ok(())
+    })
+}
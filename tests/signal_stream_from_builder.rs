@@ -0,0 +1,25 @@
+use futures_util::StreamExt;
+use gtk4::prelude::*;
+
+#[test]
+fn test_signal_stream_from_builder() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let factory = woab::BuilderFactory::from(std::fs::read_to_string("tests/just_a_button.ui")?);
+        let (bld, mut stream) = woab::signal_stream_from_builder(&factory);
+        let button: gtk4::Button = bld.get_object("btn_button")?;
+
+        button.emit_clicked();
+        button.emit_clicked();
+        let signal = stream.next().await.unwrap();
+        assert_eq!(signal.name(), "button_clicked");
+        let woab::params!(emitter: gtk4::Button) = signal.params()?;
+        assert_eq!(emitter, button);
+        assert_eq!(stream.next().await.unwrap().name(), "button_clicked");
+
+        let mut routed_stream = woab::route_signal_stream(&button, "clicked", "routed_click");
+        button.emit_clicked();
+        assert_eq!(stream.next().await.unwrap().name(), "button_clicked");
+        assert_eq!(routed_stream.next().await.unwrap().name(), "routed_click");
+        Ok(())
+    })
+}
@@ -0,0 +1,151 @@
+#![cfg(feature = "tracing")]
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+use actix::prelude::*;
+use gtk4::prelude::*;
+
+#[derive(Default)]
+struct Recorded {
+    spans: Vec<HashMap<&'static str, String>>,
+    // The message of each event, and the span it happened in.
+    events: Vec<(Option<usize>, String)>,
+    entered: Vec<usize>,
+}
+
+struct FieldsVisitor<'a>(&'a mut HashMap<&'static str, String>);
+
+impl tracing::field::Visit for FieldsVisitor<'_> {
+    fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+        self.0.insert(field.name(), value.to_owned());
+    }
+
+    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn core::fmt::Debug) {
+        self.0.insert(field.name(), format!("{:?}", value));
+    }
+}
+
+#[derive(Clone, Default)]
+struct RecordingSubscriber(Arc<Mutex<Recorded>>);
+
+impl tracing::Subscriber for RecordingSubscriber {
+    fn enabled(&self, _metadata: &tracing::Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+        let mut fields = HashMap::new();
+        fields.insert("name", span.metadata().name().to_owned());
+        span.record(&mut FieldsVisitor(&mut fields));
+        let mut recorded = self.0.lock().unwrap();
+        recorded.spans.push(fields);
+        tracing::span::Id::from_u64(recorded.spans.len() as u64)
+    }
+
+    fn record(&self, span: &tracing::span::Id, values: &tracing::span::Record<'_>) {
+        let mut recorded = self.0.lock().unwrap();
+        values.record(&mut FieldsVisitor(&mut recorded.spans[span.into_u64() as usize - 1]));
+    }
+
+    fn record_follows_from(&self, _span: &tracing::span::Id, _follows: &tracing::span::Id) {}
+
+    fn event(&self, event: &tracing::Event<'_>) {
+        let mut fields = HashMap::new();
+        event.record(&mut FieldsVisitor(&mut fields));
+        let mut recorded = self.0.lock().unwrap();
+        let span = recorded.entered.last().copied();
+        recorded.events.push((span, fields.remove("message").unwrap_or_default()));
+    }
+
+    fn enter(&self, span: &tracing::span::Id) {
+        self.0.lock().unwrap().entered.push(span.into_u64() as usize - 1);
+    }
+
+    fn exit(&self, _span: &tracing::span::Id) {
+        self.0.lock().unwrap().entered.pop();
+    }
+}
+
+struct TestActor {
+    clicks: Rc<Cell<usize>>,
+}
+
+impl actix::Actor for TestActor {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<woab::Signal<String>> for TestActor {
+    type Result = woab::SignalResult;
+
+    fn handle(&mut self, msg: woab::Signal<String>, _ctx: &mut Self::Context) -> Self::Result {
+        Ok(match msg.name() {
+            "tagged_click" => {
+                self.clicks.set(self.clicks.get() + 1);
+                None
+            }
+            _ => msg.cant_handle()?,
+        })
+    }
+}
+
+#[test]
+fn test_signal_tracing() -> anyhow::Result<()> {
+    let subscriber = RecordingSubscriber::default();
+    let clicks = Rc::new(Cell::new(0));
+    let button = RefCell::new(None::<gtk4::Button>);
+    tracing::subscriber::with_default(subscriber.clone(), || {
+        woab::test::test_main_loop_until::<anyhow::Error>(
+            || {
+                let addr = TestActor { clicks: clicks.clone() }.start();
+                let new_button = gtk4::Button::new();
+                woab::route_signal(&new_button, "clicked", "tagged_click", ("the_tag".to_owned(), addr)).unwrap();
+                // Emitted inside the Actix runtime, so the signal must be queued.
+                new_button.emit_clicked();
+                *button.borrow_mut() = Some(new_button);
+            },
+            || {
+                let clicks = clicks.get();
+                if clicks == 1 {
+                    // Emitted from the GTK loop, so the signal is handled inline.
+                    button.borrow().as_ref().unwrap().emit_clicked();
+                }
+                2 <= clicks
+            },
+            std::time::Duration::from_secs(1),
+        )
+    })?;
+
+    let recorded = subscriber.0.lock().unwrap();
+    let signal_spans = recorded
+        .spans
+        .iter()
+        .enumerate()
+        .filter(|(_, span)| span["name"] == "woab_signal")
+        .map(|(index, _)| index)
+        .collect::<Vec<_>>();
+    assert_eq!(signal_spans.len(), 2);
+    for &index in signal_spans.iter() {
+        let span = &recorded.spans[index];
+        assert_eq!(span["signal"], "tagged_click");
+        assert_eq!(span["actor"], core::any::type_name::<TestActor>());
+        assert_eq!(span["tag_type"], core::any::type_name::<String>());
+        assert_eq!(span["tag"], "the_tag");
+    }
+    let events_of = |index: usize| {
+        recorded
+            .events
+            .iter()
+            .filter(|(span, _)| *span == Some(index))
+            .map(|(_, message)| message.as_str())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        events_of(signal_spans[0]),
+        ["signal queued because the Actix runtime is busy", "queued signal handled"]
+    );
+    assert_eq!(events_of(signal_spans[1]), ["signal handled inline"]);
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use actix::prelude::*;
+
+struct Counter {
+    total: i32,
+    log: Vec<String>,
+}
+
+impl actix::Actor for Counter {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<woab::Signal> for Counter {
+    type Result = woab::SignalResult;
+
+    fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+        Ok(match msg.name() {
+            "add" => {
+                let woab::params!(amount: i32, note: String, enabled: bool) = msg.params()?;
+                if enabled {
+                    self.total += amount;
+                    self.log.push(note);
+                }
+                None
+            }
+            _ => msg.cant_handle()?,
+        })
+    }
+}
+
+// Deliberately not using `woab::test::test_main` - none of this should require GTK to be initialized.
+#[test]
+fn test_signal_without_gtk() -> anyhow::Result<()> {
+    let mut counter = Counter {
+        total: 0,
+        log: Vec::new(),
+    };
+    let mut ctx = actix::Context::<Counter>::new();
+
+    counter.handle(woab::Signal::for_test("add", (2i32, "two", true)), &mut ctx)?;
+    counter.handle(woab::Signal::for_test("add", (3i32, "three".to_owned(), false)), &mut ctx)?;
+    counter.handle(woab::Signal::for_test("add", (4i32, "four", true)), &mut ctx)?;
+    assert_eq!(counter.total, 6);
+    assert_eq!(counter.log, ["two", "four"]);
+
+    let result = counter.handle(woab::Signal::for_test("add", ("five", 5i32, true)), &mut ctx);
+    assert!(matches!(
+        result,
+        Err(woab::Error::IncorrectSignalParameterType { index: 0, .. })
+    ));
+
+    let result = counter.handle(woab::Signal::for_test("subtract", (1i32,)), &mut ctx);
+    assert!(matches!(result, Err(woab::Error::NoSuchSignalError { .. })));
+    assert!(!gtk4::is_initialized());
+    Ok(())
+}
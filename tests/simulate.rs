@@ -0,0 +1,75 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use actix::prelude::*;
+use gio::prelude::*;
+use gtk4::prelude::*;
+use woab::test::simulate;
+
+struct TestActor {
+    output: Rc<RefCell<Vec<String>>>,
+}
+
+impl actix::Actor for TestActor {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<woab::Signal> for TestActor {
+    type Result = woab::SignalResult;
+
+    fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+        self.output.borrow_mut().push(msg.name().to_owned());
+        Ok(None)
+    }
+}
+
+#[test]
+fn test_simulate() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let output = Rc::new(RefCell::new(Vec::new()));
+        let addr = TestActor { output: output.clone() }.start();
+
+        let button = gtk4::Button::new();
+        woab::route_signal(&button, "clicked", "button_clicked", addr.clone())?;
+        simulate::click(&button);
+        woab::wait_for!(*output.borrow() == ["button_clicked"])?;
+        output.borrow_mut().clear();
+
+        let entry = gtk4::Entry::new();
+        woab::route_signal(&entry, "changed", "entry_changed", addr.clone())?;
+        woab::route_signal(&entry, "activate", "entry_activated", addr.clone())?;
+        simulate::type_text(&entry, "hi!");
+        simulate::press_enter(&entry);
+        woab::wait_for!(*output.borrow() == ["entry_changed", "entry_changed", "entry_changed", "entry_activated"])?;
+        assert_eq!(entry.text(), "hi!");
+        output.borrow_mut().clear();
+
+        let list_box = gtk4::ListBox::new();
+        for text in ["a", "b"] {
+            list_box.append(&gtk4::Label::new(Some(text)));
+        }
+        assert!(simulate::select_row(&list_box, 1));
+        assert_eq!(list_box.selected_row().map(|row| row.index()), Some(1));
+        assert!(!simulate::select_row(&list_box, 2));
+
+        let model = gtk4::SingleSelection::new(Some(gtk4::StringList::new(&["a", "b", "c"])));
+        let list_view = gtk4::ListView::new(Some(model.clone()), None::<gtk4::ListItemFactory>);
+        assert!(simulate::select_item(&list_view, 2));
+        assert_eq!(model.selected(), 2);
+        assert!(!simulate::select_item(
+            &gtk4::ListView::new(None::<gtk4::SelectionModel>, None::<gtk4::ListItemFactory>),
+            0
+        ));
+
+        // The application `woab::test::test_main` runs, so that its actions can be activated.
+        let app = gio::Application::default().unwrap().downcast::<gtk4::Application>().unwrap();
+        let action = gio::SimpleAction::new("do_it", None);
+        app.add_action(&action);
+        woab::route_action(&action, addr.clone())?;
+        simulate::activate_action(&app, "app.do_it", None)?;
+        woab::wait_for!(*output.borrow() == ["do_it"])?;
+        assert!(simulate::activate_action(&app, "app.no_such_action", None).is_err());
+        assert!(simulate::activate_action(&app, "win.do_it", None).is_err());
+        Ok(())
+    })
+}
@@ -0,0 +1,65 @@
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use gtk4::prelude::*;
+use woab::test::snapshot::{compare_snapshot, render_widget, SnapshotError};
+
+#[test]
+fn test_snapshot() -> anyhow::Result<()> {
+    let color = Rc::new(Cell::new((1.0, 0.0, 0.0)));
+    let area = Rc::new(RefCell::new(None::<gtk4::DrawingArea>));
+    woab::test::test_main_loop_until::<anyhow::Error>(
+        || {
+            let drawing_area = gtk4::DrawingArea::builder().content_width(4).content_height(4).build();
+            drawing_area.set_draw_func({
+                let color = color.clone();
+                move |_, cr, width, height| {
+                    let (r, g, b) = color.get();
+                    cr.set_source_rgb(r, g, b);
+                    cr.rectangle(0.0, 0.0, width as f64, height as f64);
+                    cr.fill().unwrap();
+                }
+            });
+            let window = gtk4::Window::builder().child(&drawing_area).build();
+            window.present();
+            *area.borrow_mut() = Some(drawing_area);
+        },
+        || area.borrow().as_ref().is_some_and(|area| 0 < area.width()),
+        std::time::Duration::from_secs(5),
+    )?;
+    let area = area.take().unwrap();
+
+    assert!(matches!(
+        render_widget(&gtk4::Label::new(None)),
+        Err(SnapshotError::CannotRender(_))
+    ));
+
+    std::env::remove_var(woab::test::snapshot::UPDATE_SNAPSHOTS_ENV_VAR);
+    let dir = std::env::temp_dir().join(format!("woab-test-snapshot-{}", std::process::id()));
+    let baseline = dir.join("area.png");
+
+    // No baseline yet, so it is created.
+    compare_snapshot(&area, &baseline, 0)?;
+    assert!(baseline.exists());
+    compare_snapshot(&area, &baseline, 0)?;
+
+    color.set((0.0, 0.0, 1.0));
+    let Err(SnapshotError::Mismatch {
+        actual,
+        diff,
+        differing_pixels,
+        ..
+    }) = compare_snapshot(&area, &baseline, 0)
+    else {
+        panic!("The changed color should not match the baseline");
+    };
+    assert_eq!(differing_pixels, (area.width() * area.height()) as usize);
+    assert!(actual.exists());
+    assert!(diff.exists());
+
+    // Every channel differs by at most 255.
+    compare_snapshot(&area, &baseline, 255)?;
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use core::time::Duration;
+
+use woab::status_bar::{ClearStatusContext, PopStatus, PushStatus, StatusBar};
+
+#[test]
+fn test_status_bar() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let label = gtk4::Label::new(None);
+        let revealer = gtk4::Revealer::new();
+        StatusBar::new(&label).revealer(&revealer).start_global();
+        let status_bar = StatusBar::global().unwrap();
+        assert!(!revealer.reveals_child());
+
+        let ready = status_bar.send(PushStatus::new("Ready")).await?;
+        assert_eq!(label.text(), "Ready");
+        assert!(revealer.reveals_child());
+
+        status_bar
+            .send(PushStatus::new("Error!").priority(1).expire_after(Duration::from_millis(10)))
+            .await?;
+        status_bar.send(PushStatus::new("Saving 1").context("save")).await?;
+        // Lower priority than the error
+        assert_eq!(label.text(), "Error!");
+        woab::wait_for!(label.text() == "Saving 1")?;
+
+        // Replaces the message of the same context
+        status_bar.send(PushStatus::new("Saving 2").context("save")).await?;
+        assert_eq!(label.text(), "Saving 2");
+        status_bar.send(ClearStatusContext("save".to_owned())).await?;
+        assert_eq!(label.text(), "Ready");
+
+        assert!(status_bar.send(PopStatus(ready)).await?);
+        assert!(!status_bar.send(PopStatus(ready)).await?);
+        assert_eq!(label.text(), "");
+        assert!(!revealer.reveals_child());
+        Ok(())
+    })
+}
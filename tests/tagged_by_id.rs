@@ -0,0 +1,62 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use actix::prelude::*;
+use gtk4::prelude::*;
+
+struct TestActor {
+    output: Rc<RefCell<Vec<String>>>,
+}
+
+impl actix::Actor for TestActor {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<woab::Signal<String>> for TestActor {
+    type Result = woab::SignalResult;
+
+    fn handle(&mut self, msg: woab::Signal<String>, _ctx: &mut Self::Context) -> Self::Result {
+        Ok(match msg.name() {
+            "button_clicked" => {
+                self.output.borrow_mut().push(msg.tag().clone());
+                None
+            }
+            _ => msg.cant_handle()?,
+        })
+    }
+}
+
+#[test]
+fn test_tagged_by_id() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let factory = woab::BuilderFactory::from(
+            r#"
+            <interface>
+              <object class="GtkBox" id="box">
+                <child>
+                  <object class="GtkButton" id="first">
+                    <signal name="clicked" handler="button_clicked"/>
+                  </object>
+                </child>
+                <child>
+                  <object class="GtkButton" id="second">
+                    <signal name="clicked" handler="button_clicked"/>
+                  </object>
+                </child>
+              </object>
+            </interface>
+            "#
+            .to_owned(),
+        );
+        let output = Rc::new(RefCell::new(Vec::<String>::new()));
+        let bld = factory.instantiate_route_tagged_by_id(TestActor { output: output.clone() }.start());
+        let first: gtk4::Button = bld.get_object("first")?;
+        let second: gtk4::Button = bld.get_object("second")?;
+
+        second.emit_clicked();
+        woab::wait_for!(*output.borrow() == ["second"])?;
+        first.emit_clicked();
+        woab::wait_for!(*output.borrow() == ["second", "first"])?;
+        Ok(())
+    })
+}
@@ -0,0 +1,43 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use actix::prelude::*;
+use woab::test::clock;
+
+struct DelayedActor {
+    output: Rc<RefCell<Vec<&'static str>>>,
+}
+
+impl actix::Actor for DelayedActor {
+    type Context = actix::Context<Self>;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        ctx.run_later(Duration::from_millis(300), |actor, _ctx| {
+            actor.output.borrow_mut().push("fired");
+        });
+    }
+}
+
+#[test]
+fn test_clock() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        assert!(!clock::is_paused());
+        clock::pause();
+        assert!(clock::is_paused());
+
+        let output = Rc::new(RefCell::new(Vec::new()));
+        DelayedActor { output: output.clone() }.start();
+        // Let the actor start and set up its timer.
+        clock::advance(Duration::ZERO).await;
+
+        clock::advance(Duration::from_millis(299)).await;
+        assert!(output.borrow().is_empty());
+        clock::advance(Duration::from_millis(1)).await;
+        assert_eq!(*output.borrow(), ["fired"]);
+
+        clock::resume();
+        assert!(!clock::is_paused());
+        Ok(())
+    })
+}
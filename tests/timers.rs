@@ -0,0 +1,62 @@
+use core::time::Duration;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use actix::prelude::*;
+
+struct TestActor {
+    ticks: Rc<RefCell<Vec<&'static str>>>,
+    timers: Vec<woab::Timer>,
+}
+
+impl actix::Actor for TestActor {
+    type Context = actix::Context<Self>;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        self.timers
+            .push(woab::interval(Duration::from_millis(10), ctx.address(), || Tick("interval")));
+        woab::timeout_once(Duration::from_millis(20), ctx.address(), || Tick("once")).detach();
+        // Dropped immediately, so it should never fire.
+        let _ = woab::timeout_once(Duration::from_millis(1), ctx.address(), || Tick("cancelled"));
+    }
+}
+
+struct Tick(&'static str);
+
+impl actix::Message for Tick {
+    type Result = ();
+}
+
+impl actix::Handler<Tick> for TestActor {
+    type Result = ();
+
+    fn handle(&mut self, msg: Tick, _ctx: &mut Self::Context) -> Self::Result {
+        self.ticks.borrow_mut().push(msg.0);
+        if 3 <= self.ticks.borrow().iter().filter(|tick| **tick == "interval").count() {
+            self.timers.clear();
+        }
+    }
+}
+
+#[test]
+fn test_timers() -> anyhow::Result<()> {
+    let ticks = Rc::new(RefCell::new(Vec::new()));
+    woab::test::test_main_loop_until::<anyhow::Error>(
+        || {
+            TestActor {
+                ticks: ticks.clone(),
+                timers: Vec::new(),
+            }
+            .start();
+        },
+        || {
+            let ticks = ticks.borrow();
+            ticks.contains(&"once") && 3 <= ticks.iter().filter(|tick| **tick == "interval").count()
+        },
+        std::time::Duration::from_secs(1),
+    )?;
+    let ticks = ticks.borrow();
+    assert!(!ticks.contains(&"cancelled"));
+    assert_eq!(ticks.iter().filter(|tick| **tick == "once").count(), 1);
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[test]
+fn test_ui_batcher() -> anyhow::Result<()> {
+    let state = RefCell::new(None::<(gtk4::Label, woab::UiBatcher)>);
+    let log = Rc::new(RefCell::new(Vec::new()));
+    woab::test::test_main_loop_until::<anyhow::Error>(
+        || {
+            let label = gtk4::Label::new(None);
+            let batcher = woab::UiBatcher::new(&label);
+            for i in 1..=3 {
+                batcher.set_property(&label, "label", i.to_string()).unwrap();
+            }
+            for i in 1..=2 {
+                let log = log.clone();
+                batcher.run_keyed("keyed", move || log.borrow_mut().push(format!("keyed {}", i)));
+            }
+            for i in 1..=2 {
+                let log = log.clone();
+                batcher.run(move || log.borrow_mut().push(format!("unkeyed {}", i)));
+            }
+            // Not applied until GTK gets to process the batch.
+            assert_eq!(label.text(), "");
+            assert!(log.borrow().is_empty());
+            *state.borrow_mut() = Some((label, batcher));
+        },
+        || state.borrow().as_ref().is_some_and(|(label, _)| label.text() == "3"),
+        std::time::Duration::from_secs(1),
+    )?;
+    let (label, batcher) = state.take().unwrap();
+    // Only the last update of each key is applied, in the order the key was first submitted.
+    assert_eq!(*log.borrow(), ["keyed 2", "unkeyed 1", "unkeyed 2"]);
+
+    assert!(matches!(
+        batcher.set_property(&label, "no-such-property", "value"),
+        Err(woab::Error::NoSuchProperty { .. })
+    ));
+    assert!(matches!(
+        batcher.set_property(&label, "label", 4),
+        Err(woab::Error::IncorrectPropertyType { .. })
+    ));
+
+    batcher.set_property(&label, "label", "flushed")?;
+    assert_eq!(label.text(), "3");
+    batcher.flush();
+    assert_eq!(label.text(), "flushed");
+    Ok(())
+}
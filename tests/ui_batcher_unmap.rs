@@ -0,0 +1,40 @@
+use std::cell::{Cell, RefCell};
+
+use gtk4::prelude::*;
+
+#[test]
+fn test_ui_batcher_unmap() -> anyhow::Result<()> {
+    let state = RefCell::new(None::<(gtk4::Window, gtk4::Label, woab::UiBatcher)>);
+    let hidden = Cell::new(false);
+    woab::test::test_main_loop_until::<anyhow::Error>(
+        || {
+            let label = gtk4::Label::new(None);
+            let window = gtk4::Window::builder().child(&label).build();
+            window.present();
+            let batcher = woab::UiBatcher::new(&label);
+            *state.borrow_mut() = Some((window, label, batcher));
+        },
+        || {
+            let state = state.borrow();
+            let Some((window, label, batcher)) = state.as_ref() else {
+                return false;
+            };
+            if !hidden.get() {
+                if !label.is_mapped() {
+                    return false;
+                }
+                batcher.set_property(label, "label", "before unmap").unwrap();
+                assert_eq!(label.text(), "");
+                // The frame the update was waiting for will never come.
+                window.set_visible(false);
+                assert_eq!(label.text(), "before unmap");
+                hidden.set(true);
+                // Updates submitted after the unmap must not get stuck.
+                batcher.set_property(label, "label", "after unmap").unwrap();
+                return false;
+            }
+            label.text() == "after unmap"
+        },
+        std::time::Duration::from_secs(5),
+    )
+}
@@ -0,0 +1,71 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use actix::prelude::*;
+use gtk4::prelude::*;
+
+struct TestActor {
+    output: Rc<RefCell<Vec<String>>>,
+}
+
+impl actix::Actor for TestActor {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<woab::Signal> for TestActor {
+    type Result = woab::SignalResult;
+
+    fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+        Ok(match msg.name() {
+            "handled" => {
+                self.output.borrow_mut().push(msg.name().to_owned());
+                None
+            }
+            _ => msg.cant_handle()?,
+        })
+    }
+}
+
+struct FallbackActor {
+    output: Rc<RefCell<Vec<String>>>,
+}
+
+impl actix::Actor for FallbackActor {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<woab::Signal> for FallbackActor {
+    type Result = woab::SignalResult;
+
+    fn handle(&mut self, msg: woab::Signal, _ctx: &mut Self::Context) -> Self::Result {
+        let woab::params!(_button: gtk4::Button) = msg.params()?;
+        self.output.borrow_mut().push(format!("fallback({})", msg.name()));
+        Ok(None)
+    }
+}
+
+#[test]
+fn test_unmatched_signal_policy() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let output = Rc::new(RefCell::new(Vec::<String>::new()));
+        let addr = TestActor { output: output.clone() }.start();
+
+        let handled_button = gtk4::Button::new();
+        woab::route_signal(&handled_button, "clicked", "handled", addr.clone())?;
+        let typo_button = gtk4::Button::new();
+        woab::route_signal(&typo_button, "clicked", "handeld", addr)?;
+
+        woab::set_unmatched_signal_policy(woab::UnmatchedSignalPolicy::Warn);
+        typo_button.emit_clicked();
+        handled_button.emit_clicked();
+        woab::wait_for!(*output.borrow() == ["handled"])?;
+
+        let fallback = FallbackActor { output: output.clone() }.start();
+        woab::set_unmatched_signal_policy(woab::UnmatchedSignalPolicy::Fallback(fallback.recipient()));
+        typo_button.emit_clicked();
+        woab::wait_for!(*output.borrow() == ["handled", "fallback(handeld)"])?;
+
+        woab::set_unmatched_signal_policy(woab::UnmatchedSignalPolicy::Panic);
+        Ok(())
+    })
+}
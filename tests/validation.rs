@@ -0,0 +1,87 @@
+use gtk4::prelude::*;
+
+fn not_forbidden(text: &String) -> Result<(), String> {
+    if text == "forbidden" {
+        Err("This text is forbidden".to_owned())
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(woab::WidgetsFromBuilder, woab::PropSync, woab::Validate)]
+struct TestWidgets {
+    #[prop_sync(set, get)]
+    #[validate(required, regex = "^[a-z]+$", custom = not_forbidden)]
+    text_entry: gtk4::Entry,
+    #[prop_sync("value" as f64, set, get)]
+    #[validate(range = 1.0..=10.0)]
+    spin_button: gtk4::SpinButton,
+    #[prop_sync(set, get)]
+    #[validate(required)]
+    check_button: gtk4::CheckButton,
+    // No rules, but custom validators can still report errors for it.
+    label: gtk4::Label,
+}
+
+#[test]
+fn test_validation() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let factory = woab::BuilderFactory::from(std::fs::read_to_string("tests/various_widgets.ui")?);
+        let widgets: TestWidgets = factory.instantiate_without_routing_signals().widgets()?;
+        widgets.text_entry.set_tooltip_text(Some("original tooltip"));
+
+        let mut form = woab::Form::new(widgets).with_validator(|widgets| {
+            if widgets.get_props().spin_button == 7.0 {
+                vec![
+                    woab::validation::FieldError::new("spin_button", "Not seven"),
+                    woab::validation::FieldError::new("label", "Seven is not allowed"),
+                ]
+            } else {
+                vec![]
+            }
+        });
+
+        form.widgets().set_props(&TestWidgetsPropSetter {
+            text_entry: "",
+            spin_button: 7.0,
+            check_button: false,
+        });
+        assert!(!form.validate());
+        assert!(!form.is_valid());
+        let mut failed_fields = form.errors().iter().map(|error| error.field.as_str()).collect::<Vec<_>>();
+        failed_fields.sort();
+        assert_eq!(
+            failed_fields,
+            ["check_button", "label", "spin_button", "text_entry", "text_entry"]
+        );
+        assert!(form.widgets().label.has_css_class("error"));
+        assert!(form.widgets().text_entry.has_css_class("error"));
+        assert!(form.widgets().check_button.has_css_class("error"));
+        assert_eq!(form.widgets().spin_button.tooltip_text().as_deref(), Some("Not seven"),);
+
+        form.widgets().set_props(&TestWidgetsPropSetter {
+            text_entry: "forbidden",
+            spin_button: 2.0,
+            check_button: true,
+        });
+        assert!(!form.validate());
+        assert_eq!(
+            form.errors(),
+            [woab::validation::FieldError::new("text_entry", "This text is forbidden")]
+        );
+        assert!(!form.widgets().check_button.has_css_class("error"));
+        assert!(!form.widgets().spin_button.has_css_class("error"));
+        assert!(!form.widgets().label.has_css_class("error"));
+
+        form.widgets().set_props(&TestWidgetsPropSetter {
+            text_entry: "allowed",
+            spin_button: 2.0,
+            check_button: true,
+        });
+        assert!(form.validate());
+        assert!(form.errors().is_empty());
+        assert!(!form.widgets().text_entry.has_css_class("error"));
+        assert_eq!(form.widgets().text_entry.tooltip_text().as_deref(), Some("original tooltip"),);
+        Ok(())
+    })
+}
@@ -0,0 +1,41 @@
+use gtk4::prelude::*;
+use woab::View;
+
+#[derive(woab::WidgetsFromBuilder)]
+struct PageWidgets {
+    #[widget(root)]
+    page: gtk4::Box,
+    title: gtk4::Label,
+}
+
+fn add_to_stack(stack: &gtk4::Stack, view: &impl View) {
+    stack.add_child(view.root());
+}
+
+#[test]
+fn test_view() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let factory = woab::BuilderFactory::from(
+            r#"
+            <interface>
+              <object class="GtkBox" id="page">
+                <child>
+                  <object class="GtkLabel" id="title">
+                    <property name="label">Title</property>
+                  </object>
+                </child>
+              </object>
+            </interface>
+            "#
+            .to_owned(),
+        );
+        let widgets: PageWidgets = factory.instantiate_without_routing_signals().widgets()?;
+        assert_eq!(widgets.title.label(), "Title");
+
+        let stack = gtk4::Stack::new();
+        add_to_stack(&stack, &widgets);
+        assert_eq!(widgets.page.parent(), Some(stack.clone().upcast()));
+        assert_eq!(widgets.root_widget(), widgets.page.clone().upcast::<gtk4::Widget>());
+        Ok(())
+    })
+}
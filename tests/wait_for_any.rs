@@ -0,0 +1,26 @@
+use gio::prelude::*;
+
+#[test]
+fn test_wait_for_any() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let action1 = gio::SimpleAction::new("action1", None);
+        let action2 = gio::SimpleAction::new("action2", Some(glib::VariantTy::INT32));
+
+        // The spawned task will only run once `wait_for_any` connects the signals and waits.
+        actix::spawn({
+            let action2 = action2.clone();
+            async move {
+                action2.activate(Some(&15.to_variant()));
+            }
+        });
+        let signal = woab::wait_for_any(&[(action1.upcast_ref(), "activate"), (action2.upcast_ref(), "activate")]).await?;
+        assert_eq!(*signal.tag(), 1);
+        assert_eq!(signal.name(), "activate");
+        assert_eq!(signal.action_param::<i32>()?, 15);
+
+        // Both signals should be disconnected now.
+        action1.activate(None);
+        action2.activate(Some(&16.to_variant()));
+        Ok(())
+    })
+}
@@ -0,0 +1,57 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use actix::prelude::*;
+
+struct CountingActor {
+    counter: Rc<Cell<usize>>,
+}
+
+impl actix::Actor for CountingActor {
+    type Context = actix::Context<Self>;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        ctx.run_interval(Duration::from_millis(1), |actor, _ctx| {
+            actor.counter.set(actor.counter.get() + 1);
+        });
+    }
+}
+
+#[test]
+fn test_wait_until() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let counter = Rc::new(Cell::new(0));
+        CountingActor {
+            counter: counter.clone(),
+        }
+        .start();
+
+        let reached = woab::test::wait_until(
+            || {
+                let count = counter.get();
+                if 3 <= count {
+                    Ok(count)
+                } else {
+                    Err(count)
+                }
+            },
+            Duration::from_secs(1),
+        )
+        .await?;
+        assert!(3 <= reached);
+
+        let err = woab::test::wait_until(|| Err::<(), _>(("never", 1)), Duration::from_millis(10))
+            .await
+            .unwrap_err();
+        assert!(Duration::from_millis(10) <= err.waited);
+        assert_eq!(err.condition, None);
+        assert_eq!(err.last_state.as_deref(), Some(r#"("never", 1)"#));
+        assert!(err.to_string().ends_with(r#"(last state: ("never", 1))"#));
+
+        let err = woab::wait_for!(counter.get() == usize::MAX, Duration::from_millis(10)).unwrap_err();
+        assert_eq!(err.condition, Some("counter.get() == usize::MAX"));
+        assert_eq!(err.last_state, None);
+        Ok(())
+    })
+}
@@ -0,0 +1,34 @@
+use gio::prelude::*;
+
+#[test]
+fn test_wake_cancellation() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let action = gio::SimpleAction::new("action", None);
+        let cancellable = gio::Cancellable::new();
+        actix::spawn({
+            let cancellable = cancellable.clone();
+            async move {
+                cancellable.cancel();
+            }
+        });
+        let result = woab::wake_from_signal_cancellable(&action, &cancellable, |tx| {
+            action.connect_activate(move |_, _| {
+                let _ = tx.try_send(());
+            })
+        })
+        .await;
+        assert!(matches!(result, Err(woab::Error::Cancelled)));
+
+        // Already cancelled
+        let result = woab::wake_from_cancellable(&cancellable, |_tx: tokio::sync::mpsc::Sender<()>| {}).await;
+        assert!(matches!(result, Err(woab::Error::Cancelled)));
+
+        let cancellable = gio::Cancellable::new();
+        let result = woab::wake_from_cancellable(&cancellable, |tx| {
+            let _ = tx.try_send(17);
+        })
+        .await?;
+        assert_eq!(result, 17);
+        Ok(())
+    })
+}
@@ -3,12 +3,9 @@ use std::rc::Rc;
 
 use gio::prelude::*;
 
-#[macro_use]
-mod util;
-
 #[test]
 fn test_waking() -> anyhow::Result<()> {
-    util::test_main(async {
+    woab::test::test_main(async {
         let output = Rc::new(RefCell::new(Vec::<&'static str>::new()));
         let output = output.clone();
         let action1 = gio::SimpleAction::new("action1", None);
@@ -53,7 +50,7 @@ fn test_waking() -> anyhow::Result<()> {
         assert_eq!(action_response, 14);
         output.borrow_mut().push("after action2");
 
-        wait_for!(
+        woab::wait_for!(
             *output.borrow()
                 == [
                     "before action1",
@@ -0,0 +1,57 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use actix::prelude::*;
+
+struct WatchingActor {
+    _watch: woab::FileWatch,
+    events: Rc<RefCell<Vec<woab::FileEvent>>>,
+}
+
+impl actix::Actor for WatchingActor {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<woab::FileEvent> for WatchingActor {
+    type Result = ();
+
+    fn handle(&mut self, msg: woab::FileEvent, _ctx: &mut Self::Context) -> Self::Result {
+        self.events.borrow_mut().push(msg);
+    }
+}
+
+#[test]
+fn test_watch_file() -> anyhow::Result<()> {
+    let dir = std::env::temp_dir().join(format!("woab-test-watch-file-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join("watched.txt");
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let result: anyhow::Result<()> = woab::test::test_main_loop_until(
+        || {
+            WatchingActor::create(|ctx| WatchingActor {
+                _watch: woab::watch_file(&gio::File::for_path(&dir), ctx.address()).unwrap(),
+                events: events.clone(),
+            });
+            std::fs::write(&path, b"hello").unwrap();
+        },
+        || {
+            let created = events.borrow().contains(&woab::FileEvent::Created(path.clone()));
+            if created && path.exists() {
+                std::fs::remove_file(&path).unwrap();
+            }
+            events.borrow().contains(&woab::FileEvent::Deleted(path.clone()))
+        },
+        std::time::Duration::from_secs(5),
+    );
+    std::fs::remove_dir_all(&dir)?;
+    result?;
+    let events = events.borrow();
+    let created_at = events
+        .iter()
+        .position(|event| *event == woab::FileEvent::Created(path.clone()));
+    let deleted_at = events
+        .iter()
+        .position(|event| *event == woab::FileEvent::Deleted(path.clone()));
+    assert!(created_at.unwrap() < deleted_at.unwrap());
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use actix::prelude::*;
+use gio::prelude::*;
+
+struct SyncActor {
+    statuses: Rc<RefCell<Vec<woab::NetworkStatus>>>,
+}
+
+impl actix::Actor for SyncActor {
+    type Context = actix::Context<Self>;
+}
+
+impl actix::Handler<woab::NetworkStatus> for SyncActor {
+    type Result = ();
+
+    fn handle(&mut self, msg: woab::NetworkStatus, _ctx: &mut Self::Context) -> Self::Result {
+        self.statuses.borrow_mut().push(msg);
+    }
+}
+
+#[test]
+fn test_watch_network() -> anyhow::Result<()> {
+    woab::test::headless_main(async {
+        let statuses = Rc::new(RefCell::new(Vec::new()));
+        let addr = SyncActor {
+            statuses: statuses.clone(),
+        }
+        .start();
+        let monitor = gio::NetworkMonitor::default();
+        let current = woab::NetworkStatus::of(&monitor);
+
+        // The current state is sent immediately.
+        let watch = woab::watch_network(addr);
+        woab::wait_for!(*statuses.borrow() == [current])?;
+
+        // The network cannot really change during the test, so pretend it did.
+        monitor.emit_by_name::<()>("network-changed", &[&current.available]);
+        woab::wait_for!(*statuses.borrow() == [current, current])?;
+
+        drop(watch);
+        monitor.emit_by_name::<()>("network-changed", &[&current.available]);
+        actix::clock::sleep(core::time::Duration::from_millis(10)).await;
+        assert_eq!(statuses.borrow().len(), 2);
+        Ok(())
+    })
+}
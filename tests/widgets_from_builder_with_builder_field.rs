@@ -0,0 +1,26 @@
+use gtk4::prelude::*;
+
+#[derive(woab::WidgetsFromBuilder)]
+pub struct WidgetsWithBuilder {
+    text1: gtk4::Entry,
+    #[widget(builder)]
+    builder: gtk4::Builder,
+    #[widget(builder)]
+    builder_widgets: woab::BuilderWidgets,
+}
+
+#[test]
+fn test_widgets_from_builder_with_builder_field() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let factory = woab::BuilderFactory::from(include_str!("four_texts.ui").to_owned());
+        let widgets: WidgetsWithBuilder = factory.instantiate_without_routing_signals().widgets()?;
+
+        widgets.text1.set_text("Text 1");
+        let text1: gtk4::Entry = widgets.builder.object("text1").unwrap();
+        assert_eq!(text1.text(), "Text 1");
+        let text2: gtk4::Entry = widgets.builder_widgets.get_object("text2")?;
+        assert!(text2.text().is_empty());
+
+        Ok(())
+    })
+}
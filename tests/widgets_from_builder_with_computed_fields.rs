@@ -0,0 +1,33 @@
+use gtk4::prelude::*;
+
+#[derive(woab::WidgetsFromBuilder)]
+pub struct FlatWidgets {
+    text1: gtk4::Entry,
+    text2: gtk4::Entry,
+}
+
+pub struct EntryText(String);
+
+#[derive(woab::WidgetsFromBuilder)]
+pub struct ComputedWidgets {
+    #[widget(compute = r#"EntryText(builder.object::<gtk4::Entry>("text1").unwrap().text().into())"#)]
+    text1: EntryText,
+    #[widget(compute = r#"woab::BuilderWidgets::from(builder.clone()).get_object("text2")?"#)]
+    second: gtk4::Entry,
+}
+
+#[test]
+fn test_widgets_from_builder_with_computed_fields() -> anyhow::Result<()> {
+    woab::test::test_main(async {
+        let factory = woab::BuilderFactory::from(include_str!("four_texts.ui").to_owned());
+        let bld = factory.instantiate_without_routing_signals();
+        let flat_widgets: FlatWidgets = bld.widgets()?;
+        flat_widgets.text1.set_text("Text 1");
+
+        let computed_widgets: ComputedWidgets = bld.widgets()?;
+        assert_eq!(computed_widgets.text1.0, "Text 1");
+        assert_eq!(computed_widgets.second, flat_widgets.text2);
+
+        Ok(())
+    })
+}